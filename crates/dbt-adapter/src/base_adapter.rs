@@ -36,6 +36,14 @@ pub fn backend_of(adapter_type: AdapterType) -> Backend {
         AdapterType::Databricks => Backend::Databricks,
         AdapterType::Redshift => Backend::Redshift,
         AdapterType::Salesforce => Backend::Salesforce,
+        AdapterType::DuckDB => Backend::DuckDB,
+        AdapterType::Trino => Backend::Trino,
+        // The driver library is only known from the profile, this stands in
+        // for it wherever only the SQL conventions of the backend matter
+        AdapterType::Generic => Backend::Generic {
+            library_name: "generic",
+            entrypoint: None,
+        },
     }
 }
 
@@ -76,6 +84,19 @@ pub trait AdapterTyping {
         self.engine().quoting()
     }
 
+    /// The identifier quote character declared in the profile, which only the
+    /// dialect of the generic adapter does
+    fn quote_character(&self) -> Option<char> {
+        match self.adapter_type() {
+            AdapterType::Generic if !self.engine().is_mock() => {
+                crate::generic::dialect(self.engine().get_config())
+                    .ok()
+                    .map(|dialect| dialect.quote_character())
+            }
+            _ => None,
+        }
+    }
+
     /// Quote a component of a relation
     fn quote_component(
         &self,
@@ -1036,6 +1057,10 @@ pub trait BaseAdapter: fmt::Display + fmt::Debug + AdapterTyping + Send + Sync {
     /// clean_sql
     fn clean_sql(&self, _args: &[Value]) -> Result<Value, MinijinjaError>;
 
+    /// A query declared by the dialect of the generic profile, with its
+    /// `{name}` placeholders substituted by the given parameters
+    fn dialect_query(&self, _args: &[Value]) -> Result<Value, MinijinjaError>;
+
     /// get_relation_config
     fn get_relation_config(&self, _state: &State, _args: &[Value])
     -> Result<Value, MinijinjaError>;
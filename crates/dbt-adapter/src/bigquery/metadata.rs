@@ -16,7 +16,7 @@ use dbt_schemas::schemas::legacy_catalog::{
 };
 use dbt_schemas::schemas::relations::base::{BaseRelation, RelationPattern};
 use dbt_xdbc::query_ctx::ExecutionPhase;
-use dbt_xdbc::{Connection, MapReduce, QueryCtx};
+use dbt_xdbc::{Backend, Connection, MapReduce, QueryCtx, QueryParams};
 
 use std::collections::btree_map::Entry;
 use std::collections::{BTreeMap, BTreeSet, HashMap};
@@ -88,10 +88,11 @@ fn generate_system_table_fqn(
     }
 }
 
+/// Groups the table ids of the relations by `<project>.<dataset>`
 pub fn build_relation_clauses_bigquery(
     relations: &[Arc<dyn BaseRelation>],
-) -> AdapterResult<(WhereClausesByDb, RelationsByDb)> {
-    let mut where_by_db = BTreeMap::<String, Vec<String>>::new();
+) -> AdapterResult<(BTreeMap<String, Vec<String>>, RelationsByDb)> {
+    let mut table_ids_by_db = BTreeMap::<String, Vec<String>>::new();
     let mut rels_by_db = BTreeMap::<String, Vec<Arc<dyn BaseRelation>>>::new();
 
     for rel in relations {
@@ -110,15 +111,63 @@ pub fn build_relation_clauses_bigquery(
         let table = table_raw.trim_matches('`');
         let db_key = format!("{project}.{dataset}");
 
-        where_by_db
+        table_ids_by_db
             .entry(db_key.clone())
             .or_default()
-            .push(format!("table_id = '{table}'"));
+            .push(table.to_string());
 
         rels_by_db.entry(db_key).or_default().push(rel.clone());
     }
 
-    Ok((where_by_db, rels_by_db))
+    Ok((table_ids_by_db, rels_by_db))
+}
+
+/// Query the last modified times of the given tables of `database` from BigQuery's
+/// __TABLES__ metadata table, binding the table names as parameters
+fn freshness_query(
+    backend: Backend,
+    database: &str,
+    table_ids: &[String],
+    identifiers: &[String],
+) -> (String, QueryParams) {
+    let mut params = QueryParams::new(backend);
+    let or_block = table_ids
+        .iter()
+        .map(|table_id| format!("table_id = {}", params.push(table_id.as_str())))
+        .collect::<Vec<_>>()
+        .join(" OR ");
+
+    let table_list = identifiers
+        .iter()
+        .map(|identifier| params.push(identifier.as_str()))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let table_filter = format!("table_id IN ({table_list})");
+
+    let joined_where_clauses = if or_block.is_empty() {
+        table_filter
+    } else {
+        format!("({or_block}) AND {table_filter}")
+    };
+
+    // __TABLES__ is officially deprecated in favor of TABLES and
+    // PARTITIONS, but neither has last_modified_time. Bigquery's API
+    // has get_table. But for customers with larger source freshness
+    // workloads fanning out over all individual relations can trigger
+    // API limiting errors or run up larger bills.
+    //
+    // reference: https://discuss.google.dev/t/information-schema-tables-monitoring-last-modified-time/125698
+    let sql = format!(
+        "SELECT
+                 dataset_id AS table_schema,
+                 table_id AS table_name,
+                 TIMESTAMP_MILLIS(last_modified_time) AS last_altered,
+                 (type = 2) AS is_view
+             FROM {database}.__TABLES__
+             WHERE {joined_where_clauses}"
+    );
+    (sql, params)
 }
 
 fn make_map_f(
@@ -128,46 +177,23 @@ fn make_map_f(
 + Send
 + Sync
 + 'static {
-    move |conn: &mut dyn Connection, database_and_where_clauses: &(String, Vec<String>)| {
-        let (database, where_clauses) = &database_and_where_clauses;
-        // Query to get last modified times from BigQuery's __TABLES__ metadata table
-        let table_list = relations
-            .iter()
-            .map(|relation| format!("'{}'", relation.identifier()))
-            .collect::<Vec<_>>()
-            .join(", ");
-
-        let or_block = where_clauses.join(" OR ");
-
-        let table_filter = format!("table_id IN ({})", table_list);
-
-        let joined_where_clauses = if or_block.is_empty() {
-            table_filter
-        } else {
-            format!("({}) AND {}", or_block, table_filter)
-        };
-
-        // __TABLES__ is officially deprecated in favor of TABLES and
-        // PARTITIONS, but neither has last_modified_time. Bigquery's API
-        // has get_table. But for customers with larger source freshness
-        // workloads fanning out over all individual relations can trigger
-        // API limiting errors or run up larger bills.
-        //
-        // reference: https://discuss.google.dev/t/information-schema-tables-monitoring-last-modified-time/125698
-        let sql = format!(
-            "SELECT
-                 dataset_id AS table_schema,
-                 table_id AS table_name,
-                 TIMESTAMP_MILLIS(last_modified_time) AS last_altered,
-                 (type = 2) AS is_view
-             FROM {db}.__TABLES__
-             WHERE {joined_where_clauses}",
-            db = database,
-            joined_where_clauses = joined_where_clauses,
+    let identifiers = relations
+        .iter()
+        .map(|relation| relation.identifier().to_string())
+        .collect::<Vec<_>>();
+    move |conn: &mut dyn Connection, database_and_table_ids: &(String, Vec<String>)| {
+        let (database, table_ids) = &database_and_table_ids;
+        let (sql, params) = freshness_query(
+            adapter.engine().backend(),
+            database,
+            table_ids,
+            &identifiers,
         );
 
-        let ctx = QueryCtx::default().with_desc("Extracting freshness from information schema");
-        let (_, agate_table) = adapter.query(&ctx, &mut *conn, &sql, None)?;
+        let ctx = QueryCtx::default()
+            .with_desc("Extracting freshness from information schema")
+            .with_introspection();
+        let (_, agate_table) = adapter.query_with_params(&ctx, &mut *conn, &sql, &params)?;
         let batch = agate_table.original_record_batch();
         Ok(batch)
     }
@@ -531,7 +557,9 @@ impl MetadataAdapter for BigqueryAdapter {
                     generate_system_table_fqn(&project, &table, user_preferred_region.as_deref());
                 let sql = format!("SELECT * FROM {table_fqn} LIMIT 0");
 
-                let ctx = QueryCtx::default().with_desc("Get table schema");
+                let ctx = QueryCtx::default()
+                    .with_desc("Get table schema")
+                    .with_introspection();
                 let (_, agate_table) = adapter.query(&ctx, &mut *conn, &sql, None)?;
                 let batch = agate_table.original_record_batch();
 
@@ -593,8 +621,8 @@ impl MetadataAdapter for BigqueryAdapter {
         &self,
         relations: &[Arc<dyn BaseRelation>],
     ) -> AsyncAdapterResult<'_, BTreeMap<String, MetadataFreshness>> {
-        // Build the where clause for all relations grouped by databases
-        let (where_clauses_by_database, relations_by_database) =
+        // Group the table ids of all relations by databases
+        let (table_ids_by_database, relations_by_database) =
             match build_relation_clauses_bigquery(relations) {
                 Ok(result) => result,
                 Err(e) => {
@@ -616,7 +644,7 @@ impl MetadataAdapter for BigqueryAdapter {
         let map_f = make_map_f(relations.to_vec(), adapter);
 
         let reduce_f = move |acc: &mut Acc,
-                             database_and_where_clauses: (String, Vec<String>),
+                             database_and_table_ids: (String, Vec<String>),
                              batch_res: AdapterResult<Arc<RecordBatch>>|
               -> Result<(), Cancellable<AdapterError>> {
             let batch = batch_res?;
@@ -625,7 +653,7 @@ impl MetadataAdapter for BigqueryAdapter {
             let timestamps =
                 get_column_values::<TimestampMicrosecondArray>(&batch, "last_altered")?;
             let is_views = get_column_values::<BooleanArray>(&batch, "is_view")?;
-            let (database, _where_clauses) = &database_and_where_clauses;
+            let (database, _table_ids) = &database_and_table_ids;
             for i in 0..batch.num_rows() {
                 let schema = schemas.value(i);
                 let table = tables.value(i);
@@ -649,7 +677,7 @@ impl MetadataAdapter for BigqueryAdapter {
             Box::new(reduce_f),
             MAX_CONNECTIONS,
         );
-        let keys = where_clauses_by_database.into_iter().collect::<Vec<_>>();
+        let keys = table_ids_by_database.into_iter().collect::<Vec<_>>();
         let token = self.cancellation_token();
         map_reduce.run(Arc::new(keys), token)
     }
@@ -681,7 +709,9 @@ impl MetadataAdapter for BigqueryAdapter {
               -> AdapterResult<Vec<Arc<dyn BaseRelation>>> {
             // Deviation from core: we cannot use `list_tables` as this is not supported from ADBC
             // Pagination is handled in the ADBC driver
-            let query_ctx = QueryCtx::default().with_desc("list_relations_in_parallel");
+            let query_ctx = QueryCtx::default()
+                .with_desc("list_relations_in_parallel")
+                .with_introspection();
             adapter.list_relations(&query_ctx, conn, db_schema)
         };
 
@@ -726,6 +756,8 @@ impl MetadataAdapter for BigqueryAdapter {
 
 #[cfg(test)]
 mod tests {
+    use dbt_xdbc::params::ParamValue;
+
     use super::*;
 
     #[test]
@@ -781,4 +813,25 @@ mod tests {
             "`region-eu`.INFORMATION_SCHEMA.JOBS"
         );
     }
+
+    #[test]
+    fn test_freshness_query_binds_table_names() {
+        let hostile = "t' OR TRUE --".to_string();
+        let (sql, params) = freshness_query(
+            Backend::BigQuery,
+            "`my-project`.`my_dataset`",
+            std::slice::from_ref(&hostile),
+            &["orders".to_string(), hostile.clone()],
+        );
+        assert!(!sql.contains("OR TRUE"));
+        assert!(sql.contains("WHERE (table_id = ?) AND table_id IN (?, ?)"));
+        assert_eq!(
+            params.values(),
+            &[
+                ParamValue::Utf8(hostile.clone()),
+                ParamValue::Utf8("orders".to_string()),
+                ParamValue::Utf8(hostile)
+            ]
+        );
+    }
 }
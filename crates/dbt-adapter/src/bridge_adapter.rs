@@ -53,9 +53,12 @@ use std::sync::{Arc, LazyLock};
 //
 // This implementation provides an efficient connection management strategy:
 // 1. Each thread maintains its own connection instance
-// 2. Connections are reused across multiple operations within the same thread
+// 2. Connections are reused across multiple operations of the same DAG node
 // 3. This approach ensures proper transaction management within a DAG node
 // 4. The ConnectionGuard wrapper ensures connections are returned to the thread-local
+// 5. When the node finishes, [release_tlocal_connection] drops the connection,
+//    which returns a pooled connection to the pool where it is validated and
+//    expired. A worker waiting for its next node then holds no pool slot.
 thread_local! {
     static CONNECTION: pri::TlsConnectionContainer = pri::TlsConnectionContainer::new();
 }
@@ -78,12 +81,15 @@ static DEFAULT_BASE_BEHAVIOR_FLAGS: LazyLock<[BehaviorFlag; 2]> = LazyLock::new(
 /// This ensures that for a single thread, a connection is reused across multiple operations
 pub struct ConnectionGuard<'a> {
     conn: Option<Box<dyn Connection>>,
+    /// The node the connection was borrowed for
+    node_id: Option<String>,
     _phantom: PhantomData<&'a ()>,
 }
 impl ConnectionGuard<'_> {
-    fn new(conn: Box<dyn Connection>) -> Self {
+    fn new(conn: Box<dyn Connection>, node_id: Option<String>) -> Self {
         Self {
             conn: Some(conn),
+            node_id,
             _phantom: PhantomData,
         }
     }
@@ -103,10 +109,19 @@ impl DerefMut for ConnectionGuard<'_> {
 impl Drop for ConnectionGuard<'_> {
     fn drop(&mut self) {
         let conn = self.conn.take();
-        CONNECTION.with(|c| c.replace(conn));
+        let node_id = self.node_id.take();
+        CONNECTION.with(|c| c.replace(conn.map(|conn| (conn, node_id))));
     }
 }
 
+/// Drop the connection of the current thread, if any.
+///
+/// Called when a node finishes, so that a pooled connection goes back to the
+/// pool instead of staying checked out by a thread that has no work.
+pub fn release_tlocal_connection() {
+    drop(CONNECTION.with(|c| c.take()));
+}
+
 /// Type bridge adapter
 ///
 /// This adapter converts untyped method calls (those that use Value)
@@ -157,6 +172,11 @@ impl BridgeAdapter {
 
     /// Borrow the current thread-local connection or create one if it's not set yet.
     ///
+    /// The thread-local connection is only reused for the node it was created
+    /// for. A connection of another node is dropped and a new one is created,
+    /// so pooled connections go back to the pool between nodes and are
+    /// validated again before their next use.
+    ///
     /// A guard is returned. When destroyed, the guard returns the connection to
     /// the thread-local variable. If another connection became the thread-local
     /// in the mean time, that connection is dropped and the return proceeds as
@@ -167,14 +187,14 @@ impl BridgeAdapter {
         node_id: Option<String>,
     ) -> Result<ConnectionGuard<'_>, MinijinjaError> {
         let _span = span!("BridgeAdapter::borrow_thread_local_connection");
-        let mut conn = CONNECTION.with(|c| c.take());
-        if conn.is_none() {
-            self.new_connection(state, node_id)
-                .map(|new_conn| conn.replace(new_conn))?;
-        } else if let Some(c) = conn.as_mut() {
-            c.update_node_id(node_id);
-        }
-        let guard = ConnectionGuard::new(conn.unwrap());
+        let conn = match CONNECTION.with(|c| c.take()) {
+            Some((conn, conn_node_id)) if conn_node_id == node_id => conn,
+            prev => {
+                drop(prev);
+                self.new_connection(state, node_id.clone())?
+            }
+        };
+        let guard = ConnectionGuard::new(conn, node_id);
         Ok(guard)
     }
 
@@ -639,6 +659,7 @@ impl BaseAdapter for BridgeAdapter {
                 Some(identifier.to_string()),
                 None,
                 self.typed_adapter().quoting(),
+                self.typed_adapter().quote_character(),
             )?;
 
             if let Some(cached_entry) = self.relation_cache.get_relation(&temp_relation) {
@@ -655,8 +676,9 @@ impl BaseAdapter for BridgeAdapter {
 
             let mut conn = self.borrow_tlocal_connection(Some(state), node_id_from_state(state))?;
             let db_schema = CatalogAndSchema::from(&temp_relation);
-            let query_ctx =
-                query_ctx_from_state(state)?.with_desc("get_relation > list_relations call");
+            let query_ctx = query_ctx_from_state(state)?
+                .with_desc("get_relation > list_relations call")
+                .with_introspection();
             let maybe_relations_list =
                 self.typed_adapter
                     .list_relations(&query_ctx, conn.as_mut(), &db_schema);
@@ -681,7 +703,9 @@ impl BaseAdapter for BridgeAdapter {
         // 1. A cache miss and we failed to execute list_relations
         // 2. The schema was not previously cached
         let mut conn = self.borrow_tlocal_connection(Some(state), node_id_from_state(state))?;
-        let query_ctx = query_ctx_from_state(state)?.with_desc("get_relation adapter call");
+        let query_ctx = query_ctx_from_state(state)?
+            .with_desc("get_relation adapter call")
+            .with_introspection();
         let relation = self.typed_adapter.get_relation(
             state,
             &query_ctx,
@@ -1202,8 +1226,9 @@ impl BaseAdapter for BridgeAdapter {
         let relation = parser.get::<Value>("schema_relation")?;
         let relation = downcast_value_to_dyn_base_relation(&relation)?;
 
-        let query_ctx =
-            query_ctx_from_state(state)?.with_desc("list_relations_without_caching adapter call");
+        let query_ctx = query_ctx_from_state(state)?
+            .with_desc("list_relations_without_caching adapter call")
+            .with_introspection();
         let mut conn = self.borrow_tlocal_connection(Some(state), node_id_from_state(state))?;
         let result = self.typed_adapter.list_relations(
             &query_ctx,
@@ -1522,6 +1547,27 @@ impl BaseAdapter for BridgeAdapter {
         Ok(Value::from(self.typed_adapter.clean_sql(&sql)?))
     }
 
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn dialect_query(&self, args: &[Value]) -> Result<Value, MinijinjaError> {
+        let mut parser = ArgParser::new(args, None);
+        check_num_args(current_function_name!(), &parser, 2, 2)?;
+
+        let name = parser.get::<String>("name")?;
+        let params = parser.get::<Value>("params")?;
+        let params = minijinja_value_to_typed_struct::<BTreeMap<String, Option<String>>>(params)
+            .map_err(|e| {
+                MinijinjaError::new(MinijinjaErrorKind::SerdeDeserializeError, e.to_string())
+            })?;
+        let params = params
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_deref().unwrap_or_default()))
+            .collect::<Vec<_>>();
+
+        Ok(Value::from(
+            self.typed_adapter.dialect_query(&name, &params)?,
+        ))
+    }
+
     #[tracing::instrument(skip(self), level = "trace")]
     fn use_warehouse(&self, warehouse: Option<String>, node_id: &str) -> FsResult<bool> {
         if warehouse.is_none() {
@@ -1636,14 +1682,17 @@ mod pri {
     /// The point of this struct is to avoid calling the `Drop` destructor on
     /// the wrapped [Connection] during process exit, which dead locks on
     /// Windows.
-    pub(super) struct TlsConnectionContainer(RefCell<Option<Box<dyn Connection>>>);
+    pub(super) struct TlsConnectionContainer(RefCell<Option<TlsConnection>>);
+
+    /// A connection and the node it was borrowed for
+    pub(super) type TlsConnection = (Box<dyn Connection>, Option<String>);
 
     impl TlsConnectionContainer {
         pub(super) fn new() -> Self {
             TlsConnectionContainer(RefCell::new(None))
         }
 
-        pub(super) fn replace(&self, conn: Option<Box<dyn Connection>>) {
+        pub(super) fn replace(&self, conn: Option<TlsConnection>) {
             let prev = self.take();
             *self.0.borrow_mut() = conn;
             if prev.is_some() {
@@ -1671,7 +1720,7 @@ mod pri {
             }
         }
 
-        pub(super) fn take(&self) -> Option<Box<dyn Connection>> {
+        pub(super) fn take(&self) -> Option<TlsConnection> {
             self.0.borrow_mut().take()
         }
     }
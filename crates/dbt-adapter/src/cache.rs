@@ -254,6 +254,7 @@ mod tests {
             Some("table1".to_string()),
             None,
             DEFAULT_RESOLVED_QUOTING,
+            None,
         )
         .unwrap();
 
@@ -264,6 +265,7 @@ mod tests {
             Some("table1".to_string()),
             None,
             DEFAULT_RESOLVED_QUOTING,
+            None,
         )
         .unwrap();
 
@@ -274,6 +276,7 @@ mod tests {
             Some("table1".to_string()),
             None,
             DEFAULT_RESOLVED_QUOTING,
+            None,
         )
         .unwrap();
 
@@ -284,6 +287,7 @@ mod tests {
             Some("table2".to_string()),
             None,
             DEFAULT_RESOLVED_QUOTING,
+            None,
         )
         .unwrap();
 
@@ -294,6 +298,7 @@ mod tests {
             Some("table1".to_string()),
             None,
             DEFAULT_RESOLVED_QUOTING,
+            None,
         )
         .unwrap();
 
@@ -343,6 +348,7 @@ mod tests {
             Some("MyTable".to_string()),
             None,
             DEFAULT_RESOLVED_QUOTING,
+            None,
         )
         .unwrap();
 
@@ -358,6 +364,7 @@ mod tests {
                 schema: false,
                 identifier: false,
             },
+            None,
         )
         .unwrap();
 
@@ -388,6 +395,7 @@ mod tests {
                 schema: false,
                 identifier: false,
             },
+            None,
         )
         .unwrap();
 
@@ -417,6 +425,7 @@ mod tests {
                         Some(format!("table_{schema_id}_{i}")),
                         None,
                         DEFAULT_RESOLVED_QUOTING,
+                        None,
                     )
                     .unwrap()
                 })
@@ -477,6 +486,7 @@ mod tests {
                                     Some(format!("renamed_table_{thread_id}_{i}")),
                                     None,
                                     DEFAULT_RESOLVED_QUOTING,
+                                    None,
                                 )
                                 .unwrap();
                                 cache.rename_relation(relation, new_relation);
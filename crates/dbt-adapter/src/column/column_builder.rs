@@ -27,7 +27,9 @@ impl ColumnBuilder {
             Bigquery => Ok(Self::build_bigquery(field, type_ops)),
             Databricks => Ok(Self::build_databricks(field, type_ops)),
             Redshift => Ok(Self::build_redshift(field, type_ops)),
-            Postgres | Salesforce => Ok(Self::build_postgres_like(field, type_ops)),
+            Postgres | Salesforce | DuckDB | Trino | Generic => {
+                Ok(Self::build_postgres_like(field, type_ops))
+            }
         }
     }
 
@@ -72,6 +74,30 @@ impl ColumnBuilder {
                 Databricks, name, dtype, char_size, None, // numeric_precision
                 None, // numeric_scale
             ),
+            DuckDB => Column::new(
+                DuckDB,
+                name,
+                dtype,
+                char_size,
+                numeric_precision,
+                numeric_scale,
+            ),
+            Trino => Column::new(
+                Trino,
+                name,
+                dtype,
+                char_size,
+                numeric_precision,
+                numeric_scale,
+            ),
+            Generic => Column::new(
+                Generic,
+                name,
+                dtype,
+                char_size,
+                numeric_precision,
+                numeric_scale,
+            ),
             Salesforce => todo!("Salesforce column creation not implemented yet"),
         }
     }
@@ -0,0 +1,161 @@
+//! Connection pool settings of a target.
+//!
+//! Every target gets its own [dbt_xdbc::pool::Pool], configured with the
+//! `connection_pool` mapping of the profile. `max_size` must be at least the
+//! number of `threads`, and `min_size` connections are opened up front:
+//!
+//! ```yaml
+//! connection_pool:
+//!   min_size: 2
+//!   max_size: 16
+//!   validation_query: select 1
+//!   idle_timeout: 300   # seconds
+//!   max_lifetime: 3600  # seconds
+//!   checkout_timeout: 60
+//!   reconnect: true
+//! ```
+
+use std::time::Duration;
+
+use dbt_serde_yaml::Value as YmlValue;
+use dbt_xdbc::pool::PoolConfig;
+
+use crate::config::AdapterConfig;
+use crate::errors::{AdapterError, AdapterErrorKind, AdapterResult};
+
+fn config_error(message: impl Into<String>) -> AdapterError {
+    AdapterError::new(AdapterErrorKind::Configuration, message)
+}
+
+fn parse_size(key: &str, value: &str) -> AdapterResult<usize> {
+    value.parse::<usize>().map_err(|_| {
+        config_error(format!(
+            "connection_pool.{key} must be a non-negative integer, got '{value}'"
+        ))
+    })
+}
+
+fn parse_seconds(key: &str, value: &str) -> AdapterResult<Duration> {
+    value
+        .parse::<f64>()
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .ok_or_else(|| {
+            config_error(format!(
+                "connection_pool.{key} must be a non-negative number of seconds, got '{value}'"
+            ))
+        })
+}
+
+/// Read the pool settings from the `connection_pool` mapping of a target.
+pub fn pool_config_from_config(config: &AdapterConfig) -> AdapterResult<PoolConfig> {
+    let mut pool = PoolConfig::default();
+    let settings = match config.get("connection_pool") {
+        None | Some(YmlValue::Null(_)) => return Ok(pool),
+        Some(YmlValue::Mapping(mapping, _)) => AdapterConfig::new(mapping.clone()),
+        Some(_) => return Err(config_error("connection_pool must be a mapping")),
+    };
+
+    if let Some(min_size) = settings.get_string("min_size") {
+        pool.min_size = parse_size("min_size", &min_size)?;
+    }
+    if let Some(max_size) = settings.get_string("max_size") {
+        pool.max_size = parse_size("max_size", &max_size)?;
+        if pool.max_size == 0 {
+            return Err(config_error("connection_pool.max_size must be at least 1"));
+        }
+    }
+    // Every thread keeps a connection checked out while it runs a node, so
+    // with fewer connections than threads some of them wait for a connection
+    if let Some(threads) = config.get_string("threads")
+        && let Ok(threads) = threads.parse::<usize>()
+        && pool.max_size < threads
+    {
+        return Err(config_error(format!(
+            "connection_pool.max_size ({}) is smaller than threads ({threads}); every thread needs its own connection",
+            pool.max_size
+        )));
+    }
+    if pool.min_size > pool.max_size {
+        return Err(config_error(format!(
+            "connection_pool.min_size ({}) is greater than connection_pool.max_size ({})",
+            pool.min_size, pool.max_size
+        )));
+    }
+    if let Some(query) = settings.get_string("validation_query") {
+        let query = query.trim();
+        pool.validation_query = (!query.is_empty()).then(|| query.to_string());
+    }
+    if let Some(timeout) = settings.get_string("idle_timeout") {
+        pool.idle_timeout = Some(parse_seconds("idle_timeout", &timeout)?);
+    }
+    if let Some(lifetime) = settings.get_string("max_lifetime") {
+        pool.max_lifetime = Some(parse_seconds("max_lifetime", &lifetime)?);
+    }
+    if let Some(timeout) = settings.get_string("checkout_timeout") {
+        pool.checkout_timeout = parse_seconds("checkout_timeout", &timeout)?;
+    }
+    if let Some(reconnect) = settings.get_string("reconnect") {
+        pool.reconnect = reconnect.parse::<bool>().map_err(|_| {
+            config_error(format!(
+                "connection_pool.reconnect must be true or false, got '{reconnect}'"
+            ))
+        })?;
+    }
+    Ok(pool)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(yaml: &str) -> AdapterConfig {
+        AdapterConfig::new(dbt_serde_yaml::from_str(yaml).unwrap())
+    }
+
+    #[test]
+    fn test_pool_config_from_config() {
+        assert_eq!(
+            pool_config_from_config(&AdapterConfig::default()).unwrap(),
+            PoolConfig::default()
+        );
+
+        let pool = pool_config_from_config(&config(
+            "
+connection_pool:
+  min_size: 2
+  max_size: 16
+  validation_query: ''
+  idle_timeout: 300
+  max_lifetime: 0.5
+  reconnect: false
+",
+        ))
+        .unwrap();
+        assert_eq!(pool.min_size, 2);
+        assert_eq!(pool.max_size, 16);
+        assert_eq!(pool.validation_query, None);
+        assert_eq!(pool.idle_timeout, Some(Duration::from_secs(300)));
+        assert_eq!(pool.max_lifetime, Some(Duration::from_millis(500)));
+        assert_eq!(
+            pool.checkout_timeout,
+            PoolConfig::default().checkout_timeout
+        );
+        assert!(!pool.reconnect);
+    }
+
+    #[test]
+    fn test_invalid_pool_config() {
+        for yaml in [
+            "connection_pool: 4",
+            "connection_pool: {max_size: 0}",
+            "connection_pool: {min_size: 4, max_size: 2}",
+            "{threads: 8, connection_pool: {max_size: 4}}",
+            "connection_pool: {idle_timeout: -1}",
+            "connection_pool: {reconnect: sometimes}",
+        ] {
+            let err = pool_config_from_config(&config(yaml)).unwrap_err();
+            assert_eq!(err.kind(), AdapterErrorKind::Configuration, "{yaml}");
+        }
+    }
+}
@@ -19,7 +19,7 @@ use dbt_schemas::schemas::legacy_catalog::{
 };
 use dbt_schemas::schemas::relations::base::{BaseRelation, RelationPattern};
 use dbt_xdbc::query_ctx::ExecutionPhase;
-use dbt_xdbc::{Connection, MapReduce, QueryCtx};
+use dbt_xdbc::{Connection, MapReduce, QueryCtx, QueryParams};
 
 use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::future;
@@ -253,7 +253,9 @@ impl MetadataAdapter for DatabricksAdapter {
                 format!("DESCRIBE TABLE EXTENDED {database}.{schema}.{identifier} AS JSON;")
             };
 
-            let ctx = QueryCtx::default().with_desc("Get table schema");
+            let ctx = QueryCtx::default()
+                .with_desc("Get table schema")
+                .with_introspection();
             let (_, table) = adapter.query(&ctx, conn, &sql, None)?;
             let batch = table.original_record_batch();
 
@@ -293,15 +295,14 @@ impl MetadataAdapter for DatabricksAdapter {
         &self,
         relations: &[Arc<dyn BaseRelation>],
     ) -> AsyncAdapterResult<'_, BTreeMap<String, MetadataFreshness>> {
-        // Build the where clause for all relations grouped by databases
-        let (where_clauses_by_database, relations_by_database) =
-            match build_relation_clauses(relations) {
-                Ok(result) => result,
-                Err(e) => {
-                    let future = async move { Err(Cancellable::Error(e)) };
-                    return Box::pin(future);
-                }
-            };
+        // Group the tables of all relations by databases
+        let (tables_by_database, relations_by_database) = match build_relation_clauses(relations) {
+            Ok(result) => result,
+            Err(e) => {
+                let future = async move { Err(Cancellable::Error(e)) };
+                return Box::pin(future);
+            }
+        };
 
         type Acc = BTreeMap<String, MetadataFreshness>;
 
@@ -314,9 +315,12 @@ impl MetadataAdapter for DatabricksAdapter {
 
         let adapter = self.clone();
         let map_f = move |conn: &'_ mut dyn Connection,
-                          database_and_where_clauses: &(String, Vec<String>)|
+                          database_and_tables: &(String, Vec<(String, String)>)|
               -> AdapterResult<Arc<RecordBatch>> {
-            let (database, where_clauses) = &database_and_where_clauses;
+            let (database, tables) = &database_and_tables;
+            let mut params = QueryParams::new(adapter.engine().backend());
+            let where_clause =
+                tables_where_clause(&mut params, tables, information_schema_table_clause);
             // Query to get last modified times
             let sql = format!(
                 "SELECT
@@ -324,20 +328,21 @@ impl MetadataAdapter for DatabricksAdapter {
                 table_name,
                 last_altered,
                 (table_type = 'VIEW' OR table_type = 'MATERIALIZED_VIEW') AS is_view
-             FROM {}.INFORMATION_SCHEMA.TABLES
-             WHERE {}",
-                database,
-                where_clauses.join(" OR ")
+             FROM {database}.INFORMATION_SCHEMA.TABLES
+             WHERE {where_clause}"
             );
 
-            let ctx = QueryCtx::default().with_desc("Extracting freshness from information schema");
-            let (_adapter_response, agate_table) = adapter.query(&ctx, &mut *conn, &sql, None)?;
+            let ctx = QueryCtx::default()
+                .with_desc("Extracting freshness from information schema")
+                .with_introspection();
+            let (_adapter_response, agate_table) =
+                adapter.query_with_params(&ctx, &mut *conn, &sql, &params)?;
             let batch = agate_table.original_record_batch();
             Ok(batch)
         };
 
         let reduce_f = move |acc: &mut Acc,
-                             database_and_where_clauses: (String, Vec<String>),
+                             database_and_tables: (String, Vec<(String, String)>),
                              batch_res: AdapterResult<Arc<RecordBatch>>|
               -> Result<(), Cancellable<AdapterError>> {
             let batch = batch_res?;
@@ -347,7 +352,7 @@ impl MetadataAdapter for DatabricksAdapter {
                 get_column_values::<TimestampMicrosecondArray>(&batch, "last_altered")?;
             let is_views = get_column_values::<BooleanArray>(&batch, "is_view")?;
 
-            let (database, _where_clauses) = &database_and_where_clauses;
+            let (database, _tables) = &database_and_tables;
             for i in 0..batch.num_rows() {
                 let schema = schemas.value(i);
                 let table = tables.value(i);
@@ -370,7 +375,7 @@ impl MetadataAdapter for DatabricksAdapter {
             Box::new(reduce_f),
             MAX_CONNECTIONS,
         );
-        let keys = where_clauses_by_database.into_iter().collect::<Vec<_>>();
+        let keys = tables_by_database.into_iter().collect::<Vec<_>>();
         let token = self.cancellation_token();
         map_reduce.run(Arc::new(keys), token)
     }
@@ -400,7 +405,9 @@ impl MetadataAdapter for DatabricksAdapter {
         let map_f = move |conn: &'_ mut dyn Connection,
                           db_schema: &CatalogAndSchema|
               -> AdapterResult<Vec<Arc<dyn BaseRelation>>> {
-            let query_ctx = QueryCtx::default().with_desc("list_relations_in_parallel (UC)");
+            let query_ctx = QueryCtx::default()
+                .with_desc("list_relations_in_parallel (UC)")
+                .with_introspection();
             adapter.list_relations(&query_ctx, conn, db_schema)
         };
 
@@ -0,0 +1,229 @@
+use crate::base_adapter::{AdapterType, AdapterTyping};
+use crate::column::Column;
+use crate::duckdb::quote_identifier;
+use crate::duckdb::relation::DuckDBRelation;
+use crate::errors::{AdapterError, AdapterErrorKind, AdapterResult};
+use crate::funcs::execute_macro;
+use crate::metadata::*;
+use crate::record_batch_utils::get_column_values;
+use crate::relation_object::RelationObject;
+use crate::sql_engine::SqlEngine;
+use crate::typed_adapter::TypedBaseAdapter;
+use arrow::array::StringArray;
+use dbt_schemas::dbt_types::RelationType;
+use dbt_schemas::schemas::common::{ConstraintSupport, ConstraintType};
+use dbt_schemas::schemas::relations::base::BaseRelation;
+use dbt_xdbc::{Backend, Connection, QueryCtx, QueryParams};
+
+use minijinja::{State, Value};
+use std::fmt;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+/// Query the type of a relation, binding its name as parameters
+fn relation_type_query(
+    backend: Backend,
+    database: &str,
+    schema: &str,
+    identifier: &str,
+) -> (String, QueryParams) {
+    let mut params = QueryParams::new(backend);
+    let sql = format!(
+        "select case when table_type = 'VIEW' then 'view' else 'table' end as type
+    from system.information_schema.tables
+    where table_catalog = {}
+    and table_schema = {}
+    and table_name = {}",
+        params.push(database),
+        params.push(schema),
+        params.push(identifier)
+    );
+    (sql, params)
+}
+
+/// An adapter for DuckDB.
+#[derive(Clone)]
+pub struct DuckDBAdapter {
+    engine: Arc<SqlEngine>,
+}
+
+impl DuckDBAdapter {
+    pub fn new(engine: Arc<SqlEngine>) -> Self {
+        Self { engine }
+    }
+}
+
+impl AdapterTyping for DuckDBAdapter {
+    fn as_metadata_adapter(&self) -> Option<&dyn MetadataAdapter> {
+        Some(self)
+    }
+
+    fn as_typed_base_adapter(&self) -> &dyn TypedBaseAdapter {
+        self
+    }
+
+    fn engine(&self) -> &Arc<SqlEngine> {
+        &self.engine
+    }
+}
+
+impl Debug for DuckDBAdapter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.adapter_type())
+    }
+}
+
+impl TypedBaseAdapter for DuckDBAdapter {
+    // TODO: add_query does not appear to be necessary (few uses in
+    // macros) and should be removed and replaced with `execute`.
+    #[allow(clippy::too_many_arguments)]
+    fn add_query(
+        &self,
+        ctx: &QueryCtx,
+        conn: &'_ mut dyn Connection,
+        sql: &str,
+        auto_begin: bool,
+        _bindings: Option<&Value>,
+        _abridge_sql_log: bool,
+    ) -> AdapterResult<()> {
+        let _ = self.execute_inner(
+            self.adapter_type().into(),
+            self.engine.clone(),
+            None,
+            conn,
+            ctx,
+            sql,
+            auto_begin,
+            false, // default for fetch as in dispatch_adapter_calls()
+            None,
+            None,
+        )?;
+        Ok(())
+    }
+
+    fn quote(&self, _state: &State, identifier: &str) -> AdapterResult<String> {
+        Ok(quote_identifier(identifier))
+    }
+
+    fn get_columns_in_relation(
+        &self,
+        state: &State,
+        relation: Arc<dyn BaseRelation>,
+    ) -> AdapterResult<Vec<Column>> {
+        let result = execute_macro(
+            state,
+            &[RelationObject::new(relation).as_value()],
+            "get_columns_in_relation",
+        )?;
+        Ok(Column::vec_from_jinja_value(AdapterType::DuckDB, result)?)
+    }
+
+    fn get_relation(
+        &self,
+        state: &State,
+        ctx: &QueryCtx,
+        conn: &'_ mut dyn Connection,
+        database: &str,
+        schema: &str,
+        identifier: &str,
+    ) -> AdapterResult<Option<Arc<dyn BaseRelation>>> {
+        let query_schema = if self.quoting().schema {
+            schema.to_string()
+        } else {
+            schema.to_lowercase()
+        };
+
+        let query_identifier = if self.quoting().identifier {
+            identifier.to_string()
+        } else {
+            identifier.to_lowercase()
+        };
+
+        let query_database = if self.quoting().database {
+            database.to_string()
+        } else {
+            database.to_lowercase()
+        };
+
+        let (sql, params) = relation_type_query(
+            self.engine.backend(),
+            &query_database,
+            &query_schema,
+            &query_identifier,
+        );
+        let batch = self
+            .engine
+            .execute_with_params(Some(state), conn, ctx, &sql, &params)?;
+        if batch.num_rows() == 0 {
+            return Ok(None);
+        }
+
+        let string_array = get_column_values::<StringArray>(&batch, "type")?;
+
+        if batch.num_rows() != 1 {
+            return Err(AdapterError::new(
+                AdapterErrorKind::UnexpectedResult,
+                "Did not find 'type' for a relation",
+            ));
+        }
+
+        let relation_type = match string_array.value(0) {
+            "table" => Some(RelationType::Table),
+            "view" => Some(RelationType::View),
+            _ => return invalid_value!("Unsupported relation type {}", string_array.value(0)),
+        };
+
+        let relation = DuckDBRelation::new(
+            Some(database.to_string()),
+            Some(schema.to_string()),
+            Some(identifier.to_string()),
+            relation_type,
+            self.quoting(),
+        );
+        Ok(Some(Arc::new(relation)))
+    }
+
+    fn verify_database(&self, _database: String) -> AdapterResult<Value> {
+        // Attached databases make cross-database references legitimate in DuckDB
+        Ok(Value::from(()))
+    }
+
+    fn get_constraint_support(&self, ct: ConstraintType) -> ConstraintSupport {
+        match ct {
+            ConstraintType::Check
+            | ConstraintType::NotNull
+            | ConstraintType::Unique
+            | ConstraintType::PrimaryKey
+            | ConstraintType::ForeignKey => ConstraintSupport::Enforced,
+            _ => ConstraintSupport::NotSupported,
+        }
+    }
+}
+
+impl fmt::Display for DuckDBAdapter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DuckDBAdapter({})", self.adapter_type())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dbt_xdbc::params::ParamValue;
+
+    use super::*;
+
+    #[test]
+    fn test_relation_type_query_binds_the_name() {
+        let (sql, params) = relation_type_query(Backend::DuckDB, "main", "it's", "orders");
+        assert!(sql.contains("where table_catalog = ?"));
+        assert!(!sql.contains("it's"));
+        assert_eq!(
+            params.values(),
+            &[
+                ParamValue::Utf8("main".to_string()),
+                ParamValue::Utf8("it's".to_string()),
+                ParamValue::Utf8("orders".to_string())
+            ]
+        );
+    }
+}
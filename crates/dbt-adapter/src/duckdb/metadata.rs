@@ -0,0 +1,481 @@
+use crate::duckdb::adapter::DuckDBAdapter;
+use crate::duckdb::relation::DuckDBRelation;
+use crate::errors::{AdapterError, AdapterErrorKind};
+use crate::sql_types::{TypeOps, make_arrow_field_v2};
+use crate::typed_adapter::TypedBaseAdapter;
+use crate::{
+    AdapterResult, AdapterTyping, errors::AsyncAdapterResult, metadata::*,
+    record_batch_utils::get_column_values,
+};
+use arrow_schema::{Field, Schema};
+
+use arrow_array::{Array, BooleanArray, Decimal128Array, RecordBatch, StringArray};
+
+use dbt_common::cancellation::Cancellable;
+use dbt_schemas::schemas::{
+    common::ResolvedQuoting,
+    legacy_catalog::{CatalogNodeStats, CatalogTable, ColumnMetadata, TableMetadata},
+    relations::base::{BaseRelation, RelationPattern},
+};
+use dbt_xdbc::query_ctx::ExecutionPhase;
+use dbt_xdbc::{Backend, Connection, MapReduce, QueryCtx, QueryParams};
+
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::ops::Range;
+use std::sync::Arc;
+
+const COLUMNS_SELECT: &str =
+    "select database_name, schema_name, table_name, column_name, data_type, is_nullable, comment
+    from duckdb_columns()";
+
+/// Query the columns of a table, binding its name as parameters
+fn table_columns_query(
+    backend: Backend,
+    database: &str,
+    schema: &str,
+    identifier: &str,
+) -> (String, QueryParams) {
+    let mut params = QueryParams::new(backend);
+    let sql = format!(
+        "{COLUMNS_SELECT}
+    where database_name = {}
+    and schema_name = {}
+    and table_name = {}
+    order by column_index",
+        params.push(database),
+        params.push(schema),
+        params.push(identifier)
+    );
+    (sql, params)
+}
+
+/// Query the columns of the tables of a database matching any of the patterns
+fn columns_by_patterns_query(
+    backend: Backend,
+    database: &str,
+    patterns: &[&RelationPattern],
+) -> (String, QueryParams) {
+    let mut params = QueryParams::new(backend);
+    let database = params.push(database);
+    let predicates = patterns
+        .iter()
+        .map(|pat| {
+            let schema_pattern = params.push(pat.schema_pattern.as_str());
+            let table_pattern = params.push(pat.table_pattern.as_str());
+            format!("(schema_name ilike {schema_pattern} and table_name ilike {table_pattern})")
+        })
+        .collect::<Vec<_>>()
+        .join(" or ");
+    let sql = format!(
+        "{COLUMNS_SELECT}
+    where database_name = {database}
+    and ({predicates})
+    order by database_name, schema_name, table_name, column_index"
+    );
+    (sql, params)
+}
+
+/// Build the fields of the columns in the given rows of a `duckdb_columns()` result
+fn build_fields(
+    type_ops: &dyn TypeOps,
+    batch: &RecordBatch,
+    rows: Range<usize>,
+) -> AdapterResult<Vec<Field>> {
+    let column_names = get_column_values::<StringArray>(batch, "column_name")?;
+    let data_types = get_column_values::<StringArray>(batch, "data_type")?;
+    let is_nullables = get_column_values::<BooleanArray>(batch, "is_nullable")?;
+    let comments = get_column_values::<StringArray>(batch, "comment")?;
+
+    rows.map(|i| {
+        let comment = match comments.is_null(i) {
+            true => None,
+            false => Some(comments.value(i).to_string()),
+        };
+        make_arrow_field_v2(
+            type_ops,
+            column_names.value(i).to_string(),
+            data_types.value(i),
+            Some(is_nullables.value(i)),
+            comment,
+        )
+    })
+    .collect()
+}
+
+/// Build the schemas of the tables in a `duckdb_columns()` result ordered by table
+#[allow(clippy::type_complexity)]
+fn build_schemas_from_columns(
+    batch: &RecordBatch,
+    quoting: ResolvedQuoting,
+    type_ops: &dyn TypeOps,
+) -> AdapterResult<Vec<(String, AdapterResult<RelationSchemaPair>)>> {
+    let databases = get_column_values::<StringArray>(batch, "database_name")?;
+    let schemas = get_column_values::<StringArray>(batch, "schema_name")?;
+    let tables = get_column_values::<StringArray>(batch, "table_name")?;
+    let table_of = |i: usize| (databases.value(i), schemas.value(i), tables.value(i));
+
+    let mut result = Vec::new();
+    let mut start = 0;
+    while start < batch.num_rows() {
+        let (database, schema, table) = table_of(start);
+        let end = (start..batch.num_rows())
+            .find(|&i| table_of(i) != (database, schema, table))
+            .unwrap_or(batch.num_rows());
+
+        let relation = Arc::new(DuckDBRelation::new(
+            Some(database.to_string()),
+            Some(schema.to_string()),
+            Some(table.to_string()),
+            None,
+            quoting,
+        )) as Arc<dyn BaseRelation>;
+        let schema_pair = build_fields(type_ops, batch, start..end)
+            .map(|fields| (relation, Arc::new(Schema::new(fields))));
+        result.push((format!("{database}.{schema}.{table}"), schema_pair));
+        start = end;
+    }
+    Ok(result)
+}
+
+impl MetadataAdapter for DuckDBAdapter {
+    fn build_schemas_from_stats_sql(
+        &self,
+        stats_sql_result: Arc<RecordBatch>,
+    ) -> AdapterResult<BTreeMap<String, CatalogTable>> {
+        if stats_sql_result.num_rows() == 0 {
+            return Ok(BTreeMap::new());
+        }
+
+        let table_catalogs = get_column_values::<StringArray>(&stats_sql_result, "table_database")?;
+        let table_schemas = get_column_values::<StringArray>(&stats_sql_result, "table_schema")?;
+        let table_names = get_column_values::<StringArray>(&stats_sql_result, "table_name")?;
+        let data_types = get_column_values::<StringArray>(&stats_sql_result, "table_type")?;
+        let comments = get_column_values::<StringArray>(&stats_sql_result, "table_comment")?;
+        let table_owners = get_column_values::<StringArray>(&stats_sql_result, "table_owner")?;
+
+        let mut result = BTreeMap::<String, CatalogTable>::new();
+
+        for i in 0..table_catalogs.len() {
+            let catalog = table_catalogs.value(i);
+            let schema = table_schemas.value(i);
+            let table = table_names.value(i);
+            let data_type = data_types.value(i);
+            let comment = comments.value(i);
+            let owner = table_owners.value(i);
+
+            let fully_qualified_name = format!("{catalog}.{schema}.{table}").to_lowercase();
+
+            let entry = result.entry(fully_qualified_name.clone());
+
+            if matches!(entry, Entry::Vacant(_)) {
+                let node_metadata = TableMetadata {
+                    materialization_type: data_type.to_string(),
+                    schema: schema.to_string(),
+                    name: table.to_string(),
+                    database: Some(catalog.to_string()),
+                    comment: match comment {
+                        "" => None,
+                        _ => Some(comment.to_string()),
+                    },
+                    owner: Some(owner.to_string()),
+                };
+
+                let no_stats = CatalogNodeStats {
+                    id: "has_stats".to_string(),
+                    label: "Has Stats?".to_string(),
+                    value: serde_json::Value::Bool(false),
+                    description: Some(
+                        "Indicates whether there are statistics for this table".to_string(),
+                    ),
+                    include: false,
+                };
+
+                let node = CatalogTable {
+                    metadata: node_metadata,
+                    columns: BTreeMap::new(),
+                    stats: BTreeMap::from([("has_stats".to_string(), no_stats)]),
+                    unique_id: None,
+                };
+                result.insert(fully_qualified_name.clone(), node);
+            }
+        }
+        Ok(result)
+    }
+
+    fn build_columns_from_get_columns(
+        &self,
+        stats_sql_result: Arc<RecordBatch>,
+    ) -> AdapterResult<BTreeMap<String, BTreeMap<String, ColumnMetadata>>> {
+        if stats_sql_result.num_rows() == 0 {
+            return Ok(BTreeMap::new());
+        }
+
+        let table_catalogs = get_column_values::<StringArray>(&stats_sql_result, "table_database")?;
+        let table_schemas = get_column_values::<StringArray>(&stats_sql_result, "table_schema")?;
+        let table_names = get_column_values::<StringArray>(&stats_sql_result, "table_name")?;
+
+        let column_names = get_column_values::<StringArray>(&stats_sql_result, "column_name")?;
+        let column_indices =
+            get_column_values::<Decimal128Array>(&stats_sql_result, "column_index")?;
+        let column_types = get_column_values::<StringArray>(&stats_sql_result, "column_type")?;
+        let column_comments =
+            get_column_values::<StringArray>(&stats_sql_result, "column_comment")?;
+
+        let mut columns_by_relation = BTreeMap::new();
+
+        for i in 0..table_catalogs.len() {
+            let catalog = table_catalogs.value(i);
+            let schema = table_schemas.value(i);
+            let table = table_names.value(i);
+
+            let fully_qualified_name = format!("{catalog}.{schema}.{table}").to_lowercase();
+
+            let column_name = column_names.value(i);
+            let column_index = column_indices.value(i);
+            let column_type = column_types.value(i);
+            let column_comment = column_comments.value(i);
+
+            let column = ColumnMetadata {
+                name: column_name.to_string(),
+                index: column_index,
+                data_type: column_type.to_string(),
+                comment: match column_comment {
+                    "" => None,
+                    _ => Some(column_comment.to_string()),
+                },
+            };
+
+            columns_by_relation
+                .entry(fully_qualified_name.clone())
+                .or_insert(BTreeMap::new())
+                .insert(column_name.to_string(), column);
+        }
+        Ok(columns_by_relation)
+    }
+
+    fn list_relations_schemas(
+        &self,
+        _unique_id: Option<String>,
+        _phase: Option<ExecutionPhase>,
+        relations: &[Arc<dyn BaseRelation>],
+    ) -> AsyncAdapterResult<'_, HashMap<String, AdapterResult<Arc<Schema>>>> {
+        type Acc = HashMap<String, AdapterResult<Arc<Schema>>>;
+
+        let adapter = self.clone();
+        let new_connection_f = Box::new(move || {
+            adapter
+                .new_connection(None, None)
+                .map_err(Cancellable::Error)
+        });
+
+        let adapter = self.clone();
+        let map_f = move |conn: &'_ mut dyn Connection,
+                          relation: &Arc<dyn BaseRelation>|
+              -> AdapterResult<Arc<Schema>> {
+            let database = relation.database_as_str()?;
+            let schema = relation.schema_as_str()?;
+            let identifier = relation.identifier_as_str()?;
+
+            let (sql, params) =
+                table_columns_query(adapter.engine().backend(), &database, &schema, &identifier);
+
+            let ctx = QueryCtx::default()
+                .with_desc("Get table schema")
+                .with_introspection();
+            let (_, table) = adapter.query_with_params(&ctx, &mut *conn, &sql, &params)?;
+            let batch = table.original_record_batch();
+            let fields = build_fields(adapter.engine().type_ops(), &batch, 0..batch.num_rows())?;
+
+            if fields.is_empty() {
+                Err(AdapterError::new(
+                    AdapterErrorKind::UnexpectedResult,
+                    format!("No columns in duckdb_columns() for {database}.{schema}.{identifier}"),
+                ))
+            } else {
+                Ok(Arc::new(Schema::new(fields)))
+            }
+        };
+        let reduce_f = |acc: &mut Acc,
+                        relation: Arc<dyn BaseRelation>,
+                        schema: AdapterResult<Arc<Schema>>|
+         -> Result<(), Cancellable<AdapterError>> {
+            acc.insert(relation.semantic_fqn(), schema);
+            Ok(())
+        };
+        let map_reduce = MapReduce::new(
+            Box::new(new_connection_f),
+            Box::new(map_f),
+            Box::new(reduce_f),
+            MAX_CONNECTIONS,
+        );
+        let token = self.cancellation_token();
+        map_reduce.run(Arc::new(relations.to_vec()), token)
+    }
+
+    fn list_relations_schemas_by_patterns(
+        &self,
+        relations_pattern: &[RelationPattern],
+    ) -> AsyncAdapterResult<'_, Vec<(String, AdapterResult<RelationSchemaPair>)>> {
+        type Acc = Vec<(String, AdapterResult<RelationSchemaPair>)>;
+
+        // Group patterns by database to run one query per database
+        let mut patterns_by_database = BTreeMap::new();
+        for pat in relations_pattern {
+            patterns_by_database
+                .entry(pat.database.clone())
+                .or_insert_with(Vec::new)
+                .push(pat);
+        }
+
+        let backend = self.engine().backend();
+        let queries = patterns_by_database
+            .into_iter()
+            .map(|(database, patterns)| columns_by_patterns_query(backend, &database, &patterns))
+            .collect::<Vec<_>>();
+
+        let adapter = self.clone();
+        let new_connection_f = move || {
+            adapter
+                .new_connection(None, None)
+                .map_err(Cancellable::Error)
+        };
+
+        let adapter = self.clone();
+        let map_f = move |conn: &'_ mut dyn Connection,
+                          (sql, params): &(String, QueryParams)|
+              -> AdapterResult<Arc<RecordBatch>> {
+            let ctx = QueryCtx::default()
+                .with_desc("Get schema by pattern")
+                .with_introspection();
+            let (_, table) = adapter.query_with_params(&ctx, conn, sql, params)?;
+            Ok(table.original_record_batch())
+        };
+
+        let quoting = self.quoting();
+        let adapter = self.clone();
+        let reduce_f = move |acc: &mut Acc,
+                             _query: (String, QueryParams),
+                             batch: AdapterResult<Arc<RecordBatch>>|
+              -> Result<(), Cancellable<AdapterError>> {
+            let mut schemas =
+                build_schemas_from_columns(&batch?, quoting, adapter.engine().type_ops())?;
+            acc.append(&mut schemas);
+            Ok(())
+        };
+        let map_reduce = MapReduce::new(
+            Box::new(new_connection_f),
+            Box::new(map_f),
+            Box::new(reduce_f),
+            MAX_CONNECTIONS,
+        );
+        let token = self.cancellation_token();
+        map_reduce.run(Arc::new(queries), token)
+    }
+
+    fn freshness(
+        &self,
+        _relations: &[Arc<dyn BaseRelation>],
+    ) -> AsyncAdapterResult<'_, BTreeMap<String, MetadataFreshness>> {
+        // duckdb_tables() keeps no modification time, so sources need a
+        // loaded_at_field on DuckDB
+        let future = async move {
+            Err(Cancellable::Error(AdapterError::new(
+                AdapterErrorKind::NotSupported,
+                "Freshness from metadata is not supported by the duckdb adapter, \
+                 use loaded_at_field instead",
+            )))
+        };
+        Box::pin(future)
+    }
+
+    fn create_schemas_if_not_exists(
+        &self,
+        state: &minijinja::State<'_, '_>,
+        catalog_schemas: &BTreeMap<String, BTreeSet<String>>,
+    ) -> AdapterResult<Vec<(String, String, AdapterResult<()>)>> {
+        create_schemas_if_not_exists(Arc::new(self.clone()), state, catalog_schemas)
+    }
+
+    fn list_relations_in_parallel(
+        &self,
+        db_schemas: &[CatalogAndSchema],
+    ) -> AsyncAdapterResult<'_, BTreeMap<CatalogAndSchema, AdapterResult<RelationVec>>> {
+        type Acc = BTreeMap<CatalogAndSchema, AdapterResult<RelationVec>>;
+        let adapter = self.clone();
+        let new_connection_f = move || {
+            adapter
+                .new_connection(None, None)
+                .map_err(Cancellable::Error)
+        };
+
+        let adapter = self.clone();
+        let map_f = move |conn: &'_ mut dyn Connection,
+                          db_schema: &CatalogAndSchema|
+              -> AdapterResult<Vec<Arc<dyn BaseRelation>>> {
+            let query_ctx = QueryCtx::default()
+                .with_desc("list_relations_in_parallel")
+                .with_introspection();
+            adapter.list_relations(&query_ctx, conn, db_schema)
+        };
+
+        let reduce_f = move |acc: &mut Acc,
+                             db_schema: CatalogAndSchema,
+                             relations: AdapterResult<Vec<Arc<dyn BaseRelation>>>|
+              -> Result<(), Cancellable<AdapterError>> {
+            acc.insert(db_schema, relations);
+            Ok(())
+        };
+
+        let map_reduce = MapReduce::new(
+            Box::new(new_connection_f),
+            Box::new(map_f),
+            Box::new(reduce_f),
+            MAX_CONNECTIONS,
+        );
+        let token = self.cancellation_token();
+        map_reduce.run(Arc::new(db_schemas.to_vec()), token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dbt_xdbc::params::ParamValue;
+
+    use super::*;
+
+    #[test]
+    fn test_table_columns_query() {
+        let (sql, params) = table_columns_query(Backend::DuckDB, "main", "analytics", "orders");
+        assert!(sql.contains("from duckdb_columns()"));
+        assert!(sql.contains("where database_name = ?"));
+        assert_eq!(
+            params.values(),
+            &[
+                ParamValue::Utf8("main".to_string()),
+                ParamValue::Utf8("analytics".to_string()),
+                ParamValue::Utf8("orders".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_columns_by_patterns_binds_the_patterns() {
+        let pattern = RelationPattern::new(
+            "main".to_string(),
+            "s%".to_string(),
+            "t' or 1=1) --".to_string(),
+        );
+        let (sql, params) = columns_by_patterns_query(Backend::DuckDB, "main", &[&pattern]);
+        assert!(!sql.contains("1=1"));
+        assert!(sql.contains("and ((schema_name ilike ? and table_name ilike ?))"));
+        assert_eq!(
+            params.values(),
+            &[
+                ParamValue::Utf8("main".to_string()),
+                ParamValue::Utf8("s%".to_string()),
+                ParamValue::Utf8("t' or 1=1) --".to_string())
+            ]
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use crate::config::AdapterConfig;
+use crate::errors::{AdapterError, AdapterErrorKind, AdapterResult};
+use dbt_schemas::schemas::profiles::DuckDBAttachment;
+use dbt_serde_yaml::Value as YmlValue;
+use serde::de::DeserializeOwned;
+
+use std::collections::BTreeMap;
+
+pub mod adapter;
+pub mod metadata;
+/// DuckDB relation
+pub mod relation;
+
+fn config_field<T: DeserializeOwned>(
+    config: &AdapterConfig,
+    field: &str,
+) -> AdapterResult<Option<T>> {
+    match config.get(field) {
+        None | Some(YmlValue::Null(_)) => Ok(None),
+        Some(value) => dbt_serde_yaml::from_value(value.clone())
+            .map(Some)
+            .map_err(|e| {
+                AdapterError::new(
+                    AdapterErrorKind::Configuration,
+                    format!("Invalid '{field}' in duckdb profile: {e}"),
+                )
+            }),
+    }
+}
+
+fn quote_string_literal(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+/// Quote an identifier, doubling any embedded double quotes
+pub(crate) fn quote_identifier(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "\"\""))
+}
+
+/// Extension names, setting names and attachment types are keywords in the
+/// statements they appear in, so they are checked rather than quoted.
+fn checked_name<'a>(kind: &str, name: &'a str) -> AdapterResult<&'a str> {
+    if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        Ok(name)
+    } else {
+        Err(AdapterError::new(
+            AdapterErrorKind::Configuration,
+            format!("Invalid duckdb {kind} '{name}': only letters, digits and '_' are allowed"),
+        ))
+    }
+}
+
+fn setting_literal(value: &YmlValue) -> AdapterResult<String> {
+    match value {
+        YmlValue::Bool(b, _) => Ok(b.to_string()),
+        YmlValue::Number(n, _) => Ok(n.to_string()),
+        YmlValue::String(s, _) => Ok(quote_string_literal(s)),
+        YmlValue::Tagged(tagged, _) => setting_literal(&tagged.value),
+        _ => Err(AdapterError::new(
+            AdapterErrorKind::Configuration,
+            "duckdb settings must be strings, numbers or booleans",
+        )),
+    }
+}
+
+/// Statements to run on every new DuckDB connection so that it sees the
+/// extensions, attached databases and settings configured in the profile.
+///
+/// Each connection to an in-memory database is its own database, so this
+/// state cannot be configured once at the database level.
+pub fn connection_setup_sql(config: &AdapterConfig) -> AdapterResult<Vec<String>> {
+    let mut statements = Vec::new();
+
+    let extensions: Vec<String> = config_field(config, "extensions")?.unwrap_or_default();
+    for extension in extensions {
+        let extension = checked_name("extension", &extension)?;
+        statements.push(format!("install {extension}"));
+        statements.push(format!("load {extension}"));
+    }
+
+    let settings: BTreeMap<String, YmlValue> =
+        config_field(config, "settings")?.unwrap_or_default();
+    for (key, value) in &settings {
+        let key = checked_name("setting", key)?;
+        statements.push(format!("set {key} = {}", setting_literal(value)?));
+    }
+
+    let attachments: Vec<DuckDBAttachment> = config_field(config, "attach")?.unwrap_or_default();
+    for attachment in attachments {
+        let mut sql = format!(
+            "attach if not exists {}",
+            quote_string_literal(&attachment.path)
+        );
+        if let Some(alias) = &attachment.alias {
+            sql.push_str(&format!(" as {}", quote_identifier(alias)));
+        }
+        let mut options = Vec::new();
+        if let Some(type_) = &attachment.type_ {
+            options.push(format!("type {}", checked_name("attachment type", type_)?));
+        }
+        if attachment.read_only.unwrap_or(false) {
+            options.push("read_only".to_string());
+        }
+        if !options.is_empty() {
+            sql.push_str(&format!(" ({})", options.join(", ")));
+        }
+        statements.push(sql);
+    }
+
+    Ok(statements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(yaml: &str) -> AdapterConfig {
+        AdapterConfig::new(dbt_serde_yaml::from_str(yaml).unwrap())
+    }
+
+    #[test]
+    fn test_connection_setup_sql() {
+        let config = config(
+            r#"
+path: dev.duckdb
+extensions: [httpfs, parquet]
+settings:
+  threads: 4
+  s3_region: us-east-1
+attach:
+  - path: /tmp/other.duckdb
+  - path: "/tmp/it's.sqlite"
+    alias: lite
+    type: sqlite
+    read_only: true
+"#,
+        );
+        assert_eq!(
+            connection_setup_sql(&config).unwrap(),
+            vec![
+                "install httpfs",
+                "load httpfs",
+                "install parquet",
+                "load parquet",
+                "set s3_region = 'us-east-1'",
+                "set threads = 4",
+                "attach if not exists '/tmp/other.duckdb'",
+                "attach if not exists '/tmp/it''s.sqlite' as \"lite\" (type sqlite, read_only)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_connection_setup_sql_escapes_and_checks_names() {
+        let config = config(
+            r#"
+attach:
+  - path: /tmp/other.duckdb
+    alias: 'a"b'
+"#,
+        );
+        assert_eq!(
+            connection_setup_sql(&config).unwrap(),
+            vec!["attach if not exists '/tmp/other.duckdb' as \"a\"\"b\""]
+        );
+
+        for yaml in [
+            "extensions: ['httpfs; drop table t']",
+            "settings: {'threads = 1; drop table t; set x': 1}",
+            "attach: [{path: x.db, type: 'sqlite) ; drop table t; --'}]",
+        ] {
+            let err = connection_setup_sql(&config(yaml)).unwrap_err();
+            assert_eq!(err.kind(), AdapterErrorKind::Configuration);
+        }
+    }
+
+    #[test]
+    fn test_quote_identifier_doubles_quotes() {
+        assert_eq!(quote_identifier("my\"table"), "\"my\"\"table\"");
+    }
+
+    #[test]
+    fn test_connection_setup_sql_empty() {
+        assert!(
+            connection_setup_sql(&config("path: ':memory:'"))
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
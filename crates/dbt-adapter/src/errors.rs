@@ -63,3 +63,24 @@ pub fn adbc_error_to_adapter_error(err: adbc_core::error::Error) -> AdapterError
 
     AdapterError::new_with_sqlstate_and_vendor_code(kind, err.message, sqlstate, vendor_code)
 }
+
+/// Convert [AdapterError] to [adbc_core::error::Error], for errors that have to
+/// cross the driver interface (e.g. from the connection function of a
+/// [dbt_xdbc::pool::Pool]).
+pub fn adapter_error_to_adbc_error(err: AdapterError) -> adbc_core::error::Error {
+    use adbc_core::error::{Error, Status};
+
+    let status = match err.kind() {
+        AdapterErrorKind::Cancelled => Status::Cancelled,
+        AdapterErrorKind::Authentication => Status::Unauthenticated,
+        AdapterErrorKind::NotFound => Status::NotFound,
+        AdapterErrorKind::Configuration => Status::InvalidArguments,
+        AdapterErrorKind::Io => Status::IO,
+        _ => Status::Unknown,
+    };
+    let mut error = Error::with_message_and_status(err.message(), status);
+    let sqlstate: [u8; 5] = err.sqlstate().as_bytes().try_into().unwrap_or([b'0'; 5]);
+    error.sqlstate = sqlstate.map(|b| b as std::ffi::c_char);
+    error.vendor_code = err.vendor_code().unwrap_or(0);
+    error
+}
@@ -1,15 +1,46 @@
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use crate::AdapterType;
+use crate::base_adapter::{AdapterFactory, BaseAdapter, backend_of};
+use crate::bigquery::adapter::BigqueryAdapter;
 use crate::bigquery::relation::BigqueryRelationType;
+use crate::bridge_adapter::BridgeAdapter;
+use crate::cache::RelationCache;
+use crate::config::AdapterConfig;
+use crate::databricks::adapter::DatabricksAdapter;
 use crate::databricks::relation::DatabricksRelationType;
+use crate::duckdb::adapter::DuckDBAdapter;
+use crate::duckdb::relation::DuckDBRelationType;
+use crate::generic;
+use crate::generic::adapter::GenericAdapter;
+use crate::generic::relation::GenericRelationType;
+use crate::postgres::adapter::PostgresAdapter;
 use crate::postgres::relation::PostgresRelationType;
+use crate::query_cache::QueryCache;
+use crate::query_comment::QueryCommentConfig;
+use crate::redshift::adapter::RedshiftAdapter;
 use crate::redshift::relation::RedshiftRelationType;
-use crate::relation_object::StaticBaseRelationObject;
+use crate::relation_object::{StaticBaseRelationObject, create_relation_from_node};
 use crate::salesforce::relation::SalesforceRelationType;
+use crate::snowflake::adapter::SnowflakeAdapter;
 use crate::snowflake::relation::SnowflakeRelationType;
+use crate::sql_engine::SqlEngine;
+use crate::sql_types::{NaiveTypeOpsImpl, TypeOps};
+use crate::stmt_splitter::NaiveStmtSplitter;
+use crate::trino::adapter::TrinoAdapter;
+use crate::trino::relation::TrinoRelationType;
+use crate::typed_adapter::TypedBaseAdapter;
 
+use dbt_auth::auth_for_backend;
+use dbt_common::adapter::SchemaRegistry;
+use dbt_common::cancellation::CancellationToken;
+use dbt_common::io_args::ReplayMode;
+use dbt_common::{ErrorCode, FsResult, err};
+use dbt_schemas::schemas::InternalDbtNodeAttributes;
 use dbt_schemas::schemas::common::ResolvedQuoting;
+use dbt_schemas::schemas::project::QueryComment;
+use dbt_schemas::schemas::relations::base::BaseRelation;
 use minijinja::Value;
 
 /// Create a static relation value from an adapter type
@@ -17,6 +48,7 @@ use minijinja::Value;
 pub fn create_static_relation(
     adapter_type: AdapterType,
     quoting: ResolvedQuoting,
+    quote_character: Option<char>,
 ) -> Option<Value> {
     let result = match adapter_type {
         AdapterType::Snowflake => {
@@ -39,6 +71,19 @@ pub fn create_static_relation(
             let redshift_relation_type = RedshiftRelationType(quoting);
             StaticBaseRelationObject::new(Arc::new(redshift_relation_type))
         }
+        AdapterType::DuckDB => {
+            let duckdb_relation_type = DuckDBRelationType(quoting);
+            StaticBaseRelationObject::new(Arc::new(duckdb_relation_type))
+        }
+        AdapterType::Trino => {
+            let trino_relation_type = TrinoRelationType(quoting);
+            StaticBaseRelationObject::new(Arc::new(trino_relation_type))
+        }
+        AdapterType::Generic => {
+            let generic_relation_type =
+                GenericRelationType(quoting, quote_character.unwrap_or('"'));
+            StaticBaseRelationObject::new(Arc::new(generic_relation_type))
+        }
         AdapterType::Salesforce => {
             let salesforce_relation_type = SalesforceRelationType(quoting);
             StaticBaseRelationObject::new(Arc::new(salesforce_relation_type))
@@ -46,3 +91,121 @@ pub fn create_static_relation(
     };
     Some(Value::from_object(result))
 }
+
+/// The [AdapterFactory] for adapters that talk to the warehouse through
+/// ADBC drivers, directly or through a recording of a previous invocation.
+#[derive(Debug, Default)]
+pub struct DefaultAdapterFactory {
+    relation_cache: Arc<RelationCache>,
+}
+
+impl DefaultAdapterFactory {
+    /// Create a factory whose adapters share the given relation cache
+    pub fn new(relation_cache: Arc<RelationCache>) -> Self {
+        Self { relation_cache }
+    }
+}
+
+impl AdapterFactory for DefaultAdapterFactory {
+    fn create_adapter(
+        &self,
+        adapter_type: AdapterType,
+        config: dbt_serde_yaml::Mapping,
+        replay_mode: Option<ReplayMode>,
+        _flags: BTreeMap<String, Value>,
+        db: Option<Arc<dyn SchemaRegistry>>,
+        query_cache: Option<Arc<dyn QueryCache>>,
+        quoting: ResolvedQuoting,
+        query_comment: Option<QueryComment>,
+        token: CancellationToken,
+    ) -> FsResult<Arc<dyn BaseAdapter>> {
+        let config = AdapterConfig::new(config);
+        let stmt_splitter = Arc::new(NaiveStmtSplitter {});
+        let query_comment =
+            QueryCommentConfig::from_query_comment(query_comment, adapter_type, false);
+        // The driver and dialect of the generic adapter come from the profile
+        let (backend, dialect) = match adapter_type {
+            AdapterType::Generic => (generic::backend(&config)?, Some(generic::dialect(&config)?)),
+            _ => (backend_of(adapter_type), None),
+        };
+        let type_ops: Box<dyn TypeOps> = match &dialect {
+            Some(dialect) => Box::new(generic::GenericTypeOps::new(dialect)),
+            None => Box::new(NaiveTypeOpsImpl::new(adapter_type)),
+        };
+
+        let engine = match replay_mode {
+            None | Some(ReplayMode::FsRecord(_)) => {
+                let auth = auth_for_backend(backend).into();
+                let engine = SqlEngine::new(
+                    adapter_type,
+                    auth,
+                    config,
+                    quoting,
+                    stmt_splitter,
+                    query_cache,
+                    query_comment,
+                    type_ops,
+                    token,
+                );
+                match replay_mode {
+                    Some(ReplayMode::FsRecord(path)) => SqlEngine::new_for_recording(path, engine),
+                    _ => engine,
+                }
+            }
+            Some(ReplayMode::FsReplay(path)) => SqlEngine::new_for_replaying(
+                adapter_type,
+                path,
+                config,
+                quoting,
+                stmt_splitter,
+                query_comment,
+                type_ops,
+                token,
+            ),
+            Some(ReplayMode::DbtReplay(_)) => {
+                return err!(
+                    ErrorCode::NotSupported,
+                    "Replaying dbt-core recordings is not supported"
+                );
+            }
+        };
+
+        let typed_adapter: Arc<dyn TypedBaseAdapter> = match adapter_type {
+            AdapterType::Postgres => Arc::new(PostgresAdapter::new(engine)),
+            AdapterType::Snowflake => Arc::new(SnowflakeAdapter::new(engine)),
+            AdapterType::Bigquery => Arc::new(BigqueryAdapter::new(engine)),
+            AdapterType::Databricks => Arc::new(DatabricksAdapter::new(engine)),
+            AdapterType::Redshift => Arc::new(RedshiftAdapter::new(engine)),
+            AdapterType::DuckDB => Arc::new(DuckDBAdapter::new(engine)),
+            AdapterType::Trino => Arc::new(TrinoAdapter::new(engine)),
+            AdapterType::Generic => {
+                Arc::new(GenericAdapter::new(engine, dialect.unwrap_or_default()))
+            }
+            AdapterType::Salesforce => {
+                return err!(
+                    ErrorCode::NotSupported,
+                    "The {adapter_type} adapter cannot execute queries yet"
+                );
+            }
+        };
+        Ok(Arc::new(BridgeAdapter::new(
+            typed_adapter,
+            db,
+            self.relation_cache.clone(),
+        )))
+    }
+
+    fn create_relation_from_node(
+        &self,
+        node: &dyn InternalDbtNodeAttributes,
+        adapter_type: AdapterType,
+    ) -> Result<Arc<dyn BaseRelation>, minijinja::Error> {
+        create_relation_from_node(adapter_type, node, None, None).map_err(|e| {
+            minijinja::Error::new(minijinja::ErrorKind::InvalidOperation, e.to_string())
+        })
+    }
+
+    fn with_relation_cache(&self, relation_cache: Arc<RelationCache>) -> Arc<dyn AdapterFactory> {
+        Arc::new(Self::new(relation_cache))
+    }
+}
@@ -895,6 +895,8 @@ pub fn dispatch_adapter_calls(
         }
         "parse_columns_and_constraints" => adapter.parse_columns_and_constraints(state, args),
         "clean_sql" => adapter.clean_sql(args),
+        // only available for the generic adapter
+        "dialect_query" => adapter.dialect_query(args),
         _ => Err(MinijinjaError::new(
             MinijinjaErrorKind::InvalidOperation,
             format!("Unknown method on adapter object: '{name}'"),
@@ -907,7 +909,11 @@ pub fn dispatch_adapter_get_value(adapter: &dyn BaseAdapter, key: &Value) -> Opt
         Some("behavior") => Some(adapter.behavior()),
         // NOTE(serramatutu): BigQuery adapter calls `Relation` from `adapter.Relation`
         // instead of `api.Relation` when executing materialized views
-        Some("Relation") => create_static_relation(adapter.adapter_type(), adapter.quoting()),
+        Some("Relation") => create_static_relation(
+            adapter.adapter_type(),
+            adapter.quoting(),
+            adapter.quote_character(),
+        ),
         _ => None,
     }
 }
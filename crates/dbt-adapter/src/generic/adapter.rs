@@ -0,0 +1,153 @@
+use crate::base_adapter::{AdapterType, AdapterTyping};
+use crate::column::Column;
+use crate::errors::AdapterResult;
+use crate::funcs::execute_macro;
+use crate::metadata::*;
+use crate::relation_object::RelationObject;
+use crate::sql_engine::SqlEngine;
+use crate::typed_adapter::TypedBaseAdapter;
+use dbt_schemas::schemas::common::{ConstraintSupport, ConstraintType};
+use dbt_schemas::schemas::profiles::GenericDialect;
+use dbt_schemas::schemas::relations::base::BaseRelation;
+use dbt_xdbc::{Connection, QueryCtx};
+
+use minijinja::{State, Value};
+use std::fmt;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+/// An adapter for any ADBC driver, following the dialect declared in the profile.
+#[derive(Clone)]
+pub struct GenericAdapter {
+    engine: Arc<SqlEngine>,
+    dialect: GenericDialect,
+}
+
+impl GenericAdapter {
+    pub fn new(engine: Arc<SqlEngine>, dialect: GenericDialect) -> Self {
+        Self { engine, dialect }
+    }
+}
+
+impl AdapterTyping for GenericAdapter {
+    fn as_metadata_adapter(&self) -> Option<&dyn MetadataAdapter> {
+        None
+    }
+
+    fn as_typed_base_adapter(&self) -> &dyn TypedBaseAdapter {
+        self
+    }
+
+    fn engine(&self) -> &Arc<SqlEngine> {
+        &self.engine
+    }
+
+    fn quote_character(&self) -> Option<char> {
+        Some(self.dialect.quote_character())
+    }
+}
+
+impl Debug for GenericAdapter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.adapter_type())
+    }
+}
+
+impl TypedBaseAdapter for GenericAdapter {
+    // TODO: add_query does not appear to be necessary (few uses in
+    // macros) and should be removed and replaced with `execute`.
+    #[allow(clippy::too_many_arguments)]
+    fn add_query(
+        &self,
+        ctx: &QueryCtx,
+        conn: &'_ mut dyn Connection,
+        sql: &str,
+        auto_begin: bool,
+        _bindings: Option<&Value>,
+        _abridge_sql_log: bool,
+    ) -> AdapterResult<()> {
+        let _ = self.execute_inner(
+            self.adapter_type().into(),
+            self.engine.clone(),
+            None,
+            conn,
+            ctx,
+            sql,
+            auto_begin,
+            false, // default for fetch as in dispatch_adapter_calls()
+            None,
+            None,
+        )?;
+        Ok(())
+    }
+
+    fn quote(&self, _state: &State, identifier: &str) -> AdapterResult<String> {
+        let quote = self.dialect.quote_character();
+        let escaped = identifier.replace(quote, &format!("{quote}{quote}"));
+        Ok(format!("{quote}{escaped}{quote}"))
+    }
+
+    fn get_columns_in_relation(
+        &self,
+        state: &State,
+        relation: Arc<dyn BaseRelation>,
+    ) -> AdapterResult<Vec<Column>> {
+        let result = execute_macro(
+            state,
+            &[RelationObject::new(relation).as_value()],
+            "get_columns_in_relation",
+        )?;
+        Ok(Column::vec_from_jinja_value(AdapterType::Generic, result)?)
+    }
+
+    fn get_relation(
+        &self,
+        _state: &State,
+        ctx: &QueryCtx,
+        conn: &'_ mut dyn Connection,
+        database: &str,
+        schema: &str,
+        identifier: &str,
+    ) -> AdapterResult<Option<Arc<dyn BaseRelation>>> {
+        let db_schema = CatalogAndSchema {
+            rendered_catalog: database.to_string(),
+            rendered_schema: schema.to_string(),
+            resolved_catalog: database.to_string(),
+            resolved_schema: schema.to_string(),
+        };
+        // The dialect only knows how to list the relations of a schema
+        let relations = generic::list_relations(self, ctx, conn, &db_schema)?;
+        let quoted = self.quoting().identifier;
+        Ok(relations.into_iter().find(|relation| {
+            relation.identifier_as_str().is_ok_and(|name| {
+                if quoted {
+                    name == identifier
+                } else {
+                    name.eq_ignore_ascii_case(identifier)
+                }
+            })
+        }))
+    }
+
+    fn verify_database(&self, _database: String) -> AdapterResult<Value> {
+        // Whether the driver supports cross-database references is unknown
+        Ok(Value::from(()))
+    }
+
+    fn get_constraint_support(&self, ct: ConstraintType) -> ConstraintSupport {
+        match ct {
+            ConstraintType::NotNull => ConstraintSupport::Enforced,
+            _ => ConstraintSupport::NotSupported,
+        }
+    }
+
+    fn dialect_query(&self, name: &str, params: &[(&str, &str)]) -> AdapterResult<String> {
+        crate::generic::dialect_query(&self.dialect, name, params)
+    }
+}
+
+impl fmt::Display for GenericAdapter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "GenericAdapter({})", self.adapter_type())
+    }
+}
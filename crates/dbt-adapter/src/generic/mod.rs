@@ -0,0 +1,200 @@
+use crate::base_adapter::{AdapterType, backend_of};
+use crate::config::AdapterConfig;
+use crate::errors::{AdapterError, AdapterErrorKind, AdapterResult};
+use crate::sql_types::{SqlTypeHint, TypeOps, parse_nullable_sql_type, sql_type_hint_to_str};
+use arrow_schema::DataType;
+use dbt_schemas::schemas::profiles::GenericDialect;
+use dbt_serde_yaml::Value as YmlValue;
+use dbt_xdbc::Backend;
+use dbt_xdbc::sql::types::SqlType;
+
+use std::collections::BTreeMap;
+
+pub mod adapter;
+/// Generic relation
+pub mod relation;
+
+/// The dialect declared in the profile, or an empty one.
+pub fn dialect(config: &AdapterConfig) -> AdapterResult<GenericDialect> {
+    match config.get("dialect") {
+        None | Some(YmlValue::Null(_)) => Ok(GenericDialect::default()),
+        Some(value) => dbt_serde_yaml::from_value(value.clone()).map_err(|e| {
+            AdapterError::new(
+                AdapterErrorKind::Configuration,
+                format!("Invalid 'dialect' in generic profile: {e}"),
+            )
+        }),
+    }
+}
+
+/// The [Backend] loading the driver library named in the profile.
+pub fn backend(config: &AdapterConfig) -> AdapterResult<Backend> {
+    let driver = config.get_string("driver").ok_or_else(|| {
+        AdapterError::new(
+            AdapterErrorKind::Configuration,
+            "'driver' is required in generic profile",
+        )
+    })?;
+    let entrypoint = config.get_string("entrypoint");
+    Ok(Backend::generic(&driver, entrypoint.as_deref()))
+}
+
+/// The metadata query `name` of the dialect (e.g. `list_relations_sql`),
+/// with its placeholders substituted by `params`.
+pub fn dialect_query(
+    dialect: &GenericDialect,
+    name: &str,
+    params: &[(&str, &str)],
+) -> AdapterResult<String> {
+    let template = match name {
+        "list_relations_sql" => dialect.list_relations_sql.as_ref(),
+        "list_columns_sql" => dialect.list_columns_sql.as_ref(),
+        "list_schemas_sql" => dialect.list_schemas_sql.as_ref(),
+        _ => None,
+    };
+    let Some(template) = template else {
+        return Err(AdapterError::new(
+            AdapterErrorKind::Configuration,
+            format!("The dialect of the generic profile has no '{name}'"),
+        ));
+    };
+    Ok(render_dialect_query(template, params))
+}
+
+/// Substitute the `{name}` placeholders of a dialect query, doubling single
+/// quotes in the values so that they can be used inside string literals.
+fn render_dialect_query(template: &str, params: &[(&str, &str)]) -> String {
+    let mut sql = template.to_string();
+    for (name, value) in params {
+        sql = sql.replace(&format!("{{{name}}}"), &value.replace('\'', "''"));
+    }
+    sql
+}
+
+/// [TypeOps] using the type names declared by the dialect in the profile.
+pub struct GenericTypeOps {
+    types: BTreeMap<String, String>,
+}
+
+impl GenericTypeOps {
+    pub fn new(dialect: &GenericDialect) -> Self {
+        Self {
+            types: dialect.types.clone().unwrap_or_default(),
+        }
+    }
+}
+
+impl TypeOps for GenericTypeOps {
+    fn adapter_type(&self) -> AdapterType {
+        AdapterType::Generic
+    }
+
+    fn format_arrow_type_as_sql(
+        &self,
+        data_type: &DataType,
+        out: &mut String,
+    ) -> AdapterResult<()> {
+        let hint: SqlTypeHint = data_type.try_into()?;
+        let key = match hint {
+            SqlTypeHint::Integer => "integer",
+            SqlTypeHint::Floating => "float",
+            SqlTypeHint::Decimal => "decimal",
+            SqlTypeHint::Boolean => "boolean",
+            SqlTypeHint::Datetime => "timestamp",
+            SqlTypeHint::Date => "date",
+            SqlTypeHint::Time => "time",
+            SqlTypeHint::Text => "text",
+        };
+        match self.types.get(key) {
+            Some(type_name) => out.push_str(type_name),
+            None => out.push_str(&sql_type_hint_to_str(hint, false, AdapterType::Generic)),
+        }
+        Ok(())
+    }
+
+    fn format_sql_type(&self, sql_type: SqlType, out: &mut String) -> AdapterResult<()> {
+        sql_type
+            .write(backend_of(AdapterType::Generic), out)
+            .map_err(|e| {
+                AdapterError::new(
+                    AdapterErrorKind::NotSupported,
+                    format!("Failed to convert SQL type {sql_type:?}. Error: {e}"),
+                )
+            })
+    }
+
+    fn parse_into_nullable_arrow_type(&self, s: &str) -> AdapterResult<(DataType, bool)> {
+        let (sql_type, nullable) = parse_nullable_sql_type(s, AdapterType::Generic)?;
+        let data_type = sql_type.pick_best_arrow_type(backend_of(AdapterType::Generic));
+        Ok((data_type, nullable))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(yaml: &str) -> AdapterConfig {
+        AdapterConfig::new(dbt_serde_yaml::from_str(yaml).unwrap())
+    }
+
+    #[test]
+    fn test_backend_from_profile() {
+        let backend = backend(&config(
+            "driver: adbc_driver_flightsql\nentrypoint: AdbcDriverFlightSQLInit",
+        ))
+        .unwrap();
+        assert_eq!(backend.adbc_library_name(), Some("adbc_driver_flightsql"));
+        assert_eq!(
+            backend.adbc_driver_entrypoint(),
+            Some(&b"AdbcDriverFlightSQLInit"[..])
+        );
+        assert!(super::backend(&config("uri: ':memory:'")).is_err());
+    }
+
+    #[test]
+    fn test_render_dialect_query() {
+        assert_eq!(
+            render_dialect_query(
+                "select name from tables where schema = '{schema}' and name = '{identifier}'",
+                &[("schema", "main"), ("identifier", "it's")],
+            ),
+            "select name from tables where schema = 'main' and name = 'it''s'"
+        );
+    }
+
+    #[test]
+    fn test_dialect_query() {
+        let dialect = dialect(&config(
+            "dialect:\n  list_schemas_sql: \"select schema_name from schemata where catalog = '{database}'\"",
+        ))
+        .unwrap();
+        assert_eq!(
+            dialect_query(&dialect, "list_schemas_sql", &[("database", "db")]).unwrap(),
+            "select schema_name from schemata where catalog = 'db'"
+        );
+        assert!(dialect_query(&dialect, "list_relations_sql", &[]).is_err());
+    }
+
+    #[test]
+    fn test_type_names_from_dialect() {
+        let dialect = dialect(&config(
+            "dialect:\n  quote_character: '`'\n  types:\n    text: varchar\n    integer: int64",
+        ))
+        .unwrap();
+        assert_eq!(dialect.quote_character(), '`');
+
+        let type_ops = GenericTypeOps::new(&dialect);
+        let format = |data_type: DataType| {
+            let mut out = String::new();
+            type_ops
+                .format_arrow_type_as_sql(&data_type, &mut out)
+                .unwrap();
+            out
+        };
+        assert_eq!(format(DataType::Utf8), "varchar");
+        assert_eq!(format(DataType::Int32), "int64");
+        assert_eq!(format(DataType::Boolean), "boolean");
+        assert_eq!(format(DataType::Date32), "date");
+    }
+}
@@ -0,0 +1,265 @@
+use crate::information_schema::InformationSchema;
+use crate::relation_object::{RelationObject, StaticBaseRelation};
+
+use dbt_common::{ErrorCode, FsResult, fs_err};
+use dbt_schemas::dbt_types::RelationType;
+use dbt_schemas::schemas::common::ResolvedQuoting;
+use dbt_schemas::schemas::relations::base::{
+    BaseRelation, BaseRelationProperties, Policy, RelationPath,
+};
+use minijinja::{Error as MinijinjaError, State, Value};
+
+use std::any::Any;
+use std::sync::Arc;
+
+/// A struct representing the generic relation type for use with static methods,
+/// with the quote character of the dialect
+#[derive(Clone, Debug)]
+pub struct GenericRelationType(pub ResolvedQuoting, pub char);
+
+impl StaticBaseRelation for GenericRelationType {
+    fn try_new(
+        &self,
+        database: Option<String>,
+        schema: Option<String>,
+        identifier: Option<String>,
+        relation_type: Option<RelationType>,
+        custom_quoting: Option<ResolvedQuoting>,
+    ) -> Result<Value, MinijinjaError> {
+        Ok(RelationObject::new(Arc::new(GenericRelation::new(
+            database,
+            schema,
+            identifier,
+            relation_type,
+            custom_quoting.unwrap_or(self.0),
+            self.1,
+        )))
+        .into_value())
+    }
+
+    fn get_adapter_type(&self) -> String {
+        "generic".to_string()
+    }
+}
+
+/// A relation object for the generic adapter
+#[derive(Clone, Debug)]
+pub struct GenericRelation {
+    /// The database, schema, and identifier of the relation
+    pub path: RelationPath,
+    /// The relation type
+    pub relation_type: Option<RelationType>,
+    /// Include policy
+    pub include_policy: Policy,
+    /// Quote policy
+    pub quote_policy: Policy,
+    /// Identifier quote character of the dialect
+    pub quote_character: char,
+}
+
+impl GenericRelation {
+    /// Creates a new generic relation
+    ///
+    /// Identifier length limits of the driver are not known, so none are enforced.
+    pub fn new(
+        database: Option<String>,
+        schema: Option<String>,
+        identifier: Option<String>,
+        relation_type: Option<RelationType>,
+        custom_quoting: ResolvedQuoting,
+        quote_character: char,
+    ) -> Self {
+        Self::new_with_policy(
+            RelationPath {
+                database,
+                schema,
+                identifier,
+            },
+            relation_type,
+            Policy::enabled(),
+            custom_quoting,
+            quote_character,
+        )
+    }
+
+    /// Creates a new generic relation with an explicit include policy
+    pub fn new_with_policy(
+        path: RelationPath,
+        relation_type: Option<RelationType>,
+        include_policy: Policy,
+        quote_policy: Policy,
+        quote_character: char,
+    ) -> Self {
+        Self {
+            path,
+            relation_type,
+            include_policy,
+            quote_policy,
+            quote_character,
+        }
+    }
+}
+
+impl BaseRelationProperties for GenericRelation {
+    fn include_policy(&self) -> Policy {
+        self.include_policy
+    }
+
+    fn quote_policy(&self) -> Policy {
+        self.quote_policy
+    }
+
+    fn quote_character(&self) -> char {
+        self.quote_character
+    }
+
+    fn get_database(&self) -> FsResult<String> {
+        self.path.database.clone().ok_or_else(|| {
+            fs_err!(
+                ErrorCode::InvalidConfig,
+                "database is required for generic relation",
+            )
+        })
+    }
+
+    fn get_schema(&self) -> FsResult<String> {
+        self.path.schema.clone().ok_or_else(|| {
+            fs_err!(
+                ErrorCode::InvalidConfig,
+                "schema is required for generic relation",
+            )
+        })
+    }
+
+    fn get_identifier(&self) -> FsResult<String> {
+        self.path.identifier.clone().ok_or_else(|| {
+            fs_err!(
+                ErrorCode::InvalidConfig,
+                "identifier is required for generic relation",
+            )
+        })
+    }
+}
+
+impl BaseRelation for GenericRelation {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn create_from(&self, _: &State, _: &[Value]) -> Result<Value, MinijinjaError> {
+        unimplemented!("generic relation creation from Jinja values")
+    }
+
+    fn database(&self) -> Value {
+        Value::from(self.path.database.clone())
+    }
+
+    fn schema(&self) -> Value {
+        Value::from(self.path.schema.clone())
+    }
+
+    fn identifier(&self) -> Value {
+        Value::from(self.path.identifier.clone())
+    }
+
+    fn relation_type(&self) -> Option<RelationType> {
+        self.relation_type
+    }
+
+    fn as_value(&self) -> Value {
+        RelationObject::new(Arc::new(self.clone())).into_value()
+    }
+
+    fn adapter_type(&self) -> Option<String> {
+        Some("generic".to_string())
+    }
+
+    fn include_inner(&self, include_policy: Policy) -> Result<Value, MinijinjaError> {
+        let relation = GenericRelation::new_with_policy(
+            self.path.clone(),
+            self.relation_type,
+            include_policy,
+            self.quote_policy,
+            self.quote_character,
+        );
+        Ok(relation.as_value())
+    }
+
+    fn normalize_component(&self, component: &str) -> String {
+        // The case folding rules of the driver are not known
+        component.to_string()
+    }
+
+    fn create_relation(
+        &self,
+        database: Option<String>,
+        schema: Option<String>,
+        identifier: Option<String>,
+        relation_type: Option<RelationType>,
+        custom_quoting: Policy,
+    ) -> Result<Arc<dyn BaseRelation>, MinijinjaError> {
+        Ok(Arc::new(GenericRelation::new(
+            database,
+            schema,
+            identifier,
+            relation_type,
+            custom_quoting,
+            self.quote_character,
+        )))
+    }
+
+    fn information_schema_inner(
+        &self,
+        database: Option<String>,
+        view_name: Option<&str>,
+    ) -> Result<Value, MinijinjaError> {
+        let result = InformationSchema::try_from_relation(database, view_name)?;
+        Ok(RelationObject::new(Arc::new(result)).into_value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbt_schemas::{dbt_types::RelationType, schemas::relations::DEFAULT_RESOLVED_QUOTING};
+
+    #[test]
+    fn test_try_new_via_static_base_relation() {
+        let relation = GenericRelationType(DEFAULT_RESOLVED_QUOTING, '"')
+            .try_new(
+                Some("d".to_string()),
+                Some("s".to_string()),
+                Some("i".to_string()),
+                Some(RelationType::Table),
+                Some(DEFAULT_RESOLVED_QUOTING),
+            )
+            .unwrap();
+
+        let relation = relation.downcast_object::<RelationObject>().unwrap();
+        assert_eq!(
+            relation.inner().render_self().unwrap().as_str().unwrap(),
+            "\"d\".\"s\".\"i\""
+        );
+        assert_eq!(relation.relation_type().unwrap(), RelationType::Table);
+        assert_eq!(relation.inner().adapter_type().unwrap(), "generic");
+    }
+
+    #[test]
+    fn test_quote_character_of_the_dialect() {
+        let relation = GenericRelationType(DEFAULT_RESOLVED_QUOTING, '`')
+            .try_new(
+                Some("d".to_string()),
+                Some("s".to_string()),
+                Some("i".to_string()),
+                None,
+                None,
+            )
+            .unwrap();
+
+        let relation = relation.downcast_object::<RelationObject>().unwrap();
+        assert_eq!(
+            relation.inner().render_self().unwrap().as_str().unwrap(),
+            "`d`.`s`.`i`"
+        );
+    }
+}
@@ -9,6 +9,7 @@ pub mod bridge_adapter;
 pub mod cache;
 pub mod catalog_relation;
 pub mod column;
+pub mod connection_pool;
 pub mod errors;
 pub mod factory;
 pub mod formatter;
@@ -22,8 +23,10 @@ pub mod query_cache;
 pub mod query_comment;
 pub mod query_ctx;
 pub mod record_and_replay;
+pub mod recording;
 pub mod render_constraint;
 pub mod response;
+pub mod retry;
 pub mod snapshots;
 /// Tokenizing and fuzzy diffing of SQL strings
 pub mod sql;
@@ -48,6 +51,10 @@ pub use statement::{StmtCancellationReport, TrackedStatement, cancel_all_tracked
 pub mod bigquery;
 /// Databricks adapter
 pub mod databricks;
+/// DuckDB adapter
+pub mod duckdb;
+/// Generic ADBC adapter
+pub mod generic;
 /// Parse adapter
 pub mod parse;
 /// Postgres adapter
@@ -58,6 +65,8 @@ pub mod redshift;
 pub mod salesforce;
 /// Snowflake adapter
 pub mod snowflake;
+/// Trino adapter
+pub mod trino;
 
 pub mod mock;
 
@@ -4,7 +4,7 @@ use arrow_array::StringArray;
 use dbt_common::adapter::AdapterType;
 use dbt_schemas::dbt_types::RelationType;
 use dbt_schemas::schemas::relations::base::BaseRelation;
-use dbt_xdbc::{Connection, QueryCtx};
+use dbt_xdbc::{Backend, Connection, QueryCtx, QueryParams};
 
 use crate::databricks::relation::DatabricksRelation;
 use crate::metadata::CatalogAndSchema;
@@ -12,13 +12,12 @@ use crate::record_batch_utils::get_column_values;
 use crate::{AdapterResult, AdapterTyping};
 
 // Reference: https://github.com/databricks/dbt-databricks/blob/92f1442faabe0fce6f0375b95e46ebcbfcea4c67/dbt/include/databricks/macros/adapters/metadata.sql
-pub fn list_relations(
-    adapter: &dyn AdapterTyping,
-    ctx: &QueryCtx,
-    conn: &'_ mut dyn Connection,
-    db_schema: &CatalogAndSchema,
-) -> AdapterResult<Vec<Arc<dyn BaseRelation>>> {
-    let sql = format!("
+fn list_relations_query(backend: Backend, db_schema: &CatalogAndSchema) -> (String, QueryParams) {
+    let mut params = QueryParams::new(backend);
+    let catalog = params.push(db_schema.resolved_catalog.as_str());
+    let schema = params.push(db_schema.resolved_schema.as_str());
+    let sql = format!(
+        "
 SELECT
     table_name,
     if(table_type IN ('EXTERNAL', 'MANAGED', 'MANAGED_SHALLOW_CLONE', 'EXTERNAL_SHALLOW_CLONE'), 'table', lower(table_type)) AS table_type,
@@ -37,12 +36,22 @@ SELECT
     NULL
     ) AS databricks_table_type
 FROM `system`.`information_schema`.`tables`
-WHERE table_catalog = '{}'
-    AND table_schema = '{}'",
-                            &db_schema.resolved_catalog,
-                            &db_schema.resolved_schema);
+WHERE table_catalog = {catalog}
+    AND table_schema = {schema}"
+    );
+    (sql, params)
+}
 
-    let batch = adapter.engine().execute(None, conn, ctx, &sql)?;
+pub fn list_relations(
+    adapter: &dyn AdapterTyping,
+    ctx: &QueryCtx,
+    conn: &'_ mut dyn Connection,
+    db_schema: &CatalogAndSchema,
+) -> AdapterResult<Vec<Arc<dyn BaseRelation>>> {
+    let (sql, params) = list_relations_query(adapter.engine().backend(), db_schema);
+    let batch = adapter
+        .engine()
+        .execute_with_params(None, conn, ctx, &sql, &params)?;
 
     if batch.num_rows() == 0 {
         return Ok(Vec::new());
@@ -81,3 +90,30 @@ WHERE table_catalog = '{}'
 
     Ok(relations)
 }
+
+#[cfg(test)]
+mod tests {
+    use dbt_xdbc::params::ParamValue;
+
+    use super::*;
+
+    #[test]
+    fn test_list_relations_binds_catalog_and_schema() {
+        let db_schema = CatalogAndSchema {
+            rendered_catalog: "`main`".to_string(),
+            rendered_schema: "`it's`".to_string(),
+            resolved_catalog: "main".to_string(),
+            resolved_schema: "it's".to_string(),
+        };
+        let (sql, params) = list_relations_query(Backend::Databricks, &db_schema);
+        assert!(!sql.contains("it's"));
+        assert!(sql.contains("WHERE table_catalog = ?\n    AND table_schema = ?"));
+        assert_eq!(
+            params.values(),
+            &[
+                ParamValue::Utf8("main".to_string()),
+                ParamValue::Utf8("it's".to_string())
+            ]
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use arrow_array::StringArray;
+use dbt_schemas::dbt_types::RelationType;
+use dbt_schemas::schemas::relations::base::BaseRelation;
+use dbt_xdbc::{Backend, Connection, QueryCtx, QueryParams};
+
+use crate::duckdb::relation::DuckDBRelation;
+use crate::record_batch_utils::get_column_values;
+use crate::{AdapterResult, AdapterTyping};
+
+/// Reference: https://github.com/duckdb/dbt-duckdb/blob/master/dbt/include/duckdb/macros/adapters.sql
+fn list_relations_query(
+    backend: Backend,
+    db_schema: &super::CatalogAndSchema,
+) -> (String, QueryParams) {
+    let mut params = QueryParams::new(backend);
+    let sql = format!(
+        "select
+    table_catalog as database,
+    table_name as name,
+    table_schema as schema,
+    case
+    when table_type = 'VIEW' then 'view'
+    else 'table'
+    end as type
+from system.information_schema.tables
+where lower(table_schema) = lower({})
+and lower(table_catalog) = lower({})",
+        params.push(db_schema.resolved_schema.as_str()),
+        params.push(db_schema.resolved_catalog.as_str())
+    );
+    (sql, params)
+}
+
+pub fn list_relations(
+    adapter: &dyn AdapterTyping,
+    ctx: &QueryCtx,
+    conn: &'_ mut dyn Connection,
+    db_schema: &super::CatalogAndSchema,
+) -> AdapterResult<Vec<Arc<dyn BaseRelation>>> {
+    let (sql, params) = list_relations_query(adapter.engine().backend(), db_schema);
+    let batch = adapter
+        .engine()
+        .execute_with_params(None, conn, ctx, &sql, &params)?;
+
+    if batch.num_rows() == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut relations = Vec::new();
+
+    let table_name = get_column_values::<StringArray>(&batch, "name")?;
+    let database_name = get_column_values::<StringArray>(&batch, "database")?;
+    let schema_name = get_column_values::<StringArray>(&batch, "schema")?;
+    let table_type = get_column_values::<StringArray>(&batch, "type")?;
+
+    for i in 0..batch.num_rows() {
+        let table_name = table_name.value(i);
+        let database_name = database_name.value(i);
+        let schema_name = schema_name.value(i);
+        let table_type = table_type.value(i);
+
+        let relation = Arc::new(DuckDBRelation::new(
+            Some(database_name.to_string()),
+            Some(schema_name.to_string()),
+            Some(table_name.to_string()),
+            Some(RelationType::from(table_type)),
+            adapter.quoting(),
+        )) as Arc<dyn BaseRelation>;
+        relations.push(relation);
+    }
+
+    Ok(relations)
+}
+
+#[cfg(test)]
+mod tests {
+    use dbt_xdbc::params::ParamValue;
+
+    use super::*;
+    use crate::metadata::CatalogAndSchema;
+
+    #[test]
+    fn test_list_relations_binds_the_schema() {
+        let db_schema = CatalogAndSchema {
+            rendered_catalog: "\"main\"".to_string(),
+            rendered_schema: "\"it's\"".to_string(),
+            resolved_catalog: "main".to_string(),
+            resolved_schema: "it's".to_string(),
+        };
+        let (sql, params) = list_relations_query(Backend::DuckDB, &db_schema);
+        assert!(sql.contains("where lower(table_schema) = lower(?)"));
+        assert!(sql.contains("and lower(table_catalog) = lower(?)"));
+        assert!(!sql.contains("ilike"));
+        assert!(!sql.contains("it's"));
+        assert_eq!(
+            params.values(),
+            &[
+                ParamValue::Utf8("it's".to_string()),
+                ParamValue::Utf8("main".to_string())
+            ]
+        );
+    }
+}
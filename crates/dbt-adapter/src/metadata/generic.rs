@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use arrow_array::StringArray;
+use dbt_schemas::dbt_types::RelationType;
+use dbt_schemas::schemas::relations::base::BaseRelation;
+use dbt_xdbc::{Connection, QueryCtx};
+
+use crate::generic::relation::GenericRelation;
+use crate::generic::{dialect, dialect_query};
+use crate::record_batch_utils::get_column_values;
+use crate::{AdapterResult, AdapterTyping};
+
+/// Lists relations with the `list_relations_sql` query of the dialect
+/// declared in the profile.
+pub fn list_relations(
+    adapter: &dyn AdapterTyping,
+    ctx: &QueryCtx,
+    conn: &'_ mut dyn Connection,
+    db_schema: &super::CatalogAndSchema,
+) -> AdapterResult<Vec<Arc<dyn BaseRelation>>> {
+    let dialect = dialect(adapter.engine().get_config())?;
+    let sql = dialect_query(
+        &dialect,
+        "list_relations_sql",
+        &[
+            ("database", &db_schema.resolved_catalog),
+            ("schema", &db_schema.resolved_schema),
+        ],
+    )?;
+
+    let batch = adapter.engine().execute(None, conn, ctx, &sql)?;
+
+    if batch.num_rows() == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut relations = Vec::new();
+
+    let table_name = get_column_values::<StringArray>(&batch, "name")?;
+    let database_name = get_column_values::<StringArray>(&batch, "database")?;
+    let schema_name = get_column_values::<StringArray>(&batch, "schema")?;
+    let table_type = get_column_values::<StringArray>(&batch, "type")?;
+
+    for i in 0..batch.num_rows() {
+        let relation = Arc::new(GenericRelation::new(
+            Some(database_name.value(i).to_string()),
+            Some(schema_name.value(i).to_string()),
+            Some(table_name.value(i).to_string()),
+            Some(RelationType::from(table_type.value(i))),
+            adapter.quoting(),
+            dialect.quote_character(),
+        )) as Arc<dyn BaseRelation>;
+        relations.push(relation);
+    }
+
+    Ok(relations)
+}
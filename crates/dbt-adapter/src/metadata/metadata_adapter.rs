@@ -117,6 +117,7 @@ pub trait MetadataAdapter: TypedBaseAdapter + Send + Sync {
         parent_map: &BTreeMap<String, Vec<String>>,
     ) -> Vec<Arc<dyn BaseRelation>> {
         let adapter_type = resolved_state.adapter_type;
+        let quote_character = self.quote_character();
         let mut relations: Vec<Arc<dyn BaseRelation>> = Vec::new();
         let mut executed_unique_ids = run_stats
             .stats
@@ -147,6 +148,7 @@ pub trait MetadataAdapter: TypedBaseAdapter + Send + Sync {
                     Some(node.alias()),
                     None,
                     node.quoting(),
+                    quote_character,
                 )
                 .expect("Failed to create relations from nodes");
                 relations.push(relation);
@@ -162,6 +164,7 @@ pub trait MetadataAdapter: TypedBaseAdapter + Send + Sync {
                     Some(node.alias()),
                     None,
                     node.quoting(),
+                    quote_character,
                 )
                 .expect("Failed to create relations from nodes");
                 relations.push(relation);
@@ -177,6 +180,7 @@ pub trait MetadataAdapter: TypedBaseAdapter + Send + Sync {
                     Some(node.alias()),
                     None,
                     node.quoting(),
+                    quote_character,
                 )
                 .expect("Failed to create relations from nodes");
                 relations.push(relation);
@@ -192,6 +196,7 @@ pub trait MetadataAdapter: TypedBaseAdapter + Send + Sync {
                     Some(node.alias()),
                     None,
                     node.quoting(),
+                    quote_character,
                 )
                 .expect("Failed to create relations from nodes");
                 relations.push(relation);
@@ -230,7 +235,8 @@ pub fn create_schemas_if_not_exists(
             schema.clone(),
             None,
             None,
-            adapter.quoting()
+            adapter.quoting(),
+            adapter.quote_character(),
         )?;
         let res =
         match execute_macro(state, &[mock_relation.as_value()], "create_schema") {
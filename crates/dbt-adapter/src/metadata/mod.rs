@@ -17,13 +17,17 @@ use dbt_schemas::schemas::{
     common::ResolvedQuoting,
     relations::base::{BaseRelation, BaseRelationProperties},
 };
+use dbt_xdbc::QueryParams;
 use minijinja::{State, Value};
 
 pub(crate) mod bigquery;
 pub(crate) mod databricks;
+pub(crate) mod duckdb;
+pub(crate) mod generic;
 pub(crate) mod metadata_adapter;
 pub(crate) mod redshift;
 pub mod snowflake; // XXX: temporarily pub before the refactor is complete
+pub(crate) mod trino;
 
 // Re-export `metadata_adapter` symbols
 // NOTE: this is temporary until all the metadata-releated code
@@ -34,7 +38,8 @@ pub const ARROW_FIELD_COMMENT_METADATA_KEY: &str = "comment";
 // XXX: use original_type_string() instead of querying for this constant
 pub const ARROW_FIELD_ORIGINAL_TYPE_METADATA_KEY: &str = "type_text";
 
-pub type WhereClausesByDb = BTreeMap<String, Vec<String>>;
+/// Schema and table names of relations, keyed by database
+pub type TablesByDb = BTreeMap<String, Vec<(String, String)>>;
 pub type RelationsByDb = BTreeMap<String, Vec<Arc<dyn BaseRelation>>>;
 
 /// The two ways of representing a relation in a pair.
@@ -267,31 +272,54 @@ pub fn get_input_schema_database_and_table(
     Ok((input_schema, database.to_owned(), input_table))
 }
 
-/// Builds and returns ([WhereClausesByDb], [RelationsByDb]) from a list of [BaseRelation]
-/// [WhereClausesByDb] maps databases to statements that select their schema+tables in the relation
+/// Builds and returns ([TablesByDb], [RelationsByDb]) from a list of [BaseRelation]
+/// [TablesByDb] maps databases to the schema and table names of their relations
 /// [RelationsByDb] keys the database to the cloned [BaseRelation]
 /// We expect a fqn from the relation in format <database>.<schema>.<table>
 pub fn build_relation_clauses(
     relations: &[Arc<dyn BaseRelation>],
-) -> AdapterResult<(WhereClausesByDb, RelationsByDb)> {
-    // Build the where clause for all relations grouped by databases
-    let mut where_clauses_by_database = BTreeMap::new();
+) -> AdapterResult<(TablesByDb, RelationsByDb)> {
+    // Group the schema and table names of all relations by databases
+    let mut tables_by_database = BTreeMap::new();
     let mut relations_by_database = BTreeMap::new();
     for relation in relations {
         let (input_schema, database, input_table) = get_input_schema_database_and_table(relation)?;
 
-        where_clauses_by_database
+        tables_by_database
             .entry(database.to_owned())
             .or_insert_with(Vec::new)
-            .push(format!(
-                "table_schema = '{input_schema}' and table_name = '{input_table}'"
-            ));
+            .push((input_schema, input_table));
         relations_by_database
             .entry(database.to_owned())
             .or_insert_with(Vec::new)
             .push(relation.clone());
     }
-    Ok((where_clauses_by_database, relations_by_database))
+    Ok((tables_by_database, relations_by_database))
+}
+
+/// Builds a where clause selecting any of the given tables, with their names bound as
+/// parameters rather than spliced into the SQL text.
+///
+/// `clause` builds the condition for one table from the placeholders of its schema and name.
+pub fn tables_where_clause(
+    params: &mut QueryParams,
+    tables: &[(String, String)],
+    clause: impl Fn(&str, &str) -> String,
+) -> String {
+    tables
+        .iter()
+        .map(|(schema, table)| {
+            let schema = params.push(schema.as_str());
+            let table = params.push(table.as_str());
+            clause(&schema, &table)
+        })
+        .collect::<Vec<_>>()
+        .join(" OR ")
+}
+
+/// The condition selecting a table in `information_schema.tables`
+pub fn information_schema_table_clause(schema: &str, table: &str) -> String {
+    format!("(table_schema = {schema} and table_name = {table})")
 }
 
 pub fn find_matching_relation(
@@ -476,6 +504,8 @@ impl BaseRelation for MockBaseRelation {
 mod tests {
     use super::*;
     use dbt_test_primitives::assert_contains;
+    use dbt_xdbc::Backend;
+    use dbt_xdbc::params::ParamValue;
 
     #[test]
     fn test_build_relation_clauses() {
@@ -497,20 +527,20 @@ mod tests {
             )) as Arc<dyn BaseRelation>,
         ];
 
-        let (where_clauses, relations_by_db) = build_relation_clauses(&relations).unwrap();
+        let (tables, relations_by_db) = build_relation_clauses(&relations).unwrap();
 
-        // Test where clauses
-        assert_eq!(where_clauses.len(), 2);
+        // Test tables
+        assert_eq!(tables.len(), 2);
         assert_eq!(
-            where_clauses.get("\"db1\"").unwrap(),
+            tables.get("\"db1\"").unwrap(),
             &vec![
-                "table_schema = 'schema1' and table_name = 'table1'",
-                "table_schema = 'schema2' and table_name = 'table2'"
+                ("schema1".to_string(), "table1".to_string()),
+                ("schema2".to_string(), "table2".to_string())
             ]
         );
         assert_eq!(
-            where_clauses.get("\"db2\"").unwrap(),
-            &vec!["table_schema = 'schema1' and table_name = 'table3'"]
+            tables.get("\"db2\"").unwrap(),
+            &vec![("schema1".to_string(), "table3".to_string())]
         );
 
         // Test relations by database
@@ -519,6 +549,30 @@ mod tests {
         assert_eq!(relations_by_db.get("\"db2\"").unwrap().len(), 1);
     }
 
+    #[test]
+    fn test_tables_where_clause_binds_names() {
+        let tables = vec![
+            ("schema1".to_string(), "table1".to_string()),
+            ("it's".to_string(), "x') or 1=1 --".to_string()),
+        ];
+
+        let mut params = QueryParams::new(Backend::Postgres);
+        let clause = tables_where_clause(&mut params, &tables, information_schema_table_clause);
+        assert_eq!(
+            clause,
+            "(table_schema = $1 and table_name = $2) OR (table_schema = $3 and table_name = $4)"
+        );
+        assert_eq!(
+            params.values()[3],
+            ParamValue::Utf8("x') or 1=1 --".to_string())
+        );
+
+        let mut params = QueryParams::new(Backend::Snowflake);
+        let clause = tables_where_clause(&mut params, &tables, information_schema_table_clause);
+        assert!(!clause.contains("it's"));
+        assert_eq!(params.len(), 4);
+    }
+
     #[test]
     fn test_build_relation_clauses_invalid_fqn() {
         let relations = vec![Arc::new(MockBaseRelation::new(
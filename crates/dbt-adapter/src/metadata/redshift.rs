@@ -3,19 +3,20 @@ use std::sync::Arc;
 use arrow_array::StringArray;
 use dbt_schemas::dbt_types::RelationType;
 use dbt_schemas::schemas::relations::base::BaseRelation;
-use dbt_xdbc::{Connection, QueryCtx};
+use dbt_xdbc::{Backend, Connection, QueryCtx, QueryParams};
 
 use crate::record_batch_utils::get_column_values;
 use crate::redshift::relation::RedshiftRelation;
 use crate::{AdapterResult, AdapterTyping};
 
 /// Reference: https://github.com/dbt-labs/dbt-adapters/blob/87e81a47baa11c312003377091a9efc0ab72d88e/dbt-redshift/src/dbt/include/redshift/macros/adapters.sql#L226
-pub fn list_relations(
-    adapter: &dyn AdapterTyping,
-    ctx: &QueryCtx,
-    conn: &'_ mut dyn Connection,
+fn list_relations_query(
+    backend: Backend,
     db_schema: &super::CatalogAndSchema,
-) -> AdapterResult<Vec<Arc<dyn BaseRelation>>> {
+) -> (String, QueryParams) {
+    let mut params = QueryParams::new(backend);
+    let tables_schema = params.push(db_schema.resolved_schema.as_str());
+    let views_schema = params.push(db_schema.resolved_schema.as_str());
     let sql = format!(
         "select
     table_catalog as database,
@@ -23,7 +24,7 @@ pub fn list_relations(
     table_schema as schema,
     'table' as type
 from information_schema.tables
-where table_schema ilike '{}'
+where table_schema ilike {tables_schema}
 and table_type = 'BASE TABLE'
 union all
 select
@@ -36,11 +37,21 @@ select
     else 'view'
     end as type
 from information_schema.views
-where table_schema ilike '{}'",
-        &db_schema.resolved_schema, &db_schema.resolved_schema
+where table_schema ilike {views_schema}"
     );
+    (sql, params)
+}
 
-    let batch = adapter.engine().execute(None, conn, ctx, &sql)?;
+pub fn list_relations(
+    adapter: &dyn AdapterTyping,
+    ctx: &QueryCtx,
+    conn: &'_ mut dyn Connection,
+    db_schema: &super::CatalogAndSchema,
+) -> AdapterResult<Vec<Arc<dyn BaseRelation>>> {
+    let (sql, params) = list_relations_query(adapter.engine().backend(), db_schema);
+    let batch = adapter
+        .engine()
+        .execute_with_params(None, conn, ctx, &sql, &params)?;
 
     if batch.num_rows() == 0 {
         return Ok(Vec::new());
@@ -72,3 +83,33 @@ where table_schema ilike '{}'",
 
     Ok(relations)
 }
+
+#[cfg(test)]
+mod tests {
+    use dbt_xdbc::params::ParamValue;
+
+    use super::*;
+    use crate::metadata::CatalogAndSchema;
+
+    #[test]
+    fn test_list_relations_binds_the_schema() {
+        let hostile = "x' or '1'='1";
+        let db_schema = CatalogAndSchema {
+            rendered_catalog: "\"dev\"".to_string(),
+            rendered_schema: format!("\"{hostile}\""),
+            resolved_catalog: "dev".to_string(),
+            resolved_schema: hostile.to_string(),
+        };
+        let (sql, params) = list_relations_query(Backend::Redshift, &db_schema);
+        assert!(!sql.contains(hostile));
+        assert!(sql.contains("table_schema ilike $1"));
+        assert!(sql.contains("table_schema ilike $2"));
+        assert_eq!(
+            params.values(),
+            &[
+                ParamValue::Utf8(hostile.to_string()),
+                ParamValue::Utf8(hostile.to_string())
+            ]
+        );
+    }
+}
@@ -78,6 +78,11 @@ fn build_relations_from_show_objects(
     Ok(relations)
 }
 
+// SHOW commands do not take bind variables, so values are escaped as string literals instead
+fn show_string_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
 pub fn list_relations(
     adapter: &dyn AdapterTyping,
     ctx: &QueryCtx,
@@ -94,7 +99,7 @@ pub fn list_relations(
             db_schema,
             limit_size,
             from_name
-                .map(|name| format!(" FROM '{name}'"))
+                .map(|name| format!(" FROM {}", show_string_literal(&name)))
                 .unwrap_or_default()
         );
         let batch = adapter.engine().execute(None, conn, ctx, &sql)?;
@@ -123,3 +128,15 @@ pub fn list_relations(
     }
     Ok(relations)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_show_string_literal() {
+        assert_eq!(show_string_literal("T1"), "'T1'");
+        assert_eq!(show_string_literal("it's"), "'it\\'s'");
+        assert_eq!(show_string_literal("x\\' --"), "'x\\\\\\' --'");
+    }
+}
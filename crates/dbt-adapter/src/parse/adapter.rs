@@ -154,6 +154,7 @@ impl ParseAdapter {
             Some(identifier.to_string()),
             None,
             self.engine().quoting(),
+            self.quote_character(),
         )?
         .as_value();
 
@@ -851,6 +852,10 @@ impl BaseAdapter for ParseAdapter {
         unimplemented!("clean_sql")
     }
 
+    fn dialect_query(&self, _args: &[Value]) -> Result<Value, MinijinjaError> {
+        Ok(empty_string_value())
+    }
+
     // TODO(jason): We should probably capture any manual user engagement with the cache
     // and use this knowledge for our cache hydration
     fn cache_added(
@@ -1,15 +1,20 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 use scc::HashMap as SccHashMap;
-use std::hash::{DefaultHasher, Hash, Hasher};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use adbc_core::error::{Error as AdbcError, Result as AdbcResult, Status as AdbcStatus};
 use adbc_core::options::{OptionStatement, OptionValue};
 use arrow::array::{RecordBatch, RecordBatchIterator, RecordBatchReader};
 use arrow_schema::{ArrowError, Field, Schema};
+use dbt_common::adapter::AdapterType;
+use dbt_xdbc::bigquery::QUERY_LABELS;
 use dbt_xdbc::query_ctx::ExecutionPhase;
 use dbt_xdbc::{QueryCtx, Statement};
 
@@ -17,11 +22,57 @@ use parquet::arrow::ArrowWriter;
 use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
 use parquet::file::properties::WriterProperties;
 
+use crate::config::AdapterConfig;
+use crate::record_batch_utils::format_bound_params;
 use crate::sql::normalize::strip_sql_comments;
 
+/// Name of the query cache directory in the target directory
+pub const QUERY_CACHE_DIR_NAME: &str = "query_cache";
+
+/// Name of the index of the cached results, in the cache root
+pub const QUERY_CACHE_INDEX_FILE_NAME: &str = "index.json";
+
+/// Name of the file locked while the index is written, in the cache root
+const QUERY_CACHE_INDEX_LOCK_FILE_NAME: &str = "index.json.lock";
+
+/// How long a cached result is served by default
+pub const DEFAULT_QUERY_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Default bound on the total size of the cached results (1 GiB)
+pub const DEFAULT_QUERY_CACHE_MAX_SIZE: u64 = 1 << 30;
+
+/// Version of the index format. An index of another version is discarded.
+const QUERY_CACHE_INDEX_VERSION: u32 = 1;
+
+/// Profile fields that decide what a query sees, and so are part of the cache key.
+/// Credentials are left out on purpose.
+const SESSION_SETTING_KEYS: &[&str] = &[
+    "host",
+    "port",
+    "user",
+    "account",
+    "database",
+    "dbname",
+    "catalog",
+    "schema",
+    "dataset",
+    "project",
+    "location",
+    "warehouse",
+    "role",
+    "http_path",
+    "path",
+];
+
+/// Statement options that change from one invocation to the next without changing the
+/// result, e.g. BigQuery job labels carrying the invocation id.
+const IGNORED_STATEMENT_OPTIONS: &[&str] = &[QUERY_LABELS];
+
 #[derive(Default, Clone)]
 pub enum QueryCacheMode {
+    /// Serve cached results, but never write new ones
     Read,
+    /// Always query the warehouse and refresh the cached results
     Write,
     #[default]
     ReadWrite,
@@ -39,58 +90,40 @@ pub trait QueryCache: Send + Sync {
 pub struct QueryCacheStatement {
     query_cache_config: Arc<QueryCacheConfig>,
     counters: Arc<SccHashMap<String, usize>>,
+    index: Arc<Mutex<QueryCacheIndex>>,
     inner_stmt: Box<dyn Statement>,
     query_ctx: QueryCtx,
     sql: String,
+    // Statement options set so far, part of the cache key
+    options: BTreeMap<String, String>,
+    // Parameters bound to the statement, passed on to the inner statement once its query is set
+    params: Option<RecordBatch>,
 }
 
 impl QueryCacheStatement {
-    fn construct_output_dir(&self, node_id: &str) -> PathBuf {
-        self.query_cache_config.root_path.join(node_id)
-    }
-
-    fn construct_output_file_name(&self, node_id: &str, hash: &str, num: usize) -> PathBuf {
-        self.construct_output_dir(node_id)
-            .join(format!("{hash}_{num}.parquet"))
-    }
-
-    fn compute_sql_hash(&self) -> String {
+    fn compute_cache_key(&self) -> String {
         let sql = if self.sql.is_empty() {
             "none"
         } else {
             &self.sql
         };
-        let sql = normalize_sql_for_comparison(sql);
-        let mut hasher = DefaultHasher::new();
-        sql.hash(&mut hasher);
-        let hash = hasher.finish();
-        format!("{hash:x}")[..8.min(format!("{hash:x}").len())].to_string()
-    }
-
-    fn compute_file_index(&self, node_id: &str, phase: &ExecutionPhase, cache_key: &str) -> usize {
-        // If the phase is analyze, we need to find the max file index for the given cache_key
-        if matches!(phase, ExecutionPhase::Analyze) {
-            let output_dir = self.construct_output_dir(node_id);
-            // List all files in the directory prefixed by the cache_key, find the max file_index suffix
-            if let Ok(files) = std::fs::read_dir(output_dir)
-                && let Some(max_index) = files
-                    .filter_map(|entry| entry.ok())
-                    .filter(|entry| entry.file_name().to_str().unwrap().starts_with(cache_key))
-                    .map(|entry| {
-                        entry
-                            .file_name()
-                            .to_str()
-                            .unwrap()
-                            .split("_")
-                            .nth(1)
-                            .unwrap()
-                            .parse::<usize>()
-                            .unwrap()
-                    })
-                    .max()
-            {
-                return max_index;
-            }
+        compute_cache_key(&self.query_cache_config.key_scope, &self.options, sql)
+    }
+
+    fn compute_ordinal(&self, node_id: &str, phase: &ExecutionPhase, cache_key: &str) -> usize {
+        // If the phase is analyze, reuse the entry of the last execution of this query by the node
+        if matches!(phase, ExecutionPhase::Analyze)
+            && let Some(max_ordinal) = self
+                .index
+                .lock()
+                .unwrap()
+                .entries
+                .values()
+                .filter(|entry| entry.node_id == node_id && entry.key == cache_key)
+                .map(|entry| entry.ordinal)
+                .max()
+        {
+            return max_ordinal;
         }
         // If the phase is not analyze (or no match exists), we need to increment the counter for the given node_id
         // This is safe because analyze always happens after render, so we will only ever add a cache entry, and reuse in the next analyze
@@ -123,10 +156,11 @@ impl QueryCacheStatement {
         }
     }
 
+    /// Writes the result to `parquet_path` and returns it, along with the size of the file.
     fn write_cache<'a>(
         parquet_path: &Path,
         reader: &mut Box<dyn RecordBatchReader + Send + 'a>,
-    ) -> AdbcResult<Box<dyn RecordBatchReader + Send + 'a>> {
+    ) -> AdbcResult<(Box<dyn RecordBatchReader + Send + 'a>, u64)> {
         std::fs::create_dir_all(parquet_path.parent().unwrap())
             .map_err(|e| from_io_error(e, Some(parquet_path.parent().unwrap())))?;
         let schema = reader.schema();
@@ -141,37 +175,25 @@ impl QueryCacheStatement {
             writer.write(batch).map_err(from_parquet_error)?;
         }
         writer.close().map_err(from_parquet_error)?;
+        let size = std::fs::metadata(parquet_path)
+            .map_err(|e| from_io_error(e, Some(parquet_path)))?
+            .len();
         // re-construct the stream from the accumulated batches
         let results = batches
             .into_iter()
             .map(|batch| -> Result<RecordBatch, ArrowError> { Ok(batch) });
         let iterator = RecordBatchIterator::new(results, schema);
         let reader = Box::new(iterator);
-        Ok(reader)
-    }
-
-    fn check_ttl(&self, file_path: &Path) -> AdbcResult<bool> {
-        if let Some(ttl) = self.query_cache_config.ttl {
-            if let Ok(metadata) = std::fs::metadata(file_path) {
-                // The TTL is a duration (i.e. 12 hours), check if the file was modified within the TTL
-                if let Ok(modified) = metadata.modified() {
-                    if modified < std::time::SystemTime::now() - ttl {
-                        return Ok(false);
-                    }
-                } else {
-                    return Ok(false);
-                }
-            } else {
-                return Ok(false);
-            }
-        }
-        Ok(true)
+        Ok((reader, size))
     }
 }
 
 impl Statement for QueryCacheStatement {
     fn bind(&mut self, batch: RecordBatch) -> AdbcResult<()> {
-        self.inner_stmt.bind(batch)
+        self.options
+            .insert("params".to_string(), format_bound_params(&batch));
+        self.params = Some(batch);
+        Ok(())
     }
 
     fn bind_stream(&mut self, reader: Box<dyn RecordBatchReader + Send>) -> AdbcResult<()> {
@@ -180,6 +202,9 @@ impl Statement for QueryCacheStatement {
 
     fn execute<'a>(&'a mut self) -> AdbcResult<Box<dyn RecordBatchReader + Send + 'a>> {
         self.inner_stmt.set_sql_query(&self.query_ctx, &self.sql)?;
+        if let Some(params) = self.params.take() {
+            self.inner_stmt.bind(params)?;
+        }
         let (node_id, phase) = if let Some(node_id) = self.query_ctx.node_id()
             && let Some(phase) = self.query_ctx.phase()
             && self.query_cache_config.phases.contains(&phase)
@@ -189,37 +214,59 @@ impl Statement for QueryCacheStatement {
             return self.inner_stmt.execute();
         };
 
-        match self.query_cache_config.mode {
-            QueryCacheMode::Read | QueryCacheMode::Write => {
-                unimplemented!("QueryCacheMode::Read | QueryCacheMode::Write is not implemented")
+        // The entry is named by a hash of the query and suffixed by how many queries the node
+        // ran before it
+        let cache_key = self.compute_cache_key();
+        let ordinal = self.compute_ordinal(node_id, &phase, &cache_key);
+        let entry_path = format!("{node_id}/{cache_key}_{ordinal}.parquet");
+        let path = self.query_cache_config.root_path.join(&entry_path);
+        let now = SystemTime::now();
+
+        if !matches!(self.query_cache_config.mode, QueryCacheMode::Write) {
+            let hit = self.index.lock().unwrap().lookup(
+                &self.query_cache_config.root_path,
+                &entry_path,
+                self.query_cache_config.ttl,
+                now,
+            );
+            if hit && let Ok(reader) = self.read_cache(&path) {
+                return Ok(reader);
             }
-            QueryCacheMode::ReadWrite => {
-                // First, compute the file name by hashing the query and suffixing the index
-                let sql_hash = self.compute_sql_hash();
-                let index = self.compute_file_index(node_id, &phase, &sql_hash);
-                let path = self.construct_output_file_name(node_id, &sql_hash, index);
-                if path.exists() {
-                    if self.check_ttl(&path)? {
-                        let cache_read = self.read_cache(&path);
-                        return cache_read;
-                    } else {
-                        // Try to remove the parent directory if the file is stale
-                        let _ = std::fs::remove_dir_all(path.parent().unwrap());
-                    }
-                }
-                // Execute on the actual engine's Statement
-                let result = self.inner_stmt.execute();
-                // TODO: Add invalidation logic to ensure when a cache hit is not found, we invalidate downstreams (in Render Phase)
-                match result {
-                    Ok(mut reader) => QueryCacheStatement::write_cache(&path, &mut reader),
-                    Err(err) => {
-                        let err_msg = format!("{err}");
-                        Err(AdbcError::with_message_and_status(
-                            err_msg,
-                            AdbcStatus::Internal,
-                        ))
-                    }
+        }
+        // Execute on the actual engine's Statement
+        let result = self.inner_stmt.execute();
+        if matches!(self.query_cache_config.mode, QueryCacheMode::Read) {
+            return result;
+        }
+        // TODO: Add invalidation logic to ensure when a cache hit is not found, we invalidate downstreams (in Render Phase)
+        match result {
+            Ok(mut reader) => {
+                let (reader, size_bytes) = QueryCacheStatement::write_cache(&path, &mut reader)?;
+                let mut index = self.index.lock().unwrap();
+                index.insert(
+                    entry_path,
+                    QueryCacheEntry {
+                        node_id: node_id.to_string(),
+                        key: cache_key,
+                        ordinal,
+                        size_bytes,
+                        created_at_ms: unix_millis(now),
+                        last_accessed_at_ms: unix_millis(now),
+                        hits: 0,
+                    },
+                );
+                if let Some(max_size) = self.query_cache_config.max_size {
+                    index.evict_lru(&self.query_cache_config.root_path, max_size);
                 }
+                // The index is written once, by `QueryCacheImpl::flush`
+                Ok(reader)
+            }
+            Err(err) => {
+                let err_msg = format!("{err}");
+                Err(AdbcError::with_message_and_status(
+                    err_msg,
+                    AdbcStatus::Internal,
+                ))
             }
         }
     }
@@ -259,7 +306,11 @@ impl Statement for QueryCacheStatement {
     }
 
     fn set_option(&mut self, key: OptionStatement, value: OptionValue) -> AdbcResult<()> {
-        // TODO: Record options and then use those values when finding the file name
+        let name = key.as_ref();
+        if !IGNORED_STATEMENT_OPTIONS.contains(&name) {
+            self.options
+                .insert(format!("option.{name}"), format!("{value:?}"));
+        }
         self.inner_stmt.set_option(key, value)
     }
 }
@@ -268,7 +319,10 @@ pub struct QueryCacheConfig {
     mode: QueryCacheMode,
     root_path: PathBuf,
     ttl: Option<Duration>,
+    max_size: Option<u64>,
     phases: Vec<ExecutionPhase>,
+    // Everything besides the query that a cached result depends on
+    key_scope: BTreeMap<String, String>,
 }
 
 impl QueryCacheConfig {
@@ -282,24 +336,63 @@ impl QueryCacheConfig {
             mode,
             root_path,
             ttl,
+            max_size: None,
             phases,
+            key_scope: BTreeMap::new(),
         }
     }
+
+    /// Bound the total size of the cached results, evicting the least recently used ones.
+    pub fn with_max_size(mut self, max_size: Option<u64>) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Key the cached results by the adapter type, the target and the session settings
+    /// of the profile, so that results are never shared between warehouses or sessions.
+    pub fn with_key_scope(
+        mut self,
+        adapter_type: AdapterType,
+        target: &str,
+        config: dbt_serde_yaml::Mapping,
+    ) -> Self {
+        let config = AdapterConfig::new(config);
+        self.key_scope
+            .insert("adapter_type".to_string(), adapter_type.to_string());
+        self.key_scope
+            .insert("target".to_string(), target.to_string());
+        for key in SESSION_SETTING_KEYS {
+            if let Some(value) = config.get_string(key) {
+                self.key_scope
+                    .insert(format!("session.{key}"), value.into_owned());
+            }
+        }
+        self
+    }
 }
 
 pub struct QueryCacheImpl {
     config: Arc<QueryCacheConfig>,
     // We need to keep track of which index we are on per node id (NodeId, StatementCount)
     counters: Arc<SccHashMap<String, usize>>,
+    index: Arc<Mutex<QueryCacheIndex>>,
 }
 
 impl QueryCacheImpl {
     pub fn new(config: QueryCacheConfig) -> Self {
+        let index = QueryCacheIndex::load(&config.root_path);
         Self {
             config: Arc::new(config),
             counters: Arc::new(SccHashMap::new()),
+            index: Arc::new(Mutex::new(index)),
         }
     }
+
+    /// Write the index, and with it the hit statistics, to the cache root. Must be called
+    /// once the queries ran: nothing is written when the cache is dropped.
+    pub fn flush(&self) -> std::io::Result<()> {
+        self.index.lock().unwrap().save(&self.config.root_path)
+    }
 }
 
 impl QueryCache for QueryCacheImpl {
@@ -312,13 +405,321 @@ impl QueryCache for QueryCacheImpl {
         Box::new(QueryCacheStatement {
             query_cache_config: self.config.clone(),
             counters: self.counters.clone(),
+            index: self.index.clone(),
             inner_stmt: stmt,
             query_ctx: ctx,
             sql,
+            options: BTreeMap::new(),
+            params: None,
         })
     }
 }
 
+/// A cached query result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryCacheEntry {
+    pub node_id: String,
+    /// SHA-256 of the normalized query and everything else the result depends on
+    pub key: String,
+    /// How many times the node ran the query before, counting this one
+    pub ordinal: usize,
+    pub size_bytes: u64,
+    pub created_at_ms: u64,
+    pub last_accessed_at_ms: u64,
+    pub hits: u64,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct QueryCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    /// Entries removed because the TTL elapsed
+    pub expirations: u64,
+    /// Entries removed to stay within the size bound
+    pub evictions: u64,
+}
+
+/// What `QueryCacheIndex::prune` removed
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct QueryCachePruneSummary {
+    pub removed_entries: usize,
+    pub removed_files: usize,
+    pub removed_bytes: u64,
+}
+
+/// The index of the cached results, kept as JSON in the cache root
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QueryCacheIndex {
+    version: u32,
+    /// Entries by their path relative to the cache root
+    pub entries: BTreeMap<String, QueryCacheEntry>,
+    pub stats: QueryCacheStats,
+    /// Entries removed since the index was loaded, so that saving does not bring them back
+    #[serde(skip)]
+    removed: BTreeSet<String>,
+    /// The statistics as last loaded or saved, so that only what changed since is added to
+    /// the statistics on disk
+    #[serde(skip)]
+    saved_stats: QueryCacheStats,
+}
+
+impl Default for QueryCacheIndex {
+    fn default() -> Self {
+        Self {
+            version: QUERY_CACHE_INDEX_VERSION,
+            entries: BTreeMap::new(),
+            stats: QueryCacheStats::default(),
+            removed: BTreeSet::new(),
+            saved_stats: QueryCacheStats::default(),
+        }
+    }
+}
+
+impl QueryCacheIndex {
+    /// Load the index of the cache at `root`. A missing, unreadable or outdated index is
+    /// replaced by an empty one, leaving its files to `prune`.
+    pub fn load(root: &Path) -> Self {
+        std::fs::read(root.join(QUERY_CACHE_INDEX_FILE_NAME))
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<Self>(&bytes).ok())
+            .filter(|index| index.version == QUERY_CACHE_INDEX_VERSION)
+            .map(|mut index| {
+                index.saved_stats = index.stats.clone();
+                index
+            })
+            .unwrap_or_default()
+    }
+
+    /// Write the index to the cache at `root`, merged with the index found there, so that
+    /// processes sharing the cache keep each other's entries and statistics. Writers take
+    /// turns through a lock file, and the index is replaced by a rename so that readers never
+    /// see a partial write.
+    pub fn save(&mut self, root: &Path) -> std::io::Result<()> {
+        std::fs::create_dir_all(root)?;
+        // Unlocked when the file is closed
+        let lock_file = std::fs::File::create(root.join(QUERY_CACHE_INDEX_LOCK_FILE_NAME))?;
+        lock_file.lock()?;
+        self.merge(Self::load(root));
+
+        let mut tmp_file = tempfile::Builder::new()
+            .prefix(QUERY_CACHE_INDEX_FILE_NAME)
+            .suffix(".tmp")
+            .tempfile_in(root)?;
+        let mut writer = std::io::BufWriter::new(tmp_file.as_file_mut());
+        serde_json::to_writer(&mut writer, self)?;
+        writer.flush()?;
+        drop(writer);
+        tmp_file
+            .persist(root.join(QUERY_CACHE_INDEX_FILE_NAME))
+            .map_err(|e| e.error)?;
+        self.saved_stats = self.stats.clone();
+        self.removed.clear();
+        Ok(())
+    }
+
+    /// Merge the index saved by other processes since this one was loaded. Entries removed
+    /// here stay removed, an entry rewritten by both keeps the latest result, and the
+    /// statistics gathered here are added to the saved ones.
+    fn merge(&mut self, saved: Self) {
+        for (entry_path, saved_entry) in saved.entries {
+            if self.removed.contains(&entry_path) {
+                continue;
+            }
+            match self.entries.get_mut(&entry_path) {
+                Some(entry) => {
+                    let last_accessed_at_ms = entry
+                        .last_accessed_at_ms
+                        .max(saved_entry.last_accessed_at_ms);
+                    let hits = entry.hits.max(saved_entry.hits);
+                    if saved_entry.created_at_ms > entry.created_at_ms {
+                        *entry = saved_entry;
+                    }
+                    entry.last_accessed_at_ms = last_accessed_at_ms;
+                    entry.hits = hits;
+                }
+                None => {
+                    self.entries.insert(entry_path, saved_entry);
+                }
+            }
+        }
+        let (ours, base) = (&self.stats, &self.saved_stats);
+        self.stats = QueryCacheStats {
+            hits: saved.stats.hits + ours.hits.saturating_sub(base.hits),
+            misses: saved.stats.misses + ours.misses.saturating_sub(base.misses),
+            expirations: saved.stats.expirations
+                + ours.expirations.saturating_sub(base.expirations),
+            evictions: saved.stats.evictions + ours.evictions.saturating_sub(base.evictions),
+        };
+    }
+
+    /// Total size of the cached results, in bytes
+    pub fn total_size(&self) -> u64 {
+        self.entries.values().map(|entry| entry.size_bytes).sum()
+    }
+
+    /// Record a lookup of the entry at `entry_path`, returning whether it can be served.
+    /// An expired entry is removed along with its file.
+    fn lookup(
+        &mut self,
+        root: &Path,
+        entry_path: &str,
+        ttl: Option<Duration>,
+        now: SystemTime,
+    ) -> bool {
+        let expired = match self.entries.get(entry_path) {
+            Some(entry) => is_expired(entry, ttl, now),
+            None => {
+                self.stats.misses += 1;
+                return false;
+            }
+        };
+        if expired || !root.join(entry_path).exists() {
+            if expired {
+                self.stats.expirations += 1;
+            }
+            self.remove(root, entry_path);
+            self.stats.misses += 1;
+            return false;
+        }
+        let entry = self.entries.get_mut(entry_path).unwrap();
+        entry.hits += 1;
+        entry.last_accessed_at_ms = unix_millis(now);
+        self.stats.hits += 1;
+        true
+    }
+
+    fn insert(&mut self, entry_path: String, entry: QueryCacheEntry) {
+        self.removed.remove(&entry_path);
+        self.entries.insert(entry_path, entry);
+    }
+
+    fn remove(&mut self, root: &Path, entry_path: &str) -> Option<QueryCacheEntry> {
+        let _ = std::fs::remove_file(root.join(entry_path));
+        self.removed.insert(entry_path.to_string());
+        self.entries.remove(entry_path)
+    }
+
+    /// Remove the least recently used entries until the cached results fit in `max_size`.
+    fn evict_lru(&mut self, root: &Path, max_size: u64) -> QueryCachePruneSummary {
+        let mut summary = QueryCachePruneSummary::default();
+        let mut total_size = self.total_size();
+        if total_size <= max_size {
+            return summary;
+        }
+        let mut by_last_access = self
+            .entries
+            .iter()
+            .map(|(path, entry)| (entry.last_accessed_at_ms, path.clone()))
+            .collect::<Vec<_>>();
+        by_last_access.sort();
+        for (_, entry_path) in by_last_access {
+            if total_size <= max_size {
+                break;
+            }
+            if let Some(entry) = self.remove(root, &entry_path) {
+                total_size -= entry.size_bytes;
+                self.stats.evictions += 1;
+                summary.removed_entries += 1;
+                summary.removed_bytes += entry.size_bytes;
+            }
+        }
+        summary
+    }
+
+    /// Remove the entries whose TTL elapsed or whose file is gone, then the least recently
+    /// used entries beyond `max_size`, then the files in the cache that have no entry.
+    pub fn prune(
+        &mut self,
+        root: &Path,
+        ttl: Option<Duration>,
+        max_size: Option<u64>,
+        now: SystemTime,
+    ) -> QueryCachePruneSummary {
+        let mut summary = QueryCachePruneSummary::default();
+        let stale = self
+            .entries
+            .iter()
+            .filter(|(path, entry)| is_expired(entry, ttl, now) || !root.join(path).exists())
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>();
+        for entry_path in stale {
+            if let Some(entry) = self.remove(root, &entry_path) {
+                if is_expired(&entry, ttl, now) {
+                    self.stats.expirations += 1;
+                }
+                summary.removed_entries += 1;
+                summary.removed_bytes += entry.size_bytes;
+            }
+        }
+        if let Some(max_size) = max_size {
+            let evicted = self.evict_lru(root, max_size);
+            summary.removed_entries += evicted.removed_entries;
+            summary.removed_bytes += evicted.removed_bytes;
+        }
+
+        // Results of earlier versions of the cache, or of runs that never wrote the index
+        let Ok(dirs) = std::fs::read_dir(root) else {
+            return summary;
+        };
+        for dir in dirs.filter_map(|entry| entry.ok()) {
+            let Ok(files) = std::fs::read_dir(dir.path()) else {
+                continue;
+            };
+            for file in files.filter_map(|entry| entry.ok()) {
+                let entry_path = format!(
+                    "{}/{}",
+                    dir.file_name().to_string_lossy(),
+                    file.file_name().to_string_lossy()
+                );
+                if file.path().extension().is_some_and(|ext| ext == "parquet")
+                    && !self.entries.contains_key(&entry_path)
+                {
+                    let size = file.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+                    if std::fs::remove_file(file.path()).is_ok() {
+                        summary.removed_files += 1;
+                        summary.removed_bytes += size;
+                    }
+                }
+            }
+            // Only succeeds for directories left empty
+            let _ = std::fs::remove_dir(dir.path());
+        }
+        summary
+    }
+}
+
+fn is_expired(entry: &QueryCacheEntry, ttl: Option<Duration>, now: SystemTime) -> bool {
+    ttl.is_some_and(|ttl| {
+        unix_millis(now).saturating_sub(entry.created_at_ms) > ttl.as_millis() as u64
+    })
+}
+
+fn unix_millis(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// The cache key of a query: a SHA-256 over the key scope, the statement options and the
+/// normalized SQL, with every field length-prefixed so that fields cannot run into each other.
+fn compute_cache_key(
+    key_scope: &BTreeMap<String, String>,
+    options: &BTreeMap<String, String>,
+    sql: &str,
+) -> String {
+    let mut hasher = Sha256::new();
+    let mut update = |field: &str| {
+        hasher.update((field.len() as u64).to_le_bytes());
+        hasher.update(field.as_bytes());
+    };
+    for (key, value) in key_scope.iter().chain(options) {
+        update(key);
+        update(value);
+    }
+    update(&normalize_sql_for_comparison(sql));
+    hex::encode(hasher.finalize())
+}
+
 fn from_io_error(e: std::io::Error, path: Option<&Path>) -> adbc_core::error::Error {
     let message = if let Some(path) = path {
         format!("IO error: {:?} ({:?})", e, path.display())
@@ -356,3 +757,162 @@ fn normalize_sql_for_comparison(sql: &str) -> String {
     let re = Regex::new(r"___\d+___").unwrap();
     re.replace_all(&normalized, "").to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(size_bytes: u64, created_at_ms: u64, last_accessed_at_ms: u64) -> QueryCacheEntry {
+        QueryCacheEntry {
+            node_id: "model.test.a".to_string(),
+            key: "0".repeat(64),
+            ordinal: 1,
+            size_bytes,
+            created_at_ms,
+            last_accessed_at_ms,
+            hits: 0,
+        }
+    }
+
+    fn write_entry(root: &Path, index: &mut QueryCacheIndex, path: &str, entry: QueryCacheEntry) {
+        let file_path = root.join(path);
+        std::fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+        std::fs::write(file_path, vec![0; entry.size_bytes as usize]).unwrap();
+        index.insert(path.to_string(), entry);
+    }
+
+    #[test]
+    fn test_cache_key_normalizes_sql_and_depends_on_scope() {
+        let scope = BTreeMap::from([("target".to_string(), "dev".to_string())]);
+        let options = BTreeMap::new();
+        let key = compute_cache_key(
+            &scope,
+            &options,
+            "select * from dbt_tmp_800c2fb4_a0ba_4708_a0b1_813316032bfb",
+        );
+        assert_eq!(key.len(), 64);
+        assert_eq!(
+            key,
+            compute_cache_key(
+                &scope,
+                &options,
+                "select * from dbt_tmp_11111111_2222_3333_4444_555555555555",
+            )
+        );
+
+        let prod = BTreeMap::from([("target".to_string(), "prod".to_string())]);
+        assert_ne!(
+            key,
+            compute_cache_key(&prod, &options, "select * from dbt_tmp_")
+        );
+        let options = BTreeMap::from([("option.timeout".to_string(), "Int(10)".to_string())]);
+        assert_ne!(
+            key,
+            compute_cache_key(&scope, &options, "select * from dbt_tmp_")
+        );
+    }
+
+    #[test]
+    fn test_lookup_counts_hits_and_expires_entries() {
+        let root = tempfile::tempdir().unwrap();
+        let now = UNIX_EPOCH + Duration::from_secs(1000);
+        let mut index = QueryCacheIndex::default();
+        write_entry(
+            root.path(),
+            &mut index,
+            "a/fresh_1.parquet",
+            entry(1, 990_000, 990_000),
+        );
+        write_entry(root.path(), &mut index, "a/stale_1.parquet", entry(1, 0, 0));
+
+        let ttl = Some(Duration::from_secs(60));
+        assert!(index.lookup(root.path(), "a/fresh_1.parquet", ttl, now));
+        assert!(!index.lookup(root.path(), "a/stale_1.parquet", ttl, now));
+        assert!(!index.lookup(root.path(), "a/unknown_1.parquet", ttl, now));
+
+        assert_eq!(index.entries["a/fresh_1.parquet"].hits, 1);
+        assert!(!root.path().join("a/stale_1.parquet").exists());
+        assert_eq!(index.stats.hits, 1);
+        assert_eq!(index.stats.misses, 2);
+        assert_eq!(index.stats.expirations, 1);
+    }
+
+    #[test]
+    fn test_prune_evicts_least_recently_used_and_orphans() {
+        let root = tempfile::tempdir().unwrap();
+        let now = UNIX_EPOCH + Duration::from_secs(1000);
+        let mut index = QueryCacheIndex::default();
+        write_entry(
+            root.path(),
+            &mut index,
+            "a/old_1.parquet",
+            entry(10, 0, 100),
+        );
+        write_entry(
+            root.path(),
+            &mut index,
+            "a/new_1.parquet",
+            entry(10, 0, 200),
+        );
+        std::fs::create_dir_all(root.path().join("b")).unwrap();
+        std::fs::write(root.path().join("b/1234abcd_1.parquet"), [0; 5]).unwrap();
+
+        let summary = index.prune(root.path(), None, Some(15), now);
+        assert_eq!(
+            summary,
+            QueryCachePruneSummary {
+                removed_entries: 1,
+                removed_files: 1,
+                removed_bytes: 15,
+            }
+        );
+        assert_eq!(
+            index.entries.keys().collect::<Vec<_>>(),
+            vec!["a/new_1.parquet"]
+        );
+        assert!(!root.path().join("b").exists());
+
+        index.save(root.path()).unwrap();
+        let loaded = QueryCacheIndex::load(root.path());
+        assert_eq!(loaded.total_size(), 10);
+        assert_eq!(loaded.stats.evictions, 1);
+    }
+
+    #[test]
+    fn test_concurrent_saves_keep_each_others_entries() {
+        let root = tempfile::tempdir().unwrap();
+        let now = UNIX_EPOCH + Duration::from_secs(1000);
+        let mut first = QueryCacheIndex::load(root.path());
+        let mut second = QueryCacheIndex::load(root.path());
+        write_entry(root.path(), &mut first, "a/first_1.parquet", entry(1, 0, 0));
+        write_entry(
+            root.path(),
+            &mut second,
+            "b/second_1.parquet",
+            entry(1, 0, 0),
+        );
+        assert!(first.lookup(root.path(), "a/first_1.parquet", None, now));
+        assert!(second.lookup(root.path(), "b/second_1.parquet", None, now));
+        assert!(!second.lookup(root.path(), "b/unknown_1.parquet", None, now));
+
+        first.save(root.path()).unwrap();
+        second.save(root.path()).unwrap();
+        let loaded = QueryCacheIndex::load(root.path());
+        assert_eq!(
+            loaded.entries.keys().collect::<Vec<_>>(),
+            vec!["a/first_1.parquet", "b/second_1.parquet"]
+        );
+        assert_eq!(loaded.stats.hits, 2);
+        assert_eq!(loaded.stats.misses, 1);
+
+        // Saving again adds nothing twice, and an entry removed here stays removed
+        second.remove(root.path(), "a/first_1.parquet");
+        second.save(root.path()).unwrap();
+        let loaded = QueryCacheIndex::load(root.path());
+        assert_eq!(
+            loaded.entries.keys().collect::<Vec<_>>(),
+            vec!["b/second_1.parquet"]
+        );
+        assert_eq!(loaded.stats.hits, 2);
+    }
+}
@@ -88,6 +88,11 @@ impl QueryCommentConfig {
         }
     }
 
+    /// The (unresolved) query comment
+    pub fn comment(&self) -> &str {
+        &self.comment
+    }
+
     /// Resolve query comment given current Jinja state.
     pub fn resolve_comment(&self, state: &State) -> Result<String, Error> {
         state
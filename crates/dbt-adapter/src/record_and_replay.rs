@@ -2,6 +2,11 @@ use crate::base_adapter::backend_of;
 use crate::config::AdapterConfig;
 use crate::errors::AdapterResult;
 use crate::query_comment::QueryCommentConfig;
+use crate::record_batch_utils::format_bound_params;
+use crate::recording::{
+    LEGACY_RECORDING_FORMAT_VERSION, RECORDING_FORMAT_VERSION, RecordingManifest,
+    recording_format_version, sha256,
+};
 use crate::sql_engine::SqlEngine;
 use crate::sql_types::TypeOps;
 use crate::stmt_splitter::StmtSplitter;
@@ -16,6 +21,7 @@ use dbt_common::adapter::AdapterType;
 use dbt_common::cancellation::CancellationToken;
 use dbt_common::tracing::emit::emit_warn_log_message;
 use dbt_schemas::schemas::common::ResolvedQuoting;
+use dbt_xdbc::pool::PoolStats;
 use dbt_xdbc::{Backend, Connection, QueryCtx, Statement};
 use minijinja::State;
 use once_cell::sync::Lazy;
@@ -31,7 +37,7 @@ use std::fmt;
 use std::fs::{self, File, create_dir_all, metadata};
 use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 // The reason this is global is that we might have multiple adapters
 // (we do not limit the number of adapters people can instantiate) and
@@ -61,16 +67,30 @@ fn checksum8(input: &str) -> String {
     format!("{hash:x}")[..8.min(format!("{hash:x}").len())].to_string()
 }
 
+// Legacy recordings name queries by a 32-bit hash, which collides and
+// changes with the standard library; later ones by a SHA-256 prefix.
+fn sql_checksum(input: &str, format_version: u32) -> String {
+    if format_version == LEGACY_RECORDING_FORMAT_VERSION {
+        checksum8(input)
+    } else {
+        sha256(cleanup_schema_name(input))[..16].to_string()
+    }
+}
+
 // Build a file name from the query context. In most cases this should
 // be node id followed by the number of times that node id appeared in
 // queries thus far. However, for pre-compile we do not have node id
 // and only sql content that we checksum and then append to it a
 // sequence number.
-fn compute_file_name(query_ctx: &QueryCtx, sql: Option<&str>) -> AdbcResult<String> {
+fn compute_file_name(
+    query_ctx: &QueryCtx,
+    sql: Option<&str>,
+    format_version: u32,
+) -> AdbcResult<String> {
     let id = match query_ctx.node_id() {
         Some(node_id) => node_id.to_owned(),
         None => match sql {
-            Some(sql) => checksum8(sql),
+            Some(sql) => sql_checksum(sql, format_version),
             None => {
                 return Err(AdbcError::with_message_and_status(
                     "Neither node id nor sql was set in the query context",
@@ -87,6 +107,15 @@ fn compute_file_name(query_ctx: &QueryCtx, sql: Option<&str>) -> AdbcResult<Stri
     Ok(file_name)
 }
 
+// Parameters bound to a statement are recorded after its SQL text, so that
+// executions of the same SQL with different parameters are told apart.
+fn sql_with_params<'a>(sql: &'a str, params: Option<&str>) -> Cow<'a, str> {
+    match params {
+        Some(params) => Cow::Owned(format!("{sql}\n-- params: {params}")),
+        None => Cow::Borrowed(sql),
+    }
+}
+
 fn compute_file_name_for_node_id(node_id: Option<&str>) -> String {
     let id = node_id.unwrap_or("unknown");
     let mut entry = COUNTERS.entry(id.to_string()).or_insert(0);
@@ -163,6 +192,31 @@ pub struct RecordEngineInner {
     path: PathBuf,
     /// Actual (wrapped) engine
     engine: Arc<SqlEngine>,
+    /// Manifest of the recording, written when the recording starts and again
+    /// after every recorded statement, so that a recording cut short by the
+    /// process exiting is still complete up to its last statement
+    manifest: Mutex<RecordingManifest>,
+}
+
+impl RecordEngineInner {
+    fn record_statement(&self, key: &str, introspection: bool) {
+        let mut manifest = self.manifest.lock().unwrap();
+        if let Err(e) = manifest.record(&self.path, key, introspection) {
+            warn_manifest_not_written(&self.path, &e);
+        }
+    }
+}
+
+fn warn_manifest_not_written(path: &Path, e: &AdbcError) {
+    emit_warn_log_message(
+        ErrorCode::IoError,
+        format!(
+            "Failed to write the manifest of the recording in {}: {}",
+            path.display(),
+            e.message
+        ),
+        None,
+    );
 }
 
 /// Engine used for recording db interaction; recording engine is
@@ -172,7 +226,24 @@ pub struct RecordEngine(Arc<RecordEngineInner>);
 
 impl RecordEngine {
     pub fn new(path: PathBuf, engine: Arc<SqlEngine>) -> Self {
-        let inner = RecordEngineInner { path, engine };
+        // Recording again into the same directory keeps the statements
+        // recorded before, as their files are kept too
+        let manifest = RecordingManifest::load(&path)
+            .ok()
+            .flatten()
+            .filter(|manifest| manifest.format_version == RECORDING_FORMAT_VERSION)
+            .unwrap_or_else(|| RecordingManifest::new(engine.adapter_type()));
+        if let Err(e) = create_dir_all(&path)
+            .map_err(|e| from_io_error(e, Some(&path)))
+            .and_then(|()| manifest.save(&path))
+        {
+            warn_manifest_not_written(&path, &e);
+        }
+        let inner = RecordEngineInner {
+            path,
+            engine,
+            manifest: Mutex::new(manifest),
+        };
         RecordEngine(Arc::new(inner))
     }
 
@@ -202,6 +273,10 @@ impl RecordEngine {
         self.0.engine.get_config()
     }
 
+    pub fn pool_stats(&self) -> Option<PoolStats> {
+        self.0.engine.pool_stats()
+    }
+
     pub fn adapter_type(&self) -> AdapterType {
         self.0.engine.adapter_type()
     }
@@ -246,6 +321,14 @@ impl Connection for RecordEngineConnection {
         self.1.cancel()
     }
 
+    fn invalidate(&mut self) {
+        self.1.invalidate()
+    }
+
+    fn reconnect(&mut self) -> AdbcResult<()> {
+        self.1.reconnect()
+    }
+
     fn commit(&mut self) -> AdbcResult<()> {
         self.1.commit()
     }
@@ -292,6 +375,8 @@ impl Connection for RecordEngineConnection {
                     .map_err(|e| from_serde_error(e, Some(&metadata_path)))?;
                 fs::write(&metadata_path, metadata_json)
                     .map_err(|e| from_io_error(e, Some(&metadata_path)))?;
+                self.0
+                    .record_statement(&format!("{file_name}.get_table_schema"), true);
 
                 Ok(schema)
             }
@@ -299,6 +384,8 @@ impl Connection for RecordEngineConnection {
                 let err_msg = format!("{err}");
                 fs::write(&err_path, err_msg.clone())
                     .map_err(|e| from_io_error(e, Some(&err_path)))?;
+                self.0
+                    .record_statement(&format!("{file_name}.get_table_schema"), true);
                 // do not create json or parquet, relay original error
                 Err(AdbcError::with_message_and_status(
                     err_msg,
@@ -318,6 +405,7 @@ struct RecordEngineStatement {
     inner_stmt: Box<dyn Statement>,
     query_ctx: Option<QueryCtx>,
     sql: Option<String>,
+    params: Option<String>,
 }
 
 impl RecordEngineStatement {
@@ -330,12 +418,14 @@ impl RecordEngineStatement {
             inner_stmt,
             query_ctx: None,
             sql: None,
+            params: None,
         }
     }
 }
 
 impl Statement for RecordEngineStatement {
     fn bind(&mut self, batch: RecordBatch) -> AdbcResult<()> {
+        self.params = Some(format_bound_params(&batch));
         self.inner_stmt.bind(batch)
     }
 
@@ -353,6 +443,8 @@ impl Statement for RecordEngineStatement {
             Some(sql) => sql,
             None => "none",
         };
+        let sql = sql_with_params(sql, self.params.as_deref());
+        let sql = sql.as_ref();
 
         // Execute on the actual engine's Statement
         let result = self.inner_stmt.execute();
@@ -360,7 +452,7 @@ impl Statement for RecordEngineStatement {
         let path = self.record_engine.path.clone();
         create_dir_all(&path).map_err(|e| from_io_error(e, Some(&path)))?;
 
-        let file_name = compute_file_name(&query_ctx, Some(sql))?;
+        let file_name = compute_file_name(&query_ctx, Some(sql), RECORDING_FORMAT_VERSION)?;
         let sql_path = path.join(format!("{file_name}.sql"));
         let err_path = path.join(format!("{file_name}.err"));
         let parquet_path = path.join(format!("{file_name}.parquet"));
@@ -382,6 +474,8 @@ impl Statement for RecordEngineStatement {
                     writer.write(batch).map_err(from_parquet_error)?;
                 }
                 writer.close().map_err(from_parquet_error)?;
+                self.record_engine
+                    .record_statement(&file_name, query_ctx.is_introspection());
                 // re-construct the stream from the accumulated batches
                 let results = batches
                     .into_iter()
@@ -394,6 +488,8 @@ impl Statement for RecordEngineStatement {
                 let err_msg = format!("{err}");
                 fs::write(&err_path, err_msg.clone())
                     .map_err(|e| from_io_error(e, Some(&err_path)))?;
+                self.record_engine
+                    .record_statement(&file_name, query_ctx.is_introspection());
                 // do not create json or parquet, relay original error
                 Err(AdbcError::with_message_and_status(
                     err_msg,
@@ -449,6 +545,8 @@ struct ReplayEngineInner {
     backend: Backend,
     /// Path to recordings
     path: PathBuf,
+    /// Format version of the recordings, or why they cannot be replayed
+    format_version: Result<u32, String>,
     /// Adapter config
     config: AdapterConfig,
     quoting: ResolvedQuoting,
@@ -480,10 +578,12 @@ impl ReplayEngine {
         type_ops: Box<dyn TypeOps>,
         token: CancellationToken,
     ) -> Self {
+        let format_version = recording_format_version(&path).map_err(|e| e.message);
         let inner = ReplayEngineInner {
             adapter_type,
             backend: backend_of(adapter_type),
             path,
+            format_version,
             config,
             quoting,
             stmt_splitter,
@@ -620,6 +720,7 @@ struct ReplayEngineStatement {
     replay_engine: Arc<ReplayEngineInner>,
     query_ctx: Option<QueryCtx>,
     sql: Option<String>,
+    params: Option<String>,
 }
 
 impl ReplayEngineStatement {
@@ -628,18 +729,19 @@ impl ReplayEngineStatement {
             replay_engine,
             query_ctx: None,
             sql: None,
+            params: None,
         }
     }
 }
 
-fn from_parquet_error(e: parquet::errors::ParquetError) -> adbc_core::error::Error {
+pub(crate) fn from_parquet_error(e: parquet::errors::ParquetError) -> adbc_core::error::Error {
     adbc_core::error::Error::with_message_and_status(
         format!("Parquet error: {e:?}"),
         adbc_core::error::Status::IO,
     )
 }
 
-fn from_io_error(e: std::io::Error, path: Option<&Path>) -> adbc_core::error::Error {
+pub(crate) fn from_io_error(e: std::io::Error, path: Option<&Path>) -> adbc_core::error::Error {
     let message = if let Some(path) = path {
         format!("IO error: {:?} ({:?})", e, path.display())
     } else {
@@ -648,7 +750,10 @@ fn from_io_error(e: std::io::Error, path: Option<&Path>) -> adbc_core::error::Er
     adbc_core::error::Error::with_message_and_status(message, adbc_core::error::Status::IO)
 }
 
-fn from_serde_error(e: serde_json::Error, path: Option<&Path>) -> adbc_core::error::Error {
+pub(crate) fn from_serde_error(
+    e: serde_json::Error,
+    path: Option<&Path>,
+) -> adbc_core::error::Error {
     let message = if let Some(path) = path {
         format!("Serde error: {:?} ({:?})", e, path.display())
     } else {
@@ -658,8 +763,9 @@ fn from_serde_error(e: serde_json::Error, path: Option<&Path>) -> adbc_core::err
 }
 
 impl Statement for ReplayEngineStatement {
-    fn bind(&mut self, _batch: RecordBatch) -> AdbcResult<()> {
-        todo!("ReplayEngineStatement::bind")
+    fn bind(&mut self, batch: RecordBatch) -> AdbcResult<()> {
+        self.params = Some(format_bound_params(&batch));
+        Ok(())
     }
 
     fn bind_stream(&mut self, _reader: Box<dyn RecordBatchReader + Send>) -> AdbcResult<()> {
@@ -676,9 +782,16 @@ impl Statement for ReplayEngineStatement {
             Some(sql) => sql,
             None => "none",
         };
+        let replay_sql = sql_with_params(replay_sql, self.params.as_deref());
+        let replay_sql = replay_sql.as_ref();
 
         let path = self.replay_engine.full_path();
-        let file_name = compute_file_name(&query_ctx, Some(replay_sql))?;
+        let format_version = self
+            .replay_engine
+            .format_version
+            .clone()
+            .map_err(|msg| AdbcError::with_message_and_status(msg, AdbcStatus::InvalidState))?;
+        let file_name = compute_file_name(&query_ctx, Some(replay_sql), format_version)?;
         let parquet_path = path.join(format!("{file_name}.parquet"));
         let sql_path = path.join(format!("{file_name}.sql"));
         let err_path = path.join(format!("{file_name}.err"));
@@ -686,18 +799,27 @@ impl Statement for ReplayEngineStatement {
         // Query has to match to the recorded one, otherwise we
         // have issues with ordering or recording
         if !fs::exists(&sql_path).map_err(|e| from_io_error(e, Some(&sql_path)))? {
-            panic!(
-                "Missing query file ({:?}) during replay. Query: {}",
-                &sql_path, replay_sql,
-            );
+            return Err(AdbcError::with_message_and_status(
+                format!(
+                    "No recording of this query in {} (expected {}). Query: {}",
+                    path.display(),
+                    sql_path.display(),
+                    replay_sql,
+                ),
+                AdbcStatus::NotFound,
+            ));
         }
         // dbt_tmp_800c2fb4_a0ba_4708_a0b1_813316032bfb
         let record_sql =
             fs::read_to_string(&sql_path).map_err(|e| from_io_error(e, Some(&sql_path)))?;
         if normalize_dbt_tmp_name(&record_sql) != normalize_dbt_tmp_name(replay_sql) {
-            panic!(
-                "Recorded query ({record_sql}) and actual query ({replay_sql}) do not match ({sql_path:?})"
-            );
+            return Err(AdbcError::with_message_and_status(
+                format!(
+                    "Recorded query ({record_sql}) and actual query ({replay_sql}) do not match ({})",
+                    sql_path.display()
+                ),
+                AdbcStatus::InvalidState,
+            ));
         }
 
         if err_path.exists() {
@@ -795,4 +917,139 @@ mod tests {
         let expected = "SELECT * FROM dbt_tmp_";
         assert_eq!(normalize_dbt_tmp_name(input), expected);
     }
+
+    #[test]
+    fn test_sql_checksum_depends_on_format_version() {
+        let sql = "select * from ___1700000000___schema.t";
+        assert_eq!(
+            sql_checksum(sql, LEGACY_RECORDING_FORMAT_VERSION),
+            checksum8(sql)
+        );
+        let checksum = sql_checksum(sql, RECORDING_FORMAT_VERSION);
+        assert_eq!(checksum.len(), 16);
+        assert_eq!(
+            checksum,
+            sql_checksum("select * from schema.t", RECORDING_FORMAT_VERSION)
+        );
+    }
+
+    #[test]
+    fn test_bound_params_are_part_of_the_recorded_sql() {
+        let sql = "select * from information_schema.tables where table_schema = $1";
+        assert_eq!(sql_with_params(sql, None), sql);
+        let recorded = sql_with_params(sql, Some("('it''s')"));
+        assert_eq!(recorded, format!("{sql}\n-- params: ('it''s')"));
+        assert_ne!(
+            sql_checksum(&recorded, RECORDING_FORMAT_VERSION),
+            sql_checksum(
+                &sql_with_params(sql, Some("('other')")),
+                RECORDING_FORMAT_VERSION
+            )
+        );
+    }
+
+    #[test]
+    fn test_replay_redacted_recording() {
+        use crate::query_comment::QueryCommentConfig;
+        use crate::recording::redact_recording;
+        use crate::sql_types::NaiveTypeOpsImpl;
+        use crate::stmt_splitter::NaiveStmtSplitter;
+        use arrow::array::{Array, StringArray};
+        use dbt_common::cancellation::never_cancels;
+
+        let node_id = "model.test.redacted_replay";
+        let statements = [
+            (
+                "select table_name as name from information_schema.tables",
+                "name",
+                "orders",
+            ),
+            (
+                "select email from analytics.customers",
+                "email",
+                "a@example.com",
+            ),
+        ];
+
+        let recording_dir = tempfile::tempdir().unwrap();
+        let path = recording_dir.path();
+        let mut keys = Vec::new();
+        for (i, (sql, column, value)) in statements.iter().enumerate() {
+            let key = format!("{node_id}-{i}");
+            fs::write(path.join(format!("{key}.sql")), sql).unwrap();
+            let schema = Arc::new(Schema::new(vec![Field::new(*column, DataType::Utf8, true)]));
+            let batch = RecordBatch::try_new(
+                schema.clone(),
+                vec![Arc::new(StringArray::from(vec![*value]))],
+            )
+            .unwrap();
+            let file = File::create(path.join(format!("{key}.parquet"))).unwrap();
+            let mut writer = ArrowWriter::try_new(file, schema, None).unwrap();
+            writer.write(&batch).unwrap();
+            writer.close().unwrap();
+            keys.push(key);
+        }
+        // Only the adapter's own catalog query is kept by the redaction
+        let mut manifest = RecordingManifest::new(AdapterType::Postgres);
+        for (i, key) in keys.iter().enumerate() {
+            manifest.record(path, key, i == 0).unwrap();
+        }
+        assert_eq!(redact_recording(path, path).unwrap(), 1);
+
+        let engine = ReplayEngine::new(
+            AdapterType::Postgres,
+            path.to_path_buf(),
+            AdapterConfig::default(),
+            ResolvedQuoting::default(),
+            Arc::new(NaiveStmtSplitter {}),
+            QueryCommentConfig::from_query_comment(None, AdapterType::Postgres, false),
+            Box::new(NaiveTypeOpsImpl::new(AdapterType::Postgres)),
+            never_cancels(),
+        );
+        let mut conn = engine.new_connection(None, None).unwrap();
+        let ctx = QueryCtx::default().with_node_id(node_id);
+        let replayed = statements
+            .iter()
+            .map(|(sql, _, _)| {
+                let mut stmt = conn.new_statement().unwrap();
+                stmt.set_sql_query(&ctx, sql).unwrap();
+                let batch = stmt.execute().unwrap().next().unwrap().unwrap();
+                batch
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<StringArray>()
+                    .unwrap()
+                    .value(0)
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(replayed, vec!["orders", "email_0"]);
+    }
+
+    #[test]
+    fn test_replay_without_recording_fails() {
+        use crate::query_comment::QueryCommentConfig;
+        use crate::sql_types::NaiveTypeOpsImpl;
+        use crate::stmt_splitter::NaiveStmtSplitter;
+        use dbt_common::cancellation::never_cancels;
+
+        let recording_dir = tempfile::tempdir().unwrap();
+        let engine = ReplayEngine::new(
+            AdapterType::Postgres,
+            recording_dir.path().to_path_buf(),
+            AdapterConfig::default(),
+            ResolvedQuoting::default(),
+            Arc::new(NaiveStmtSplitter {}),
+            QueryCommentConfig::from_query_comment(None, AdapterType::Postgres, false),
+            Box::new(NaiveTypeOpsImpl::new(AdapterType::Postgres)),
+            never_cancels(),
+        );
+        let mut conn = engine.new_connection(None, None).unwrap();
+        let mut stmt = conn.new_statement().unwrap();
+        let ctx = QueryCtx::default().with_node_id("model.test.never_recorded");
+        stmt.set_sql_query(&ctx, "select 1").unwrap();
+        let err = stmt.execute().err().unwrap();
+        assert_eq!(err.status, AdbcStatus::NotFound);
+        assert!(err.message.contains("No recording of this query"));
+    }
 }
@@ -9,6 +9,7 @@ use arrow::array::{
 };
 use arrow::datatypes::DataType;
 use arrow::record_batch::RecordBatch;
+use arrow::util::display::{ArrayFormatter, FormatOptions};
 
 pub fn extract_first_value_as_i64(batch: &RecordBatch) -> Option<i64> {
     let column = batch.column(0);
@@ -86,6 +87,43 @@ where
         .to_owned())
 }
 
+/// Render the parameters bound to a statement as SQL literals, one tuple per row,
+/// e.g. `('it''s', 10)`.
+///
+/// Used to tell apart executions of the same SQL text with different parameters.
+pub fn format_bound_params(batch: &RecordBatch) -> String {
+    let options = FormatOptions::default().with_null("NULL");
+    let formatters = batch
+        .columns()
+        .iter()
+        .map(|column| ArrayFormatter::try_new(column.as_ref(), &options))
+        .collect::<Result<Vec<_>, _>>();
+    let Ok(formatters) = formatters else {
+        return format!("{batch:?}");
+    };
+    (0..batch.num_rows())
+        .map(|row| {
+            let values = batch
+                .columns()
+                .iter()
+                .zip(&formatters)
+                .map(|(column, formatter)| {
+                    let value = formatter.value(row).to_string();
+                    if column.is_valid(row)
+                        && matches!(column.data_type(), DataType::Utf8 | DataType::LargeUtf8)
+                    {
+                        format!("'{}'", value.replace('\'', "''"))
+                    } else {
+                        value
+                    }
+                })
+                .collect::<Vec<_>>();
+            format!("({})", values.join(", "))
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -142,4 +180,24 @@ mod tests {
             "arrow_array::array::primitive_array::PrimitiveArray<arrow_array::types::Int32Type>"
         ));
     }
+
+    #[test]
+    fn test_format_bound_params() {
+        let schema = Schema::new(vec![
+            Field::new("", DataType::Utf8, true),
+            Field::new("", DataType::Int32, false),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(StringArray::from(vec![Some("x'); drop table t; --"), None])),
+                Arc::new(Int32Array::from(vec![1, 2])),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            format_bound_params(&batch),
+            "('x''); drop table t; --', 1), (NULL, 2)"
+        );
+    }
 }
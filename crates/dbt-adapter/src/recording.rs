@@ -0,0 +1,583 @@
+//! Manifest, redaction and diffing of the recordings written by the record engine.
+//!
+//! A recording is a directory with, for every recorded statement, the query
+//! (`<key>.sql`), its result (`<key>.parquet`) or error (`<key>.err`), and a
+//! `recording.json` manifest describing the format and every statement in it.
+
+use crate::record_and_replay::{from_io_error, from_parquet_error, from_serde_error};
+
+use adbc_core::error::{Error as AdbcError, Result as AdbcResult, Status as AdbcStatus};
+use arrow::array::{Array, ArrayRef, BooleanArray, Int32Array, Int64Array, StringArray};
+use arrow::compute::{can_cast_types, cast};
+use arrow::record_batch::RecordBatch;
+use arrow_schema::{ArrowError, DataType, Schema};
+use dbt_common::adapter::AdapterType;
+use parquet::arrow::ArrowWriter;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::file::properties::WriterProperties;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use similar::TextDiff;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fs::{self, File};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Name of the manifest in a recording directory
+pub const RECORDING_MANIFEST_FILE_NAME: &str = "recording.json";
+
+/// Version of the recordings written by this build
+pub const RECORDING_FORMAT_VERSION: u32 = 2;
+
+/// Version of the recordings written before the manifest existed, which name
+/// statements without a node id by a 32-bit hash of the query
+pub const LEGACY_RECORDING_FORMAT_VERSION: u32 = 1;
+
+// Suffixes of the files of a recorded statement
+const STATEMENT_FILE_SUFFIXES: &[&str] = &[".metadata.json", ".sql", ".parquet", ".err"];
+
+// Suffix of the statements recorded by `Connection::get_table_schema`
+const GET_TABLE_SCHEMA_SUFFIX: &str = ".get_table_schema";
+
+/// A recorded statement, as described by the manifest
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedStatement {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sql_sha256: Option<String>,
+    /// The columns of the result, as `name: type` pairs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result_sha256: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_sha256: Option<String>,
+    /// Whether an adapter issued the statement to read the catalog of the
+    /// warehouse, see [dbt_xdbc::QueryCtx::with_introspection]
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub introspection: bool,
+}
+
+impl RecordedStatement {
+    /// Describe the statement recorded under `key` in `dir` from its files.
+    pub fn read(dir: &Path, key: &str) -> AdbcResult<Self> {
+        let sql_sha256 = read_optional(&dir.join(format!("{key}.sql")))?.map(|sql| sha256(&sql));
+        let error_sha256 = read_optional(&dir.join(format!("{key}.err")))?.map(|err| sha256(&err));
+        let parquet_path = dir.join(format!("{key}.parquet"));
+        let (schema, result_sha256) = match read_optional(&parquet_path)? {
+            // An empty file stands for a statement without a result set
+            Some(bytes) if bytes.is_empty() => (Some(String::new()), Some(sha256(&bytes))),
+            Some(bytes) => {
+                let file =
+                    File::open(&parquet_path).map_err(|e| from_io_error(e, Some(&parquet_path)))?;
+                let builder =
+                    ParquetRecordBatchReaderBuilder::try_new(file).map_err(from_parquet_error)?;
+                (Some(format_schema(builder.schema())), Some(sha256(&bytes)))
+            }
+            None => (None, None),
+        };
+        Ok(Self {
+            sql_sha256,
+            schema,
+            result_sha256,
+            error_sha256,
+            introspection: false,
+        })
+    }
+}
+
+/// The manifest of a recording
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordingManifest {
+    pub format_version: u32,
+    pub adapter_type: AdapterType,
+    /// Whether the results were replaced by synthetic data
+    #[serde(default)]
+    pub redacted: bool,
+    /// The recorded statements by the name of their files, without suffix
+    #[serde(default)]
+    pub statements: BTreeMap<String, RecordedStatement>,
+}
+
+impl RecordingManifest {
+    pub fn new(adapter_type: AdapterType) -> Self {
+        Self {
+            format_version: RECORDING_FORMAT_VERSION,
+            adapter_type,
+            redacted: false,
+            statements: BTreeMap::new(),
+        }
+    }
+
+    /// Load the manifest of the recording in `dir`, if it has one.
+    pub fn load(dir: &Path) -> AdbcResult<Option<Self>> {
+        let path = dir.join(RECORDING_MANIFEST_FILE_NAME);
+        let Some(bytes) = read_optional(&path)? else {
+            return Ok(None);
+        };
+        let manifest: Self =
+            serde_json::from_slice(&bytes).map_err(|e| from_serde_error(e, Some(&path)))?;
+        Ok(Some(manifest))
+    }
+
+    /// Atomically write the manifest to the recording in `dir`.
+    pub fn save(&self, dir: &Path) -> AdbcResult<()> {
+        let path = dir.join(RECORDING_MANIFEST_FILE_NAME);
+        let tmp_path = dir.join(format!("{RECORDING_MANIFEST_FILE_NAME}.tmp"));
+        let json = serde_json::to_vec_pretty(self).map_err(|e| from_serde_error(e, Some(&path)))?;
+        fs::write(&tmp_path, json).map_err(|e| from_io_error(e, Some(&tmp_path)))?;
+        fs::rename(&tmp_path, &path).map_err(|e| from_io_error(e, Some(&path)))
+    }
+
+    /// Describe the statement recorded under `key` and write the manifest.
+    pub fn record(&mut self, dir: &Path, key: &str, introspection: bool) -> AdbcResult<()> {
+        let mut statement = RecordedStatement::read(dir, key)?;
+        statement.introspection = introspection;
+        self.statements.insert(key.to_string(), statement);
+        self.save(dir)
+    }
+}
+
+/// The format version of the recording in `dir`: the legacy version if it has
+/// no manifest, or an error if it was written by a newer build.
+pub fn recording_format_version(dir: &Path) -> AdbcResult<u32> {
+    match RecordingManifest::load(dir)? {
+        None => Ok(LEGACY_RECORDING_FORMAT_VERSION),
+        Some(manifest) if manifest.format_version > RECORDING_FORMAT_VERSION => {
+            Err(AdbcError::with_message_and_status(
+                format!(
+                    "The recording in {} has format version {}, but this build only reads up to version {}",
+                    dir.display(),
+                    manifest.format_version,
+                    RECORDING_FORMAT_VERSION
+                ),
+                AdbcStatus::InvalidState,
+            ))
+        }
+        Some(manifest) => Ok(manifest.format_version),
+    }
+}
+
+/// The statements of the recording in `dir`, from its manifest or, for legacy
+/// recordings, from its files.
+pub fn recorded_statements(dir: &Path) -> AdbcResult<BTreeMap<String, RecordedStatement>> {
+    if let Some(manifest) = RecordingManifest::load(dir)? {
+        return Ok(manifest.statements);
+    }
+    statement_keys(dir)?
+        .into_iter()
+        .map(|key| Ok((key.clone(), RecordedStatement::read(dir, &key)?)))
+        .collect()
+}
+
+/// Copy the recording in `dir` to `output`, replacing every value of every result
+/// with synthetic data of the same type. Schemas, row counts and nulls are kept, so
+/// that the recording still replays. Returns the number of results redacted.
+///
+/// Only the results of the statements the adapters tagged as introspection, and
+/// the recorded table schemas, are copied as is: the names and types of relations
+/// and columns they return decide what a replay does next. Everything else,
+/// including catalog queries issued from macros, is redacted.
+///
+/// `output` may be `dir` to redact in place.
+pub fn redact_recording(dir: &Path, output: &Path) -> AdbcResult<usize> {
+    let version = recording_format_version(dir)?;
+    let source_manifest = match RecordingManifest::load(dir)? {
+        Some(manifest) => manifest,
+        None => {
+            return Err(AdbcError::with_message_and_status(
+                format!(
+                    "The recording in {} has no {RECORDING_MANIFEST_FILE_NAME}; record it again to redact it",
+                    dir.display()
+                ),
+                AdbcStatus::InvalidState,
+            ));
+        }
+    };
+    fs::create_dir_all(output).map_err(|e| from_io_error(e, Some(output)))?;
+
+    let mut manifest = RecordingManifest::new(source_manifest.adapter_type);
+    manifest.format_version = version;
+    manifest.redacted = true;
+    let mut num_redacted = 0;
+    for key in statement_keys(dir)? {
+        let introspection = key.ends_with(GET_TABLE_SCHEMA_SUFFIX)
+            || source_manifest
+                .statements
+                .get(&key)
+                .is_some_and(|statement| statement.introspection);
+        for suffix in STATEMENT_FILE_SUFFIXES {
+            let source = dir.join(format!("{key}{suffix}"));
+            let destination = output.join(format!("{key}{suffix}"));
+            if !source.exists() {
+                continue;
+            }
+            if *suffix == ".parquet"
+                && source.metadata().is_ok_and(|m| m.len() > 0)
+                && !introspection
+            {
+                redact_parquet_file(&source, &destination)?;
+                num_redacted += 1;
+            } else if source != destination {
+                fs::copy(&source, &destination).map_err(|e| from_io_error(e, Some(&source)))?;
+            }
+        }
+        let mut statement = RecordedStatement::read(output, &key)?;
+        statement.introspection = introspection;
+        manifest.statements.insert(key.clone(), statement);
+    }
+    manifest.save(output)?;
+    Ok(num_redacted)
+}
+
+fn redact_parquet_file(source: &Path, destination: &Path) -> AdbcResult<()> {
+    let file = File::open(source).map_err(|e| from_io_error(e, Some(source)))?;
+    let builder = ParquetRecordBatchReaderBuilder::try_new(file).map_err(from_parquet_error)?;
+    let schema = builder.schema().clone();
+    let batches = builder
+        .build()
+        .map_err(from_parquet_error)?
+        .map(|batch| batch.and_then(|batch| redact_batch(&batch)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let file = File::create(destination).map_err(|e| from_io_error(e, Some(destination)))?;
+    let props = WriterProperties::builder().build();
+    let mut writer = ArrowWriter::try_new(file, schema, Some(props)).map_err(from_parquet_error)?;
+    for batch in &batches {
+        writer.write(batch).map_err(from_parquet_error)?;
+    }
+    writer.close().map_err(from_parquet_error)?;
+    Ok(())
+}
+
+/// Replace every value of the batch by synthetic data of the same type, keeping nulls.
+pub fn redact_batch(batch: &RecordBatch) -> Result<RecordBatch, ArrowError> {
+    let schema = batch.schema();
+    let columns = schema
+        .fields()
+        .iter()
+        .zip(batch.columns())
+        .map(|(field, column)| {
+            redact_array(field.name(), column).ok_or_else(|| {
+                ArrowError::NotYetImplemented(format!(
+                    "Cannot redact column '{}' of type {}",
+                    field.name(),
+                    field.data_type()
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    RecordBatch::try_new(schema, columns)
+}
+
+fn redact_array(name: &str, array: &ArrayRef) -> Option<ArrayRef> {
+    let data_type = array.data_type();
+    let valid = |i: usize| array.is_valid(i);
+    let synthetic: ArrayRef = match data_type {
+        DataType::Null => return Some(array.clone()),
+        DataType::Boolean => Arc::new(
+            (0..array.len())
+                .map(|i| valid(i).then_some(i % 2 == 0))
+                .collect::<BooleanArray>(),
+        ),
+        _ if is_string_like(data_type) => Arc::new(
+            (0..array.len())
+                .map(|i| valid(i).then(|| format!("{name}_{i}")))
+                .collect::<StringArray>(),
+        ),
+        // Zero fits decimals of any precision and scale
+        DataType::Decimal128(..) | DataType::Decimal256(..) => Arc::new(
+            (0..array.len())
+                .map(|i| valid(i).then_some(0))
+                .collect::<Int64Array>(),
+        ),
+        _ if can_cast_types(&DataType::Int64, data_type) => Arc::new(
+            (0..array.len())
+                .map(|i| valid(i).then_some((i % 10) as i64))
+                .collect::<Int64Array>(),
+        ),
+        _ if can_cast_types(&DataType::Int32, data_type) => Arc::new(
+            (0..array.len())
+                .map(|i| valid(i).then_some((i % 10) as i32))
+                .collect::<Int32Array>(),
+        ),
+        // Nested types lose their values along with their structure
+        _ if array.null_count() == array.len() => return Some(array.clone()),
+        _ => return None,
+    };
+    cast(&synthetic, data_type).ok()
+}
+
+fn is_string_like(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Utf8
+        | DataType::LargeUtf8
+        | DataType::Utf8View
+        | DataType::Binary
+        | DataType::LargeBinary
+        | DataType::BinaryView => true,
+        DataType::Dictionary(_, value_type) => is_string_like(value_type),
+        _ => false,
+    }
+}
+
+/// How a statement differs between two recordings
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangedStatement {
+    pub key: String,
+    /// What changed: `sql`, `schema`, `result` or `error`
+    pub changes: Vec<&'static str>,
+    /// A unified diff of the query, if it changed and both versions are available
+    pub sql_diff: Option<String>,
+}
+
+/// The statements added, removed or changed between two recordings
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RecordingDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<ChangedStatement>,
+}
+
+impl RecordingDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl fmt::Display for RecordingDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for key in &self.added {
+            writeln!(f, "+ {key}")?;
+        }
+        for key in &self.removed {
+            writeln!(f, "- {key}")?;
+        }
+        for changed in &self.changed {
+            writeln!(f, "~ {} ({})", changed.key, changed.changes.join(", "))?;
+            if let Some(sql_diff) = &changed.sql_diff {
+                for line in sql_diff.lines() {
+                    writeln!(f, "    {line}")?;
+                }
+            }
+        }
+        write!(
+            f,
+            "{} added, {} removed, {} changed",
+            self.added.len(),
+            self.removed.len(),
+            self.changed.len()
+        )
+    }
+}
+
+/// Compare the statements of the recordings in `old` and `new`.
+pub fn diff_recordings(old: &Path, new: &Path) -> AdbcResult<RecordingDiff> {
+    let old_statements = recorded_statements(old)?;
+    let new_statements = recorded_statements(new)?;
+
+    let mut diff = RecordingDiff::default();
+    for (key, new_statement) in &new_statements {
+        let Some(old_statement) = old_statements.get(key) else {
+            diff.added.push(key.clone());
+            continue;
+        };
+        let changes = [
+            ("sql", old_statement.sql_sha256 != new_statement.sql_sha256),
+            ("schema", old_statement.schema != new_statement.schema),
+            (
+                "result",
+                old_statement.result_sha256 != new_statement.result_sha256,
+            ),
+            (
+                "error",
+                old_statement.error_sha256 != new_statement.error_sha256,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(what, changed)| changed.then_some(what))
+        .collect::<Vec<_>>();
+        if changes.is_empty() {
+            continue;
+        }
+        let sql_diff = if changes.contains(&"sql") {
+            let old_sql = fs::read_to_string(old.join(format!("{key}.sql"))).ok();
+            let new_sql = fs::read_to_string(new.join(format!("{key}.sql"))).ok();
+            old_sql.zip(new_sql).map(|(old_sql, new_sql)| {
+                TextDiff::from_lines(&old_sql, &new_sql)
+                    .unified_diff()
+                    .context_radius(2)
+                    .to_string()
+            })
+        } else {
+            None
+        };
+        diff.changed.push(ChangedStatement {
+            key: key.clone(),
+            changes,
+            sql_diff,
+        });
+    }
+    diff.removed = old_statements
+        .keys()
+        .filter(|key| !new_statements.contains_key(*key))
+        .cloned()
+        .collect();
+    Ok(diff)
+}
+
+/// The keys of the statements recorded in `dir`, from the names of their files
+fn statement_keys(dir: &Path) -> AdbcResult<BTreeSet<String>> {
+    let entries = fs::read_dir(dir).map_err(|e| from_io_error(e, Some(dir)))?;
+    let mut keys = BTreeSet::new();
+    for entry in entries {
+        let entry = entry.map_err(|e| from_io_error(e, Some(dir)))?;
+        let file_name = entry.file_name().to_string_lossy().into_owned();
+        if let Some(key) = STATEMENT_FILE_SUFFIXES
+            .iter()
+            .find_map(|suffix| file_name.strip_suffix(suffix))
+        {
+            keys.insert(key.to_string());
+        }
+    }
+    Ok(keys)
+}
+
+fn read_optional(path: &Path) -> AdbcResult<Option<Vec<u8>>> {
+    match fs::read(path) {
+        Ok(bytes) => Ok(Some(bytes)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(from_io_error(e, Some(path))),
+    }
+}
+
+fn format_schema(schema: &Schema) -> String {
+    schema
+        .fields()
+        .iter()
+        .map(|field| format!("{}: {}", field.name(), field.data_type()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub(crate) fn sha256(bytes: impl AsRef<[u8]>) -> String {
+    hex::encode(Sha256::digest(bytes.as_ref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Float64Array, TimestampMicrosecondArray};
+    use arrow_schema::{Field, TimeUnit};
+
+    #[test]
+    fn test_redact_batch_keeps_schema_and_nulls() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("email", DataType::Utf8, true),
+            Field::new("amount", DataType::Float64, false),
+            Field::new(
+                "created_at",
+                DataType::Timestamp(TimeUnit::Microsecond, None),
+                true,
+            ),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(StringArray::from(vec![Some("a@example.com"), None])),
+                Arc::new(Float64Array::from(vec![12.5, 99.0])),
+                Arc::new(TimestampMicrosecondArray::from(vec![
+                    None,
+                    Some(1_700_000_000_000_000),
+                ])),
+            ],
+        )
+        .unwrap();
+
+        let redacted = redact_batch(&batch).unwrap();
+        assert_eq!(redacted.schema(), schema);
+        assert_eq!(redacted.num_rows(), 2);
+
+        let emails = redacted
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(emails.value(0), "email_0");
+        assert!(emails.is_null(1));
+        let amounts = redacted
+            .column(1)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(amounts.values().to_vec(), vec![0.0, 1.0]);
+        assert!(redacted.column(2).is_null(0));
+        assert!(!redacted.column(2).is_null(1));
+    }
+
+    #[test]
+    fn test_diff_recordings() {
+        let old = tempfile::tempdir().unwrap();
+        let new = tempfile::tempdir().unwrap();
+        for (dir, files) in [
+            (
+                old.path(),
+                vec![
+                    ("model.a-0.sql", "select 1"),
+                    ("model.b-0.sql", "select 2"),
+                    ("model.c-0.sql", "select 3"),
+                ],
+            ),
+            (
+                new.path(),
+                vec![
+                    ("model.a-0.sql", "select 1"),
+                    ("model.b-0.sql", "select 20"),
+                    ("model.d-0.sql", "select 4"),
+                    ("model.d-0.err", "boom"),
+                ],
+            ),
+        ] {
+            for (name, content) in files {
+                fs::write(dir.join(name), content).unwrap();
+            }
+        }
+
+        let diff = diff_recordings(old.path(), new.path()).unwrap();
+        assert_eq!(diff.added, vec!["model.d-0"]);
+        assert_eq!(diff.removed, vec!["model.c-0"]);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].key, "model.b-0");
+        assert_eq!(diff.changed[0].changes, vec!["sql"]);
+        assert!(
+            diff.changed[0]
+                .sql_diff
+                .as_ref()
+                .unwrap()
+                .contains("+select 20")
+        );
+        assert!(diff_recordings(old.path(), old.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_recording_format_version() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(
+            recording_format_version(dir.path()).unwrap(),
+            LEGACY_RECORDING_FORMAT_VERSION
+        );
+
+        let mut manifest = RecordingManifest::new(AdapterType::Postgres);
+        manifest.save(dir.path()).unwrap();
+        assert_eq!(
+            recording_format_version(dir.path()).unwrap(),
+            RECORDING_FORMAT_VERSION
+        );
+
+        manifest.format_version = RECORDING_FORMAT_VERSION + 1;
+        manifest.save(dir.path()).unwrap();
+        let err = recording_format_version(dir.path()).unwrap_err();
+        assert_eq!(err.status, AdbcStatus::InvalidState);
+    }
+}
@@ -1,8 +1,8 @@
 use crate::redshift::adapter::RedshiftAdapter;
 
 use crate::metadata::{
-    CatalogAndSchema, RelationVec, RelationsByDb, WhereClausesByDb, find_matching_relation,
-    get_input_schema_database_and_table,
+    CatalogAndSchema, RelationVec, build_relation_clauses, find_matching_relation,
+    tables_where_clause,
 };
 use crate::{
     AdapterResult, AdapterTyping, TypedBaseAdapter,
@@ -23,7 +23,7 @@ use dbt_schemas::schemas::legacy_catalog::{
 };
 use dbt_schemas::schemas::relations::base::{BaseRelation, RelationPattern};
 use dbt_xdbc::query_ctx::ExecutionPhase;
-use dbt_xdbc::{Connection, MapReduce, QueryCtx};
+use dbt_xdbc::{Backend, Connection, MapReduce, QueryCtx, QueryParams};
 
 use crate::sql_types::make_arrow_field_v2;
 use std::collections::btree_map::Entry;
@@ -31,36 +31,35 @@ use std::{
     collections::{BTreeMap, BTreeSet, HashMap},
     sync::Arc,
 };
-// TODO: see if this can be shared for other adapters
-
-/// Builds and returns ([WhereClausesByDb], [RelationsByDb]) from a list of [BaseRelation]
-/// [WhereClausesByDb] maps databases to statements that select their schema+tables in the relation
-/// [RelationsByDb] keys the database to the cloned [BaseRelation]
-/// We expect a fqn from the relation in format <database>.<schema>.<table>
-fn build_relation_clauses_redshift(
-    relations: &[Arc<dyn BaseRelation>],
-) -> AdapterResult<(WhereClausesByDb, RelationsByDb)> {
-    // Build the where clause for all relations grouped by databases
-    let mut where_clauses_by_database = BTreeMap::new();
-    let mut relations_by_database = BTreeMap::new();
-    for relation in relations {
-        let (input_schema, database, input_table) = get_input_schema_database_and_table(relation)?;
-
-        where_clauses_by_database
-            .entry(database.to_owned())
-            .or_insert_with(Vec::new)
-            .push(format!(
-                "(
-                    upper(ns.nspname) = upper('{input_schema}')
-                and upper(c.relname) = upper('{input_table}')
+/// The condition selecting a table in `pg_class` joined with `pg_namespace`
+fn pg_class_table_clause(schema: &str, table: &str) -> String {
+    format!(
+        "(
+                    upper(ns.nspname) = upper({schema})
+                and upper(c.relname) = upper({table})
                 )"
-            ));
-        relations_by_database
-            .entry(database.to_owned())
-            .or_insert_with(Vec::new)
-            .push(relation.clone());
-    }
-    Ok((where_clauses_by_database, relations_by_database))
+    )
+}
+
+/// Query the columns of a table, binding its names as parameters
+fn table_columns_query(
+    backend: Backend,
+    catalog: &str,
+    schema: &str,
+    identifier: &str,
+) -> (String, QueryParams) {
+    let mut params = QueryParams::new(backend);
+    let sql = format!(
+        "SELECT column_name, data_type, is_nullable, remarks
+    FROM SVV_ALL_COLUMNS
+    WHERE database_name = {}
+    AND schema_name = {}
+    AND table_name = {}",
+        params.push(catalog),
+        params.push(schema),
+        params.push(identifier)
+    );
+    (sql, params)
 }
 
 impl MetadataAdapter for RedshiftAdapter {
@@ -573,16 +572,13 @@ impl MetadataAdapter for RedshiftAdapter {
             let schema = relation.schema_as_str()?;
             let identifier = relation.identifier_as_str()?;
 
-            let sql = format!(
-                "SELECT column_name, data_type, is_nullable, remarks
-    FROM SVV_ALL_COLUMNS
-    WHERE database_name = '{catalog}'
-    AND schema_name = '{schema}'
-    AND table_name = '{identifier}'"
-            );
+            let (sql, params) =
+                table_columns_query(adapter.engine().backend(), &catalog, &schema, &identifier);
 
-            let ctx = QueryCtx::default().with_desc("Get table schema");
-            let (_, table) = adapter.query(&ctx, &mut *conn, &sql, None)?;
+            let ctx = QueryCtx::default()
+                .with_desc("Get table schema")
+                .with_introspection();
+            let (_, table) = adapter.query_with_params(&ctx, &mut *conn, &sql, &params)?;
             let batch = table.original_record_batch();
             // Build fields from the response
             let mut fields = Vec::new();
@@ -659,14 +655,13 @@ impl MetadataAdapter for RedshiftAdapter {
         &self,
         relations: &[Arc<dyn BaseRelation>],
     ) -> AsyncAdapterResult<'_, BTreeMap<String, MetadataFreshness>> {
-        let (where_clauses_by_database, relations_by_database) =
-            match build_relation_clauses_redshift(relations) {
-                Ok(result) => result,
-                Err(e) => {
-                    let future = async move { Err(Cancellable::Error(e)) };
-                    return Box::pin(future);
-                }
-            };
+        let (tables_by_database, relations_by_database) = match build_relation_clauses(relations) {
+            Ok(result) => result,
+            Err(e) => {
+                let future = async move { Err(Cancellable::Error(e)) };
+                return Box::pin(future);
+            }
+        };
         type Acc = BTreeMap<String, MetadataFreshness>;
 
         let adapter = self.clone();
@@ -678,9 +673,11 @@ impl MetadataAdapter for RedshiftAdapter {
 
         let adapter = self.clone();
         let map_f = move |conn: &'_ mut dyn Connection,
-                          database_and_where_clauses: &(String, Vec<String>)|
+                          database_and_tables: &(String, Vec<(String, String)>)|
               -> AdapterResult<Arc<RecordBatch>> {
-            let (_, where_clauses) = &database_and_where_clauses;
+            let (_, tables) = &database_and_tables;
+            let mut params = QueryParams::new(adapter.engine().backend());
+            let where_clause = tables_where_clause(&mut params, tables, pg_class_table_clause);
             // Query to get last modified times.
             // Sourced from dbt-core at https://github.com/dbt-labs/dbt-adapters/blob/0d2ad15e54bbb0da1ecadcea661e3338e148a558/dbt-redshift/src/dbt/include/redshift/macros/metadata/relation_last_modified.sql
             // Note that the trim is necessary to remove
@@ -703,20 +700,22 @@ impl MetadataAdapter for RedshiftAdapter {
                      on qd.table_id = c.oid
                  where qd.step_name = 'insert'
                  and (
-                 {}
+                 {where_clause}
                  )
-                 group by 1, 2, 4",
-                where_clauses.join(" OR ")
+                 group by 1, 2, 4"
             );
 
-            let ctx = QueryCtx::default().with_desc("Extracting freshness from information schema");
-            let (_adapter_response, agate_table) = adapter.query(&ctx, &mut *conn, &sql, None)?;
+            let ctx = QueryCtx::default()
+                .with_desc("Extracting freshness from information schema")
+                .with_introspection();
+            let (_adapter_response, agate_table) =
+                adapter.query_with_params(&ctx, &mut *conn, &sql, &params)?;
             let batch = agate_table.original_record_batch();
             Ok(batch)
         };
 
         let reduce_f = move |acc: &mut Acc,
-                             database_and_where_clauses: (String, Vec<String>),
+                             database_and_tables: (String, Vec<(String, String)>),
                              batch_res: AdapterResult<Arc<RecordBatch>>|
               -> Result<(), Cancellable<AdapterError>> {
             let batch = batch_res?;
@@ -725,7 +724,7 @@ impl MetadataAdapter for RedshiftAdapter {
             let timestamps =
                 get_column_values::<TimestampMicrosecondArray>(&batch, "last_modified")?;
 
-            let (database, _where_clauses) = &database_and_where_clauses;
+            let (database, _tables) = &database_and_tables;
             for i in 0..batch.num_rows() {
                 let schema = schemas.value(i);
                 let table = tables.value(i);
@@ -749,7 +748,7 @@ impl MetadataAdapter for RedshiftAdapter {
             Box::new(reduce_f),
             MAX_CONNECTIONS,
         );
-        let keys = where_clauses_by_database.into_iter().collect::<Vec<_>>();
+        let keys = tables_by_database.into_iter().collect::<Vec<_>>();
         let token = self.cancellation_token();
         map_reduce.run(Arc::new(keys), token)
     }
@@ -771,7 +770,9 @@ impl MetadataAdapter for RedshiftAdapter {
         let map_f = move |conn: &'_ mut dyn Connection,
                           db_schema: &CatalogAndSchema|
               -> AdapterResult<Vec<Arc<dyn BaseRelation>>> {
-            let query_ctx = QueryCtx::default().with_desc("list_relations_in_parallel");
+            let query_ctx = QueryCtx::default()
+                .with_desc("list_relations_in_parallel")
+                .with_introspection();
             adapter.list_relations(&query_ctx, conn, db_schema)
         };
 
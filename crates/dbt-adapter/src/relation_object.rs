@@ -19,10 +19,13 @@ use crate::bigquery::relation_config::{
     BigqueryMaterializedViewConfig, BigqueryMaterializedViewConfigObject,
 };
 use crate::databricks::relation::DatabricksRelation;
+use crate::duckdb::relation::DuckDBRelation;
+use crate::generic::relation::GenericRelation;
 use crate::postgres::relation::PostgresRelation;
 use crate::redshift::relation::RedshiftRelation;
 use crate::salesforce::relation::SalesforceRelation;
 use crate::snowflake::relation::SnowflakeRelation;
+use crate::trino::relation::TrinoRelation;
 
 use std::sync::Arc;
 use std::{fmt, ops::Deref};
@@ -189,6 +192,9 @@ impl Object for RelationObject {
 ///
 /// Unlike [internal_create_relation]
 /// This is supposed to be used in places that are invoked by the Jinja rendering process
+///
+/// `quote_character` is the one declared in the profile, if any (see
+/// [crate::AdapterTyping::quote_character])
 pub fn create_relation(
     adapter_type: AdapterType,
     database: String,
@@ -196,6 +202,7 @@ pub fn create_relation(
     identifier: Option<String>,
     relation_type: Option<RelationType>,
     custom_quoting: ResolvedQuoting,
+    quote_character: Option<char>,
 ) -> Result<Arc<dyn BaseRelation>, MinijinjaError> {
     let relation = match adapter_type {
         AdapterType::Postgres => Arc::new(PostgresRelation::try_new(
@@ -239,6 +246,28 @@ pub fn create_relation(
             None,
             false,
         )) as Arc<dyn BaseRelation>,
+        AdapterType::DuckDB => Arc::new(DuckDBRelation::new(
+            Some(database),
+            Some(schema),
+            identifier,
+            relation_type,
+            custom_quoting,
+        )) as Arc<dyn BaseRelation>,
+        AdapterType::Trino => Arc::new(TrinoRelation::new(
+            Some(database),
+            Some(schema),
+            identifier,
+            relation_type,
+            custom_quoting,
+        )) as Arc<dyn BaseRelation>,
+        AdapterType::Generic => Arc::new(GenericRelation::new(
+            Some(database),
+            Some(schema),
+            identifier,
+            relation_type,
+            custom_quoting,
+            quote_character.unwrap_or('"'),
+        )) as Arc<dyn BaseRelation>,
         AdapterType::Salesforce => Arc::new(SalesforceRelation::new(
             Some(database),
             Some(schema),
@@ -260,6 +289,7 @@ pub fn create_relation_internal(
     identifier: Option<String>,
     relation_type: Option<RelationType>,
     custom_quoting: ResolvedQuoting,
+    quote_character: Option<char>,
 ) -> FsResult<Arc<dyn BaseRelation>> {
     let result = create_relation(
         adapter_type,
@@ -268,6 +298,7 @@ pub fn create_relation_internal(
         identifier,
         relation_type,
         custom_quoting,
+        quote_character,
     )
     .map_err(|e| FsError::from_jinja_err(e, "Failed to create relation"))?;
     Ok(result)
@@ -277,6 +308,7 @@ pub fn create_relation_from_node(
     adapter_type: AdapterType,
     node: &dyn InternalDbtNodeAttributes,
     _sample_config: Option<RunFilter>,
+    quote_character: Option<char>,
 ) -> FsResult<Arc<dyn BaseRelation>> {
     create_relation_internal(
         adapter_type,
@@ -285,6 +317,7 @@ pub fn create_relation_from_node(
         Some(node.base().alias.clone()), // all identifiers are consolidated to alias in InternalDbtNode
         Some(RelationType::from(node.materialized())),
         node.quoting(),
+        quote_character,
     )
 }
 
@@ -0,0 +1,343 @@
+//! Classification of transient warehouse errors and the retry policy applied
+//! around statement execution.
+//!
+//! Only errors that are known to be transient (throttling, dropped connections,
+//! a warehouse that is still starting, HTTP 429/503 from the service) are
+//! retried. Everything else, including errors we know nothing about, is
+//! reported on the first failure.
+//!
+//! Retries are opt-in: a retried statement runs again as a whole, which is
+//! only safe for statements that are idempotent, so targets that want retries
+//! set `statement_retries` explicitly. The `retries` key of some profiles
+//! already configures the driver and is left alone.
+//!
+//! A serialization failure or deadlock inside an open transaction is never
+//! retried: the transaction is aborted and running the failed statement again
+//! would commit it without the statements that preceded it.
+
+use std::time::Duration;
+
+use crate::AdapterType;
+use crate::config::AdapterConfig;
+use crate::errors::{AdapterError, AdapterErrorKind, AdapterResult};
+
+/// Number of retries of a statement that failed with a transient error
+pub const DEFAULT_RETRIES: u32 = 0;
+/// Wait before the first retry, doubled on every following retry
+pub const DEFAULT_RETRY_BACKOFF: Duration = Duration::from_secs(1);
+/// Upper bound on the wait between two retries
+pub const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(60);
+
+/// How often and how patiently statements that fail with a transient error are retried.
+///
+/// Configured per target with the `statement_retries` and
+/// `statement_retry_backoff` (seconds) keys.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    pub retries: u32,
+    pub backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            retries: DEFAULT_RETRIES,
+            backoff: DEFAULT_RETRY_BACKOFF,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries
+    pub const fn none() -> Self {
+        Self {
+            retries: 0,
+            backoff: Duration::ZERO,
+        }
+    }
+
+    /// Read the policy from the `statement_retries` and `statement_retry_backoff`
+    /// keys of a target
+    pub fn from_config(config: &AdapterConfig) -> AdapterResult<Self> {
+        let mut policy = Self::default();
+        if let Some(retries) = config.get_string("statement_retries") {
+            policy.retries = retries.parse::<u32>().map_err(|_| {
+                AdapterError::new(
+                    AdapterErrorKind::Configuration,
+                    format!("statement_retries must be a non-negative integer, got '{retries}'"),
+                )
+            })?;
+        }
+        if let Some(backoff) = config.get_string("statement_retry_backoff") {
+            policy.backoff = backoff
+                .parse::<f64>()
+                .ok()
+                .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                .ok_or_else(|| {
+                    AdapterError::new(
+                        AdapterErrorKind::Configuration,
+                        format!(
+                            "statement_retry_backoff must be a non-negative number of seconds, got '{backoff}'"
+                        ),
+                    )
+                })?;
+        }
+        Ok(policy)
+    }
+
+    /// The wait before the given retry (starting at 1)
+    pub fn backoff_for(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        self.backoff.saturating_mul(factor).min(MAX_RETRY_BACKOFF)
+    }
+}
+
+/// SQLSTATE classes and codes that are transient on every backend
+///
+/// 08: connection exception
+const TRANSIENT_SQLSTATE_CLASSES: &[&str] = &["08"];
+
+/// Message fragments (lowercase) of transient errors reported by any backend,
+/// usually from the HTTP or network layer of the driver
+const TRANSIENT_MESSAGE_PATTERNS: &[&str] = &[
+    "connection reset",
+    "connection refused",
+    "connection closed",
+    "broken pipe",
+    "connection timed out",
+    "i/o timeout",
+    "too many requests",
+    "service unavailable",
+    "bad gateway",
+    "gateway timeout",
+    "temporarily unavailable",
+];
+
+/// Postgres (and Redshift) SQLSTATEs of failures that succeed when retried
+///
+/// 40001: serialization_failure, 40P01: deadlock_detected,
+/// 53300: too_many_connections, 57P01: admin_shutdown,
+/// 57P02: crash_shutdown, 57P03: cannot_connect_now
+const POSTGRES_TRANSIENT_SQLSTATES: &[&str] =
+    &["40001", "40P01", "53300", "57P01", "57P02", "57P03"];
+
+/// Snowflake vendor codes of failures to reach the service
+///
+/// 250001: could not connect to the Snowflake backend,
+/// 250003: failed to get a response from the service
+const SNOWFLAKE_TRANSIENT_VENDOR_CODES: &[i32] = &[250001, 250003];
+
+const SNOWFLAKE_TRANSIENT_MESSAGE_PATTERNS: &[&str] =
+    &["is being resumed", "request was throttled", "please retry"];
+
+const BIGQUERY_TRANSIENT_MESSAGE_PATTERNS: &[&str] = &[
+    "ratelimitexceeded",
+    "jobratelimitexceeded",
+    "exceeded rate limits",
+    "backenderror",
+    "internalerror",
+    "retry your job",
+];
+
+const DATABRICKS_TRANSIENT_MESSAGE_PATTERNS: &[&str] = &[
+    "temporarily_unavailable",
+    "request_limit_exceeded",
+    "warehouse is starting",
+    "cluster is starting",
+];
+
+/// Trino error names of a cluster that is starting, shutting down or out of
+/// workers
+const TRINO_TRANSIENT_MESSAGE_PATTERNS: &[&str] = &[
+    "no_nodes_available",
+    "server_starting_up",
+    "server_shutting_down",
+    "remote_task_error",
+];
+
+/// Whether the error is transient, i.e. executing the same statement again is
+/// expected to succeed
+pub fn is_transient_error(adapter_type: AdapterType, e: &AdapterError) -> bool {
+    match e.kind() {
+        AdapterErrorKind::Driver | AdapterErrorKind::SqlExecution | AdapterErrorKind::Io => {}
+        _ => return false,
+    }
+
+    let sqlstate = e.sqlstate();
+    if TRANSIENT_SQLSTATE_CLASSES
+        .iter()
+        .any(|class| sqlstate.starts_with(class))
+    {
+        return true;
+    }
+    let message = e.message().to_ascii_lowercase();
+    let matches_any = |patterns: &[&str]| patterns.iter().any(|p| message.contains(p));
+    if matches_any(TRANSIENT_MESSAGE_PATTERNS) {
+        return true;
+    }
+
+    match adapter_type {
+        AdapterType::Postgres | AdapterType::Redshift => {
+            POSTGRES_TRANSIENT_SQLSTATES.contains(&sqlstate)
+        }
+        AdapterType::Snowflake => {
+            e.vendor_code()
+                .is_some_and(|code| SNOWFLAKE_TRANSIENT_VENDOR_CODES.contains(&code))
+                || matches_any(SNOWFLAKE_TRANSIENT_MESSAGE_PATTERNS)
+        }
+        AdapterType::Bigquery => matches_any(BIGQUERY_TRANSIENT_MESSAGE_PATTERNS),
+        AdapterType::Databricks => matches_any(DATABRICKS_TRANSIENT_MESSAGE_PATTERNS),
+        AdapterType::Trino => matches_any(TRINO_TRANSIENT_MESSAGE_PATTERNS),
+        AdapterType::Salesforce | AdapterType::DuckDB | AdapterType::Generic => false,
+    }
+}
+
+/// Postgres (and Redshift) SQLSTATEs of failures that abort the transaction the
+/// statement ran in
+///
+/// 40001: serialization_failure, 40P01: deadlock_detected
+const POSTGRES_TRANSACTION_ABORTED_SQLSTATES: &[&str] = &["40001", "40P01"];
+
+/// Whether the error aborts the transaction the statement ran in, in which case
+/// the statement can only be retried on its own outside of a transaction.
+pub fn is_transaction_aborted(adapter_type: AdapterType, e: &AdapterError) -> bool {
+    match adapter_type {
+        AdapterType::Postgres | AdapterType::Redshift => {
+            POSTGRES_TRANSACTION_ABORTED_SQLSTATES.contains(&e.sqlstate())
+        }
+        _ => false,
+    }
+}
+
+/// Message fragments (lowercase) of errors reporting that the session of the
+/// connection is gone, so that no statement can succeed on it anymore
+const CONNECTION_LOST_MESSAGE_PATTERNS: &[&str] = &[
+    "broken pipe",
+    "connection reset",
+    "connection closed",
+    "server closed the connection",
+    "terminating connection",
+    "unexpected eof",
+    "session no longer exists",
+    "session has expired",
+    "invalid sessionhandle",
+];
+
+/// Whether the error means that the connection it happened on is lost (e.g.
+/// the warehouse dropped it while it was idle), in which case the connection
+/// has to be replaced before any retry can succeed.
+pub fn is_connection_lost(e: &AdapterError) -> bool {
+    match e.kind() {
+        AdapterErrorKind::Driver | AdapterErrorKind::SqlExecution | AdapterErrorKind::Io => {}
+        _ => return false,
+    }
+    if TRANSIENT_SQLSTATE_CLASSES
+        .iter()
+        .any(|class| e.sqlstate().starts_with(class))
+    {
+        return true;
+    }
+    let message = e.message().to_ascii_lowercase();
+    CONNECTION_LOST_MESSAGE_PATTERNS
+        .iter()
+        .any(|p| message.contains(p))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn driver_error(msg: &str, sqlstate: &str, vendor_code: Option<i32>) -> AdapterError {
+        let sqlstate: [u8; 5] = sqlstate.as_bytes().try_into().unwrap();
+        AdapterError::new_with_sqlstate_and_vendor_code(
+            AdapterErrorKind::Driver,
+            msg.to_string(),
+            sqlstate,
+            vendor_code,
+        )
+    }
+
+    #[test]
+    fn test_transient_errors_by_backend() {
+        let reset = driver_error("read tcp: connection reset by peer", "00000", None);
+        assert!(is_transient_error(AdapterType::Snowflake, &reset));
+        assert!(is_transient_error(AdapterType::DuckDB, &reset));
+
+        let shutdown = driver_error("terminating connection", "57P01", None);
+        assert!(is_transient_error(AdapterType::Postgres, &shutdown));
+        assert!(!is_transient_error(AdapterType::Snowflake, &shutdown));
+
+        let unreachable = driver_error("could not connect", "00000", Some(250001));
+        assert!(is_transient_error(AdapterType::Snowflake, &unreachable));
+
+        let rate_limited = driver_error(
+            "googleapi: Error 403: Exceeded rate limits: too many table update operations, rateLimitExceeded",
+            "00000",
+            None,
+        );
+        assert!(is_transient_error(AdapterType::Bigquery, &rate_limited));
+
+        let starting = driver_error(
+            "trino: query failed (200 OK): \"SERVER_STARTING_UP: Trino server is still initializing\"",
+            "00000",
+            None,
+        );
+        assert!(is_transient_error(AdapterType::Trino, &starting));
+        assert!(!is_transient_error(AdapterType::Postgres, &starting));
+
+        let syntax = driver_error("syntax error at or near \"selec\"", "42601", None);
+        assert!(!is_transient_error(AdapterType::Postgres, &syntax));
+
+        let cancelled = AdapterError::new(AdapterErrorKind::Cancelled, "service unavailable");
+        assert!(!is_transient_error(AdapterType::Databricks, &cancelled));
+    }
+
+    #[test]
+    fn test_connection_lost_errors() {
+        let pipe = driver_error("write tcp 10.0.0.1:443: write: broken pipe", "00000", None);
+        assert!(is_connection_lost(&pipe));
+        let link = driver_error("could not send data to server", "08006", None);
+        assert!(is_connection_lost(&link));
+        let expired = driver_error(
+            "390112 (08001): Your session has expired. Please login again.",
+            "08001",
+            Some(390112),
+        );
+        assert!(is_connection_lost(&expired));
+
+        let throttled = driver_error("Too Many Requests", "00000", None);
+        assert!(!is_connection_lost(&throttled));
+        assert!(!is_transaction_aborted(AdapterType::Postgres, &throttled));
+
+        let deadlock = driver_error("deadlock detected", "40P01", None);
+        assert!(is_transaction_aborted(AdapterType::Postgres, &deadlock));
+        assert!(!is_transaction_aborted(AdapterType::Snowflake, &deadlock));
+        assert!(!is_connection_lost(&deadlock));
+        let syntax = driver_error("syntax error at or near \"selec\"", "42601", None);
+        assert!(!is_connection_lost(&syntax));
+    }
+
+    #[test]
+    fn test_retry_policy_from_config() {
+        let config = AdapterConfig::new(
+            dbt_serde_yaml::from_str("statement_retries: 3\nstatement_retry_backoff: 0.5").unwrap(),
+        );
+        let policy = RetryPolicy::from_config(&config).unwrap();
+        assert_eq!(policy.retries, 3);
+        assert_eq!(policy.backoff_for(1), Duration::from_millis(500));
+        assert_eq!(policy.backoff_for(3), Duration::from_secs(2));
+        assert_eq!(policy.backoff_for(20), MAX_RETRY_BACKOFF);
+
+        assert_eq!(
+            RetryPolicy::from_config(&AdapterConfig::default()).unwrap(),
+            RetryPolicy::default()
+        );
+
+        let config = AdapterConfig::new(dbt_serde_yaml::from_str("statement_retries: -1").unwrap());
+        assert!(RetryPolicy::from_config(&config).is_err());
+
+        // The driver retries of Redshift profiles do not opt into statement retries
+        let config = AdapterConfig::new(dbt_serde_yaml::from_str("retries: 1").unwrap());
+        assert_eq!(RetryPolicy::from_config(&config).unwrap().retries, 0);
+    }
+}
@@ -19,11 +19,46 @@ use dbt_schemas::schemas::legacy_catalog::{
 };
 use dbt_schemas::schemas::relations::base::{BaseRelation, RelationPattern};
 use dbt_xdbc::query_ctx::ExecutionPhase;
-use dbt_xdbc::{Connection, MapReduce, QueryCtx};
+use dbt_xdbc::{Backend, Connection, MapReduce, QueryCtx, QueryParams};
 
 use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::sync::Arc;
 
+/// Query the columns of the relations of `database` matching any of the patterns
+fn columns_by_patterns_query(
+    backend: Backend,
+    database: &str,
+    patterns: &[&RelationPattern],
+) -> (String, QueryParams) {
+    let mut params = QueryParams::new(backend);
+    let predicates = patterns
+        .iter()
+        .map(|pat| {
+            let schema_pattern = params.push(pat.schema_pattern.as_str());
+            let table_pattern = params.push(pat.table_pattern.as_str());
+            format!("(TABLE_SCHEMA ILIKE {schema_pattern} AND TABLE_NAME ILIKE {table_pattern})")
+        })
+        .collect::<Vec<_>>();
+    let predicates_union = predicates.join(" OR ");
+    let sql = format!(
+        "SELECT
+    TABLE_CATALOG,
+    TABLE_SCHEMA,
+    TABLE_NAME,
+    COLUMN_NAME,
+    DATA_TYPE,
+    IS_NULLABLE,
+    CHARACTER_MAXIMUM_LENGTH,
+    NUMERIC_PRECISION,
+    NUMERIC_SCALE,
+    COMMENT
+FROM {database}.INFORMATION_SCHEMA.COLUMNS
+WHERE {predicates_union}
+ORDER BY TABLE_CATALOG, TABLE_SCHEMA, TABLE_NAME, ORDINAL_POSITION"
+    );
+    (sql, params)
+}
+
 impl MetadataAdapter for SnowflakeAdapter {
     fn build_schemas_from_stats_sql(
         &self,
@@ -278,7 +313,9 @@ impl MetadataAdapter for SnowflakeAdapter {
         let adapter = self.clone();
         let map_f =
             move |conn: &'_ mut dyn Connection, sql: &String| -> AdapterResult<Arc<RecordBatch>> {
-                let ctx = QueryCtx::default().with_desc("List user functions");
+                let ctx = QueryCtx::default()
+                    .with_desc("List user functions")
+                    .with_introspection();
                 let (_, table) = adapter.query(&ctx, conn, sql, None)?;
                 let batch = table.original_record_batch();
                 Ok(batch)
@@ -380,7 +417,9 @@ impl MetadataAdapter for SnowflakeAdapter {
                           table_name: &String|
               -> AdapterResult<Arc<Schema>> {
             let sql = format!("describe table {};", &table_name);
-            let mut ctx = QueryCtx::default().with_desc("Get table schema");
+            let mut ctx = QueryCtx::default()
+                .with_desc("Get table schema")
+                .with_introspection();
             if let Some(node_id) = unique_id.clone() {
                 ctx = ctx.with_node_id(&node_id);
             }
@@ -426,37 +465,10 @@ impl MetadataAdapter for SnowflakeAdapter {
                 .push(pat);
         }
 
+        let backend = self.engine().backend();
         let queries = patterns_by_database
             .into_iter()
-            .map(|(database, patterns)| {
-                // Build the query for all relations in this database
-                let predicates = patterns
-                    .iter()
-                    .map(|pat| {
-                        format!(
-                            "(TABLE_SCHEMA ILIKE '{}' AND TABLE_NAME ILIKE '{}')",
-                            pat.schema_pattern, pat.table_pattern
-                        )
-                    })
-                    .collect::<Vec<_>>();
-                let predicates_union = predicates.join(" OR ");
-                format!(
-                    "SELECT
-    TABLE_CATALOG,
-    TABLE_SCHEMA,
-    TABLE_NAME,
-    COLUMN_NAME,
-    DATA_TYPE,
-    IS_NULLABLE,
-    CHARACTER_MAXIMUM_LENGTH,
-    NUMERIC_PRECISION,
-    NUMERIC_SCALE,
-    COMMENT
-FROM {database}.INFORMATION_SCHEMA.COLUMNS
-WHERE {predicates_union}
-ORDER BY TABLE_CATALOG, TABLE_SCHEMA, TABLE_NAME, ORDINAL_POSITION"
-                )
-            });
+            .map(|(database, patterns)| columns_by_patterns_query(backend, &database, &patterns));
 
         let adapter = self.clone();
         let new_connection_f = move || {
@@ -467,19 +479,22 @@ ORDER BY TABLE_CATALOG, TABLE_SCHEMA, TABLE_NAME, ORDINAL_POSITION"
 
         // map_f runs the queries, reduce_f decodes the result set and builds the schemas
         let adapter = self.clone();
-        let map_f =
-            move |conn: &'_ mut dyn Connection, sql: &String| -> AdapterResult<Arc<RecordBatch>> {
-                let ctx = QueryCtx::default().with_desc("Get schema by pattern");
-                let (_, table) = adapter.query(&ctx, conn, sql, None)?;
-                let batch = table.original_record_batch();
-                Ok(batch)
-            };
+        let map_f = move |conn: &'_ mut dyn Connection,
+                          (sql, params): &(String, QueryParams)|
+              -> AdapterResult<Arc<RecordBatch>> {
+            let ctx = QueryCtx::default()
+                .with_desc("Get schema by pattern")
+                .with_introspection();
+            let (_, table) = adapter.query_with_params(&ctx, conn, sql, params)?;
+            let batch = table.original_record_batch();
+            Ok(batch)
+        };
 
         let quoting = self.quoting();
 
         let adapter = self.clone();
         let reduce_f = move |acc: &mut Acc,
-                             _sql: String,
+                             _query: (String, QueryParams),
                              batch_res: AdapterResult<Arc<RecordBatch>>|
               -> Result<(), Cancellable<AdapterError>> {
             let batch = batch_res?;
@@ -511,15 +526,14 @@ ORDER BY TABLE_CATALOG, TABLE_SCHEMA, TABLE_NAME, ORDINAL_POSITION"
         &self,
         relations: &[Arc<dyn BaseRelation>],
     ) -> AsyncAdapterResult<'_, BTreeMap<String, MetadataFreshness>> {
-        // Build the where clause for all relations grouped by databases
-        let (where_clauses_by_database, relations_by_database) =
-            match build_relation_clauses(relations) {
-                Ok(result) => result,
-                Err(e) => {
-                    let future = async move { Err(Cancellable::Error(e)) };
-                    return Box::pin(future);
-                }
-            };
+        // Group the tables of all relations by databases
+        let (tables_by_database, relations_by_database) = match build_relation_clauses(relations) {
+            Ok(result) => result,
+            Err(e) => {
+                let future = async move { Err(Cancellable::Error(e)) };
+                return Box::pin(future);
+            }
+        };
 
         type Acc = BTreeMap<String, MetadataFreshness>;
 
@@ -532,9 +546,12 @@ ORDER BY TABLE_CATALOG, TABLE_SCHEMA, TABLE_NAME, ORDINAL_POSITION"
 
         let adapter = self.clone();
         let map_f = move |conn: &'_ mut dyn Connection,
-                          database_and_where_clauses: &(String, Vec<String>)|
+                          database_and_tables: &(String, Vec<(String, String)>)|
               -> AdapterResult<Arc<RecordBatch>> {
-            let (database, where_clauses) = &database_and_where_clauses;
+            let (database, tables) = &database_and_tables;
+            let mut params = QueryParams::new(adapter.engine().backend());
+            let where_clause =
+                tables_where_clause(&mut params, tables, information_schema_table_clause);
             // Query to get last modified times
             let sql = format!(
                 "SELECT
@@ -542,20 +559,21 @@ ORDER BY TABLE_CATALOG, TABLE_SCHEMA, TABLE_NAME, ORDINAL_POSITION"
                 table_name,
                 last_altered,
                 (table_type = 'VIEW' OR table_type = 'MATERIALIZED VIEW') AS is_view
-             FROM {}.INFORMATION_SCHEMA.TABLES
-             WHERE {}",
-                database,
-                where_clauses.join(" OR ")
+             FROM {database}.INFORMATION_SCHEMA.TABLES
+             WHERE {where_clause}"
             );
 
-            let ctx = QueryCtx::default().with_desc("Extracting freshness from information schema");
-            let (_adapter_response, agate_table) = adapter.query(&ctx, &mut *conn, &sql, None)?;
+            let ctx = QueryCtx::default()
+                .with_desc("Extracting freshness from information schema")
+                .with_introspection();
+            let (_adapter_response, agate_table) =
+                adapter.query_with_params(&ctx, &mut *conn, &sql, &params)?;
             let batch = agate_table.original_record_batch();
             Ok(batch)
         };
 
         let reduce_f = move |acc: &mut Acc,
-                             database_and_where_clauses: (String, Vec<String>),
+                             database_and_tables: (String, Vec<(String, String)>),
                              batch_res: AdapterResult<Arc<RecordBatch>>|
               -> Result<(), Cancellable<AdapterError>> {
             let batch = batch_res?;
@@ -565,7 +583,7 @@ ORDER BY TABLE_CATALOG, TABLE_SCHEMA, TABLE_NAME, ORDINAL_POSITION"
                 get_column_values::<TimestampMillisecondArray>(&batch, "LAST_ALTERED")?;
             let is_views = get_column_values::<BooleanArray>(&batch, "IS_VIEW")?;
 
-            let (database, _where_clauses) = &database_and_where_clauses;
+            let (database, _tables) = &database_and_tables;
             for i in 0..batch.num_rows() {
                 let schema = schemas.value(i);
                 let table = tables.value(i);
@@ -589,7 +607,7 @@ ORDER BY TABLE_CATALOG, TABLE_SCHEMA, TABLE_NAME, ORDINAL_POSITION"
             Box::new(reduce_f),
             MAX_CONNECTIONS,
         );
-        let keys = where_clauses_by_database.into_iter().collect::<Vec<_>>();
+        let keys = tables_by_database.into_iter().collect::<Vec<_>>();
         let token = self.cancellation_token();
         map_reduce.run(Arc::new(keys), token)
     }
@@ -612,7 +630,9 @@ ORDER BY TABLE_CATALOG, TABLE_SCHEMA, TABLE_NAME, ORDINAL_POSITION"
         let map_f = move |conn: &'_ mut dyn Connection,
                           db_schema: &CatalogAndSchema|
               -> AdapterResult<Vec<Arc<dyn BaseRelation>>> {
-            let query_ctx = QueryCtx::default().with_desc("list_relations_in_parallel");
+            let query_ctx = QueryCtx::default()
+                .with_desc("list_relations_in_parallel")
+                .with_introspection();
             adapter.list_relations(&query_ctx, conn, db_schema)
         };
 
@@ -748,6 +768,7 @@ fn build_schemas_from_information_schema(
                 Some(table.to_string()),
                 None,
                 quoting,
+                None,
             ) {
                 Ok(relation) => relation,
                 Err(e) => {
@@ -808,3 +829,29 @@ fn build_schemas_from_information_schema(
 
     Ok(result)
 }
+
+#[cfg(test)]
+mod tests {
+    use dbt_xdbc::params::ParamValue;
+
+    use super::*;
+
+    #[test]
+    fn test_columns_by_patterns_binds_the_patterns() {
+        let pattern = RelationPattern {
+            database: "DB".to_string(),
+            schema_pattern: "S%".to_string(),
+            table_pattern: "T' OR 1=1) --".to_string(),
+        };
+        let (sql, params) = columns_by_patterns_query(Backend::Snowflake, "DB", &[&pattern]);
+        assert!(!sql.contains("1=1"));
+        assert!(sql.contains("WHERE (TABLE_SCHEMA ILIKE ? AND TABLE_NAME ILIKE ?)"));
+        assert_eq!(
+            params.values(),
+            &[
+                ParamValue::Utf8("S%".to_string()),
+                ParamValue::Utf8("T' OR 1=1) --".to_string())
+            ]
+        );
+    }
+}
@@ -2,14 +2,17 @@ use crate::auth::Auth;
 use crate::base_adapter::backend_of;
 use crate::bigquery::adapter::ADBC_EXECUTE_INVOCATION_OPTION;
 use crate::config::AdapterConfig;
+use crate::connection_pool::pool_config_from_config;
 use crate::databricks::databricks_compute_from_state;
+use crate::duckdb::connection_setup_sql as duckdb_connection_setup_sql;
 use crate::errors::{
-    AdapterError, AdapterErrorKind, AdapterResult, adbc_error_to_adapter_error,
-    arrow_error_to_adapter_error,
+    AdapterError, AdapterErrorKind, AdapterResult, adapter_error_to_adbc_error,
+    adbc_error_to_adapter_error, arrow_error_to_adapter_error,
 };
 use crate::query_cache::QueryCache;
 use crate::query_comment::{EMPTY_CONFIG, QueryCommentConfig};
 use crate::record_and_replay::{RecordEngine, ReplayEngine};
+use crate::retry::{RetryPolicy, is_connection_lost, is_transaction_aborted, is_transient_error};
 use crate::sql_types::{NaiveTypeOpsImpl, TypeOps};
 use crate::stmt_splitter::StmtSplitter;
 use crate::{AdapterResponse, TrackedStatement};
@@ -24,13 +27,19 @@ use dbt_common::adapter::AdapterType;
 use dbt_common::cancellation::{Cancellable, CancellationToken, never_cancels};
 use dbt_common::create_debug_span;
 use dbt_common::hashing::code_hash;
+use dbt_common::tracing::emit::{emit_debug_event, emit_warn_event};
 use dbt_common::tracing::span_info::record_current_span_status_from_attrs;
 use dbt_frontend_common::dialect::Dialect;
 use dbt_schemas::schemas::common::ResolvedQuoting;
-use dbt_schemas::schemas::telemetry::{QueryExecuted, QueryOutcome};
+use dbt_schemas::schemas::telemetry::{
+    ConnectionPoolStats, QueryExecuted, QueryOutcome, QueryRetried,
+};
 use dbt_xdbc::bigquery::QUERY_LABELS;
+use dbt_xdbc::pool::{Pool, PoolStats};
 use dbt_xdbc::semaphore::Semaphore;
-use dbt_xdbc::{Backend, Connection, Database, QueryCtx, Statement, connection, database, driver};
+use dbt_xdbc::{
+    Backend, Connection, Database, QueryCtx, QueryParams, Statement, connection, database, driver,
+};
 use minijinja::State;
 use std::borrow::Cow;
 use tracy_client::span;
@@ -38,7 +47,7 @@ use tracy_client::span;
 use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 use std::sync::RwLock;
-use std::sync::{Arc, LazyLock};
+use std::sync::{Arc, LazyLock, Weak};
 use std::{thread, time::Duration};
 
 pub type Options = Vec<(String, OptionValue)>;
@@ -103,6 +112,8 @@ pub struct ActualEngine {
     configured_databases: RwLock<DatabaseMap>,
     /// Semaphore for limiting the number of concurrent connections
     semaphore: Arc<Semaphore>,
+    /// Pool of the connections to the target, or the error in its settings
+    pool: AdapterResult<Pool>,
     /// Resolved quoting policy
     quoting: ResolvedQuoting,
     /// Statement splitter
@@ -129,6 +140,7 @@ impl ActualEngine {
         type_ops: Box<dyn TypeOps>,
         query_cache: Option<Arc<dyn QueryCache>>,
         token: CancellationToken,
+        this: Weak<ActualEngine>,
     ) -> Self {
         let threads = config
             .get("threads")
@@ -147,6 +159,17 @@ impl ActualEngine {
         } else {
             u32::MAX
         };
+        // The pool opens connections through the engine that owns it
+        let pool = pool_config_from_config(&config).map(|pool_config| {
+            let connect_f = Box::new(move || match this.upgrade() {
+                Some(engine) => engine.connect().map_err(adapter_error_to_adbc_error),
+                None => Err(adbc_core::error::Error::with_message_and_status(
+                    "The SQL engine of the connection pool was dropped",
+                    adbc_core::error::Status::InvalidState,
+                )),
+            });
+            Pool::new(pool_config, connect_f)
+        });
         Self {
             adapter_type,
             auth,
@@ -154,6 +177,7 @@ impl ActualEngine {
             quoting,
             configured_databases: RwLock::new(DatabaseMap::default()),
             semaphore: Arc::new(Semaphore::new(permits)),
+            pool,
             splitter,
             type_ops,
             query_comment,
@@ -226,30 +250,40 @@ impl ActualEngine {
         Ok(conn)
     }
 
+    /// Open a new connection to the target, bypassing the pool.
+    fn connect(&self) -> AdapterResult<Box<dyn Connection>> {
+        let mut conn = self.new_connection_with_config(&self.config)?;
+        if self.adapter_type() == AdapterType::DuckDB {
+            let ctx = QueryCtx::default().with_desc("duckdb connection setup");
+            for sql in duckdb_connection_setup_sql(&self.config)? {
+                let mut stmt = conn.new_statement().map_err(adbc_error_to_adapter_error)?;
+                stmt.set_sql_query(&ctx, &sql)
+                    .map_err(adbc_error_to_adapter_error)?;
+                stmt.execute_update().map_err(adbc_error_to_adapter_error)?;
+            }
+        }
+        Ok(conn)
+    }
+
     fn new_connection(
         &self,
         state: Option<&State>,
         _node_id: Option<String>,
     ) -> AdapterResult<Box<dyn Connection>> {
-        match self.adapter_type() {
-            AdapterType::Databricks => {
-                if let Some(databricks_compute) = state.and_then(databricks_compute_from_state) {
-                    let augmented_config = {
-                        let mut mapping = self.config.repr().clone();
-                        mapping.insert("databricks_compute".into(), databricks_compute.into());
-                        AdapterConfig::new(mapping)
-                    };
-                    self.new_connection_with_config(&augmented_config)
-                } else {
-                    self.new_connection_with_config(&self.config)
-                }
-            }
-            _ => {
-                // TODO(felipecrv): Make this codepath more efficient
-                // (no need to reconfigure the default database)
-                self.new_connection_with_config(&self.config)
-            }
+        // Connections to a compute other than the one of the target are not pooled
+        if self.adapter_type() == AdapterType::Databricks
+            && let Some(databricks_compute) = state.and_then(databricks_compute_from_state)
+        {
+            let augmented_config = {
+                let mut mapping = self.config.repr().clone();
+                mapping.insert("databricks_compute".into(), databricks_compute.into());
+                AdapterConfig::new(mapping)
+            };
+            return self.new_connection_with_config(&augmented_config);
         }
+        let pool = self.pool.as_ref().map_err(Clone::clone)?;
+        let conn = pool.checkout().map_err(adbc_error_to_adapter_error)?;
+        Ok(Box::new(conn))
     }
 
     fn cancellation_token(&self) -> CancellationToken {
@@ -257,6 +291,35 @@ impl ActualEngine {
     }
 }
 
+impl Drop for ActualEngine {
+    fn drop(&mut self) {
+        if let Ok(pool) = &self.pool {
+            let stats = pool.stats();
+            if stats.checkouts > 0 {
+                emit_debug_event(
+                    ConnectionPoolStats::new(
+                        self.adapter_type.as_ref().to_owned(),
+                        stats.checkouts,
+                        stats.waits,
+                        stats.wait_time.as_millis() as u64,
+                        stats.connects,
+                        stats.reconnects,
+                        stats.validation_failures,
+                        stats.expired,
+                    ),
+                    Some(
+                        format!(
+                            "Connection pool of the {} target: {stats}",
+                            self.adapter_type
+                        )
+                        .as_str(),
+                    ),
+                );
+            }
+        }
+    }
+}
+
 /// A simple bridge between adapters and the drivers.
 #[derive(Clone)]
 pub enum SqlEngine {
@@ -285,18 +348,21 @@ impl SqlEngine {
         type_ops: Box<dyn TypeOps>,
         token: CancellationToken,
     ) -> Arc<Self> {
-        let engine = ActualEngine::new(
-            adapter_type,
-            auth,
-            config,
-            quoting,
-            stmt_splitter,
-            query_comment,
-            type_ops,
-            query_cache,
-            token,
-        );
-        Arc::new(SqlEngine::Warehouse(Arc::new(engine)))
+        let engine = Arc::new_cyclic(|this| {
+            ActualEngine::new(
+                adapter_type,
+                auth,
+                config,
+                quoting,
+                stmt_splitter,
+                query_comment,
+                type_ops,
+                query_cache,
+                token,
+                this.clone(),
+            )
+        });
+        Arc::new(SqlEngine::Warehouse(engine))
     }
 
     /// Create a new [`SqlEngine::Replay`] based on the given path and adapter type.
@@ -403,6 +469,15 @@ impl SqlEngine {
         Ok(conn)
     }
 
+    /// Counters of the connection pool of the target, if connections are pooled.
+    pub fn pool_stats(&self) -> Option<PoolStats> {
+        match self {
+            Self::Warehouse(actual_engine) => actual_engine.pool.as_ref().ok().map(Pool::stats),
+            Self::Record(record_engine) => record_engine.pool_stats(),
+            Self::Replay(_) | Self::Mock(_) => None,
+        }
+    }
+
     /// Get the adapter type for this engine
     pub fn adapter_type(&self) -> AdapterType {
         match self {
@@ -456,6 +531,35 @@ impl SqlEngine {
         sql: &str,
         options: Options,
         fetch: bool,
+    ) -> AdapterResult<RecordBatch> {
+        self.execute_statement(state, ctx, conn, sql, options, None, fetch)
+    }
+
+    /// Execute a single SQL statement with the parameters bound to its placeholders.
+    pub fn execute_with_params(
+        &self,
+        state: Option<&State>,
+        conn: &'_ mut dyn Connection,
+        ctx: &QueryCtx,
+        sql: &str,
+        params: &QueryParams,
+    ) -> AdapterResult<RecordBatch> {
+        let params = params
+            .to_record_batch()
+            .map_err(adbc_error_to_adapter_error)?;
+        self.execute_statement(state, ctx, conn, sql, Options::new(), Some(params), true)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn execute_statement(
+        &self,
+        state: Option<&State>,
+        ctx: &QueryCtx,
+        conn: &'_ mut dyn Connection,
+        sql: &str,
+        options: Options,
+        params: Option<RecordBatch>,
+        fetch: bool,
     ) -> AdapterResult<RecordBatch> {
         assert!(!sql.is_empty() || !options.is_empty());
 
@@ -500,6 +604,74 @@ impl SqlEngine {
             ));
         }
 
+        let policy = self.retry_policy()?;
+        let adapter_type = self.adapter_type();
+        let mut retry = 0;
+        loop {
+            let res = self.execute_attempt(ctx, conn, &sql, options.clone(), params.clone(), fetch);
+            if let Err(e) = &res
+                && is_connection_lost(e)
+            {
+                // A pooled connection is replaced before its next statement
+                conn.invalidate();
+            }
+            match res {
+                Err(e) if retry < policy.retries && is_transient_error(adapter_type, &e) => {
+                    // Only the whole transaction could be replayed, the earlier
+                    // statements of an open one are lost with the rollback
+                    if is_transaction_aborted(adapter_type, &e) && in_open_transaction(ctx, conn) {
+                        return Err(e);
+                    }
+                    retry += 1;
+                    let backoff = policy.backoff_for(retry);
+                    emit_warn_event(
+                        QueryRetried::new(
+                            code_hash(&sql),
+                            adapter_type.as_ref().to_owned(),
+                            ctx.node_id().cloned(),
+                            retry,
+                            policy.retries,
+                            u32::try_from(backoff.as_millis()).unwrap_or(u32::MAX),
+                            None,
+                            Some(e.message().to_string()),
+                            e.vendor_code(),
+                        ),
+                        Some(
+                            format!(
+                                "Retrying statement after a transient error ({retry}/{}) in {:.1}s: {}",
+                                policy.retries,
+                                backoff.as_secs_f64(),
+                                e.message()
+                            )
+                            .as_str(),
+                        ),
+                    );
+                    if !sleep_unless_cancelled(backoff, &self.cancellation_token()) {
+                        return Err(AdapterError::new(
+                            AdapterErrorKind::Cancelled,
+                            "SQL statement execution was cancelled",
+                        ));
+                    }
+                    // The session is gone, the statement can only succeed on a new one
+                    if is_connection_lost(&e) && conn.reconnect().is_err() {
+                        return Err(e);
+                    }
+                }
+                res => return res,
+            }
+        }
+    }
+
+    /// Execute a statement once, reporting the attempt as its own query span.
+    fn execute_attempt(
+        &self,
+        ctx: &QueryCtx,
+        conn: &'_ mut dyn Connection,
+        sql: &str,
+        options: Options,
+        params: Option<RecordBatch>,
+        fetch: bool,
+    ) -> AdapterResult<RecordBatch> {
         let token = self.cancellation_token();
         let do_execute = |conn: &'_ mut dyn Connection| -> Result<
             (Arc<Schema>, Vec<RecordBatch>),
@@ -511,7 +683,7 @@ impl SqlEngine {
             let mut stmt = match self.query_cache() {
                 Some(query_cache) => query_cache.new_statement(stmt, ctx.clone(), sql.to_string()),
                 None => {
-                    stmt.set_sql_query(ctx, sql.as_ref())?;
+                    stmt.set_sql_query(ctx, sql)?;
                     stmt
                 }
             };
@@ -519,6 +691,9 @@ impl SqlEngine {
             options
                 .into_iter()
                 .try_for_each(|(key, value)| stmt.set_option(OptionStatement::Other(key), value))?;
+            if let Some(params) = params {
+                stmt.bind(params)?;
+            }
 
             // Make sure we don't create more statements after global cancellation.
             token.check_cancellation()?;
@@ -544,7 +719,7 @@ impl SqlEngine {
         };
         let _span = span!("SqlEngine::execute");
 
-        let sql_hash = code_hash(sql.as_ref());
+        let sql_hash = code_hash(sql);
         let adapter_type = self.adapter_type();
         let _query_span_guard = create_debug_span(QueryExecuted::start(
             sql.to_string(),
@@ -634,6 +809,17 @@ impl SqlEngine {
         }
     }
 
+    /// The policy for retrying statements that fail with a transient error.
+    ///
+    /// Recordings store every statement once, so neither recording nor
+    /// replaying retries: the recorded outcome is final.
+    pub fn retry_policy(&self) -> AdapterResult<RetryPolicy> {
+        match self {
+            Self::Warehouse(actual_engine) => RetryPolicy::from_config(&actual_engine.config),
+            Self::Record(_) | Self::Replay(_) | Self::Mock(_) => Ok(RetryPolicy::none()),
+        }
+    }
+
     // Get query cache
     pub fn query_cache(&self) -> Option<&Arc<dyn QueryCache>> {
         match self {
@@ -654,6 +840,42 @@ impl SqlEngine {
     }
 }
 
+/// Slice in which the wait before a retry is split to notice cancellation early
+const RETRY_SLEEP_STEP: Duration = Duration::from_millis(100);
+
+/// Wait for the given duration unless cancelled first.
+///
+/// Returns false if the token was cancelled before the wait was over.
+fn sleep_unless_cancelled(duration: Duration, token: &CancellationToken) -> bool {
+    let mut remaining = duration;
+    while !remaining.is_zero() {
+        if token.is_cancelled() {
+            return false;
+        }
+        let step = remaining.min(RETRY_SLEEP_STEP);
+        thread::sleep(step);
+        remaining -= step;
+    }
+    !token.is_cancelled()
+}
+
+/// Whether the statement that just failed ran inside an open transaction
+/// (explicit or with autocommit disabled), which the failure left aborted so
+/// that the connection refuses anything but a rollback. In autocommit mode
+/// only the failed statement was rolled back and the connection accepts the
+/// probe.
+fn in_open_transaction(ctx: &QueryCtx, conn: &'_ mut dyn Connection) -> bool {
+    let mut probe = || -> AdapterResult<()> {
+        let mut stmt = conn.new_statement().map_err(adbc_error_to_adapter_error)?;
+        stmt.set_sql_query(ctx, "select 1")
+            .map_err(adbc_error_to_adapter_error)?;
+        stmt.execute_update().map_err(adbc_error_to_adapter_error)?;
+        Ok(())
+    };
+    // When in doubt, assume a transaction and do not retry
+    probe().is_err()
+}
+
 /// Execute query and retry in case of an error. Retry is done (up to
 /// the given limit) regardless of the error encountered.
 ///
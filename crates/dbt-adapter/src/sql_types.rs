@@ -16,6 +16,9 @@ pub const REDSHIFT_METADATA_SQL_TYPE_KEY: &str = "Type";
 pub const BIGQUERY_METADATA_SQL_TYPE_KEY: &str = "Type";
 // XXX: Snowflake does DATA_TYPE for GetTableSchema and SNOWFLAKE_TYPE for other queries...
 pub const SNOWFLAKE_METADATA_SQL_TYPE_KEY: &str = "DATA_TYPE";
+pub const DUCKDB_METADATA_SQL_TYPE_KEY: &str = "DUCKDB:type";
+pub const TRINO_METADATA_SQL_TYPE_KEY: &str = "TRINO:type";
+pub const GENERIC_METADATA_SQL_TYPE_KEY: &str = "SQL:type";
 
 /// An Arrow schema containing SDF types
 #[derive(Clone)]
@@ -113,7 +116,7 @@ impl TypeOps for NaiveTypeOpsImpl {
     ) -> AdapterResult<()> {
         let adapter_type = self.0;
         match adapter_type {
-            AdapterType::Postgres | AdapterType::Salesforce => {
+            AdapterType::Postgres | AdapterType::Salesforce | AdapterType::DuckDB => {
                 postgres::try_format_type(data_type, true, out)
             }
             _ => {
@@ -248,6 +251,9 @@ pub const fn get_field_sql_type_metadata_key(adapter_type: AdapterType) -> &'sta
         AdapterType::Databricks => todo!(),
         AdapterType::Postgres => todo!(),
         AdapterType::Salesforce => todo!(),
+        AdapterType::DuckDB => DUCKDB_METADATA_SQL_TYPE_KEY,
+        AdapterType::Trino => TRINO_METADATA_SQL_TYPE_KEY,
+        AdapterType::Generic => GENERIC_METADATA_SQL_TYPE_KEY,
     }
 }
 
@@ -295,7 +301,7 @@ impl SdfSchemaBuilder {
                 );
                 Ok(Arc::new(field))
             }
-            Redshift | Databricks => {
+            Redshift | Databricks | DuckDB | Trino | Generic => {
                 let metadata = field.metadata();
                 let current_type = field.data_type();
                 let nullable = field.is_nullable();
@@ -328,7 +334,7 @@ impl SdfSchemaBuilder {
     pub fn build_sdf_schema(self, type_ops: &dyn TypeOps) -> AdapterResult<SdfSchema> {
         use AdapterType::*;
         match self.adapter_type {
-            Bigquery | Redshift | Databricks => {
+            Bigquery | Redshift | Databricks | DuckDB | Trino | Generic => {
                 let original_fields = self.original.fields();
                 let mut sdf_fields = Vec::with_capacity(original_fields.len());
                 for field in original_fields {
@@ -452,6 +458,7 @@ pub fn sql_type_hint_to_str<'a>(
         (Bigquery, Decimal) => "float64", // TODO: fix to "int64" if has_decimal_places is false
         (Databricks, Floating) => "bigint", // TODO: fix to "double" if has_decimal_places is true
         (Databricks, Decimal) => "double", // TODO: fix to "bigint" if has_decimal_places is false
+        (Trino, Decimal) => "double",    // TODO: fix to "integer" if has_decimal_places is false
         (_, Floating) => "integer",      // TODO: fix to "float8" if has_decimal_places is true
         (_, Decimal) => "float8",        // TODO: fix to "integer" if has_decimal_places is false
 
@@ -462,6 +469,7 @@ pub fn sql_type_hint_to_str<'a>(
         // ## convert_datetime_type()
         (Bigquery, Datetime) => "datetime",
         (Databricks, Datetime) => "timestamp",
+        (DuckDB | Trino | Generic, Datetime) => "timestamp",
         (_, Datetime) => "timestamp without time zone",
 
         // ## convert_date_type()
@@ -472,6 +480,7 @@ pub fn sql_type_hint_to_str<'a>(
 
         // ## convert_text_type()
         (Bigquery | Databricks, Text) => "string",
+        (Trino, Text) => "varchar",
         (_, Text) => "text",
     };
     Cow::Borrowed(str)
@@ -608,7 +617,7 @@ pub const fn max_varchar_size(adapter_type: AdapterType) -> Option<usize> {
         // FIXME: Actual MAX is 134_217_728 - 16_777_216 is the default value
         Snowflake => Some(16_777_216),
         Redshift => Some(256),
-        Postgres | Bigquery | Databricks | Salesforce => None,
+        Postgres | Bigquery | Databricks | Salesforce | DuckDB | Trino | Generic => None,
     }
 }
 
@@ -618,7 +627,7 @@ pub const fn max_varbinary_size(adapter_type: AdapterType) -> Option<usize> {
         Snowflake => Some(16_777_216),
         Redshift => Some(65_535),
         // TODO: define limits for more systems
-        Postgres | Bigquery | Databricks | Salesforce => None,
+        Postgres | Bigquery | Databricks | Salesforce | DuckDB | Trino | Generic => None,
     }
 }
 
@@ -757,15 +766,19 @@ pub fn var_size(adapter_type: AdapterType, data_type: &DataType) -> Option<usize
         // For VARCHAR types, no explicit size in Snowflake unless specified
         (Snowflake, DataType::Utf8 | DataType::Utf8View) => None,
         // XXX: need to think about the defaults for these adapters
-        (Postgres | Bigquery | Databricks | Salesforce, DataType::Utf8 | DataType::Utf8View) => {
-            None
-        }
+        (
+            Postgres | Bigquery | Databricks | Salesforce | DuckDB | Trino | Generic,
+            DataType::Utf8 | DataType::Utf8View,
+        ) => None,
 
         // Bytes
         // TODO(jason): We need to report the correct size and not just a default
         (Redshift, DataType::Binary) => max_varbinary_size(Redshift),
         // XXX: need to think about the defaults for these adapters
-        (Snowflake | Postgres | Bigquery | Databricks | Salesforce, DataType::Binary) => None,
+        (
+            Snowflake | Postgres | Bigquery | Databricks | Salesforce | DuckDB | Trino | Generic,
+            DataType::Binary,
+        ) => None,
 
         // Snowflake: For timestamp/date/time types, extract precision if available
         (Snowflake, dt) if snowflake::is_time(dt).is_yes() => {
@@ -945,8 +958,23 @@ mod tests {
             convert_datetime_type(Redshift),
             "timestamp without time zone"
         );
+        assert_eq!(convert_datetime_type(DuckDB), "timestamp");
+        assert_eq!(convert_datetime_type(Trino), "timestamp");
+    }
+
+    #[test]
+    fn test_field_sql_type_metadata_key_matches_driver() {
+        for adapter_type in [DuckDB, Trino, Generic] {
+            assert_eq!(
+                get_field_sql_type_metadata_key(adapter_type),
+                metadata_sql_type_key(backend_of(adapter_type))
+            );
+        }
     }
-    const ALL_ADAPTERS: [AdapterType; 5] = [Bigquery, Databricks, Postgres, Snowflake, Redshift];
+
+    const ALL_ADAPTERS: [AdapterType; 7] = [
+        Bigquery, Databricks, Postgres, Snowflake, Redshift, DuckDB, Trino,
+    ];
 
     #[test]
     fn test_convert_date_type() {
@@ -974,5 +1002,6 @@ mod tests {
         assert_eq!(convert_text_type(Postgres), "text");
         assert_eq!(convert_text_type(Snowflake), "text");
         assert_eq!(convert_text_type(Redshift), "text");
+        assert_eq!(convert_text_type(Trino), "varchar");
     }
 }
@@ -0,0 +1,206 @@
+use crate::base_adapter::{AdapterType, AdapterTyping};
+use crate::column::Column;
+use crate::errors::{AdapterError, AdapterErrorKind, AdapterResult, arrow_error_to_adapter_error};
+use crate::funcs::execute_macro;
+use crate::metadata::*;
+use crate::record_batch_utils::column_by_name;
+use crate::relation_object::RelationObject;
+use crate::sql_engine::SqlEngine;
+use crate::trino::quote_catalog;
+use crate::trino::relation::TrinoRelation;
+use crate::typed_adapter::TypedBaseAdapter;
+use arrow::array::{Array, StringArray};
+use arrow::compute::cast;
+use arrow_schema::DataType;
+use dbt_schemas::dbt_types::RelationType;
+use dbt_schemas::schemas::common::{ConstraintSupport, ConstraintType};
+use dbt_schemas::schemas::relations::base::BaseRelation;
+use dbt_xdbc::{Connection, QueryCtx, QueryParams};
+
+use minijinja::{State, Value};
+use std::fmt;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+/// An adapter for Trino and Starburst.
+#[derive(Clone)]
+pub struct TrinoAdapter {
+    engine: Arc<SqlEngine>,
+}
+
+impl TrinoAdapter {
+    pub fn new(engine: Arc<SqlEngine>) -> Self {
+        Self { engine }
+    }
+}
+
+impl AdapterTyping for TrinoAdapter {
+    fn as_metadata_adapter(&self) -> Option<&dyn MetadataAdapter> {
+        Some(self)
+    }
+
+    fn as_typed_base_adapter(&self) -> &dyn TypedBaseAdapter {
+        self
+    }
+
+    fn engine(&self) -> &Arc<SqlEngine> {
+        &self.engine
+    }
+}
+
+impl Debug for TrinoAdapter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.adapter_type())
+    }
+}
+
+impl TypedBaseAdapter for TrinoAdapter {
+    // TODO: add_query does not appear to be necessary (few uses in
+    // macros) and should be removed and replaced with `execute`.
+    #[allow(clippy::too_many_arguments)]
+    fn add_query(
+        &self,
+        ctx: &QueryCtx,
+        conn: &'_ mut dyn Connection,
+        sql: &str,
+        auto_begin: bool,
+        _bindings: Option<&Value>,
+        _abridge_sql_log: bool,
+    ) -> AdapterResult<()> {
+        let _ = self.execute_inner(
+            self.adapter_type().into(),
+            self.engine.clone(),
+            None,
+            conn,
+            ctx,
+            sql,
+            auto_begin,
+            false, // default for fetch as in dispatch_adapter_calls()
+            None,
+            None,
+        )?;
+        Ok(())
+    }
+
+    fn quote(&self, _state: &State, identifier: &str) -> AdapterResult<String> {
+        Ok(format!("\"{identifier}\""))
+    }
+
+    fn get_columns_in_relation(
+        &self,
+        state: &State,
+        relation: Arc<dyn BaseRelation>,
+    ) -> AdapterResult<Vec<Column>> {
+        let result = execute_macro(
+            state,
+            &[RelationObject::new(relation).as_value()],
+            "get_columns_in_relation",
+        )?;
+        Ok(Column::vec_from_jinja_value(AdapterType::Trino, result)?)
+    }
+
+    fn get_relation(
+        &self,
+        state: &State,
+        ctx: &QueryCtx,
+        conn: &'_ mut dyn Connection,
+        database: &str,
+        schema: &str,
+        identifier: &str,
+    ) -> AdapterResult<Option<Arc<dyn BaseRelation>>> {
+        // information_schema reports names as the connectors store them,
+        // which is lowercase for all the common ones
+        let query_schema = if self.quoting().schema {
+            schema.to_string()
+        } else {
+            schema.to_lowercase()
+        };
+
+        let query_identifier = if self.quoting().identifier {
+            identifier.to_string()
+        } else {
+            identifier.to_lowercase()
+        };
+
+        let query_database = if self.quoting().database {
+            database.to_string()
+        } else {
+            database.to_lowercase()
+        };
+
+        let mut params = QueryParams::new(self.engine.backend());
+        let sql = format!(
+            r#"
+            select case when table_type = 'VIEW' then 'view' else 'table' end as type
+            from {}.information_schema.tables
+            where table_schema = {}
+              and table_name = {}
+            "#,
+            quote_catalog(&query_database),
+            params.push(query_schema.as_str()),
+            params.push(query_identifier.as_str()),
+        );
+
+        let batch = self
+            .engine
+            .execute_with_params(Some(state), conn, ctx, &sql, &params)?;
+        if batch.num_rows() == 0 {
+            return Ok(None);
+        }
+
+        // drivers may return the column as Utf8View or LargeUtf8
+        let column = cast(column_by_name(&batch, "type")?, &DataType::Utf8)
+            .map_err(arrow_error_to_adapter_error)?;
+        let string_array = column
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .ok_or_else(|| {
+                AdapterError::new(
+                    AdapterErrorKind::UnexpectedResult,
+                    "Expected 'type' to be a string column",
+                )
+            })?;
+
+        if string_array.len() != 1 {
+            return Err(AdapterError::new(
+                AdapterErrorKind::UnexpectedResult,
+                "Did not find 'type' for a relation",
+            ));
+        }
+
+        let relation_type = match string_array.value(0) {
+            "table" => Some(RelationType::Table),
+            "view" => Some(RelationType::View),
+            _ => return invalid_value!("Unsupported relation type {}", string_array.value(0)),
+        };
+
+        let relation = TrinoRelation::new(
+            Some(database.to_string()),
+            Some(schema.to_string()),
+            Some(identifier.to_string()),
+            relation_type,
+            self.quoting(),
+        );
+        Ok(Some(Arc::new(relation)))
+    }
+
+    fn verify_database(&self, _database: String) -> AdapterResult<Value> {
+        // A Trino cluster federates its catalogs, so cross-database
+        // references are legitimate
+        Ok(Value::from(()))
+    }
+
+    fn get_constraint_support(&self, ct: ConstraintType) -> ConstraintSupport {
+        // Only not null is part of the table definitions of the connectors
+        match ct {
+            ConstraintType::NotNull => ConstraintSupport::Enforced,
+            _ => ConstraintSupport::NotSupported,
+        }
+    }
+}
+
+impl fmt::Display for TrinoAdapter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TrinoAdapter({})", self.adapter_type())
+    }
+}
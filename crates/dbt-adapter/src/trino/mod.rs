@@ -0,0 +1,24 @@
+pub mod adapter;
+pub mod metadata;
+/// Trino relation
+pub mod relation;
+
+/// Quote a catalog name so that it can qualify `information_schema`.
+///
+/// Each Trino catalog has its own `information_schema`, and identifiers
+/// cannot be bound as parameters, so this is the only name that is
+/// interpolated in metadata queries.
+pub fn quote_catalog(catalog: &str) -> String {
+    format!("\"{}\"", catalog.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote_catalog() {
+        assert_eq!(quote_catalog("hive"), "\"hive\"");
+        assert_eq!(quote_catalog("a\"b"), "\"a\"\"b\"");
+    }
+}
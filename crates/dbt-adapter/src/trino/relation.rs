@@ -0,0 +1,235 @@
+use crate::information_schema::InformationSchema;
+use crate::relation_object::{RelationObject, StaticBaseRelation};
+
+use dbt_common::{ErrorCode, FsResult, fs_err};
+use dbt_schemas::dbt_types::RelationType;
+use dbt_schemas::schemas::common::ResolvedQuoting;
+use dbt_schemas::schemas::relations::base::{
+    BaseRelation, BaseRelationProperties, Policy, RelationPath,
+};
+use minijinja::{Error as MinijinjaError, State, Value};
+
+use std::any::Any;
+use std::sync::Arc;
+
+/// A struct representing the Trino relation type for use with static methods
+#[derive(Clone, Debug)]
+pub struct TrinoRelationType(pub ResolvedQuoting);
+
+impl StaticBaseRelation for TrinoRelationType {
+    fn try_new(
+        &self,
+        database: Option<String>,
+        schema: Option<String>,
+        identifier: Option<String>,
+        relation_type: Option<RelationType>,
+        custom_quoting: Option<ResolvedQuoting>,
+    ) -> Result<Value, MinijinjaError> {
+        Ok(RelationObject::new(Arc::new(TrinoRelation::new(
+            database,
+            schema,
+            identifier,
+            relation_type,
+            custom_quoting.unwrap_or(self.0),
+        )))
+        .into_value())
+    }
+
+    fn get_adapter_type(&self) -> String {
+        "trino".to_string()
+    }
+}
+
+/// A relation object for trino adapter
+#[derive(Clone, Debug)]
+pub struct TrinoRelation {
+    /// The database, schema, and identifier of the relation
+    pub path: RelationPath,
+    /// The relation type
+    pub relation_type: Option<RelationType>,
+    /// Include policy
+    pub include_policy: Policy,
+    /// Quote policy
+    pub quote_policy: Policy,
+}
+
+impl TrinoRelation {
+    /// Creates a new Trino relation
+    ///
+    /// Trino folds unquoted identifiers to lowercase and most connectors
+    /// store names in lowercase, see [BaseRelation::normalize_component].
+    pub fn new(
+        database: Option<String>,
+        schema: Option<String>,
+        identifier: Option<String>,
+        relation_type: Option<RelationType>,
+        custom_quoting: ResolvedQuoting,
+    ) -> Self {
+        Self::new_with_policy(
+            RelationPath {
+                database,
+                schema,
+                identifier,
+            },
+            relation_type,
+            Policy::enabled(),
+            custom_quoting,
+        )
+    }
+
+    /// Creates a new Trino relation with an explicit include policy
+    pub fn new_with_policy(
+        path: RelationPath,
+        relation_type: Option<RelationType>,
+        include_policy: Policy,
+        quote_policy: Policy,
+    ) -> Self {
+        Self {
+            path,
+            relation_type,
+            include_policy,
+            quote_policy,
+        }
+    }
+}
+
+impl BaseRelationProperties for TrinoRelation {
+    fn include_policy(&self) -> Policy {
+        self.include_policy
+    }
+
+    fn quote_policy(&self) -> Policy {
+        self.quote_policy
+    }
+
+    fn quote_character(&self) -> char {
+        '"'
+    }
+    fn get_database(&self) -> FsResult<String> {
+        self.path.database.clone().ok_or_else(|| {
+            fs_err!(
+                ErrorCode::InvalidConfig,
+                "database is required for trino relation",
+            )
+        })
+    }
+
+    fn get_schema(&self) -> FsResult<String> {
+        self.path.schema.clone().ok_or_else(|| {
+            fs_err!(
+                ErrorCode::InvalidConfig,
+                "schema is required for trino relation",
+            )
+        })
+    }
+
+    fn get_identifier(&self) -> FsResult<String> {
+        self.path.identifier.clone().ok_or_else(|| {
+            fs_err!(
+                ErrorCode::InvalidConfig,
+                "identifier is required for trino relation",
+            )
+        })
+    }
+}
+
+impl BaseRelation for TrinoRelation {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn create_from(&self, _: &State, _: &[Value]) -> Result<Value, MinijinjaError> {
+        unimplemented!("Trino relation creation from Jinja values")
+    }
+
+    fn database(&self) -> Value {
+        Value::from(self.path.database.clone())
+    }
+
+    fn schema(&self) -> Value {
+        Value::from(self.path.schema.clone())
+    }
+
+    fn identifier(&self) -> Value {
+        Value::from(self.path.identifier.clone())
+    }
+
+    fn relation_type(&self) -> Option<RelationType> {
+        self.relation_type
+    }
+
+    fn as_value(&self) -> Value {
+        RelationObject::new(Arc::new(self.clone())).into_value()
+    }
+
+    fn adapter_type(&self) -> Option<String> {
+        Some("trino".to_string())
+    }
+
+    fn include_inner(&self, include_policy: Policy) -> Result<Value, MinijinjaError> {
+        let relation = TrinoRelation::new_with_policy(
+            self.path.clone(),
+            self.relation_type,
+            include_policy,
+            self.quote_policy,
+        );
+        Ok(relation.as_value())
+    }
+
+    fn normalize_component(&self, component: &str) -> String {
+        component.to_lowercase()
+    }
+
+    fn create_relation(
+        &self,
+        database: Option<String>,
+        schema: Option<String>,
+        identifier: Option<String>,
+        relation_type: Option<RelationType>,
+        custom_quoting: Policy,
+    ) -> Result<Arc<dyn BaseRelation>, MinijinjaError> {
+        Ok(Arc::new(TrinoRelation::new(
+            database,
+            schema,
+            identifier,
+            relation_type,
+            custom_quoting,
+        )))
+    }
+
+    fn information_schema_inner(
+        &self,
+        database: Option<String>,
+        view_name: Option<&str>,
+    ) -> Result<Value, MinijinjaError> {
+        let result = InformationSchema::try_from_relation(database, view_name)?;
+        Ok(RelationObject::new(Arc::new(result)).into_value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbt_schemas::{dbt_types::RelationType, schemas::relations::DEFAULT_RESOLVED_QUOTING};
+
+    #[test]
+    fn test_try_new_via_static_base_relation() {
+        let relation = TrinoRelationType(DEFAULT_RESOLVED_QUOTING)
+            .try_new(
+                Some("d".to_string()),
+                Some("s".to_string()),
+                Some("i".repeat(100)),
+                Some(RelationType::Table),
+                Some(DEFAULT_RESOLVED_QUOTING),
+            )
+            .unwrap();
+
+        let relation = relation.downcast_object::<RelationObject>().unwrap();
+        assert_eq!(
+            relation.inner().render_self().unwrap().as_str().unwrap(),
+            format!("\"d\".\"s\".\"{}\"", "i".repeat(100))
+        );
+        assert_eq!(relation.relation_type().unwrap(), RelationType::Table);
+        assert_eq!(relation.inner().adapter_type().unwrap(), "trino");
+    }
+}
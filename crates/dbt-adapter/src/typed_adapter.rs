@@ -31,7 +31,7 @@ use dbt_schemas::schemas::relations::relation_configs::BaseRelationConfig;
 use dbt_schemas::schemas::{CommonAttributes, InternalDbtNodeAttributes, InternalDbtNodeWrapper};
 use dbt_xdbc::bigquery::QUERY_LINK_FAILED_JOB;
 use dbt_xdbc::salesforce::DATA_TRANSFORM_RUN_TIMEOUT;
-use dbt_xdbc::{Connection, QueryCtx};
+use dbt_xdbc::{Connection, QueryCtx, QueryParams};
 use indexmap::IndexMap;
 use minijinja::{State, Value, args};
 
@@ -73,6 +73,10 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
         static DATABRICKS: [DbtIncrementalStrategy; 4] =
             [Append, Merge, InsertOverwrite, ReplaceWhere];
         static REDSHIFT: [DbtIncrementalStrategy; 4] = [Append, DeleteInsert, Merge, Microbatch];
+        static DUCKDB: [DbtIncrementalStrategy; 4] = [Append, DeleteInsert, Merge, Microbatch];
+        static TRINO: [DbtIncrementalStrategy; 4] = [Append, DeleteInsert, Merge, Microbatch];
+        // Only strategies that need nothing beyond plain insert and delete
+        static GENERIC: [DbtIncrementalStrategy; 2] = [Append, DeleteInsert];
 
         match self.adapter_type() {
             AdapterType::Postgres => &POSTGRES,
@@ -80,6 +84,9 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
             AdapterType::Bigquery => &BIGQUERY,
             AdapterType::Databricks => &DATABRICKS,
             AdapterType::Redshift => &REDSHIFT,
+            AdapterType::DuckDB => &DUCKDB,
+            AdapterType::Trino => &TRINO,
+            AdapterType::Generic => &GENERIC,
             AdapterType::Salesforce => {
                 unimplemented!("Salesforce valid_incremental_strategies not implemented")
             }
@@ -259,6 +266,26 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
         )
     }
 
+    /// Execute a single query with the parameters bound to its placeholders and get
+    /// results in an [AgateTable].
+    ///
+    /// Names and other values coming from the project must be passed this way
+    /// rather than formatted into the SQL text.
+    fn query_with_params(
+        &self,
+        ctx: &QueryCtx,
+        conn: &'_ mut dyn Connection,
+        sql: &str,
+        params: &QueryParams,
+    ) -> AdapterResult<(AdapterResponse, AgateTable)> {
+        let batch = self
+            .engine()
+            .execute_with_params(None, conn, ctx, sql, params)?;
+        let response = AdapterResponse::new(&batch, self.adapter_type());
+        let table = AgateTable::from_record_batch(Arc::new(batch));
+        Ok((response, table))
+    }
+
     /// Execute a query with a new connection
     fn execute_with_new_connection(
         &self,
@@ -313,7 +340,10 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
             | AdapterType::Databricks
             | AdapterType::Redshift
             | AdapterType::Postgres
-            | AdapterType::Salesforce => Err(AdapterError::new(
+            | AdapterType::Salesforce
+            | AdapterType::DuckDB
+            | AdapterType::Trino
+            | AdapterType::Generic => Err(AdapterError::new(
                 AdapterErrorKind::Internal,
                 format!(
                     "Python models are not supported for {} adapter",
@@ -367,6 +397,7 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
                 AdapterType::Bigquery => "schema_name",
                 AdapterType::Postgres | AdapterType::Redshift => "nspname",
                 AdapterType::Salesforce => "name",
+                AdapterType::DuckDB | AdapterType::Trino | AdapterType::Generic => "schema_name",
             };
             get_column_values::<StringArray>(&result_set, col_name)?
         };
@@ -1089,6 +1120,9 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
             Bigquery => bigquery::list_relations(adapter, query_ctx, conn, db_schema),
             Databricks => databricks::list_relations(adapter, query_ctx, conn, db_schema),
             Redshift => redshift::list_relations(adapter, query_ctx, conn, db_schema),
+            DuckDB => duckdb::list_relations(adapter, query_ctx, conn, db_schema),
+            Trino => trino::list_relations(adapter, query_ctx, conn, db_schema),
+            Generic => generic::list_relations(adapter, query_ctx, conn, db_schema),
             Postgres | Salesforce => {
                 let err = AdapterError::new(
                     AdapterErrorKind::Internal,
@@ -1184,6 +1218,17 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
         unimplemented!("only available with Databricks adapter")
     }
 
+    /// dialect_query
+    fn dialect_query(&self, name: &str, _params: &[(&str, &str)]) -> AdapterResult<String> {
+        Err(AdapterError::new(
+            AdapterErrorKind::NotSupported,
+            format!(
+                "adapter.dialect_query('{name}') is only available with the generic adapter, not the {} adapter",
+                self.adapter_type()
+            ),
+        ))
+    }
+
     /// relation_max_name_length
     fn relation_max_name_length(&self) -> AdapterResult<u32> {
         unimplemented!("only available with Postgres and Redshift adapters")
@@ -0,0 +1,56 @@
+use crate::{AdapterConfig, Auth, AuthError};
+
+use dbt_xdbc::{Backend, database, duckdb};
+
+pub struct DuckDBAuth;
+
+impl Auth for DuckDBAuth {
+    fn backend(&self) -> Backend {
+        Backend::DuckDB
+    }
+
+    fn configure(&self, config: &AdapterConfig) -> Result<database::Builder, AuthError> {
+        let mut builder = database::Builder::new(self.backend());
+
+        // DuckDB is an embedded database: there are no credentials, only the
+        // path of the database file. Extensions, attachments and settings are
+        // applied per connection by the adapter.
+        let path = config
+            .get_string("path")
+            .filter(|path| !path.is_empty())
+            .unwrap_or(duckdb::IN_MEMORY_PATH.into());
+        builder.with_named_option(duckdb::PATH, path)?;
+
+        Ok(builder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use adbc_core::options::{OptionDatabase, OptionValue};
+    use dbt_serde_yaml::Mapping;
+
+    fn configured_path(config: Mapping) -> String {
+        let builder = DuckDBAuth {}
+            .configure(&AdapterConfig::new(config))
+            .unwrap();
+        let (key, value) = builder.into_iter().next().unwrap();
+        assert!(matches!(key, OptionDatabase::Other(ref name) if name == duckdb::PATH));
+        match value {
+            OptionValue::String(path) => path,
+            _ => panic!("unexpected value"),
+        }
+    }
+
+    #[test]
+    fn test_path() {
+        let config = Mapping::from_iter([("path".into(), "jaffle_shop.duckdb".into())]);
+        assert_eq!(configured_path(config), "jaffle_shop.duckdb");
+    }
+
+    #[test]
+    fn test_default_to_in_memory() {
+        assert_eq!(configured_path(Mapping::default()), duckdb::IN_MEMORY_PATH);
+    }
+}
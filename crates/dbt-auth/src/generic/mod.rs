@@ -0,0 +1,92 @@
+use crate::{AdapterConfig, Auth, AuthError};
+
+use dbt_xdbc::{Backend, database};
+
+/// Authentication for any ADBC driver: the options in the profile are handed
+/// to the driver without interpretation.
+pub struct GenericAuth {
+    /// A [Backend::Generic] naming the driver library
+    pub backend: Backend,
+}
+
+impl Auth for GenericAuth {
+    fn backend(&self) -> Backend {
+        self.backend
+    }
+
+    fn configure(&self, config: &AdapterConfig) -> Result<database::Builder, AuthError> {
+        let mut builder = database::Builder::new(self.backend());
+
+        // Not every driver takes a URL (e.g. SQLite takes `:memory:` or a
+        // path), so the uri is passed on without parsing it
+        if let Some(uri) = config.get_string("uri") {
+            builder.with_named_option("uri", uri)?;
+        }
+
+        match config.get("driver_options") {
+            None | Some(dbt_serde_yaml::Value::Null(_)) => {}
+            Some(dbt_serde_yaml::Value::Mapping(options, _)) => {
+                let options = AdapterConfig::new(options.clone());
+                for key in options.repr().keys() {
+                    let Some(key) = key.as_str() else {
+                        return Err(AuthError::config("driver_options keys must be strings"));
+                    };
+                    if let Some(value) = options.get_string(key) {
+                        builder.with_named_option(key, value)?;
+                    }
+                }
+            }
+            Some(_) => return Err(AuthError::config("driver_options must be a mapping")),
+        }
+
+        Ok(builder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use adbc_core::options::{OptionDatabase, OptionValue};
+
+    #[test]
+    fn test_configure_passes_options_through() {
+        let config = AdapterConfig::new(
+            dbt_serde_yaml::from_str(
+                r#"
+driver: adbc_driver_sqlite
+uri: ":memory:"
+driver_options:
+  adbc.sqlite.load_extension.enabled: true
+  batch_rows: 1024
+"#,
+            )
+            .unwrap(),
+        );
+        let auth = GenericAuth {
+            backend: Backend::Generic {
+                library_name: "adbc_driver_sqlite",
+                entrypoint: None,
+            },
+        };
+        let options = auth
+            .configure(&config)
+            .unwrap()
+            .into_iter()
+            .filter_map(|(key, value)| match (key, value) {
+                (OptionDatabase::Other(key), OptionValue::String(value)) => Some((key, value)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            options,
+            vec![
+                ("uri".to_string(), ":memory:".to_string()),
+                (
+                    "adbc.sqlite.load_extension.enabled".to_string(),
+                    "true".to_string()
+                ),
+                ("batch_rows".to_string(), "1024".to_string()),
+            ]
+        );
+    }
+}
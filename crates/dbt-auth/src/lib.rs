@@ -10,10 +10,13 @@ mod config;
 // Database-specific auth implementations
 mod bigquery;
 mod databricks;
+mod duckdb;
+mod generic;
 mod postgres;
 mod redshift;
 mod salesforce;
 mod snowflake;
+mod trino;
 
 pub use config::AdapterConfig;
 
@@ -35,8 +38,9 @@ pub fn auth_for_backend(backend: Backend) -> Box<dyn Auth> {
         Backend::Databricks | Backend::DatabricksODBC => Box::new(databricks::DatabricksAuth {}),
         Backend::Redshift | Backend::RedshiftODBC => Box::new(redshift::RedshiftAuth {}),
         Backend::Salesforce => Box::new(salesforce::SalesforceAuth {}),
-        Backend::DuckDB => unimplemented!("duckdb backend authentication"),
-        Backend::Generic { .. } => unimplemented!("generic backend authentication"),
+        Backend::DuckDB => Box::new(duckdb::DuckDBAuth {}),
+        Backend::Trino => Box::new(trino::TrinoAuth {}),
+        Backend::Generic { .. } => Box::new(generic::GenericAuth { backend }),
     }
 }
 
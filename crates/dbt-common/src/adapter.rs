@@ -48,6 +48,12 @@ pub enum AdapterType {
     Redshift,
     /// Salesforce
     Salesforce,
+    /// DuckDB
+    DuckDB,
+    /// Trino (and Starburst)
+    Trino,
+    /// Any ADBC driver, with the SQL dialect described in the profile
+    Generic,
 }
 
 impl From<AdapterType> for Dialect {
@@ -62,6 +68,12 @@ impl From<AdapterType> for Dialect {
             // https://developer.salesforce.com/docs/data/data-cloud-query-guide/references/data-cloud-query-api-reference/c360a-api-query-v2-call-overview.html
             // falls back to Postgresql at the moment
             AdapterType::Salesforce => Dialect::Postgresql,
+            // DuckDB follows the Postgres dialect closely enough for parsing purposes
+            AdapterType::DuckDB => Dialect::Postgresql,
+            AdapterType::Trino => Dialect::Trino,
+            // Nothing is known about the dialect of a generic driver, Postgresql
+            // is the closest to ANSI SQL
+            AdapterType::Generic => Dialect::Postgresql,
         }
     }
 }
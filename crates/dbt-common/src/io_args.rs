@@ -256,6 +256,10 @@ pub struct EvalArgs {
     pub upgrade: bool,
     // Generate lock file only
     pub lock: bool,
+    // Install packages from the package cache only
+    pub offline: bool,
+    // The directory to vendor locked packages into
+    pub vendor: Option<PathBuf>,
     // The profile to use
     pub profile: Option<String>,
     // The target within the profile to use for the dbt run
@@ -308,6 +312,8 @@ pub struct EvalArgs {
     pub write_json: bool,
     /// Write a catalog.json file to the target directory
     pub write_catalog: bool,
+    /// Reuse rendered sql files of the previous invocation
+    pub partial_parse: bool,
     /// Show schema on the command line
     pub schema: Vec<JsonSchemaTypes>,
 
@@ -429,7 +435,11 @@ impl EvalArgsBuilder {
     ) -> Self {
         match adapter_type {
             // include adapters that don't support static analysis here
-            Some(AdapterType::Salesforce) | None => {
+            Some(AdapterType::Salesforce)
+            | Some(AdapterType::DuckDB)
+            | Some(AdapterType::Trino)
+            | Some(AdapterType::Generic)
+            | None => {
                 #[cfg(debug_assertions)]
                 {
                     println!(
@@ -733,6 +743,50 @@ impl ListOutputFormat {
     }
 }
 
+/// Output format of the graph command
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    Hash,
+    Eq,
+    ValueEnum,
+    Default,
+    EnumIter,
+    Display,
+)]
+#[strum(serialize_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+#[clap(rename_all = "lowercase")]
+pub enum GraphFormat {
+    /// Graphviz DOT
+    #[default]
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+    /// GraphML (XML)
+    Graphml,
+    /// JSON adjacency list
+    Json,
+}
+
+/// Optional layers of the graph command, on top of the nodes of the DAG
+#[derive(
+    Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Hash, Eq, ValueEnum, EnumIter, Display,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+#[clap(rename_all = "snake_case")]
+pub enum GraphLayer {
+    Exposures,
+    Metrics,
+    SemanticModels,
+    SavedQueries,
+}
+
 #[derive(Debug, Clone)]
 pub enum ReplayMode {
     DbtReplay(PathBuf),
@@ -11,6 +11,7 @@ use std::{
     ffi::OsStr,
     io::Read,
     path::{Path, PathBuf},
+    sync::{Arc, Mutex},
 };
 
 /// A trait for reporting status messages and errors that occur during execution.
@@ -32,6 +33,81 @@ pub trait StatusReporter: Any + Send + Sync {
     fn bulk_publish_empty(&self, file_paths: Vec<PathBuf>);
 }
 
+/// A [StatusReporter] which passes everything on to the reporter it wraps, if
+/// any, and keeps the errors and warnings so that they can be reported again.
+pub struct RecordingStatusReporter {
+    inner: Option<Arc<dyn StatusReporter>>,
+    /// The reported `(is_error, code, message)`, in order
+    diagnostics: Mutex<Vec<(bool, ErrorCode, String)>>,
+}
+
+impl RecordingStatusReporter {
+    pub fn new(inner: Option<Arc<dyn StatusReporter>>) -> Self {
+        Self {
+            inner,
+            diagnostics: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Takes the errors and warnings reported so far
+    pub fn take_diagnostics(&self) -> Vec<(bool, ErrorCode, String)> {
+        std::mem::take(&mut *self.diagnostics.lock().unwrap())
+    }
+}
+
+impl StatusReporter for RecordingStatusReporter {
+    fn collect_error(&self, error: &FsError) {
+        self.diagnostics
+            .lock()
+            .unwrap()
+            .push((true, error.code, error.message()));
+        if let Some(inner) = &self.inner {
+            inner.collect_error(error);
+        }
+    }
+
+    fn collect_warning(&self, warning: &FsError) {
+        self.diagnostics
+            .lock()
+            .unwrap()
+            .push((false, warning.code, warning.message()));
+        if let Some(inner) = &self.inner {
+            inner.collect_warning(warning);
+        }
+    }
+
+    fn collect_node_evaluation(
+        &self,
+        file_path: PathBuf,
+        execution_phase: ExecutionPhase,
+        node_outcome: NodeOutcome,
+        upstream_target: Option<(String, String, bool)>,
+        static_analysis_off_reason: (Option<StaticAnalysisOffReason>, Span),
+    ) {
+        if let Some(inner) = &self.inner {
+            inner.collect_node_evaluation(
+                file_path,
+                execution_phase,
+                node_outcome,
+                upstream_target,
+                static_analysis_off_reason,
+            );
+        }
+    }
+
+    fn show_progress(&self, action: &str, target: &str, description: Option<&str>) {
+        if let Some(inner) = &self.inner {
+            inner.show_progress(action, target, description);
+        }
+    }
+
+    fn bulk_publish_empty(&self, file_paths: Vec<PathBuf>) {
+        if let Some(inner) = &self.inner {
+            inner.bulk_publish_empty(file_paths);
+        }
+    }
+}
+
 /// Reads the contents of a file as a string.
 pub fn try_read_yml_to_str(path: &Path) -> FsResult<String> {
     let mut file = File::open(path).map_err(|e| {
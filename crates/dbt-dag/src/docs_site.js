@@ -0,0 +1,17 @@
+const search = document.getElementById("search");
+const typeFilter = document.getElementById("type-filter");
+function filterNodes() {
+  const terms = search.value.toLowerCase().split(/\s+/).filter(Boolean);
+  const type = typeFilter.value;
+  let shown = 0;
+  for (const row of document.querySelectorAll("#nodes tbody tr")) {
+    const text = row.dataset.search;
+    const visible = (!type || row.dataset.type === type) && terms.every(t => text.includes(t));
+    row.hidden = !visible;
+    if (visible) shown++;
+  }
+  document.getElementById("count").textContent = shown;
+}
+search.addEventListener("input", filterNodes);
+typeFilter.addEventListener("change", filterNodes);
+filterNodes();
@@ -0,0 +1,714 @@
+//! Static HTML documentation site generated from the manifest.
+//!
+//! The site is a flat directory of HTML pages (one per model, seed, snapshot,
+//! analysis, source, exposure and metric), a searchable index and a
+//! stylesheet. It does not load anything from the network, so it can be
+//! opened straight from a CI artifact.
+//!
+//! Pages are built from the serialized manifest, i.e. the same shape as
+//! `manifest.json`. When a legacy `catalog.json` is available, column types,
+//! table stats and columns missing from the project are added to the pages.
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt::Write;
+use std::path::Path;
+
+use dbt_common::{ErrorCode, FsResult, fs_err, stdfs};
+use dbt_schemas::schemas::legacy_catalog::{CatalogTable, DbtCatalog};
+use dbt_schemas::schemas::manifest::DbtManifest;
+use serde_json::Value;
+
+use crate::graph_export::escape_xml as escape_html;
+
+/// Resource types which get their own page
+const DOCUMENTED_TYPES: [&str; 7] = [
+    "model", "seed", "snapshot", "analysis", "source", "exposure", "metric",
+];
+
+const STYLESHEET: &str = include_str!("docs_site.css");
+const SEARCH_SCRIPT: &str = include_str!("docs_site.js");
+
+// Layout of the lineage diagram
+const BOX_WIDTH: usize = 240;
+const BOX_HEIGHT: usize = 28;
+const ROW: usize = 40;
+const COLUMN: usize = 320;
+
+/// All pages of the documentation site, by file name
+#[derive(Debug, Default)]
+pub struct DocsSite {
+    pub pages: BTreeMap<String, String>,
+}
+
+impl DocsSite {
+    pub fn build(manifest: &DbtManifest, catalog: Option<&DbtCatalog>) -> FsResult<Self> {
+        let manifest = serde_json::to_value(manifest).map_err(|e| {
+            fs_err!(
+                ErrorCode::SerializationError,
+                "Failed to serialize manifest: {}",
+                e
+            )
+        })?;
+        Ok(Self::from_manifest_json(&manifest, catalog))
+    }
+
+    /// Build the site from a manifest in the `manifest.json` format
+    pub fn from_manifest_json(manifest: &Value, catalog: Option<&DbtCatalog>) -> Self {
+        let site = SiteData::new(manifest, catalog);
+        let mut pages = BTreeMap::new();
+        pages.insert("index.html".to_string(), site.index_page());
+        pages.insert("style.css".to_string(), STYLESHEET.to_string());
+        for (unique_id, node) in &site.documented {
+            pages.insert(page_name(unique_id), site.node_page(unique_id, node));
+        }
+        Self { pages }
+    }
+
+    /// Write all pages to `dir`, creating it if needed
+    pub fn write(&self, dir: &Path) -> FsResult<()> {
+        stdfs::create_dir_all(dir)?;
+        for (name, contents) in &self.pages {
+            stdfs::write(dir.join(name), contents)?;
+        }
+        Ok(())
+    }
+}
+
+struct SiteData<'a> {
+    project_name: String,
+    documented: BTreeMap<&'a str, &'a Value>,
+    /// attached node -> tests on it
+    tests: BTreeMap<&'a str, Vec<&'a Value>>,
+    parent_map: BTreeMap<&'a str, Vec<&'a str>>,
+    child_map: BTreeMap<&'a str, Vec<&'a str>>,
+    catalog: Option<&'a DbtCatalog>,
+}
+
+impl<'a> SiteData<'a> {
+    fn new(manifest: &'a Value, catalog: Option<&'a DbtCatalog>) -> Self {
+        let mut documented = BTreeMap::new();
+        let mut tests: BTreeMap<&str, Vec<&Value>> = BTreeMap::new();
+        for section in ["nodes", "sources", "exposures", "metrics"] {
+            let Some(entries) = manifest.get(section).and_then(Value::as_object) else {
+                continue;
+            };
+            for (unique_id, node) in entries {
+                let resource_type = str_field(node, "resource_type")
+                    .unwrap_or_else(|| unique_id.split('.').next().unwrap_or_default());
+                if resource_type == "test" {
+                    for attached in tested_nodes(node) {
+                        tests.entry(attached).or_default().push(node);
+                    }
+                } else if DOCUMENTED_TYPES.contains(&resource_type) {
+                    documented.insert(unique_id.as_str(), node);
+                }
+            }
+        }
+
+        let edge_map = |key: &str| {
+            manifest
+                .get(key)
+                .and_then(Value::as_object)
+                .map(|map| {
+                    map.iter()
+                        .map(|(unique_id, ids)| (unique_id.as_str(), str_array(ids)))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        Self {
+            project_name: manifest
+                .pointer("/metadata/project_name")
+                .and_then(Value::as_str)
+                .or_else(|| {
+                    documented
+                        .values()
+                        .find_map(|node| str_field(node, "package_name"))
+                })
+                .unwrap_or("dbt project")
+                .to_string(),
+            documented,
+            tests,
+            parent_map: edge_map("parent_map"),
+            child_map: edge_map("child_map"),
+            catalog,
+        }
+    }
+
+    fn index_page(&self) -> String {
+        let mut body = String::new();
+        let types = self
+            .documented
+            .values()
+            .filter_map(|node| str_field(node, "resource_type"))
+            .collect::<BTreeSet<_>>();
+        let _ = write!(
+            body,
+            "<div class=\"search\"><input id=\"search\" type=\"search\" placeholder=\"Search by name, description, tag, column...\" autofocus>\
+             <select id=\"type-filter\"><option value=\"\">All types</option>"
+        );
+        for resource_type in &types {
+            let _ = write!(
+                body,
+                "<option value=\"{0}\">{0}</option>",
+                escape_html(resource_type)
+            );
+        }
+        let _ = write!(
+            body,
+            "</select> <span><span id=\"count\">{}</span> nodes</span></div>",
+            self.documented.len()
+        );
+        body.push_str(
+            "<table id=\"nodes\"><thead><tr><th>Name</th><th>Type</th><th>Package</th><th>Description</th></tr></thead><tbody>",
+        );
+        for (unique_id, node) in &self.documented {
+            let resource_type = str_field(node, "resource_type").unwrap_or_default();
+            let search = [
+                display_name(node),
+                unique_id.to_string(),
+                str_field(node, "description")
+                    .unwrap_or_default()
+                    .to_string(),
+                str_array(node.get("tags").unwrap_or(&Value::Null)).join(" "),
+                columns(node).into_keys().collect::<Vec<_>>().join(" "),
+            ]
+            .join(" ")
+            .to_lowercase();
+            let _ = write!(
+                body,
+                "<tr data-type=\"{}\" data-search=\"{}\"><td><a href=\"{}\">{}</a></td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                escape_html(resource_type),
+                escape_html(&search),
+                page_name(unique_id),
+                escape_html(&display_name(node)),
+                escape_html(resource_type),
+                escape_html(str_field(node, "package_name").unwrap_or_default()),
+                escape_html(first_line(
+                    str_field(node, "description").unwrap_or_default()
+                )),
+            );
+        }
+        body.push_str("</tbody></table>");
+        let _ = write!(body, "<script>{SEARCH_SCRIPT}</script>");
+        self.layout(&self.project_name, &body)
+    }
+
+    fn node_page(&self, unique_id: &str, node: &Value) -> String {
+        let resource_type = str_field(node, "resource_type").unwrap_or_default();
+        let catalog_table = self.catalog.and_then(|catalog| {
+            if resource_type == "source" {
+                catalog.sources.get(unique_id)
+            } else {
+                catalog.nodes.get(unique_id)
+            }
+        });
+
+        let mut body = String::new();
+        let _ = write!(
+            body,
+            "<h1>{} <span class=\"badge\">{}</span></h1>",
+            escape_html(&display_name(node)),
+            escape_html(resource_type)
+        );
+        body.push_str(&details(unique_id, node));
+        body.push_str("<h2>Description</h2>");
+        body.push_str(&description(str_field(node, "description")));
+
+        if matches!(resource_type, "model" | "seed" | "snapshot" | "source") {
+            body.push_str(&self.columns_section(unique_id, node, catalog_table));
+        }
+        if let Some(tests) = self.tests.get(unique_id) {
+            body.push_str(
+                "<h2>Tests</h2><table><thead><tr><th>Test</th><th>Column</th></tr></thead><tbody>",
+            );
+            for test in tests {
+                let _ = write!(
+                    body,
+                    "<tr><td>{}</td><td>{}</td></tr>",
+                    escape_html(&test_name(test)),
+                    escape_html(str_field(test, "column_name").unwrap_or_default())
+                );
+            }
+            body.push_str("</tbody></table>");
+        }
+        if let Some(table) = catalog_table {
+            body.push_str(&catalog_section(table));
+        }
+        for (title, key) in [("Code", "raw_code"), ("Compiled code", "compiled_code")] {
+            if let Some(code) = str_field(node, key).filter(|code| !code.trim().is_empty()) {
+                let _ = write!(
+                    body,
+                    "<h2>{title}</h2><pre><code>{}</code></pre>",
+                    escape_html(code)
+                );
+            }
+        }
+        body.push_str(&self.lineage_section(unique_id));
+        self.layout(&display_name(node), &body)
+    }
+
+    fn columns_section(
+        &self,
+        unique_id: &str,
+        node: &Value,
+        catalog_table: Option<&CatalogTable>,
+    ) -> String {
+        let mut columns = columns(node)
+            .into_iter()
+            .map(|(name, column)| {
+                let data_type = str_field(column, "data_type").map(str::to_string);
+                let description = str_field(column, "description").map(str::to_string);
+                (name.to_lowercase(), (name, data_type, description))
+            })
+            .collect::<BTreeMap<_, _>>();
+        if let Some(table) = catalog_table {
+            for (name, column) in &table.columns {
+                let entry = columns
+                    .entry(name.to_lowercase())
+                    .or_insert_with(|| (name.clone(), None, column.comment.clone()));
+                entry.1 = Some(column.data_type.clone());
+            }
+        }
+        if columns.is_empty() {
+            return String::new();
+        }
+
+        let mut out = String::from(
+            "<h2>Columns</h2><table><thead><tr><th>Column</th><th>Type</th><th>Description</th><th>Tests</th></tr></thead><tbody>",
+        );
+        let tests = self.tests.get(unique_id);
+        for (key, (name, data_type, description)) in columns {
+            let column_tests = tests
+                .into_iter()
+                .flatten()
+                .filter(|test| {
+                    str_field(test, "column_name")
+                        .is_some_and(|column| column.to_lowercase() == key)
+                })
+                .map(|test| test_name(test))
+                .collect::<Vec<_>>();
+            let _ = write!(
+                out,
+                "<tr><td><code>{}</code></td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                escape_html(&name),
+                escape_html(data_type.as_deref().unwrap_or_default()),
+                escape_html(description.as_deref().unwrap_or_default()),
+                escape_html(&column_tests.join(", ")),
+            );
+        }
+        out.push_str("</tbody></table>");
+        out
+    }
+
+    fn lineage_section(&self, unique_id: &str) -> String {
+        let direct = |edges: &BTreeMap<&'a str, Vec<&'a str>>| {
+            edges
+                .get(unique_id)
+                .into_iter()
+                .flatten()
+                .copied()
+                .filter(|other| !is_test(other))
+                .collect::<Vec<_>>()
+        };
+        let parents = direct(&self.parent_map);
+        let children = direct(&self.child_map);
+        let mut out = String::from("<h2>Lineage</h2>");
+        out.push_str(&self.lineage_svg(unique_id, &parents, &children));
+        for (title, edges) in [
+            ("Upstream", &self.parent_map),
+            ("Downstream", &self.child_map),
+        ] {
+            let reachable = reachable(unique_id, edges);
+            if reachable.is_empty() {
+                continue;
+            }
+            let _ = write!(out, "<h3>{title} ({})</h3><ul>", reachable.len());
+            for (other, depth) in reachable {
+                let _ = write!(
+                    out,
+                    "<li>{} <span class=\"depth\">{depth}</span></li>",
+                    self.link(other)
+                );
+            }
+            out.push_str("</ul>");
+        }
+        out
+    }
+
+    /// Direct parents on the left, direct children on the right
+    fn lineage_svg(&self, unique_id: &str, parents: &[&str], children: &[&str]) -> String {
+        let rows = parents.len().max(children.len()).max(1);
+        let height = rows * ROW + 10;
+        let center = |count: usize, index: usize| (rows - count) * ROW / 2 + index * ROW + 5;
+        let node_y = center(1, 0);
+        let mut out = format!(
+            "<svg class=\"lineage\" width=\"{}\" height=\"{height}\" xmlns=\"http://www.w3.org/2000/svg\">",
+            COLUMN * 2 + BOX_WIDTH
+        );
+        for (column, ids) in [(0, parents), (2, children)] {
+            for (index, other) in ids.iter().enumerate() {
+                let y = center(ids.len(), index);
+                let (x1, y1, x2, y2) = if column == 0 {
+                    (BOX_WIDTH, y, COLUMN, node_y)
+                } else {
+                    (COLUMN + BOX_WIDTH, node_y, 2 * COLUMN, y)
+                };
+                let _ = write!(
+                    out,
+                    "<line x1=\"{x1}\" y1=\"{}\" x2=\"{x2}\" y2=\"{}\"/>",
+                    y1 + BOX_HEIGHT / 2,
+                    y2 + BOX_HEIGHT / 2
+                );
+                out.push_str(&self.svg_box(other, column * COLUMN, y, false));
+            }
+        }
+        out.push_str(&self.svg_box(unique_id, COLUMN, node_y, true));
+        out.push_str("</svg>");
+        out
+    }
+
+    fn svg_box(&self, unique_id: &str, x: usize, y: usize, current: bool) -> String {
+        let label = self
+            .documented
+            .get(unique_id)
+            .map(|node| display_name(node))
+            .unwrap_or_else(|| unique_id.to_string());
+        let class = if current { "node current" } else { "node" };
+        let rect = format!(
+            "<g class=\"{class} {}\"><rect x=\"{x}\" y=\"{y}\" width=\"{BOX_WIDTH}\" height=\"{BOX_HEIGHT}\" rx=\"4\"/><text x=\"{}\" y=\"{}\">{}</text></g>",
+            unique_id.split('.').next().unwrap_or_default(),
+            x + 8,
+            y + 18,
+            escape_html(&truncate(&label, 30))
+        );
+        if !current && self.documented.contains_key(unique_id) {
+            format!("<a href=\"{}\">{rect}</a>", page_name(unique_id))
+        } else {
+            rect
+        }
+    }
+
+    fn link(&self, unique_id: &str) -> String {
+        match self.documented.get(unique_id) {
+            Some(node) => format!(
+                "<a href=\"{}\">{}</a> <span class=\"badge\">{}</span>",
+                page_name(unique_id),
+                escape_html(&display_name(node)),
+                escape_html(str_field(node, "resource_type").unwrap_or_default())
+            ),
+            None => format!("<code>{}</code>", escape_html(unique_id)),
+        }
+    }
+
+    fn layout(&self, title: &str, body: &str) -> String {
+        format!(
+            "<!DOCTYPE html>\n<html lang=\"en\"><head><meta charset=\"utf-8\"><title>{} | {}</title>\
+             <link rel=\"stylesheet\" href=\"style.css\"></head><body>\
+             <nav><a href=\"index.html\">{}</a></nav><main>{body}</main></body></html>\n",
+            escape_html(title),
+            escape_html(&self.project_name),
+            escape_html(&self.project_name),
+        )
+    }
+}
+
+fn details(unique_id: &str, node: &Value) -> String {
+    let mut rows = vec![("Unique id", unique_id.to_string())];
+    for (label, pointer) in [
+        ("Package", "/package_name"),
+        ("Path", "/original_file_path"),
+        ("Materialization", "/config/materialized"),
+        ("Relation", "/relation_name"),
+        ("Loader", "/loader"),
+        ("Type", "/type"),
+        ("Label", "/label"),
+        ("Maturity", "/maturity"),
+        ("Owner", "/owner/name"),
+        ("Owner email", "/owner/email"),
+        ("URL", "/url"),
+    ] {
+        if let Some(value) = node.pointer(pointer).and_then(Value::as_str)
+            && !value.is_empty()
+        {
+            rows.push((label, value.to_string()));
+        }
+    }
+    let tags = str_array(node.get("tags").unwrap_or(&Value::Null));
+    if !tags.is_empty() {
+        rows.push(("Tags", tags.join(", ")));
+    }
+    let mut out = String::from("<table class=\"details\"><tbody>");
+    for (label, value) in rows {
+        let _ = write!(
+            out,
+            "<tr><th>{label}</th><td>{}</td></tr>",
+            escape_html(&value)
+        );
+    }
+    out.push_str("</tbody></table>");
+    out
+}
+
+fn catalog_section(table: &CatalogTable) -> String {
+    let mut out = String::from("<h2>Catalog</h2><table class=\"details\"><tbody>");
+    let metadata = &table.metadata;
+    let relation = [
+        metadata.database.as_deref(),
+        Some(metadata.schema.as_str()),
+        Some(metadata.name.as_str()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join(".");
+    for (label, value) in [
+        ("Relation", Some(relation.as_str())),
+        ("Type", Some(metadata.materialization_type.as_str())),
+        ("Owner", metadata.owner.as_deref()),
+        ("Comment", metadata.comment.as_deref()),
+    ] {
+        if let Some(value) = value.filter(|value| !value.is_empty()) {
+            let _ = write!(
+                out,
+                "<tr><th>{label}</th><td>{}</td></tr>",
+                escape_html(value)
+            );
+        }
+    }
+    for stat in table.stats.values().filter(|stat| stat.include) {
+        let value = match &stat.value {
+            Value::String(value) => value.clone(),
+            value => value.to_string(),
+        };
+        let _ = write!(
+            out,
+            "<tr><th>{}</th><td>{}</td></tr>",
+            escape_html(&stat.label),
+            escape_html(&value)
+        );
+    }
+    out.push_str("</tbody></table>");
+    out
+}
+
+/// All nodes reachable through `edges`, with their distance
+fn reachable<'a>(
+    unique_id: &'a str,
+    edges: &BTreeMap<&'a str, Vec<&'a str>>,
+) -> BTreeMap<&'a str, usize> {
+    let mut seen = BTreeMap::new();
+    let mut queue = VecDeque::from([(unique_id, 0)]);
+    while let Some((current, depth)) = queue.pop_front() {
+        for &next in edges.get(current).into_iter().flatten() {
+            if next != unique_id && !is_test(next) && !seen.contains_key(next) {
+                seen.insert(next, depth + 1);
+                queue.push_back((next, depth + 1));
+            }
+        }
+    }
+    seen
+}
+
+/// Tests are listed on the pages of the nodes they test, not in the lineage
+fn is_test(unique_id: &str) -> bool {
+    unique_id.starts_with("test.") || unique_id.starts_with("unit_test.")
+}
+
+/// The nodes a test is attached to
+fn tested_nodes(test: &Value) -> Vec<&str> {
+    match str_field(test, "attached_node") {
+        Some(attached) => vec![attached],
+        None => str_array(test.pointer("/depends_on/nodes").unwrap_or(&Value::Null)),
+    }
+}
+
+fn test_name(test: &Value) -> String {
+    test.pointer("/test_metadata/name")
+        .and_then(Value::as_str)
+        .or_else(|| str_field(test, "name"))
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Columns by name; the manifest stores them as a map, older artifacts as a list
+fn columns(node: &Value) -> BTreeMap<String, &Value> {
+    match node.get("columns") {
+        Some(Value::Object(columns)) => columns
+            .iter()
+            .map(|(name, column)| {
+                (
+                    str_field(column, "name").unwrap_or(name).to_string(),
+                    column,
+                )
+            })
+            .collect(),
+        Some(Value::Array(columns)) => columns
+            .iter()
+            .filter_map(|column| Some((str_field(column, "name")?.to_string(), column)))
+            .collect(),
+        _ => BTreeMap::new(),
+    }
+}
+
+fn display_name(node: &Value) -> String {
+    let name = str_field(node, "name").unwrap_or_default();
+    match str_field(node, "source_name") {
+        Some(source_name) => format!("{source_name}.{name}"),
+        None => name.to_string(),
+    }
+}
+
+fn description(description: Option<&str>) -> String {
+    match description.map(str::trim).filter(|d| !d.is_empty()) {
+        Some(description) => description
+            .split("\n\n")
+            .map(|paragraph| format!("<p>{}</p>", escape_html(paragraph.trim())))
+            .collect(),
+        None => "<p class=\"empty\">No description</p>".to_string(),
+    }
+}
+
+fn str_field<'a>(value: &'a Value, key: &str) -> Option<&'a str> {
+    value.get(key).and_then(Value::as_str)
+}
+
+fn str_array(value: &Value) -> Vec<&str> {
+    value
+        .as_array()
+        .map(|values| values.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
+
+fn first_line(text: &str) -> &str {
+    text.trim().lines().next().unwrap_or_default()
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        text.to_string()
+    } else {
+        let truncated = text.chars().take(max_chars - 1).collect::<String>();
+        format!("{truncated}…")
+    }
+}
+
+/// File name of the page of a node
+pub fn page_name(unique_id: &str) -> String {
+    let name = unique_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    format!("{name}.html")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbt_schemas::schemas::legacy_catalog::ColumnMetadata;
+    use serde_json::json;
+
+    #[test]
+    fn test_docs_site() {
+        let manifest = json!({
+            "metadata": {"project_name": "proj"},
+            "nodes": {
+                "model.proj.orders": {
+                    "resource_type": "model",
+                    "name": "orders",
+                    "package_name": "proj",
+                    "description": "All orders.\n\nOne row per <order>.",
+                    "columns": {"id": {"name": "id", "description": "Primary key"}},
+                    "config": {"materialized": "table"},
+                    "tags": ["finance"],
+                    "raw_code": "select * from {{ source('shop', 'raw_orders') }}",
+                },
+                "test.proj.unique_orders_id.abc": {
+                    "resource_type": "test",
+                    "name": "unique_orders_id",
+                    "column_name": "id",
+                    "attached_node": "model.proj.orders",
+                    "test_metadata": {"name": "unique"},
+                },
+            },
+            "sources": {
+                "source.proj.shop.raw_orders": {
+                    "resource_type": "source",
+                    "name": "raw_orders",
+                    "source_name": "shop",
+                    "package_name": "proj",
+                },
+            },
+            "exposures": {
+                "exposure.proj.dashboard": {
+                    "resource_type": "exposure",
+                    "name": "dashboard",
+                    "type": "dashboard",
+                    "owner": {"name": "Analytics"},
+                },
+            },
+            "metrics": {},
+            "parent_map": {
+                "model.proj.orders": ["source.proj.shop.raw_orders"],
+                "exposure.proj.dashboard": ["model.proj.orders"],
+            },
+            "child_map": {
+                "source.proj.shop.raw_orders": ["model.proj.orders"],
+                "model.proj.orders": ["exposure.proj.dashboard", "test.proj.unique_orders_id.abc"],
+            },
+        });
+        let mut catalog = DbtCatalog::default();
+        let mut table = CatalogTable::default();
+        table.columns.insert(
+            "ID".to_string(),
+            ColumnMetadata {
+                data_type: "integer".to_string(),
+                name: "ID".to_string(),
+                ..Default::default()
+            },
+        );
+        table.columns.insert(
+            "AMOUNT".to_string(),
+            ColumnMetadata {
+                data_type: "numeric".to_string(),
+                name: "AMOUNT".to_string(),
+                ..Default::default()
+            },
+        );
+        catalog.nodes.insert("model.proj.orders".to_string(), table);
+
+        let site = DocsSite::from_manifest_json(&manifest, Some(&catalog));
+        assert_eq!(
+            site.pages.keys().collect::<Vec<_>>(),
+            [
+                "exposure.proj.dashboard.html",
+                "index.html",
+                "model.proj.orders.html",
+                "source.proj.shop.raw_orders.html",
+                "style.css",
+            ]
+        );
+
+        let index = &site.pages["index.html"];
+        assert!(index.contains("<a href=\"model.proj.orders.html\">orders</a>"));
+        assert!(index.contains("<a href=\"source.proj.shop.raw_orders.html\">shop.raw_orders</a>"));
+        assert!(!index.contains("unique_orders_id.html"));
+
+        let model = &site.pages["model.proj.orders.html"];
+        assert!(model.contains("<p>One row per &lt;order&gt;.</p>"));
+        assert!(model.contains(
+            "<tr><td><code>id</code></td><td>integer</td><td>Primary key</td><td>unique</td></tr>"
+        ));
+        assert!(model.contains("<td><code>AMOUNT</code></td><td>numeric</td>"));
+        assert!(model.contains("<h3>Upstream (1)</h3>"));
+        assert!(model.contains("<a href=\"exposure.proj.dashboard.html\">"));
+        assert!(!model.contains("<script src"));
+    }
+}
@@ -0,0 +1,382 @@
+//! Export of the dependency graph of a project for other tools: Graphviz DOT,
+//! Mermaid, GraphML and a JSON adjacency list.
+//!
+//! Edges point from a node to the nodes depending on it, i.e. in the direction
+//! the data flows.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use dbt_common::io_args::{GraphFormat, GraphLayer};
+use dbt_common::{ErrorCode, FsResult, fs_err};
+use dbt_schemas::schemas::telemetry::NodeType;
+use dbt_schemas::schemas::{InternalDbtNodeAttributes, Nodes};
+use serde::Serialize;
+
+use crate::selector::build_deps;
+
+/// A node of the exported graph and the attributes it carries
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GraphNode {
+    pub unique_id: String,
+    pub name: String,
+    pub resource_type: String,
+    pub package_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub materialized: Option<String>,
+    pub tags: Vec<String>,
+}
+
+/// The dependency graph restricted to the exported nodes
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LineageGraph {
+    pub nodes: BTreeMap<String, GraphNode>,
+    /// node -> the exported nodes depending on it
+    pub adjacency: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl LineageGraph {
+    /// Build the graph of `selected` nodes (all nodes if `None`).
+    ///
+    /// Exposures, metrics, semantic models and saved queries are only part of the
+    /// graph if their layer is requested. Nodes left out are bridged, so a metric
+    /// on top of a hidden semantic model still points back to the model below it.
+    pub fn build(
+        nodes: &Nodes,
+        selected: Option<&BTreeSet<String>>,
+        layers: &[GraphLayer],
+    ) -> Self {
+        let deps = build_deps(nodes);
+        let graph_nodes = nodes
+            .iter()
+            .filter(|(unique_id, node)| {
+                selected.is_none_or(|selected| selected.contains(*unique_id))
+                    && layer(node.resource_type()).is_none_or(|layer| layers.contains(&layer))
+            })
+            .map(|(unique_id, node)| (unique_id.clone(), graph_node(node)))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut adjacency = graph_nodes
+            .keys()
+            .map(|unique_id| (unique_id.clone(), BTreeSet::new()))
+            .collect::<BTreeMap<_, _>>();
+        for unique_id in graph_nodes.keys() {
+            let mut visited = BTreeSet::new();
+            let mut stack = deps[unique_id].iter().collect::<Vec<_>>();
+            while let Some(parent) = stack.pop() {
+                if !visited.insert(parent) {
+                    continue;
+                }
+                if let Some(children) = adjacency.get_mut(parent) {
+                    children.insert(unique_id.clone());
+                } else {
+                    stack.extend(&deps[parent]);
+                }
+            }
+        }
+
+        Self {
+            nodes: graph_nodes,
+            adjacency,
+        }
+    }
+
+    pub fn render(&self, format: GraphFormat) -> FsResult<String> {
+        match format {
+            GraphFormat::Dot => Ok(self.to_dot()),
+            GraphFormat::Mermaid => Ok(self.to_mermaid()),
+            GraphFormat::Graphml => Ok(self.to_graphml()),
+            GraphFormat::Json => serde_json::to_string_pretty(self).map_err(|e| {
+                fs_err!(
+                    ErrorCode::SerializationError,
+                    "Failed to serialize graph: {}",
+                    e
+                )
+            }),
+        }
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.adjacency
+            .iter()
+            .flat_map(|(parent, children)| children.iter().map(move |child| (parent, child)))
+    }
+
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph dbt {\n    rankdir=LR;\n    node [style=filled];\n");
+        for node in self.nodes.values() {
+            let _ = writeln!(
+                out,
+                "    \"{}\" [label=\"{}\", shape={}, fillcolor=\"{}\", resource_type=\"{}\", package=\"{}\", materialized=\"{}\", tags=\"{}\"];",
+                escape_dot(&node.unique_id),
+                escape_dot(&node.name),
+                dot_shape(&node.resource_type),
+                color(&node.resource_type),
+                escape_dot(&node.resource_type),
+                escape_dot(&node.package_name),
+                escape_dot(node.materialized.as_deref().unwrap_or_default()),
+                escape_dot(&node.tags.join(",")),
+            );
+        }
+        for (parent, child) in self.edges() {
+            let _ = writeln!(
+                out,
+                "    \"{}\" -> \"{}\";",
+                escape_dot(parent),
+                escape_dot(child)
+            );
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    pub fn to_mermaid(&self) -> String {
+        // Mermaid ids can't contain most punctuation, so nodes are numbered
+        let ids = self
+            .nodes
+            .keys()
+            .enumerate()
+            .map(|(index, unique_id)| (unique_id, format!("n{index}")))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut out = String::from("flowchart LR\n");
+        for (unique_id, node) in &self.nodes {
+            let mut label = format!("{}<br/><small>{}", node.name, node.resource_type);
+            if let Some(materialized) = &node.materialized {
+                let _ = write!(label, " · {materialized}");
+            }
+            label.push_str("</small>");
+            let _ = writeln!(
+                out,
+                "    {}[\"{}\"]:::{}",
+                ids[unique_id],
+                escape_mermaid(&label),
+                node.resource_type
+            );
+        }
+        for (parent, child) in self.edges() {
+            let _ = writeln!(out, "    {} --> {}", ids[parent], ids[child]);
+        }
+        let resource_types = self
+            .nodes
+            .values()
+            .map(|node| node.resource_type.as_str())
+            .collect::<BTreeSet<_>>();
+        for resource_type in resource_types {
+            let _ = writeln!(
+                out,
+                "    classDef {resource_type} fill:{}",
+                color(resource_type)
+            );
+        }
+        out
+    }
+
+    pub fn to_graphml(&self) -> String {
+        let mut out = String::from(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n",
+            "  <key id=\"name\" for=\"node\" attr.name=\"name\" attr.type=\"string\"/>\n",
+            "  <key id=\"resource_type\" for=\"node\" attr.name=\"resource_type\" attr.type=\"string\"/>\n",
+            "  <key id=\"package\" for=\"node\" attr.name=\"package\" attr.type=\"string\"/>\n",
+            "  <key id=\"materialized\" for=\"node\" attr.name=\"materialized\" attr.type=\"string\"/>\n",
+            "  <key id=\"tags\" for=\"node\" attr.name=\"tags\" attr.type=\"string\"/>\n",
+            "  <graph id=\"dbt\" edgedefault=\"directed\">\n",
+        ));
+        for node in self.nodes.values() {
+            let _ = writeln!(out, "    <node id=\"{}\">", escape_xml(&node.unique_id));
+            let mut data = vec![
+                ("name", node.name.clone()),
+                ("resource_type", node.resource_type.clone()),
+                ("package", node.package_name.clone()),
+            ];
+            if let Some(materialized) = &node.materialized {
+                data.push(("materialized", materialized.clone()));
+            }
+            data.push(("tags", node.tags.join(",")));
+            for (key, value) in data {
+                let _ = writeln!(
+                    out,
+                    "      <data key=\"{key}\">{}</data>",
+                    escape_xml(&value)
+                );
+            }
+            out.push_str("    </node>\n");
+        }
+        for (parent, child) in self.edges() {
+            let _ = writeln!(
+                out,
+                "    <edge source=\"{}\" target=\"{}\"/>",
+                escape_xml(parent),
+                escape_xml(child)
+            );
+        }
+        out.push_str("  </graph>\n</graphml>\n");
+        out
+    }
+}
+
+fn graph_node(node: &dyn InternalDbtNodeAttributes) -> GraphNode {
+    let resource_type = node.resource_type();
+    let materialized = matches!(
+        resource_type,
+        NodeType::Model | NodeType::Seed | NodeType::Snapshot | NodeType::Function
+    )
+    .then(|| node.materialized().to_string());
+    GraphNode {
+        unique_id: node.common().unique_id.clone(),
+        name: node.common().name.clone(),
+        resource_type: resource_type.as_ref().to_string(),
+        package_name: node.package_name(),
+        materialized,
+        tags: node.tags(),
+    }
+}
+
+/// The optional layer a resource type belongs to, if any
+fn layer(resource_type: NodeType) -> Option<GraphLayer> {
+    match resource_type {
+        NodeType::Exposure => Some(GraphLayer::Exposures),
+        NodeType::Metric => Some(GraphLayer::Metrics),
+        NodeType::SemanticModel => Some(GraphLayer::SemanticModels),
+        NodeType::SavedQuery => Some(GraphLayer::SavedQueries),
+        _ => None,
+    }
+}
+
+fn dot_shape(resource_type: &str) -> &'static str {
+    match resource_type {
+        "source" => "cylinder",
+        "seed" => "folder",
+        "test" | "unit_test" => "note",
+        "exposure" => "house",
+        "metric" | "saved_query" => "hexagon",
+        "semantic_model" => "component",
+        _ => "box",
+    }
+}
+
+fn color(resource_type: &str) -> &'static str {
+    match resource_type {
+        "model" => "#cfe2f3",
+        "source" => "#d9ead3",
+        "seed" => "#fff2cc",
+        "snapshot" => "#f4cccc",
+        "test" | "unit_test" => "#eeeeee",
+        "exposure" => "#fce5cd",
+        _ => "#d9d2e9",
+    }
+}
+
+fn escape_dot(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_mermaid(value: &str) -> String {
+    value.replace('"', "#quot;")
+}
+
+pub(crate) fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbt_schemas::schemas::{DbtExposure, DbtModel};
+    use std::sync::Arc;
+
+    fn nodes() -> Nodes {
+        let mut nodes = Nodes::default();
+        for (name, deps, tags) in [
+            ("a", vec![], vec!["nightly"]),
+            ("b", vec!["model.proj.a"], vec![]),
+        ] {
+            let mut model = DbtModel::default();
+            model.__common_attr__.unique_id = format!("model.proj.{name}");
+            model.__common_attr__.name = name.to_string();
+            model.__common_attr__.package_name = "proj".to_string();
+            model.__common_attr__.tags = tags.into_iter().map(String::from).collect();
+            model.__base_attr__.depends_on.nodes = deps.into_iter().map(String::from).collect();
+            nodes
+                .models
+                .insert(model.__common_attr__.unique_id.clone(), Arc::new(model));
+        }
+        let mut exposure = DbtExposure {
+            __common_attr__: Default::default(),
+            __base_attr__: Default::default(),
+            __exposure_attr__: Default::default(),
+            deprecated_config: Default::default(),
+        };
+        exposure.__common_attr__.unique_id = "exposure.proj.dash\"board".to_string();
+        exposure.__common_attr__.name = "dash\"board".to_string();
+        exposure.__base_attr__.depends_on.nodes = vec!["model.proj.b".to_string()];
+        nodes.exposures.insert(
+            exposure.__common_attr__.unique_id.clone(),
+            Arc::new(exposure),
+        );
+        nodes
+    }
+
+    #[test]
+    fn test_layers_and_bridged_edges() {
+        let nodes = nodes();
+        let graph = LineageGraph::build(&nodes, None, &[]);
+        assert_eq!(
+            graph.nodes.keys().collect::<Vec<_>>(),
+            ["model.proj.a", "model.proj.b"]
+        );
+        assert_eq!(graph.nodes["model.proj.a"].tags, ["nightly"]);
+
+        let graph = LineageGraph::build(&nodes, None, &[GraphLayer::Exposures]);
+        assert_eq!(graph.nodes.len(), 3);
+        assert!(
+            graph.nodes["exposure.proj.dash\"board"]
+                .materialized
+                .is_none()
+        );
+
+        // a -> b -> exposure, with b not selected
+        let selected = BTreeSet::from([
+            "model.proj.a".to_string(),
+            "exposure.proj.dash\"board".to_string(),
+        ]);
+        let graph = LineageGraph::build(&nodes, Some(&selected), &[GraphLayer::Exposures]);
+        assert_eq!(
+            graph.adjacency["model.proj.a"],
+            BTreeSet::from(["exposure.proj.dash\"board".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let graph = LineageGraph::build(&nodes(), None, &[GraphLayer::Exposures]);
+
+        let dot = graph.render(GraphFormat::Dot).unwrap();
+        assert!(dot.contains("\"model.proj.a\" -> \"model.proj.b\";"));
+        assert!(dot.contains("\"model.proj.b\" -> \"exposure.proj.dash\\\"board\";"));
+        assert!(dot.contains("tags=\"nightly\""));
+
+        let mermaid = graph.render(GraphFormat::Mermaid).unwrap();
+        assert!(mermaid.starts_with("flowchart LR\n"));
+        assert!(mermaid.contains("n1 --> n2"));
+        assert!(mermaid.contains("dash#quot;board"));
+
+        let graphml = graph.render(GraphFormat::Graphml).unwrap();
+        assert!(graphml.contains("<edge source=\"model.proj.a\" target=\"model.proj.b\"/>"));
+        assert!(graphml.contains("<node id=\"exposure.proj.dash&quot;board\">"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&graph.render(GraphFormat::Json).unwrap()).unwrap();
+        assert_eq!(
+            json["adjacency"]["model.proj.a"],
+            serde_json::json!(["model.proj.b"])
+        );
+        assert_eq!(json["nodes"]["model.proj.b"]["resource_type"], "model");
+    }
+}
@@ -1,2 +1,5 @@
 pub mod deps_mgmt;
+pub mod docs_site;
+pub mod graph_export;
 pub mod schedule;
+pub mod selector;
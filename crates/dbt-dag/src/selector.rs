@@ -0,0 +1,1212 @@
+//! Evaluation of node selectors (`--select`, `--exclude`, yml selectors) against
+//! the resolved nodes of a project.
+//!
+//! The grammar lives in [dbt_common::node_selector]; this module gives it meaning:
+//! every [MethodName] is matched against [Nodes], graph operators (`+`, `n+`, `@`)
+//! walk the dependency DAG and indirect selection attaches tests to the result.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::path::Path;
+
+use dbt_common::io_args::ClapResourceType;
+use dbt_common::node_selector::{
+    IndirectSelection, MethodName, SelectExpression, SelectionCriteria,
+};
+use dbt_common::{ErrorCode, FsResult, err};
+use dbt_schemas::schemas::common::FreshnessStatus;
+use dbt_schemas::schemas::macros::DbtMacro;
+use dbt_schemas::schemas::telemetry::NodeType;
+use dbt_schemas::schemas::{
+    DbtSource, DbtTest, FreshnessResultsArtifact, FreshnessResultsNode, InternalDbtNodeAttributes,
+    ModificationType, Nodes, PreviousState,
+};
+
+use crate::deps_mgmt::topological_sort;
+use crate::schedule::Schedule;
+
+type JsonValue = serde_json::Value;
+
+/// The node statuses written to run_results.json, as accepted by `result:`
+const RESULT_STATUSES: [&str; 6] = ["success", "error", "fail", "warn", "skipped", "pass"];
+
+/// Evaluates select expressions over a fixed set of nodes.
+pub struct NodeSelector<'a> {
+    nodes: &'a Nodes,
+    root_project_name: &'a str,
+    // node -> the nodes it depends on
+    deps: BTreeMap<String, BTreeSet<String>>,
+    // node -> the nodes depending on it
+    dependents: BTreeMap<String, BTreeSet<String>>,
+    // previous state (--state) and the current macros to compare against it
+    previous_state: Option<(&'a PreviousState, &'a BTreeMap<String, DbtMacro>)>,
+    // the previous run results and freshness results by unique_id, looked up for every node
+    previous_run_results: HashMap<&'a str, &'a str>,
+    previous_source_freshness: HashMap<&'a str, &'a FreshnessResultsNode>,
+    // freshness results of the current run (target/sources.json), for `source_status:fresher`
+    current_source_freshness: Option<HashMap<&'a str, &'a FreshnessResultsNode>>,
+}
+
+impl<'a> NodeSelector<'a> {
+    pub fn new(nodes: &'a Nodes, root_project_name: &'a str) -> Self {
+        let deps = build_deps(nodes);
+        let mut dependents: BTreeMap<String, BTreeSet<String>> =
+            deps.keys().map(|k| (k.clone(), BTreeSet::new())).collect();
+        for (node, parents) in &deps {
+            for parent in parents {
+                dependents
+                    .entry(parent.clone())
+                    .or_default()
+                    .insert(node.clone());
+            }
+        }
+        Self {
+            nodes,
+            root_project_name,
+            deps,
+            dependents,
+            previous_state: None,
+            previous_run_results: HashMap::new(),
+            previous_source_freshness: HashMap::new(),
+            current_source_freshness: None,
+        }
+    }
+
+    /// Enable the `state:` selector method by comparing against a previous state
+    pub fn with_previous_state(
+        mut self,
+        previous_state: &'a PreviousState,
+        current_macros: &'a BTreeMap<String, DbtMacro>,
+    ) -> Self {
+        self.previous_state = Some((previous_state, current_macros));
+        self.previous_run_results = previous_state.run_result_statuses();
+        self.previous_source_freshness = previous_state.source_freshness_by_unique_id();
+        self
+    }
+
+    /// Provide the latest source freshness results, which `source_status:fresher`
+    /// compares against the ones of the previous state
+    pub fn with_current_source_freshness(
+        mut self,
+        current_source_freshness: &'a FreshnessResultsArtifact,
+    ) -> Self {
+        self.current_source_freshness = Some(current_source_freshness.results_by_unique_id());
+        self
+    }
+
+    /// The dependency graph of all nodes (node -> its parents).
+    pub fn deps(&self) -> &BTreeMap<String, BTreeSet<String>> {
+        &self.deps
+    }
+
+    /// Compute the selected nodes and wrap them into a [Schedule].
+    ///
+    /// Without a select expression every node is selected. Tests are attached
+    /// to the selection according to the indirect selection mode of each
+    /// expression (eager by default), following dbt-core's semantics.
+    pub fn schedule(
+        &self,
+        select: Option<&SelectExpression>,
+        exclude: Option<&SelectExpression>,
+        resource_types: &[ClapResourceType],
+        exclude_resource_types: &[ClapResourceType],
+    ) -> FsResult<Schedule<String>> {
+        let (mut direct, mut indirect, mode) = match select {
+            Some(expr) => {
+                let (direct, indirect) = self.select_recursively(expr)?;
+                (direct, indirect, indirect_mode(expr))
+            }
+            None => {
+                let all = self.deps.keys().cloned().collect::<BTreeSet<_>>();
+                (all.clone(), all, IndirectSelection::default())
+            }
+        };
+        indirect.extend(direct.iter().cloned());
+
+        if let Some(expr) = exclude {
+            let (excluded_direct, excluded_indirect) = self.select_recursively(expr)?;
+            direct.retain(|id| !excluded_direct.contains(id));
+            indirect.retain(|id| !excluded_direct.contains(id) && !excluded_indirect.contains(id));
+        }
+        let mut selected = self.incorporate_indirect_nodes(direct, &indirect, mode);
+
+        let included_types = resource_types
+            .iter()
+            .map(NodeType::from)
+            .collect::<BTreeSet<_>>();
+        let excluded_types = exclude_resource_types
+            .iter()
+            .map(NodeType::from)
+            .collect::<BTreeSet<_>>();
+        selected.retain(|id| {
+            let Some(node) = self.nodes.get_node(id) else {
+                return false;
+            };
+            let resource_type = node.resource_type();
+            (included_types.is_empty() || included_types.contains(&resource_type))
+                && !excluded_types.contains(&resource_type)
+        });
+
+        Ok(self.to_schedule(selected, select.cloned(), exclude.cloned()))
+    }
+
+    /// Evaluate an expression to the set of selected unique ids, with graph
+    /// operators and indirect selection applied.
+    pub fn evaluate(&self, expr: &SelectExpression) -> FsResult<BTreeSet<String>> {
+        Ok(self.select_recursively(expr)?.0)
+    }
+
+    /// Returns the directly selected nodes and the tests that are only
+    /// candidates for indirect selection (see dbt-core's `select_nodes_recursively`).
+    fn select_recursively(
+        &self,
+        expr: &SelectExpression,
+    ) -> FsResult<(BTreeSet<String>, BTreeSet<String>)> {
+        match expr {
+            SelectExpression::Atom(criteria) => {
+                let selected = self.evaluate_criteria(criteria)?;
+                Ok(self.expand_selection(selected, criteria.indirect.unwrap_or_default()))
+            }
+            SelectExpression::And(exprs) | SelectExpression::Or(exprs) => {
+                let is_and = matches!(expr, SelectExpression::And(_));
+                let (excludes, includes) = split_excludes(exprs);
+                let mut direct: Option<BTreeSet<String>> = None;
+                let mut indirect: Option<BTreeSet<String>> = None;
+                for sub in includes {
+                    let (sub_direct, sub_indirect) = self.select_recursively(sub)?;
+                    let sub_indirect = sub_indirect
+                        .union(&sub_direct)
+                        .cloned()
+                        .collect::<BTreeSet<_>>();
+                    direct = Some(combine(direct, sub_direct, is_and));
+                    indirect = Some(combine(indirect, sub_indirect, is_and));
+                }
+                let all = || self.deps.keys().cloned().collect::<BTreeSet<_>>();
+                let (mut direct, mut indirect) = match (direct, indirect) {
+                    (Some(direct), Some(indirect)) => (direct, indirect),
+                    // an expression made only of excludes starts from all nodes
+                    _ => (all(), all()),
+                };
+                for exclude in excludes {
+                    let (excluded, _) = self.select_recursively(exclude)?;
+                    direct.retain(|id| !excluded.contains(id));
+                    indirect.retain(|id| !excluded.contains(id));
+                }
+                let direct =
+                    self.incorporate_indirect_nodes(direct, &indirect, indirect_mode(expr));
+                Ok((direct, indirect))
+            }
+            SelectExpression::Exclude(inner) => {
+                let (excluded, _) = self.select_recursively(inner)?;
+                let remaining = self
+                    .deps
+                    .keys()
+                    .filter(|id| !excluded.contains(*id))
+                    .cloned()
+                    .collect::<BTreeSet<_>>();
+                Ok((remaining.clone(), remaining))
+            }
+        }
+    }
+
+    fn evaluate_criteria(&self, criteria: &SelectionCriteria) -> FsResult<BTreeSet<String>> {
+        let mut matched = BTreeSet::new();
+        for (unique_id, node) in self.nodes.iter() {
+            if self.matches(criteria, node)? {
+                matched.insert(unique_id.clone());
+            }
+        }
+
+        let mut result = matched.clone();
+        if let Some(depth) = criteria.parents_depth {
+            result.extend(walk(&self.deps, &matched, depth));
+        }
+        if let Some(depth) = criteria.children_depth {
+            result.extend(walk(&self.dependents, &matched, depth));
+        }
+        if criteria.childrens_parents {
+            // The parents of the selected nodes count too, a leaf has no children
+            let mut children = walk(&self.dependents, &matched, u32::MAX);
+            children.extend(matched.iter().cloned());
+            result.extend(walk(&self.deps, &children, u32::MAX));
+            result.extend(children);
+        }
+
+        if let Some(exclude) = &criteria.exclude {
+            let excluded = self.evaluate(exclude)?;
+            result.retain(|id| !excluded.contains(id));
+        }
+        Ok(result)
+    }
+
+    fn matches(
+        &self,
+        criteria: &SelectionCriteria,
+        node: &dyn InternalDbtNodeAttributes,
+    ) -> FsResult<bool> {
+        let value = criteria.value.as_str();
+        let matched = match criteria.method {
+            MethodName::Fqn => value == "*" || fqn_matches(node, value),
+            MethodName::Tag => node.tags().iter().any(|tag| glob_matches(value, tag)),
+            MethodName::Path => path_matches(&node.original_file_path(), value),
+            MethodName::File => {
+                let path = node.original_file_path();
+                let file_name = path.file_name().map(|s| s.to_string_lossy());
+                let stem = path.file_stem().map(|s| s.to_string_lossy());
+                file_name.is_some_and(|name| glob_matches(value, &name))
+                    || stem.is_some_and(|stem| glob_matches(value, &stem))
+            }
+            MethodName::Package => {
+                let package = if value == "this" {
+                    self.root_project_name
+                } else {
+                    value
+                };
+                glob_matches(package, &node.package_name())
+            }
+            MethodName::Config => config_matches(node, &criteria.method_args, value),
+            MethodName::Group => node
+                .get_group()
+                .is_some_and(|group| glob_matches(value, &group)),
+            MethodName::Access => node
+                .get_access()
+                .is_some_and(|access| access.to_string() == value),
+            MethodName::Version => version_matches(node, value)?,
+            MethodName::ResourceType => node.resource_type().as_ref() == value,
+            MethodName::TestType => test_type_matches(node, value)?,
+            MethodName::TestName => test_name_matches(node, value),
+            MethodName::Source => source_matches(node, value)?,
+            MethodName::Exposure => named_matches(node, NodeType::Exposure, value)?,
+            MethodName::Metric => named_matches(node, NodeType::Metric, value)?,
+            MethodName::SemanticModel => named_matches(node, NodeType::SemanticModel, value)?,
+            MethodName::SavedQuery => named_matches(node, NodeType::SavedQuery, value)?,
+            MethodName::UnitTest => named_matches(node, NodeType::UnitTest, value)?,
+            MethodName::Function => named_matches(node, NodeType::Function, value)?,
+            MethodName::State => self.state_matches(node, value)?,
+            MethodName::Result => self.result_matches(node, value)?,
+            MethodName::SourceStatus => self.source_status_matches(node, value)?,
+            MethodName::Column => {
+                return err!(
+                    ErrorCode::SelectorError,
+                    "The 'column' selector method is not supported for node selection"
+                );
+            }
+        };
+        Ok(matched)
+    }
+
+    fn state_matches(
+        &self,
+        node: &dyn InternalDbtNodeAttributes,
+        selector: &str,
+    ) -> FsResult<bool> {
+        let Some((previous_state, current_macros)) = self.previous_state else {
+            return err!(
+                ErrorCode::SelectorError,
+                "The 'state' selector method requires a previous state, use --state <dir>"
+            );
+        };
+        if previous_state.nodes.is_none() {
+            return err!(
+                ErrorCode::SelectorError,
+                "Could not load a manifest from the previous state in {}",
+                previous_state.state_path.display()
+            );
+        }
+        let (state, sub_selector) = match selector.split_once('.') {
+            Some((state, sub_selector)) => (state, Some(sub_selector)),
+            None => (selector, None),
+        };
+        Ok(match (state, sub_selector) {
+            ("new", None) => previous_state.is_new(node),
+            ("old", None) => previous_state.exists(node),
+            ("unmodified", None) => {
+                previous_state.exists(node)
+                    && !previous_state.is_modified(node, None, current_macros)
+            }
+            ("modified", None) => previous_state.is_modified(node, None, current_macros),
+            ("modified", Some(sub_selector)) => {
+                let Ok(modification_type) = sub_selector.parse::<ModificationType>() else {
+                    return err!(
+                        ErrorCode::SelectorError,
+                        "Invalid state selector 'modified.{}': expected one of 'body', 'configs', 'relation', 'persisted_descriptions', 'macros' or 'contract'",
+                        sub_selector
+                    );
+                };
+                previous_state.is_modified(node, Some(modification_type), current_macros)
+            }
+            _ => {
+                return err!(
+                    ErrorCode::SelectorError,
+                    "Invalid state selector '{}': expected one of 'new', 'old', 'modified' or 'unmodified'",
+                    selector
+                );
+            }
+        })
+    }
+
+    fn previous_state(&self, method: &MethodName) -> FsResult<&'a PreviousState> {
+        match self.previous_state {
+            Some((previous_state, _)) => Ok(previous_state),
+            None => err!(
+                ErrorCode::SelectorError,
+                "The '{}' selector method requires a previous state, use --state <dir>",
+                method
+            ),
+        }
+    }
+
+    fn result_matches(&self, node: &dyn InternalDbtNodeAttributes, status: &str) -> FsResult<bool> {
+        if !RESULT_STATUSES.contains(&status) {
+            return err!(
+                ErrorCode::SelectorError,
+                "Invalid result selector 'result:{}': expected one of {}",
+                status,
+                RESULT_STATUSES.join(", ")
+            );
+        }
+        let previous_state = self.previous_state(&MethodName::Result)?;
+        if previous_state.run_results.is_none() {
+            return err!(
+                ErrorCode::SelectorError,
+                "The 'result' selector method requires run_results.json in {}",
+                previous_state.state_path.display()
+            );
+        }
+        Ok(self
+            .previous_run_results
+            .get(node.common().unique_id.as_str())
+            == Some(&status))
+    }
+
+    fn source_status_matches(
+        &self,
+        node: &dyn InternalDbtNodeAttributes,
+        status: &str,
+    ) -> FsResult<bool> {
+        let previous_state = self.previous_state(&MethodName::SourceStatus)?;
+        if previous_state.source_freshness_results.is_none() {
+            return err!(
+                ErrorCode::SelectorError,
+                "The 'source_status' selector method requires sources.json in {}",
+                previous_state.state_path.display()
+            );
+        }
+        if node.resource_type() != NodeType::Source {
+            return Ok(false);
+        }
+        let unique_id = node.common().unique_id.as_str();
+        let previous = self.previous_source_freshness.get(unique_id);
+        Ok(match status {
+            // new data landed since the previous freshness check
+            "fresher" => {
+                let Some(current_source_freshness) = &self.current_source_freshness else {
+                    return err!(
+                        ErrorCode::SelectorError,
+                        "The 'source_status:fresher' selector requires the results of a recent 'source freshness' run (sources.json) in the target path"
+                    );
+                };
+                match current_source_freshness.get(unique_id) {
+                    Some(current) if current.status != FreshnessStatus::RuntimeError => previous
+                        .is_none_or(|previous| {
+                            previous.status == FreshnessStatus::RuntimeError
+                                || current.max_loaded_at > previous.max_loaded_at
+                        }),
+                    _ => false,
+                }
+            }
+            // the data is older than the warn_after/error_after thresholds
+            "stale" => previous.is_some_and(|previous| {
+                matches!(
+                    previous.status,
+                    FreshnessStatus::Warn | FreshnessStatus::Error
+                )
+            }),
+            // the freshness query itself failed
+            "error" => {
+                previous.is_some_and(|previous| previous.status == FreshnessStatus::RuntimeError)
+            }
+            _ => {
+                return err!(
+                    ErrorCode::SelectorError,
+                    "Invalid source_status selector 'source_status:{}': expected one of 'fresher', 'stale' or 'error'",
+                    status
+                );
+            }
+        })
+    }
+
+    /// Split the tests downstream of `selected` into directly selected ones and
+    /// candidates for indirect selection (see dbt-core's `expand_selection`).
+    fn expand_selection(
+        &self,
+        selected: BTreeSet<String>,
+        mode: IndirectSelection,
+    ) -> (BTreeSet<String>, BTreeSet<String>) {
+        let selected_and_parents = if mode == IndirectSelection::Buildable {
+            let mut ancestors = walk(&self.deps, &selected, u32::MAX);
+            ancestors.extend(selected.iter().cloned());
+            ancestors
+        } else {
+            BTreeSet::new()
+        };
+
+        let mut direct = selected.clone();
+        let mut indirect = BTreeSet::new();
+        for unique_id in walk(&self.dependents, &selected, 1) {
+            if selected.contains(&unique_id) || !self.can_select_indirectly(&unique_id) {
+                continue;
+            }
+            let parents = &self.deps[&unique_id];
+            if mode == IndirectSelection::Eager || parents.is_subset(&selected) {
+                direct.insert(unique_id);
+            } else if mode == IndirectSelection::Buildable
+                && parents.is_subset(&selected_and_parents)
+            {
+                direct.insert(unique_id);
+            } else if mode != IndirectSelection::Empty {
+                indirect.insert(unique_id);
+            }
+        }
+        (direct, indirect)
+    }
+
+    /// Add the indirect candidates whose parents are all part of the selection
+    /// (see dbt-core's `incorporate_indirect_nodes`).
+    fn incorporate_indirect_nodes(
+        &self,
+        direct: BTreeSet<String>,
+        indirect: &BTreeSet<String>,
+        mode: IndirectSelection,
+    ) -> BTreeSet<String> {
+        if &direct == indirect {
+            return direct;
+        }
+        let covered = match mode {
+            IndirectSelection::Cautious => direct.clone(),
+            IndirectSelection::Buildable => {
+                let mut ancestors = walk(&self.deps, &direct, u32::MAX);
+                ancestors.extend(direct.iter().cloned());
+                ancestors
+            }
+            IndirectSelection::Eager | IndirectSelection::Empty => return direct,
+        };
+        let mut selected = direct;
+        for unique_id in indirect {
+            if self.can_select_indirectly(unique_id)
+                && self
+                    .deps
+                    .get(unique_id)
+                    .is_some_and(|parents| parents.is_subset(&covered))
+            {
+                selected.insert(unique_id.clone());
+            }
+        }
+        selected
+    }
+
+    fn can_select_indirectly(&self, unique_id: &str) -> bool {
+        self.nodes
+            .get_node(unique_id)
+            .is_some_and(|node| matches!(node.resource_type(), NodeType::Test | NodeType::UnitTest))
+    }
+
+    fn to_schedule(
+        &self,
+        selected_nodes: BTreeSet<String>,
+        select: Option<SelectExpression>,
+        exclude: Option<SelectExpression>,
+    ) -> Schedule<String> {
+        let mut frontier_nodes = BTreeSet::new();
+        for id in &selected_nodes {
+            if let Some(parents) = self.deps.get(id) {
+                frontier_nodes.extend(
+                    parents
+                        .iter()
+                        .filter(|p| !selected_nodes.contains(*p))
+                        .cloned(),
+                );
+            }
+        }
+
+        let mut deps = BTreeMap::new();
+        for id in selected_nodes.iter().chain(frontier_nodes.iter()) {
+            let parents = self
+                .deps
+                .get(id)
+                .map(|parents| {
+                    parents
+                        .iter()
+                        .filter(|p| selected_nodes.contains(*p) || frontier_nodes.contains(*p))
+                        .cloned()
+                        .collect()
+                })
+                .unwrap_or_default();
+            deps.insert(id.clone(), parents);
+        }
+
+        let (mut sorted_nodes, frontier): (Vec<_>, Vec<_>) = topological_sort(&deps)
+            .into_iter()
+            .partition(|id| selected_nodes.contains(id));
+        sorted_nodes.extend(frontier);
+
+        Schedule {
+            deps,
+            sorted_nodes,
+            selected_nodes,
+            frontier_nodes,
+            unused_nodes: BTreeSet::new(),
+            select,
+            exclude,
+        }
+    }
+}
+
+/// Build the dependency graph (node -> parents) over all nodes. Edges to
+/// nodes that are not part of `nodes` (e.g. disabled ones) are dropped.
+pub fn build_deps(nodes: &Nodes) -> BTreeMap<String, BTreeSet<String>> {
+    let mut deps = BTreeMap::new();
+    for (unique_id, node) in nodes.iter() {
+        let parents = node
+            .base()
+            .depends_on
+            .nodes
+            .iter()
+            .filter(|parent| *parent != unique_id && nodes.contains(parent))
+            .cloned()
+            .collect::<BTreeSet<_>>();
+        deps.insert(unique_id.clone(), parents);
+    }
+    deps
+}
+
+/// Breadth-first walk from `start` along `edges`, up to `depth` hops.
+/// The start nodes themselves are not part of the result (unless reachable).
+fn walk(
+    edges: &BTreeMap<String, BTreeSet<String>>,
+    start: &BTreeSet<String>,
+    depth: u32,
+) -> BTreeSet<String> {
+    let mut visited = BTreeSet::new();
+    let mut queue = start
+        .iter()
+        .map(|id| (id.clone(), 0u32))
+        .collect::<VecDeque<_>>();
+    while let Some((id, dist)) = queue.pop_front() {
+        if dist >= depth {
+            continue;
+        }
+        if let Some(next) = edges.get(&id) {
+            for n in next {
+                if visited.insert(n.clone()) {
+                    queue.push_back((n.clone(), dist + 1));
+                }
+            }
+        }
+    }
+    visited
+}
+
+fn combine(
+    acc: Option<BTreeSet<String>>,
+    next: BTreeSet<String>,
+    intersect: bool,
+) -> BTreeSet<String> {
+    match acc {
+        Some(acc) if intersect => acc.intersection(&next).cloned().collect(),
+        Some(mut acc) => {
+            acc.extend(next);
+            acc
+        }
+        None => next,
+    }
+}
+
+fn split_excludes(exprs: &[SelectExpression]) -> (Vec<&SelectExpression>, Vec<&SelectExpression>) {
+    let mut excludes = Vec::new();
+    let mut includes = Vec::new();
+    for expr in exprs {
+        match expr {
+            SelectExpression::Exclude(inner) => excludes.push(inner.as_ref()),
+            other => includes.push(other),
+        }
+    }
+    (excludes, includes)
+}
+
+/// The indirect selection mode of an expression: the first mode found on any
+/// of its atoms, eager if none is set.
+pub fn indirect_mode(expr: &SelectExpression) -> IndirectSelection {
+    fn find(expr: &SelectExpression) -> Option<IndirectSelection> {
+        match expr {
+            SelectExpression::Atom(criteria) => criteria.indirect,
+            SelectExpression::And(exprs) | SelectExpression::Or(exprs) => {
+                exprs.iter().find_map(find)
+            }
+            SelectExpression::Exclude(_) => None,
+        }
+    }
+    find(expr).unwrap_or_default()
+}
+
+/// fnmatch-style matching; falls back to equality for invalid patterns.
+pub(crate) fn glob_matches(pattern: &str, value: &str) -> bool {
+    match glob::Pattern::new(pattern) {
+        Ok(p) => p.matches(value),
+        Err(_) => pattern == value,
+    }
+}
+
+fn has_wildcard(s: &str) -> bool {
+    s.contains(['*', '?', '[', ']'])
+}
+
+/// Port of dbt-core's `is_selected_node`
+fn fqn_matches(node: &dyn InternalDbtNodeAttributes, selector: &str) -> bool {
+    let fqn = &node.common().fqn;
+    let selector_parts = selector.split('.').collect::<Vec<_>>();
+    if node.is_versioned() && fqn.len() >= 2 {
+        let name = &fqn[fqn.len() - 2];
+        if name == selector {
+            return true;
+        }
+        if selector_parts.len() > 1
+            && name == selector_parts[0]
+            && fqn[fqn.len() - 1] == selector_parts[selector_parts.len() - 1]
+        {
+            return true;
+        }
+    } else if fqn.last().is_some_and(|name| name == selector) {
+        return true;
+    }
+
+    // Dots in model names act as namespace separators
+    let flat_fqn = fqn
+        .iter()
+        .flat_map(|segment| segment.split('.'))
+        .collect::<Vec<_>>();
+    if flat_fqn.len() < selector_parts.len() {
+        return false;
+    }
+    for (i, part) in selector_parts.iter().enumerate() {
+        if has_wildcard(part) {
+            return glob_matches(&selector_parts[i..].join("."), &flat_fqn[i..].join("."));
+        } else if flat_fqn[i] != *part {
+            return false;
+        }
+    }
+    true
+}
+
+fn path_matches(file_path: &Path, selector: &str) -> bool {
+    let selector = selector.trim_end_matches('/');
+    if selector.is_empty() || selector == "." {
+        return true;
+    }
+    let selector = selector.strip_prefix("./").unwrap_or(selector);
+    if has_wildcard(selector) {
+        return file_path
+            .ancestors()
+            .any(|p| glob_matches(selector, &p.to_string_lossy()));
+    }
+    file_path.starts_with(selector)
+}
+
+fn config_matches(node: &dyn InternalDbtNodeAttributes, keys: &[String], selector: &str) -> bool {
+    let Ok(config) = serde_json::to_value(node.serialized_config()) else {
+        return false;
+    };
+    let mut value = &config;
+    for key in keys {
+        match value.get(key) {
+            Some(v) => value = v,
+            None => return false,
+        }
+    }
+    match value {
+        JsonValue::Array(items) => items.iter().any(|item| scalar_matches(item, selector)),
+        other => scalar_matches(other, selector),
+    }
+}
+
+fn scalar_matches(value: &JsonValue, selector: &str) -> bool {
+    match value {
+        JsonValue::String(s) => s == selector,
+        JsonValue::Bool(b) => b.to_string() == selector.to_ascii_lowercase(),
+        JsonValue::Number(n) => n.to_string() == selector,
+        _ => false,
+    }
+}
+
+fn version_matches(node: &dyn InternalDbtNodeAttributes, selector: &str) -> FsResult<bool> {
+    let version = node.version().map(|v| v.to_string());
+    let latest = node.latest_version().map(|v| v.to_string());
+    let compare = |a: &str, b: &str| match (a.parse::<f64>(), b.parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.partial_cmp(&b),
+        _ => Some(a.cmp(b)),
+    };
+    Ok(match selector {
+        "none" => version.is_none(),
+        "latest" => version.is_some() && version == latest,
+        "prerelease" => match (&version, &latest) {
+            (Some(v), Some(l)) => compare(v, l) == Some(std::cmp::Ordering::Greater),
+            _ => false,
+        },
+        "old" => match (&version, &latest) {
+            (Some(v), Some(l)) => compare(v, l) == Some(std::cmp::Ordering::Less),
+            _ => false,
+        },
+        _ => {
+            return err!(
+                ErrorCode::SelectorError,
+                "Invalid version type selector '{}': expected one of 'latest', 'prerelease', 'old' or 'none'",
+                selector
+            );
+        }
+    })
+}
+
+fn test_type_matches(node: &dyn InternalDbtNodeAttributes, selector: &str) -> FsResult<bool> {
+    let data_test = node.as_any().downcast_ref::<DbtTest>();
+    Ok(match selector {
+        "generic" => data_test.is_some_and(|t| t.__test_attr__.test_metadata.is_some()),
+        "singular" => data_test.is_some_and(|t| t.__test_attr__.test_metadata.is_none()),
+        "data" => data_test.is_some(),
+        "unit" => node.resource_type() == NodeType::UnitTest,
+        _ => {
+            return err!(
+                ErrorCode::SelectorError,
+                "Invalid test type selector '{}': expected one of 'generic', 'singular', 'data' or 'unit'",
+                selector
+            );
+        }
+    })
+}
+
+fn test_name_matches(node: &dyn InternalDbtNodeAttributes, selector: &str) -> bool {
+    if let Some(test) = node.as_any().downcast_ref::<DbtTest>() {
+        match &test.__test_attr__.test_metadata {
+            Some(metadata) => {
+                glob_matches(selector, &metadata.name)
+                    || metadata.namespace.as_ref().is_some_and(|ns| {
+                        glob_matches(selector, &format!("{ns}.{}", metadata.name))
+                    })
+            }
+            None => glob_matches(selector, &node.name()),
+        }
+    } else {
+        node.resource_type() == NodeType::UnitTest && glob_matches(selector, &node.name())
+    }
+}
+
+fn source_matches(node: &dyn InternalDbtNodeAttributes, selector: &str) -> FsResult<bool> {
+    let Some(source) = node.as_any().downcast_ref::<DbtSource>() else {
+        return Ok(false);
+    };
+    let parts = selector.split('.').collect::<Vec<_>>();
+    let (package, source_name, table) = match parts.as_slice() {
+        [source_name] => ("*", *source_name, "*"),
+        [source_name, table] => ("*", *source_name, *table),
+        [package, source_name, table] => (*package, *source_name, *table),
+        _ => {
+            return err!(
+                ErrorCode::SelectorError,
+                "Invalid source selector value '{}': expected 'source_name', 'source_name.table' or 'package.source_name.table'",
+                selector
+            );
+        }
+    };
+    Ok(glob_matches(package, &source.__common_attr__.package_name)
+        && glob_matches(source_name, &source.__source_attr__.source_name)
+        && glob_matches(table, &source.__common_attr__.name))
+}
+
+/// Matches `name` or `package.name` for nodes of the given resource type
+fn named_matches(
+    node: &dyn InternalDbtNodeAttributes,
+    resource_type: NodeType,
+    selector: &str,
+) -> FsResult<bool> {
+    if node.resource_type() != resource_type {
+        return Ok(false);
+    }
+    let parts = selector.split('.').collect::<Vec<_>>();
+    let (package, name) = match parts.as_slice() {
+        [name] => ("*", *name),
+        [package, name] => (*package, *name),
+        _ => {
+            return err!(
+                ErrorCode::SelectorError,
+                "Invalid {} selector value '{}': expected 'name' or 'package.name'",
+                resource_type.as_ref(),
+                selector
+            );
+        }
+    };
+    Ok(glob_matches(package, &node.package_name()) && glob_matches(name, &node.name()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbt_common::node_selector::parse_model_specifiers;
+    use dbt_schemas::schemas::common::DbtChecksum;
+    use dbt_schemas::schemas::{DbtModel, RunResultsArtifact, TestMetadata};
+    use serde_json::json;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    fn model(name: &str, deps: &[&str], tags: &[&str]) -> (String, Arc<DbtModel>) {
+        let unique_id = format!("model.proj.{name}");
+        let mut model = DbtModel::default();
+        model.__common_attr__.unique_id = unique_id.clone();
+        model.__common_attr__.name = name.to_string();
+        model.__common_attr__.package_name = "proj".to_string();
+        model.__common_attr__.fqn = vec!["proj".to_string(), "staging".to_string(), name.into()];
+        model.__common_attr__.original_file_path =
+            PathBuf::from(format!("models/staging/{name}.sql"));
+        model.__common_attr__.tags = tags.iter().map(|t| t.to_string()).collect();
+        model.__base_attr__.depends_on.nodes = deps.iter().map(|d| d.to_string()).collect();
+        (unique_id, Arc::new(model))
+    }
+
+    fn test(name: &str, deps: &[&str]) -> (String, Arc<DbtTest>) {
+        let unique_id = format!("test.proj.{name}");
+        let mut test = DbtTest::default();
+        test.__common_attr__.unique_id = unique_id.clone();
+        test.__common_attr__.name = name.to_string();
+        test.__common_attr__.package_name = "proj".to_string();
+        test.__common_attr__.fqn = vec!["proj".to_string(), name.into()];
+        test.__base_attr__.depends_on.nodes = deps.iter().map(|d| d.to_string()).collect();
+        test.__test_attr__.test_metadata = Some(TestMetadata {
+            name: "not_null".to_string(),
+            kwargs: BTreeMap::new(),
+            namespace: None,
+        });
+        (unique_id, Arc::new(test))
+    }
+
+    // a <- b <- c, d standalone; t_b tests b, t_bd tests b and d
+    fn nodes() -> Nodes {
+        let mut nodes = Nodes::default();
+        nodes.models.extend([
+            model("a", &[], &["nightly"]),
+            model("b", &["model.proj.a"], &[]),
+            model("c", &["model.proj.b"], &["nightly"]),
+            model("d", &[], &[]),
+        ]);
+        nodes.tests.extend([
+            test("t_b", &["model.proj.b"]),
+            test("t_bd", &["model.proj.b", "model.proj.d"]),
+        ]);
+        nodes
+    }
+
+    fn select(nodes: &Nodes, spec: &str) -> BTreeSet<String> {
+        let tokens = spec.split(' ').map(String::from).collect::<Vec<_>>();
+        let expr = parse_model_specifiers(&tokens).unwrap();
+        NodeSelector::new(nodes, "proj")
+            .schedule(Some(&expr), None, &[], &[])
+            .unwrap()
+            .selected_nodes
+    }
+
+    fn ids(ids: &[&str]) -> BTreeSet<String> {
+        ids.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_methods() {
+        let nodes = nodes();
+        assert_eq!(
+            select(&nodes, "tag:nightly"),
+            ids(&["model.proj.a", "model.proj.c"])
+        );
+        assert_eq!(
+            select(&nodes, "path:models/staging/c.sql"),
+            ids(&["model.proj.c"])
+        );
+        // eager indirect selection pulls in tests that depend on d
+        assert_eq!(
+            select(&nodes, "proj.staging.d"),
+            ids(&["model.proj.d", "test.proj.t_bd"])
+        );
+        assert_eq!(select(&nodes, "package:this").len(), 6);
+        assert_eq!(
+            select(&nodes, "test_type:generic"),
+            ids(&["test.proj.t_b", "test.proj.t_bd"])
+        );
+    }
+
+    #[test]
+    fn test_graph_operators() {
+        let nodes = nodes();
+        assert_eq!(
+            select(&nodes, "+b,resource_type:model"),
+            ids(&["model.proj.a", "model.proj.b"])
+        );
+        assert_eq!(
+            select(&nodes, "a+1,resource_type:model"),
+            ids(&["model.proj.a", "model.proj.b"])
+        );
+        assert_eq!(
+            select(&nodes, "@b,resource_type:model"),
+            ids(&["model.proj.a", "model.proj.b", "model.proj.c"])
+        );
+        // the ancestors of a leaf are selected although it has no children
+        assert_eq!(
+            select(&nodes, "@c,resource_type:model"),
+            ids(&["model.proj.a", "model.proj.b", "model.proj.c"])
+        );
+    }
+
+    #[test]
+    fn test_indirect_selection() {
+        let nodes = nodes();
+        assert_eq!(
+            select(&nodes, "b"),
+            ids(&["model.proj.b", "test.proj.t_b", "test.proj.t_bd"])
+        );
+
+        let mut expr = parse_model_specifiers(&["b".to_string()]).unwrap();
+        expr.set_indirect_selection(IndirectSelection::Cautious);
+        let selected = NodeSelector::new(&nodes, "proj")
+            .schedule(Some(&expr), None, &[], &[])
+            .unwrap()
+            .selected_nodes;
+        assert_eq!(selected, ids(&["model.proj.b", "test.proj.t_b"]));
+    }
+
+    #[test]
+    fn test_exclude_and_schedule_order() {
+        let nodes = nodes();
+        let select = parse_model_specifiers(&["+c".to_string()]).unwrap();
+        let exclude = parse_model_specifiers(&["b".to_string()]).unwrap();
+        let schedule = NodeSelector::new(&nodes, "proj")
+            .schedule(Some(&select), Some(&exclude), &[], &[])
+            .unwrap();
+        assert_eq!(
+            schedule.selected_nodes,
+            ids(&["model.proj.a", "model.proj.c"])
+        );
+        assert_eq!(schedule.frontier_nodes, ids(&["model.proj.b"]));
+        assert_eq!(
+            schedule.sorted_nodes,
+            vec!["model.proj.a", "model.proj.c", "model.proj.b"]
+        );
+    }
+
+    #[test]
+    fn test_state_selectors() {
+        let previous_nodes = nodes();
+        let previous_macros = BTreeMap::from([
+            (
+                "macro.proj.m1".to_string(),
+                macro_def("m1", &["macro.proj.m2"], "m1"),
+            ),
+            ("macro.proj.m2".to_string(), macro_def("m2", &[], "m2")),
+        ]);
+        let previous_state = PreviousState {
+            nodes: Some(previous_nodes.deep_clone()),
+            macros: Some(previous_macros.clone()),
+            run_results: None,
+            source_freshness_results: None,
+            state_path: PathBuf::from("prod"),
+        };
+
+        // c's body changed, d calls a macro whose dependency changed, e is new
+        let mut nodes = previous_nodes;
+        let (_, c) = model("c", &["model.proj.b"], &["nightly"]);
+        let mut c = (*c).clone();
+        c.__common_attr__.checksum = DbtChecksum::hash(b"select 2");
+        nodes.models.insert("model.proj.c".to_string(), Arc::new(c));
+        let (_, d) = model("d", &[], &[]);
+        let mut d = (*d).clone();
+        d.__base_attr__.depends_on.macros = vec!["macro.proj.m1".to_string()];
+        nodes.models.insert("model.proj.d".to_string(), Arc::new(d));
+        nodes.models.extend([model("e", &["model.proj.c"], &[])]);
+        let mut current_macros = previous_macros;
+        current_macros.insert(
+            "macro.proj.m2".to_string(),
+            macro_def("m2", &[], "m2 changed"),
+        );
+
+        let selector =
+            NodeSelector::new(&nodes, "proj").with_previous_state(&previous_state, &current_macros);
+        let state_select = |spec: &str| {
+            let expr = parse_model_specifiers(&[spec.to_string()]).unwrap();
+            selector
+                .schedule(Some(&expr), None, &[ClapResourceType::Model], &[])
+                .unwrap()
+                .selected_nodes
+        };
+        assert_eq!(state_select("state:new"), ids(&["model.proj.e"]));
+        assert_eq!(
+            state_select("state:modified.body"),
+            ids(&["model.proj.c", "model.proj.e"])
+        );
+        assert_eq!(
+            state_select("state:modified.macros"),
+            ids(&["model.proj.d", "model.proj.e"])
+        );
+        assert_eq!(
+            state_select("state:modified"),
+            ids(&["model.proj.c", "model.proj.d", "model.proj.e"])
+        );
+        assert_eq!(
+            state_select("state:unmodified"),
+            ids(&["model.proj.a", "model.proj.b"])
+        );
+        assert_eq!(
+            state_select("+state:modified.body"),
+            ids(&[
+                "model.proj.a",
+                "model.proj.b",
+                "model.proj.c",
+                "model.proj.e"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_result_and_source_status_selectors() {
+        let mut nodes = nodes();
+        let mut source = DbtSource::default();
+        source.__common_attr__.unique_id = "source.proj.raw.orders".to_string();
+        source.__common_attr__.name = "orders".to_string();
+        source.__common_attr__.package_name = "proj".to_string();
+        nodes.sources.insert(
+            "source.proj.raw.orders".to_string(),
+            Arc::new(source.clone()),
+        );
+        source.__common_attr__.unique_id = "source.proj.raw.customers".to_string();
+        source.__common_attr__.name = "customers".to_string();
+        nodes
+            .sources
+            .insert("source.proj.raw.customers".to_string(), Arc::new(source));
+
+        let run_result = |unique_id: &str, status: &str| {
+            json!({
+                "unique_id": unique_id,
+                "status": status,
+                "timing": [],
+                "thread_id": "Thread-1",
+                "execution_time": 0.1,
+                "adapter_response": {},
+            })
+        };
+        let run_results: RunResultsArtifact = serde_json::from_value(json!({
+            "metadata": {
+                "dbt_schema_version": "https://schemas.getdbt.com/dbt/run-results/v6.json",
+                "dbt_version": "2.0.0",
+                "generated_at": "2026-10-16T00:00:00Z",
+                "invocation_id": "1",
+            },
+            "results": [
+                run_result("model.proj.a", "success"),
+                run_result("model.proj.b", "error"),
+                run_result("model.proj.c", "skipped"),
+                run_result("test.proj.t_b", "fail"),
+            ],
+            "elapsed_time": 1.0,
+            "args": {"command": "build", "which": "build", "__other__": {}},
+        }))
+        .unwrap();
+        let freshness = |orders: serde_json::Value, customers: serde_json::Value| {
+            serde_json::from_value::<FreshnessResultsArtifact>(json!({
+                "metadata": {
+                    "dbt_schema_version": "https://schemas.getdbt.com/dbt/sources/v3.json",
+                    "dbt_version": "2.0.0",
+                    "generated_at": "2026-10-16T00:00:00Z",
+                    "invocation_id": "1",
+                },
+                "results": [orders, customers],
+                "elapsed_time": 1.0,
+            }))
+            .unwrap()
+        };
+        let previous_state = PreviousState {
+            nodes: Some(nodes.deep_clone()),
+            macros: Some(BTreeMap::new()),
+            run_results: Some(run_results),
+            source_freshness_results: Some(freshness(
+                json!({
+                    "unique_id": "source.proj.raw.orders",
+                    "status": "warn",
+                    "max_loaded_at": "2026-10-15T00:00:00Z",
+                }),
+                json!({
+                    "unique_id": "source.proj.raw.customers",
+                    "status": "runtime error",
+                    "error": "relation raw.customers does not exist",
+                }),
+            )),
+            state_path: PathBuf::from("prod"),
+        };
+        let current_source_freshness = freshness(
+            json!({
+                "unique_id": "source.proj.raw.orders",
+                "status": "pass",
+                "max_loaded_at": "2026-10-16T00:00:00Z",
+            }),
+            json!({
+                "unique_id": "source.proj.raw.customers",
+                "status": "runtime error",
+            }),
+        );
+
+        let macros = BTreeMap::new();
+        let selector = NodeSelector::new(&nodes, "proj")
+            .with_previous_state(&previous_state, &macros)
+            .with_current_source_freshness(&current_source_freshness);
+        let selected = |spec: &str| {
+            let expr = parse_model_specifiers(&[spec.to_string()]).unwrap();
+            selector
+                .schedule(Some(&expr), None, &[], &[])
+                .unwrap()
+                .selected_nodes
+        };
+        assert_eq!(selected("result:skipped"), ids(&["model.proj.c"]));
+        assert_eq!(selected("result:fail"), ids(&["test.proj.t_b"]));
+        assert_eq!(
+            selected("result:error+"),
+            ids(&[
+                "model.proj.b",
+                "model.proj.c",
+                "test.proj.t_b",
+                "test.proj.t_bd"
+            ])
+        );
+        assert_eq!(
+            selected("source_status:fresher"),
+            ids(&["source.proj.raw.orders"])
+        );
+        assert_eq!(
+            selected("source_status:stale"),
+            ids(&["source.proj.raw.orders"])
+        );
+        assert_eq!(
+            selected("source_status:error"),
+            ids(&["source.proj.raw.customers"])
+        );
+
+        let expr = parse_model_specifiers(&["result:broken".to_string()]).unwrap();
+        assert!(selector.schedule(Some(&expr), None, &[], &[]).is_err());
+        let expr = parse_model_specifiers(&["result:error".to_string()]).unwrap();
+        assert!(
+            NodeSelector::new(&nodes, "proj")
+                .schedule(Some(&expr), None, &[], &[])
+                .is_err()
+        );
+    }
+
+    fn macro_def(name: &str, deps: &[&str], sql: &str) -> DbtMacro {
+        let mut macro_def = DbtMacro {
+            name: name.to_string(),
+            unique_id: format!("macro.proj.{name}"),
+            macro_sql: sql.to_string(),
+            ..Default::default()
+        };
+        macro_def.depends_on.macros = deps.iter().map(|d| d.to_string()).collect();
+        macro_def
+    }
+}
@@ -1,20 +1,16 @@
+use crate::registry::PackageRegistries;
 use crate::semver::{Version, VersionSpecifier, versions_compatible};
 use dbt_common::tracing::emit::emit_warn_log_message;
 use dbt_common::{ErrorCode, FsResult, err, fs_err, io_args::IoArgs};
 use dbt_schemas::schemas::packages::DbtPackageEntry;
 use dbt_schemas::schemas::serde::StringOrArrayOfStrings;
-use reqwest::{Client, StatusCode};
-use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
-use reqwest_retry::{
-    RetryTransientMiddleware, policies::ExponentialBackoff as RetryExponentialBackoff,
-};
+use reqwest::StatusCode;
 use serde::Deserialize;
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 
 pub const DBT_HUB_URL: &str = "https://hub.getdbt.com";
 pub const DBT_CORE_FIXED_VERSION: &str = "1.8.7";
-const MAX_CLIENT_RETRIES: u32 = 3;
 
 #[derive(Deserialize, Clone, Debug)]
 pub struct HubPackageDownloads {
@@ -47,34 +43,46 @@ pub struct HubPackageJson {
 }
 
 pub struct HubClient {
-    pub client: ClientWithMiddleware,
+    pub registries: PackageRegistries,
     pub base_url: String,
-    pub index: Option<HashSet<String>>,
+    /// Package indexes, by the URL of the hub or registry that served them
+    pub indexes: HashMap<String, HashSet<String>>,
     pub hub_packages: HashMap<String, HubPackageJson>,
 }
 
 impl HubClient {
     pub fn new(base_url: &str) -> Self {
-        let retry_policy =
-            RetryExponentialBackoff::builder().build_with_max_retries(MAX_CLIENT_RETRIES);
-        let client = ClientBuilder::new(Client::new())
-            // Retry failed requests.
-            .with(RetryTransientMiddleware::new_with_policy(retry_policy))
-            .build();
+        Self::with_registries(base_url, PackageRegistries::default())
+    }
+
+    /// A client for the hub at `base_url` that fetches the packages in the
+    /// scope of a configured registry from that registry instead
+    pub fn with_registries(base_url: &str, registries: PackageRegistries) -> Self {
         Self {
-            client,
+            registries,
             base_url: base_url.to_string(),
-            index: None,
+            indexes: HashMap::new(),
             hub_packages: HashMap::new(),
         }
     }
 
+    fn hub_url_for(&self, package: &str) -> String {
+        self.registries
+            .hub_url_for(package)
+            .unwrap_or(&self.base_url)
+            .to_string()
+    }
+
     pub async fn hydrate_index(&mut self) -> FsResult<()> {
-        if self.index.is_some() {
+        self.hydrate_index_of(self.base_url.clone()).await
+    }
+
+    async fn hydrate_index_of(&mut self, hub_url: String) -> FsResult<()> {
+        if self.indexes.contains_key(&hub_url) {
             return Ok(());
         }
-        let url = format!("{}/api/v1/index.json", self.base_url);
-        let res = self.client.get(&url).send().await.map_err(|e| {
+        let url = format!("{hub_url}/api/v1/index.json");
+        let res = self.registries.get(&url)?.send().await.map_err(|e| {
             fs_err!(
                 ErrorCode::RuntimeError,
                 "Failed to get index from {url}; status: {}",
@@ -89,7 +97,7 @@ impl HubClient {
                     e
                 )
             })?;
-            self.index = Some(index.into_iter().collect());
+            self.indexes.insert(hub_url, index.into_iter().collect());
             Ok(())
         } else {
             err!(
@@ -104,8 +112,8 @@ impl HubClient {
         if let Some(hub_package) = self.hub_packages.get(package) {
             return Ok(hub_package.clone());
         }
-        let url = format!("{}/api/v1/{}.json", self.base_url, package);
-        let res = self.client.get(&url).send().await.map_err(|e| {
+        let url = format!("{}/api/v1/{}.json", self.hub_url_for(package), package);
+        let res = self.registries.get(&url)?.send().await.map_err(|e| {
             fs_err!(
                 ErrorCode::RuntimeError,
                 "Failed to get package from {url}; status: {}",
@@ -133,14 +141,12 @@ impl HubClient {
     }
 
     pub async fn check_index(&mut self, package: &str) -> FsResult<bool> {
-        if self.index.is_none() {
-            self.hydrate_index().await?;
-        }
-        if let Some(index) = &self.index {
-            Ok(index.contains(package))
-        } else {
-            Ok(false)
-        }
+        let hub_url = self.hub_url_for(package);
+        self.hydrate_index_of(hub_url.clone()).await?;
+        Ok(self
+            .indexes
+            .get(&hub_url)
+            .is_some_and(|index| index.contains(package)))
     }
 
     pub async fn get_compatible_versions(
@@ -155,70 +161,42 @@ impl HubClient {
         Ok(hub_package.versions.keys().cloned().collect())
     }
 
-    /// Checks if the current dbt version satisfies the package's require_dbt_version constraint
-    /// and issues a warning if it doesn't.
+    /// Returns the package's require_dbt_version constraint, formatted for display,
+    /// if the current dbt version does not satisfy it.
     ///
-    /// Uses CARGO_PKG_VERSION as the current dbt version.
-    ///
-    /// # Arguments
-    /// * `io` - IO arguments for emitting warnings
-    /// * `package_name` - The name of the package being checked
-    /// * `package_version` - The specific version metadata from the hub
-    pub fn check_require_dbt_version(
-        &self,
-        io: &IoArgs,
-        package_name: &str,
-        package_version: &HubPackageVersion,
-    ) {
+    /// Uses CARGO_PKG_VERSION as the current dbt version. Requirements that cannot
+    /// be parsed are treated as satisfied.
+    pub fn unsatisfied_dbt_version(package_version: &HubPackageVersion) -> Option<String> {
         let current_version = env!("CARGO_PKG_VERSION");
         // Check if package has version requirements
-        if let Some(ref required_versions) = package_version.require_dbt_version {
-            // Convert StringOrArrayOfStrings to Vec<String>
-            let version_strings: Vec<String> = match required_versions {
-                StringOrArrayOfStrings::String(s) => vec![s.clone()],
-                StringOrArrayOfStrings::ArrayOfStrings(arr) => arr.clone(),
-            };
-
-            // Parse required versions
-            let mut all_versions = Vec::new();
-            for version_str in &version_strings {
-                match VersionSpecifier::from_str(version_str) {
-                    Ok(spec) => all_versions.push(Version::Spec(spec)),
-                    Err(_) => {
-                        // If we can't parse a version requirement, skip validation
-                        return;
-                    }
-                }
-            }
+        let required_versions = package_version.require_dbt_version.as_ref()?;
+        // Convert StringOrArrayOfStrings to Vec<String>
+        let version_strings: Vec<String> = match required_versions {
+            StringOrArrayOfStrings::String(s) => vec![s.clone()],
+            StringOrArrayOfStrings::ArrayOfStrings(arr) => arr.clone(),
+        };
 
-            // Add current version as exact match
-            match VersionSpecifier::from_str(&format!("={}", current_version)) {
-                Ok(current_spec) => all_versions.push(Version::Spec(current_spec)),
-                Err(_) => {
-                    // If we can't parse current version, skip validation
-                    return;
-                }
-            }
+        // Parse required versions
+        let mut all_versions = Vec::new();
+        for version_str in &version_strings {
+            // If we can't parse a version requirement, skip validation
+            let spec = VersionSpecifier::from_str(version_str).ok()?;
+            all_versions.push(Version::Spec(spec));
+        }
 
-            // Check if versions are compatible
-            if !versions_compatible(&all_versions) {
-                let version_display = if version_strings.len() == 1 {
-                    version_strings[0].clone()
-                } else {
-                    format!("[{}]", version_strings.join(", "))
-                };
+        // Add current version as exact match; if we can't parse it, skip validation
+        let current_spec = VersionSpecifier::from_str(&format!("={}", current_version)).ok()?;
+        all_versions.push(Version::Spec(current_spec));
 
-                emit_warn_log_message(
-                    ErrorCode::DependencyWarning,
-                    format!(
-                        "Package '{}' requires dbt version {}, but current version is {}. \
-                         This package may not be compatible with your dbt version.",
-                        package_name, version_display, current_version
-                    ),
-                    io.status_reporter.as_ref(),
-                );
-            }
+        // Check if versions are compatible
+        if versions_compatible(&all_versions) {
+            return None;
         }
+        Some(if version_strings.len() == 1 {
+            version_strings[0].clone()
+        } else {
+            format!("[{}]", version_strings.join(", "))
+        })
     }
 
     /// Checks if a package is deprecated or redirected and shows appropriate warnings
@@ -272,75 +250,14 @@ impl HubClient {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use dbt_common::FsError;
-    use dbt_common::io_args::{IoArgs, StaticAnalysisOffReason};
-    use dbt_common::io_utils::StatusReporter;
+    use dbt_common::io_args::IoArgs;
     use std::collections::HashMap;
-    use std::path::PathBuf;
-    use std::sync::{Arc, Mutex};
-
-    // Mock status reporter for testing
-    #[derive(Default)]
-    struct MockStatusReporter {
-        warnings: Arc<Mutex<Vec<String>>>,
-    }
-
-    impl MockStatusReporter {
-        fn new() -> Self {
-            Self {
-                warnings: Arc::new(Mutex::new(Vec::new())),
-            }
-        }
-
-        fn get_warnings(&self) -> Vec<String> {
-            self.warnings.lock().unwrap().clone()
-        }
-
-        fn warning_count(&self) -> usize {
-            self.warnings.lock().unwrap().len()
-        }
-    }
-
-    impl StatusReporter for MockStatusReporter {
-        fn collect_error(&self, _error: &FsError) {}
-
-        fn collect_warning(&self, warning: &FsError) {
-            self.warnings.lock().unwrap().push(warning.message());
-        }
-
-        fn collect_node_evaluation(
-            &self,
-            _file_path: PathBuf,
-            _execution_phase: dbt_telemetry::ExecutionPhase,
-            _node_outcome: dbt_telemetry::NodeOutcome,
-            _upstream_target: Option<(String, String, bool)>,
-            _static_analysis_off_reason: (Option<StaticAnalysisOffReason>, dbt_serde_yaml::Span),
-        ) {
-        }
-
-        fn show_progress(&self, _action: &str, _target: &str, _description: Option<&str>) {}
-
-        fn bulk_publish_empty(&self, _file_paths: Vec<PathBuf>) {}
-    }
 
     // Helper function to create a test IoArgs
     fn create_test_io_args() -> IoArgs {
         IoArgs::default()
     }
 
-    // Helper function to create a test IoArgs with mock status reporter
-    #[allow(clippy::field_reassign_with_default)]
-    fn create_test_io_args_with_reporter() -> (IoArgs, Arc<MockStatusReporter>) {
-        let reporter = Arc::new(MockStatusReporter::new());
-        let mut io_args = IoArgs::default();
-        io_args.status_reporter = Some(reporter.clone());
-        //let io_args = IoArgs {
-        //    status_reporter: Some(reporter.clone()),
-        //    ..Default::default()
-        //};
-        (io_args, reporter)
-    }
-
     // Helper function to create a test HubPackageJson with deprecated flag
     fn create_deprecated_package() -> HubPackageJson {
         let mut versions = HashMap::new();
@@ -703,8 +620,7 @@ mod tests {
     }
 
     #[test]
-    fn test_check_require_dbt_version_compatible() {
-        let client = HubClient::new("https://test.example.com");
+    fn test_unsatisfied_dbt_version_compatible() {
         let version = HubPackageVersion {
             name: "test_package".to_string(),
             packages: vec![],
@@ -715,22 +631,12 @@ mod tests {
             require_dbt_version: Some(StringOrArrayOfStrings::String(">=1.5.0".to_string())),
         };
 
-        let (io_args, reporter) = create_test_io_args_with_reporter();
-
-        // This should NOT trigger a warning since CARGO_PKG_VERSION >= 1.5.0
-        client.check_require_dbt_version(&io_args, "test-org/test_package", &version);
-
-        // Verify no warnings were issued
-        assert_eq!(
-            reporter.warning_count(),
-            0,
-            "Expected no warnings for compatible version"
-        );
+        // CARGO_PKG_VERSION >= 1.5.0
+        assert_eq!(HubClient::unsatisfied_dbt_version(&version), None);
     }
 
     #[test]
-    fn test_check_require_dbt_version_incompatible() {
-        let client = HubClient::new("https://test.example.com");
+    fn test_unsatisfied_dbt_version_incompatible() {
         let version = HubPackageVersion {
             name: "test_package".to_string(),
             packages: vec![],
@@ -741,31 +647,15 @@ mod tests {
             require_dbt_version: Some(StringOrArrayOfStrings::String(">=100.0.0".to_string())),
         };
 
-        let (io_args, reporter) = create_test_io_args_with_reporter();
-
-        // This SHOULD trigger a warning since CARGO_PKG_VERSION < 100.0.0
-        client.check_require_dbt_version(&io_args, "test-org/test_package", &version);
-
-        // Verify a warning was issued
+        // CARGO_PKG_VERSION < 100.0.0
         assert_eq!(
-            reporter.warning_count(),
-            1,
-            "Expected one warning for incompatible version"
-        );
-        let warnings = reporter.get_warnings();
-        assert!(
-            warnings[0].contains("test-org/test_package"),
-            "Warning should mention package name"
-        );
-        assert!(
-            warnings[0].contains(">=100.0.0"),
-            "Warning should mention required version"
+            HubClient::unsatisfied_dbt_version(&version).as_deref(),
+            Some(">=100.0.0")
         );
     }
 
     #[test]
-    fn test_check_require_dbt_version_range_compatible() {
-        let client = HubClient::new("https://test.example.com");
+    fn test_unsatisfied_dbt_version_range_compatible() {
         let version = HubPackageVersion {
             name: "test_package".to_string(),
             packages: vec![],
@@ -779,22 +669,12 @@ mod tests {
             ])),
         };
 
-        let (io_args, reporter) = create_test_io_args_with_reporter();
-
-        // This should NOT trigger a warning since 1.0.0 <= CARGO_PKG_VERSION < 100.0.0
-        client.check_require_dbt_version(&io_args, "test-org/test_package", &version);
-
-        // Verify no warnings were issued
-        assert_eq!(
-            reporter.warning_count(),
-            0,
-            "Expected no warnings for compatible version range"
-        );
+        // 1.0.0 <= CARGO_PKG_VERSION < 100.0.0
+        assert_eq!(HubClient::unsatisfied_dbt_version(&version), None);
     }
 
     #[test]
-    fn test_check_require_dbt_version_range_incompatible() {
-        let client = HubClient::new("https://test.example.com");
+    fn test_unsatisfied_dbt_version_range_incompatible() {
         let version = HubPackageVersion {
             name: "test_package".to_string(),
             packages: vec![],
@@ -808,31 +688,15 @@ mod tests {
             ])),
         };
 
-        let (io_args, reporter) = create_test_io_args_with_reporter();
-
-        // This SHOULD trigger a warning since CARGO_PKG_VERSION < 100.0.0 (outside range)
-        client.check_require_dbt_version(&io_args, "test-org/test_package", &version);
-
-        // Verify a warning was issued
+        // CARGO_PKG_VERSION < 100.0.0 (outside range)
         assert_eq!(
-            reporter.warning_count(),
-            1,
-            "Expected one warning for incompatible version range"
-        );
-        let warnings = reporter.get_warnings();
-        assert!(
-            warnings[0].contains("test-org/test_package"),
-            "Warning should mention package name"
-        );
-        assert!(
-            warnings[0].contains("[>=100.0.0, <200.0.0]"),
-            "Warning should mention required version range"
+            HubClient::unsatisfied_dbt_version(&version).as_deref(),
+            Some("[>=100.0.0, <200.0.0]")
         );
     }
 
     #[test]
-    fn test_check_require_dbt_version_no_requirement() {
-        let client = HubClient::new("https://test.example.com");
+    fn test_unsatisfied_dbt_version_no_requirement() {
         let version = HubPackageVersion {
             name: "test_package".to_string(),
             packages: vec![],
@@ -840,19 +704,10 @@ mod tests {
                 tarball: "https://example.com/tarball.tar.gz".to_string(),
             },
             fusion_schema_compat: None,
-            require_dbt_version: None, // No version requirement
+            require_dbt_version: None,
         };
 
-        let (io_args, reporter) = create_test_io_args_with_reporter();
-
-        // This should NOT trigger any warning (no requirement = compatible)
-        client.check_require_dbt_version(&io_args, "test-org/test_package", &version);
-
-        // Verify no warnings were issued
-        assert_eq!(
-            reporter.warning_count(),
-            0,
-            "Expected no warnings when no version requirement exists"
-        );
+        // No requirement = compatible
+        assert_eq!(HubClient::unsatisfied_dbt_version(&version), None);
     }
 }
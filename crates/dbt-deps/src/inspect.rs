@@ -0,0 +1,398 @@
+//! Introspection of locked packages, for `deps tree`, `deps why` and `deps outdated`.
+//!
+//! The graph is built from the package lock together with the dependencies
+//! that each installed package declares in its own packages.yml or
+//! dependencies.yml, as resolved by the loader.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::str::FromStr;
+
+use dbt_common::io_args::IoArgs;
+use dbt_common::tracing::emit::emit_warn_log_message;
+use dbt_common::{ErrorCode, FsResult};
+use dbt_schemas::schemas::packages::{DbtPackageLock, DbtPackagesLock, PackageVersion};
+use serde::Serialize;
+
+use crate::hub_client::HubClient;
+use crate::hub_client_from_env;
+use crate::semver::VersionSpecifier;
+
+/// A locked package and the packages it depends on
+#[derive(Debug, Clone, Serialize)]
+pub struct PackageNode {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub entry_type: String,
+    pub source: String,
+    /// The locked version of a hub package, or the revision of a git or private package
+    pub version: Option<String>,
+    pub dependencies: Vec<String>,
+}
+
+impl PackageNode {
+    fn label(&self) -> String {
+        match &self.version {
+            Some(version) => format!("{} {} ({})", self.name, version, self.entry_type),
+            None => format!("{} ({})", self.name, self.entry_type),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PackageGraph {
+    pub root: String,
+    /// The packages listed by the root project
+    pub dependencies: Vec<String>,
+    pub packages: BTreeMap<String, PackageNode>,
+}
+
+/// The chains of packages through which the root project depends on a package
+#[derive(Debug, Clone, Serialize)]
+pub struct PackageWhy {
+    pub package: String,
+    /// The packages of the root project that pull the package in
+    pub required_by: Vec<String>,
+    /// Every path from the root project to the package, both included
+    pub paths: Vec<Vec<String>>,
+}
+
+impl PackageGraph {
+    /// Builds the graph of the locked packages. `dependencies` maps package
+    /// names, including the root project, to the names of their dependencies;
+    /// packages that are not locked (such as internal packages) are left out.
+    pub fn new(
+        root: &str,
+        dependencies: &BTreeMap<String, BTreeSet<String>>,
+        dbt_packages_lock: &DbtPackagesLock,
+    ) -> Self {
+        let dependencies_of = |name: &str| -> Vec<String> {
+            dependencies
+                .get(name)
+                .map(|names| {
+                    names
+                        .iter()
+                        .filter(|name| dbt_packages_lock.get_by_name(name).is_some())
+                        .cloned()
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        let packages = dbt_packages_lock
+            .packages
+            .iter()
+            .map(|package| {
+                let name = package.package_name();
+                let node = PackageNode {
+                    dependencies: dependencies_of(&name),
+                    entry_type: package.entry_type(),
+                    source: package.entry_name(),
+                    version: locked_version(package),
+                    name: name.clone(),
+                };
+                (name, node)
+            })
+            .collect();
+        Self {
+            root: root.to_string(),
+            dependencies: dependencies_of(root),
+            packages,
+        }
+    }
+
+    /// Renders the graph as an indented tree. Packages that appear more than
+    /// once are expanded only the first time and marked with `(*)` after that.
+    pub fn render_tree(&self) -> String {
+        let mut lines = vec![self.root.clone()];
+        let mut expanded = BTreeSet::new();
+        self.render_children(&self.dependencies, "", &mut expanded, &mut lines);
+        lines.join("\n")
+    }
+
+    fn render_children(
+        &self,
+        children: &[String],
+        prefix: &str,
+        expanded: &mut BTreeSet<String>,
+        lines: &mut Vec<String>,
+    ) {
+        for (i, child) in children.iter().enumerate() {
+            let last = i + 1 == children.len();
+            let (branch, indent) = if last {
+                ("└── ", "    ")
+            } else {
+                ("├── ", "│   ")
+            };
+            let Some(node) = self.packages.get(child) else {
+                continue;
+            };
+            if !expanded.insert(child.clone()) {
+                let marker = if node.dependencies.is_empty() {
+                    ""
+                } else {
+                    " (*)"
+                };
+                lines.push(format!("{prefix}{branch}{}{marker}", node.label()));
+                continue;
+            }
+            lines.push(format!("{prefix}{branch}{}", node.label()));
+            self.render_children(
+                &node.dependencies,
+                &format!("{prefix}{indent}"),
+                expanded,
+                lines,
+            );
+        }
+    }
+
+    /// Every path from the root project to `package`, or `None` if the package is not locked
+    pub fn why(&self, package: &str) -> Option<PackageWhy> {
+        if !self.packages.contains_key(package) {
+            return None;
+        }
+        let mut paths = Vec::new();
+        let mut path = vec![self.root.clone()];
+        self.collect_paths(&self.dependencies, package, &mut path, &mut paths);
+        let required_by = paths
+            .iter()
+            .filter_map(|path| path.get(1).cloned())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        Some(PackageWhy {
+            package: package.to_string(),
+            required_by,
+            paths,
+        })
+    }
+
+    fn collect_paths(
+        &self,
+        children: &[String],
+        target: &str,
+        path: &mut Vec<String>,
+        paths: &mut Vec<Vec<String>>,
+    ) {
+        for child in children {
+            // Guard against dependency cycles between packages
+            if path.contains(child) {
+                continue;
+            }
+            path.push(child.clone());
+            if child == target {
+                paths.push(path.clone());
+            } else if let Some(node) = self.packages.get(child) {
+                self.collect_paths(&node.dependencies, target, path, paths);
+            }
+            path.pop();
+        }
+    }
+}
+
+fn locked_version(package: &DbtPackageLock) -> Option<String> {
+    match package {
+        DbtPackageLock::Hub(hub) => Some(match &hub.version {
+            PackageVersion::String(version) => version.clone(),
+            PackageVersion::Number(version) => version.to_string(),
+            PackageVersion::Array(versions) => versions.join(", "),
+        }),
+        DbtPackageLock::Git(git) => Some(git.revision.clone()),
+        DbtPackageLock::Private(private) => Some(private.revision.clone()),
+        DbtPackageLock::Local(_) | DbtPackageLock::Tarball(_) => None,
+    }
+}
+
+/// A locked hub package that has newer versions, or that the hub has deprecated or moved
+#[derive(Debug, Clone, Serialize)]
+pub struct OutdatedPackage {
+    pub name: String,
+    pub package: String,
+    pub locked: String,
+    /// The newest version whose require-dbt-version admits the running dbt
+    pub latest_compatible: Option<String>,
+    pub latest: Option<String>,
+    pub deprecated: bool,
+    /// The hub package this one has been renamed or moved to
+    pub moved_to: Option<String>,
+}
+
+/// Compares every locked hub package to the versions currently on the hub.
+/// Prereleases are only reported for packages locked to a prerelease.
+pub async fn outdated_packages(
+    io: &IoArgs,
+    dbt_packages_lock: &DbtPackagesLock,
+) -> FsResult<Vec<OutdatedPackage>> {
+    let mut hub_registry = hub_client_from_env()?;
+    let mut outdated = Vec::new();
+    for package in dbt_packages_lock.packages.iter() {
+        let DbtPackageLock::Hub(hub_lock) = package else {
+            continue;
+        };
+        let Some(locked) = locked_version(package) else {
+            continue;
+        };
+        let Ok(locked_spec) = VersionSpecifier::from_str(&locked) else {
+            emit_warn_log_message(
+                ErrorCode::DependencyWarning,
+                format!(
+                    "Cannot compare locked version '{}' of package '{}'",
+                    locked, hub_lock.package
+                ),
+                io.status_reporter.as_ref(),
+            );
+            continue;
+        };
+        let hub_package = hub_registry.get_hub_package(&hub_lock.package).await?;
+        let mut versions = hub_package
+            .versions
+            .iter()
+            .filter_map(|(version, metadata)| {
+                let spec = VersionSpecifier::from_str(version).ok()?;
+                (locked_spec.is_prerelease() || !spec.is_prerelease()).then_some((spec, metadata))
+            })
+            .collect::<Vec<_>>();
+        versions.sort_by(|(a, _), (b, _)| b.cmp(a));
+        let latest = versions.first().map(|(spec, _)| spec.clone());
+        let latest_compatible = versions
+            .iter()
+            .find(|(_, metadata)| HubClient::unsatisfied_dbt_version(metadata).is_none())
+            .map(|(spec, _)| spec.clone());
+        let moved_to = moved_to(
+            &hub_lock.package,
+            hub_package.redirectnamespace.as_deref(),
+            hub_package.redirectname.as_deref(),
+        );
+        let is_newer =
+            |spec: &Option<VersionSpecifier>| spec.as_ref().is_some_and(|spec| *spec > locked_spec);
+        if is_newer(&latest)
+            || is_newer(&latest_compatible)
+            || hub_package.deprecated
+            || moved_to.is_some()
+        {
+            outdated.push(OutdatedPackage {
+                name: hub_lock.name.clone(),
+                package: hub_lock.package.clone(),
+                locked,
+                latest_compatible: latest_compatible.map(|spec| spec.to_version_string(true)),
+                latest: latest.map(|spec| spec.to_version_string(true)),
+                deprecated: hub_package.deprecated,
+                moved_to,
+            });
+        }
+    }
+    Ok(outdated)
+}
+
+/// The `namespace/name` a hub package has been redirected to, if any
+fn moved_to(package: &str, namespace: Option<&str>, name: Option<&str>) -> Option<String> {
+    if namespace.is_none() && name.is_none() {
+        return None;
+    }
+    let (current_namespace, current_name) = package.split_once('/').unwrap_or(("", package));
+    Some(format!(
+        "{}/{}",
+        namespace.unwrap_or(current_namespace),
+        name.unwrap_or(current_name)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbt_schemas::schemas::packages::{HubPackageLock, LocalPackageLock};
+
+    fn hub_lock(name: &str, version: &str) -> DbtPackageLock {
+        DbtPackageLock::Hub(HubPackageLock {
+            package: format!("dbt-labs/{name}"),
+            name: name.to_string(),
+            version: PackageVersion::String(version.to_string()),
+            sha256: None,
+            tree_sha256: None,
+        })
+    }
+
+    fn graph() -> PackageGraph {
+        let dbt_packages_lock = DbtPackagesLock {
+            packages: vec![
+                hub_lock("dbt_utils", "1.1.1"),
+                hub_lock("codegen", "0.12.1"),
+                hub_lock("audit_helper", "0.12.0"),
+                DbtPackageLock::Local(LocalPackageLock {
+                    local: "../shared".into(),
+                    name: "shared".to_string(),
+                }),
+            ],
+            sha1_hash: String::new(),
+        };
+        let dependencies = BTreeMap::from([
+            (
+                "my_project".to_string(),
+                BTreeSet::from(["codegen".to_string(), "shared".to_string()]),
+            ),
+            (
+                "codegen".to_string(),
+                BTreeSet::from(["dbt_utils".to_string()]),
+            ),
+            (
+                "shared".to_string(),
+                BTreeSet::from([
+                    "audit_helper".to_string(),
+                    "dbt_utils".to_string(),
+                    "dbt_internal".to_string(),
+                ]),
+            ),
+            (
+                "audit_helper".to_string(),
+                BTreeSet::from(["dbt_utils".to_string()]),
+            ),
+        ]);
+        PackageGraph::new("my_project", &dependencies, &dbt_packages_lock)
+    }
+
+    #[test]
+    fn test_render_tree() {
+        let expected = [
+            "my_project",
+            "├── codegen 0.12.1 (hub)",
+            "│   └── dbt_utils 1.1.1 (hub)",
+            "└── shared (local)",
+            "    ├── audit_helper 0.12.0 (hub)",
+            "    │   └── dbt_utils 1.1.1 (hub)",
+            "    └── dbt_utils 1.1.1 (hub)",
+        ];
+        assert_eq!(graph().render_tree(), expected.join("\n"));
+    }
+
+    #[test]
+    fn test_why() {
+        let graph = graph();
+        let why = graph.why("dbt_utils").unwrap();
+        assert_eq!(why.required_by, vec!["codegen", "shared"]);
+        assert_eq!(
+            why.paths,
+            vec![
+                vec!["my_project", "codegen", "dbt_utils"],
+                vec!["my_project", "shared", "audit_helper", "dbt_utils"],
+                vec!["my_project", "shared", "dbt_utils"],
+            ]
+        );
+        assert!(graph.why("dbt_internal").is_none());
+    }
+
+    #[test]
+    fn test_moved_to() {
+        assert_eq!(moved_to("dbt-labs/codegen", None, None), None);
+        assert_eq!(
+            moved_to("fishtown-analytics/codegen", Some("dbt-labs"), None).as_deref(),
+            Some("dbt-labs/codegen")
+        );
+        assert_eq!(
+            moved_to(
+                "dbt-labs/dbt_expectations",
+                Some("metaplane"),
+                Some("dbt_expectations")
+            )
+            .as_deref(),
+            Some("metaplane/dbt_expectations")
+        );
+    }
+}
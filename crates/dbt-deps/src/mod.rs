@@ -3,9 +3,13 @@ mod steps;
 mod add_package;
 mod github_client;
 mod hub_client;
+pub mod inspect;
+mod package_cache;
 pub mod package_listing;
 pub mod private_package;
+mod registry;
 pub mod semver;
+mod solver;
 mod tarball;
 mod tarball_client;
 pub mod types;
@@ -23,11 +27,41 @@ use dbt_common::{
 use dbt_jinja_utils::jinja_environment::JinjaEnv;
 use dbt_schemas::schemas::packages::{DbtPackagesLock, UpstreamProject};
 use hub_client::{DBT_HUB_URL, HubClient};
+use package_cache::{PackageCache, vendor_packages};
+use registry::PackageRegistries;
 use std::{collections::BTreeMap, path::Path};
+pub use steps::load_recorded_dbt_packages_lock;
 use steps::{
     compute_package_lock, install_packages, load_dbt_packages,
     load_dbt_packages_lock_without_validation, try_load_valid_dbt_packages_lock,
 };
+use utils::carry_over_package_digests;
+
+/// The hub client for the configured package registries. Packages outside
+/// the scope of every registry come from the registry without scopes, if any,
+/// else from `DBT_PACKAGE_HUB_URL` or the public dbt hub.
+fn hub_client_from_env() -> FsResult<HubClient> {
+    let registries = PackageRegistries::load()?;
+    let hub_url = match registries.default_hub_url() {
+        Some(hub_url) => hub_url.to_string(),
+        None => {
+            let hub_url_from_env = std::env::var("DBT_PACKAGE_HUB_URL");
+            hub_url_from_env
+                .as_deref()
+                .map(|s| {
+                    if s.ends_with('/') {
+                        // dbt-core required a trailing slash - here we support but do not require it.
+                        &s[0..s.len() - 1]
+                    } else {
+                        s
+                    }
+                })
+                .unwrap_or(DBT_HUB_URL)
+                .to_string()
+        }
+    };
+    Ok(HubClient::with_registries(&hub_url, registries))
+}
 
 /// Loads and installs packages, and returns the packages lock and the dependencies map
 #[allow(clippy::cognitive_complexity, clippy::too_many_arguments)]
@@ -39,23 +73,14 @@ pub async fn get_or_install_packages(
     add_package: Option<String>,
     upgrade: bool,
     lock: bool,
+    offline: bool,
+    vendor_dir: Option<&Path>,
     vars: BTreeMap<String, dbt_serde_yaml::Value>,
     version_check: bool,
     token: &CancellationToken,
 ) -> FsResult<(DbtPackagesLock, Vec<UpstreamProject>)> {
-    let hub_url_from_env = std::env::var("DBT_PACKAGE_HUB_URL");
-    let hub_url = hub_url_from_env
-        .as_deref()
-        .map(|s| {
-            if s.ends_with('/') {
-                // dbt-core required a trailing slash - here we support but do not require it.
-                &s[0..s.len() - 1]
-            } else {
-                s
-            }
-        })
-        .unwrap_or(DBT_HUB_URL);
-    let mut hub_registry = HubClient::new(hub_url);
+    let mut hub_registry = hub_client_from_env()?;
+    let package_cache = PackageCache::global();
 
     // Add package first if specified, then load the package definition
     if let Some(add_package) = add_package {
@@ -72,7 +97,7 @@ pub async fn get_or_install_packages(
         Vec::new()
     };
 
-    let dbt_packages_lock = if let Some(ref dbt_packages) = package_def {
+    let mut dbt_packages_lock = if let Some(ref dbt_packages) = package_def {
         if !upgrade
             && !lock
             && let Some(dbt_packages_lock) =
@@ -80,9 +105,15 @@ pub async fn get_or_install_packages(
         {
             show_progress!(io, fsinfo!(LOADING.into(), package_yml_name.to_string()));
             dbt_packages_lock
+        } else if offline {
+            return err!(
+                ErrorCode::InvalidConfig,
+                "Cannot resolve packages in offline mode: package-lock.yml is missing or out of date with {}. Run deps without --offline first.",
+                package_yml_name
+            );
         } else {
             show_progress!(io, fsinfo!(FETCHING.into(), package_yml_name.to_string()));
-            compute_package_lock(
+            let mut dbt_packages_lock = compute_package_lock(
                 io,
                 &vars,
                 env,
@@ -91,7 +122,13 @@ pub async fn get_or_install_packages(
                 version_check,
                 token,
             )
-            .await?
+            .await?;
+            // Packages that are still pinned the same way keep their recorded
+            // digests; only --upgrade starts over.
+            if !upgrade && let Some(previous) = load_recorded_dbt_packages_lock(io) {
+                carry_over_package_digests(&previous, &mut dbt_packages_lock);
+            }
+            dbt_packages_lock
         }
     } else {
         // No packages.yml defined - try to load from package-lock.yml if it exists
@@ -131,10 +168,16 @@ pub async fn get_or_install_packages(
             &vars,
             &mut hub_registry,
             env,
-            &dbt_packages_lock,
+            &mut dbt_packages_lock,
             packages_install_path,
+            package_cache.as_ref(),
+            upgrade,
+            offline,
         )
         .await?;
+        if let Some(vendor_dir) = vendor_dir {
+            vendor_packages(&dbt_packages_lock, packages_install_path, vendor_dir)?;
+        }
     }
 
     // A package is considered "missing" if the 'dbt_project.yml' file for that
@@ -168,8 +211,11 @@ pub async fn get_or_install_packages(
                 &vars,
                 &mut hub_registry,
                 env,
-                &dbt_packages_lock,
+                &mut dbt_packages_lock,
                 packages_install_path,
+                package_cache.as_ref(),
+                upgrade,
+                offline,
             )
             .await?;
             for package in dbt_packages_lock.packages.iter() {
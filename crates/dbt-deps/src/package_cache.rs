@@ -0,0 +1,397 @@
+//! Content-addressed cache of installed packages, shared across projects.
+//!
+//! Every locked package with a recorded digest is stored once under
+//! `<cache>/<name>-<key>/`, where the key hashes the lock entry (type, source,
+//! pinned version or revision) together with its SHA-256. The directory holds
+//! the installed package tree in `package/` and the tree digest in
+//! `tree.sha256`, which marks the entry as complete.
+//!
+//! Nothing read from the cache is trusted: before an entry is reused, the
+//! digest of its `package/` tree is recomputed and compared with the tree
+//! digest recorded in `package-lock.yml`. That is the lock's `sha256` for git
+//! and private packages and its `tree_sha256` for hub and tarball packages,
+//! which `deps` records after installing them from a tarball that matched the
+//! lock's `sha256`.
+//!
+//! The cache lives in `${XDG_CACHE_HOME}/com.getdbt/packages` (or the
+//! platform equivalent) unless `DBT_PACKAGE_CACHE_DIR` points elsewhere.
+//! `deps --vendor <dir>` writes the same layout, so a vendored directory can
+//! be installed from with `DBT_PACKAGE_CACHE_DIR=<dir> dbt deps --offline`.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use dbt_common::tracing::emit::emit_info_log_message;
+use dbt_common::{ErrorCode, FsResult, fs_err, stdfs};
+use dbt_schemas::schemas::packages::{DbtPackageLock, DbtPackagesLock, PackageVersion};
+use sha2::{Digest, Sha256};
+
+use crate::utils::sha256_hash_tree;
+
+const PACKAGE_CACHE_DIR_ENV: &str = "DBT_PACKAGE_CACHE_DIR";
+const PACKAGE_DIR: &str = "package";
+const TREE_DIGEST_FILE: &str = "tree.sha256";
+
+pub struct PackageCache {
+    root: PathBuf,
+}
+
+impl PackageCache {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// The shared cache, or `None` if the platform has no cache directory
+    pub fn global() -> Option<Self> {
+        if let Ok(dir) = std::env::var(PACKAGE_CACHE_DIR_ENV)
+            && !dir.is_empty()
+        {
+            return Some(Self::new(dir));
+        }
+        dirs::cache_dir().map(|dir| Self::new(dir.join("com.getdbt").join("packages")))
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// The cache entry of a locked package. Local packages and packages
+    /// without a recorded tree digest cannot be cached.
+    pub fn entry_path(&self, package: &DbtPackageLock) -> Option<PathBuf> {
+        let sha256 = package.sha256()?;
+        package.tree_sha256()?;
+        let pin = match package {
+            DbtPackageLock::Local(_) => return None,
+            DbtPackageLock::Hub(hub) => match &hub.version {
+                PackageVersion::String(version) => version.clone(),
+                PackageVersion::Number(version) => version.to_string(),
+                PackageVersion::Array(versions) => versions.join(","),
+            },
+            DbtPackageLock::Git(git) => git.revision.clone(),
+            DbtPackageLock::Private(private) => private.revision.clone(),
+            DbtPackageLock::Tarball(_) => String::new(),
+        };
+        let mut hasher = Sha256::new();
+        for part in [
+            package.entry_type().as_str(),
+            package.entry_name().as_str(),
+            pin.as_str(),
+            sha256,
+        ] {
+            hasher.update(part.as_bytes());
+            hasher.update([0]);
+        }
+        let key = format!("{:x}", hasher.finalize());
+        Some(
+            self.root
+                .join(format!("{}-{}", package.package_name(), &key[..16])),
+        )
+    }
+
+    pub fn contains(&self, package: &DbtPackageLock) -> bool {
+        self.entry_path(package)
+            .is_some_and(|entry| entry.join(TREE_DIGEST_FILE).exists())
+    }
+
+    /// Copies a cached package to `install_path`. Returns `false` if the
+    /// package is not cached; entries that do not match the lock are evicted
+    /// and reported as not cached.
+    pub fn restore(&self, package: &DbtPackageLock, install_path: &Path) -> FsResult<bool> {
+        let (Some(entry), Some(locked)) = (self.entry_path(package), package.tree_sha256()) else {
+            return Ok(false);
+        };
+        if !entry.join(TREE_DIGEST_FILE).exists() {
+            return Ok(false);
+        }
+        if sha256_hash_tree(&entry.join(PACKAGE_DIR))? != locked {
+            stdfs::remove_dir_all(&entry)?;
+            return Ok(false);
+        }
+        copy_tree(&entry.join(PACKAGE_DIR), install_path)?;
+        Ok(true)
+    }
+
+    /// Stores an installed package. Entries are written to a temporary
+    /// directory first and renamed into place, so concurrent jobs sharing the
+    /// cache never observe a partial entry. A package that no longer matches
+    /// the lock is refused.
+    pub fn store(&self, package: &DbtPackageLock, installed_path: &Path) -> FsResult<()> {
+        let (Some(entry), Some(locked)) = (self.entry_path(package), package.tree_sha256()) else {
+            return Ok(());
+        };
+        if entry.join(TREE_DIGEST_FILE).exists() {
+            return Ok(());
+        }
+        stdfs::create_dir_all(&self.root)?;
+        let staging = tempfile::Builder::new()
+            .prefix(".tmp-")
+            .tempdir_in(&self.root)
+            .map_err(|e| {
+                fs_err!(
+                    ErrorCode::IoError,
+                    "Failed to create staging dir in package cache {}: {}",
+                    self.root.display(),
+                    e
+                )
+            })?;
+        copy_tree(installed_path, &staging.path().join(PACKAGE_DIR))?;
+        let tree_digest = sha256_hash_tree(&staging.path().join(PACKAGE_DIR))?;
+        if tree_digest != locked {
+            return Err(fs_err!(
+                ErrorCode::PackageIntegrityError,
+                "Installed package '{}' hashes to {} but package-lock.yml records {}",
+                package.package_name(),
+                tree_digest,
+                locked
+            ));
+        }
+        stdfs::write(staging.path().join(TREE_DIGEST_FILE), tree_digest)?;
+        if entry.exists() {
+            // A stale entry without its digest file, left behind by an interrupted eviction
+            stdfs::remove_dir_all(&entry)?;
+        }
+        // Losing the race against another job storing the same entry is fine
+        if let Err(e) = std::fs::rename(staging.path(), &entry)
+            && !entry.join(TREE_DIGEST_FILE).exists()
+        {
+            return Err(fs_err!(
+                ErrorCode::IoError,
+                "Failed to store package in cache at {}: {}",
+                entry.display(),
+                e
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Copies every locked package from the packages install path into
+/// `vendor_dir`, using the package cache layout, and removes the entries of
+/// packages that are no longer locked. Local packages are not vendored.
+pub fn vendor_packages(
+    dbt_packages_lock: &DbtPackagesLock,
+    packages_install_path: &Path,
+    vendor_dir: &Path,
+) -> FsResult<()> {
+    let vendor = PackageCache::new(vendor_dir);
+    stdfs::create_dir_all(vendor.root())?;
+    let mut entries = HashSet::new();
+    for package_lock in dbt_packages_lock.packages.iter() {
+        let Some(entry) = vendor.entry_path(package_lock) else {
+            continue;
+        };
+        vendor.store(
+            package_lock,
+            &packages_install_path.join(package_lock.package_name()),
+        )?;
+        entries.insert(entry);
+    }
+    // Only directories that are cache entries are pruned, never anything else
+    // that happens to live in the vendor directory
+    for entry in stdfs::read_dir(vendor.root())? {
+        let path = entry
+            .map_err(|e| fs_err!(ErrorCode::IoError, "Failed to read directory entry: {}", e))?
+            .path();
+        if path.join(TREE_DIGEST_FILE).is_file() && !entries.contains(&path) {
+            stdfs::remove_dir_all(&path)?;
+        }
+    }
+    emit_info_log_message(format!(
+        "Vendored {} package(s) into {}",
+        entries.len(),
+        vendor_dir.display()
+    ));
+    Ok(())
+}
+
+/// Recursively copies `src` to `dst`, preserving symlinks and skipping `.git`.
+fn copy_tree(src: &Path, dst: &Path) -> FsResult<()> {
+    stdfs::create_dir_all(dst)?;
+    for entry in stdfs::read_dir(src)? {
+        let entry = entry
+            .map_err(|e| fs_err!(ErrorCode::IoError, "Failed to read directory entry: {}", e))?;
+        if entry.file_name() == ".git" {
+            continue;
+        }
+        let from = entry.path();
+        let to = dst.join(entry.file_name());
+        let file_type = entry.file_type().map_err(|e| {
+            fs_err!(
+                ErrorCode::IoError,
+                "Failed to read file type of {}: {}",
+                from.display(),
+                e
+            )
+        })?;
+        if file_type.is_symlink() {
+            let target = std::fs::read_link(&from).map_err(|e| {
+                fs_err!(
+                    ErrorCode::IoError,
+                    "Failed to read link {}: {}",
+                    from.display(),
+                    e
+                )
+            })?;
+            stdfs::symlink(&target, &to)?;
+        } else if file_type.is_dir() {
+            copy_tree(&from, &to)?;
+        } else {
+            stdfs::copy(&from, &to)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbt_schemas::schemas::packages::{GitPackageLock, HubPackageLock};
+    use std::collections::HashMap;
+
+    fn hub_lock(version: &str, sha256: Option<&str>, tree_sha256: &str) -> DbtPackageLock {
+        DbtPackageLock::Hub(HubPackageLock {
+            package: "dbt-labs/dbt_utils".to_string(),
+            name: "dbt_utils".to_string(),
+            version: PackageVersion::String(version.to_string()),
+            sha256: sha256.map(str::to_string),
+            tree_sha256: Some(tree_sha256.to_string()),
+        })
+    }
+
+    fn installed_package() -> tempfile::TempDir {
+        let installed = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(installed.path().join("macros")).unwrap();
+        std::fs::write(installed.path().join("dbt_project.yml"), "name: dbt_utils").unwrap();
+        std::fs::write(
+            installed.path().join("macros/a.sql"),
+            "{% macro a() %}{% endmacro %}",
+        )
+        .unwrap();
+        installed
+    }
+
+    #[test]
+    fn test_store_and_restore() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let cache = PackageCache::new(cache_dir.path());
+        let installed = installed_package();
+        let tree = sha256_hash_tree(installed.path()).unwrap();
+
+        let package = hub_lock("1.1.1", Some("aaaa"), &tree);
+        assert!(!cache.contains(&package));
+        cache.store(&package, installed.path()).unwrap();
+        assert!(cache.contains(&package));
+        // Another version, or the same version with another digest, is a different entry
+        assert!(!cache.contains(&hub_lock("1.2.0", Some("aaaa"), &tree)));
+        assert!(!cache.contains(&hub_lock("1.1.1", Some("bbbb"), &tree)));
+        // Packages without a digest are never cached
+        assert!(cache.entry_path(&hub_lock("1.1.1", None, &tree)).is_none());
+        // Nor is content that does not match the lock
+        assert!(
+            cache
+                .store(&hub_lock("1.3.0", Some("aaaa"), "cccc"), installed.path())
+                .is_err()
+        );
+
+        let install_dir = tempfile::tempdir().unwrap();
+        let install_path = install_dir.path().join("dbt_utils");
+        assert!(cache.restore(&package, &install_path).unwrap());
+        assert_eq!(sha256_hash_tree(&install_path).unwrap(), tree);
+    }
+
+    #[test]
+    fn test_restore_evicts_modified_entry() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let cache = PackageCache::new(cache_dir.path());
+        let installed = installed_package();
+
+        let package = hub_lock(
+            "1.1.1",
+            Some("aaaa"),
+            &sha256_hash_tree(installed.path()).unwrap(),
+        );
+        cache.store(&package, installed.path()).unwrap();
+        let entry = cache.entry_path(&package).unwrap();
+        std::fs::write(
+            entry.join(PACKAGE_DIR).join("dbt_project.yml"),
+            "name: evil",
+        )
+        .unwrap();
+
+        let install_dir = tempfile::tempdir().unwrap();
+        assert!(
+            !cache
+                .restore(&package, &install_dir.path().join("dbt_utils"))
+                .unwrap()
+        );
+        assert!(!entry.exists());
+    }
+
+    #[test]
+    fn test_restore_checks_entry_against_lock() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let cache = PackageCache::new(cache_dir.path());
+        let installed = installed_package();
+        let tree = sha256_hash_tree(installed.path()).unwrap();
+        let install_dir = tempfile::tempdir().unwrap();
+
+        // Entries whose content and tree.sha256 were both replaced
+        let git = DbtPackageLock::Git(GitPackageLock {
+            git: "https://github.com/dbt-labs/dbt-utils.git"
+                .to_string()
+                .into(),
+            name: "dbt_utils".to_string(),
+            revision: "abc123".to_string(),
+            warn_unpinned: None,
+            subdirectory: None,
+            sha256: Some(tree.clone()),
+            __unrendered__: HashMap::new(),
+        });
+        let hub = hub_lock("1.1.1", Some("aaaa"), &tree);
+        for package in [git, hub] {
+            cache.store(&package, installed.path()).unwrap();
+            let entry = cache.entry_path(&package).unwrap();
+            std::fs::write(
+                entry.join(PACKAGE_DIR).join("dbt_project.yml"),
+                "name: evil",
+            )
+            .unwrap();
+            std::fs::write(
+                entry.join(TREE_DIGEST_FILE),
+                sha256_hash_tree(&entry.join(PACKAGE_DIR)).unwrap(),
+            )
+            .unwrap();
+            assert!(
+                !cache
+                    .restore(&package, &install_dir.path().join("dbt_utils"))
+                    .unwrap()
+            );
+            assert!(!entry.exists());
+        }
+    }
+
+    #[test]
+    fn test_vendor_packages_prunes_stale_entries() {
+        let install_dir = tempfile::tempdir().unwrap();
+        let installed = install_dir.path().join("dbt_utils");
+        std::fs::create_dir_all(&installed).unwrap();
+        std::fs::write(installed.join("dbt_project.yml"), "name: dbt_utils").unwrap();
+        let tree = sha256_hash_tree(&installed).unwrap();
+
+        let vendor_dir = tempfile::tempdir().unwrap();
+        let vendor = PackageCache::new(vendor_dir.path());
+        let stale = hub_lock("1.0.0", Some("aaaa"), &tree);
+        vendor.store(&stale, &installed).unwrap();
+        std::fs::create_dir_all(vendor_dir.path().join("docs")).unwrap();
+
+        let package = hub_lock("1.1.1", Some("bbbb"), &tree);
+        let dbt_packages_lock = DbtPackagesLock {
+            packages: vec![package.clone()],
+            sha1_hash: String::new(),
+        };
+        vendor_packages(&dbt_packages_lock, install_dir.path(), vendor_dir.path()).unwrap();
+        assert!(vendor.contains(&package));
+        assert!(!vendor.contains(&stale));
+        assert!(vendor_dir.path().join("docs").exists());
+    }
+}
@@ -21,7 +21,10 @@ use dbt_schemas::schemas::{
     project::{DbtProject, DbtProjectNameOnly},
 };
 
-use crate::{private_package::get_resolved_url, utils::get_local_package_full_path};
+use crate::{
+    private_package::get_resolved_url, registry::PackageRegistries,
+    utils::get_local_package_full_path,
+};
 
 use super::types::{
     GitUnpinnedPackage, HubUnpinnedPackage, LocalPinnedPackage, LocalUnpinnedPackage,
@@ -77,14 +80,21 @@ pub struct PackageListing {
     pub io_args: IoArgs,
     pub vars: BTreeMap<String, dbt_serde_yaml::Value>,
     pub packages: HashMap<String, UnpinnedPackage>,
+    /// Supplies the git providers that private packages resolve against
+    pub registries: PackageRegistries,
 }
 
 impl PackageListing {
-    pub fn new(io_args: IoArgs, vars: BTreeMap<String, dbt_serde_yaml::Value>) -> Self {
+    pub fn new(
+        io_args: IoArgs,
+        vars: BTreeMap<String, dbt_serde_yaml::Value>,
+        registries: PackageRegistries,
+    ) -> Self {
         Self {
             io_args,
             vars,
             packages: HashMap::new(),
+            registries,
         }
     }
 
@@ -307,7 +317,7 @@ impl PackageListing {
 
                 private_package.private = Verbatim::from(private_package_private);
 
-                let private_package_url = get_resolved_url(&private_package)?;
+                let private_package_url = get_resolved_url(&private_package, &self.registries)?;
 
                 // Create key that includes subdirectory if present
                 let mut package_key = private_package_url.clone();
@@ -577,7 +587,8 @@ mod tests {
     #[test]
     fn test_handle_remote_package_with_subdirectory() {
         let io_args = IoArgs::default();
-        let mut package_listing = PackageListing::new(io_args, BTreeMap::new());
+        let mut package_listing =
+            PackageListing::new(io_args, BTreeMap::new(), PackageRegistries::default());
 
         // Create two git packages with the same URL but different subdirectories
         let git_package_1 = UnpinnedPackage::Git(GitUnpinnedPackage {
@@ -647,7 +658,8 @@ mod tests {
     #[test]
     fn test_handle_remote_package_same_url_no_subdirectory() {
         let io_args = IoArgs::default();
-        let mut package_listing = PackageListing::new(io_args, BTreeMap::new());
+        let mut package_listing =
+            PackageListing::new(io_args, BTreeMap::new(), PackageRegistries::default());
 
         // Create two git packages with the same URL and no subdirectory
         let git_package_1 = UnpinnedPackage::Git(GitUnpinnedPackage {
@@ -0,0 +1,629 @@
+//! Version solver for hub packages.
+//!
+//! Hub packages are resolved together, once every requirement on them is
+//! known: the ranges declared by the root project and by git, local, private
+//! and tarball packages, plus the `packages` of every hub package version that
+//! gets selected. The solver tries the newest versions first and backtracks
+//! when a selection leaves some package without a version that satisfies all
+//! of its requirements. Backtracking jumps straight to the most recent
+//! selection involved in the conflict, so unrelated selections are not
+//! revisited.
+//!
+//! When no solution exists, the error lists each package that could not be
+//! satisfied together with every range on it and who required that range.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::str::FromStr;
+
+use dbt_common::cancellation::CancellationToken;
+use dbt_common::{ErrorCode, FsResult, err};
+use dbt_schemas::schemas::packages::DbtPackageEntry;
+
+use crate::hub_client::{DBT_CORE_FIXED_VERSION, HubClient};
+use crate::semver::{Version, VersionSpecifier, versions_compatible};
+use crate::types::{HubPinnedPackage, HubUnpinnedPackage};
+
+/// How many distinct conflicts are listed when resolution fails
+const MAX_REPORTED_CONFLICTS: usize = 5;
+
+/// The version range that one package (or the root project) requires of a hub package
+#[derive(Debug, Clone)]
+pub struct HubRequirement {
+    pub package: String,
+    pub versions: Vec<Version>,
+    pub install_prerelease: bool,
+    pub required_by: String,
+}
+
+impl HubRequirement {
+    pub fn new(unpinned_package: &HubUnpinnedPackage, required_by: impl Into<String>) -> Self {
+        Self {
+            package: unpinned_package.package.clone(),
+            versions: unpinned_package.versions.clone(),
+            install_prerelease: unpinned_package.install_prerelease.unwrap_or_default()
+                || unpinned_package.versions.iter().any(|v| v.is_prerelease()),
+            required_by: required_by.into(),
+        }
+    }
+
+    fn allows(&self, version: &VersionSpecifier) -> bool {
+        let mut versions = self.versions.clone();
+        versions.push(Version::Spec(version.clone()));
+        versions_compatible(&versions)
+    }
+}
+
+/// One published version of a hub package
+#[derive(Debug, Clone)]
+pub struct HubCandidate {
+    pub version: String,
+    pub spec: VersionSpecifier,
+    pub dependencies: Vec<HubRequirement>,
+    /// The require-dbt-version range of this version, if the running dbt does not satisfy it
+    pub unsatisfied_dbt_version: Option<String>,
+}
+
+/// The selected hub packages
+#[derive(Debug, Clone)]
+pub struct HubResolution {
+    pub packages: Vec<HubPinnedPackage>,
+    /// Newer versions that matched every requirement but were skipped
+    /// because they do not support the running dbt
+    pub warnings: Vec<String>,
+}
+
+/// A solution: package -> version, plus the warnings about skipped versions
+#[derive(Debug, Clone)]
+pub struct Solution {
+    pub versions: BTreeMap<String, String>,
+    pub warnings: Vec<String>,
+}
+
+/// Resolves every hub package reachable from `requirements` to a single version.
+///
+/// Package metadata is fetched from the hub up front; the search itself does
+/// not go back to the network. With `version_check`, versions whose
+/// require-dbt-version excludes the running dbt are never selected.
+pub async fn resolve_hub_packages(
+    hub_registry: &mut HubClient,
+    requirements: &[HubRequirement],
+    version_check: bool,
+    token: &CancellationToken,
+) -> FsResult<HubResolution> {
+    let mut candidates: HashMap<String, Vec<HubCandidate>> = HashMap::new();
+    let mut names = HashMap::new();
+    let mut queue: VecDeque<String> = requirements.iter().map(|r| r.package.clone()).collect();
+    while let Some(package) = queue.pop_front() {
+        if candidates.contains_key(&package) {
+            continue;
+        }
+        token.check_cancellation()?;
+        if !hub_registry.check_index(&package).await? {
+            return err!(
+                ErrorCode::InvalidConfig,
+                "Package not found in hub registry: '{}'",
+                package
+            );
+        }
+        let hub_package = hub_registry.get_hub_package(&package).await?;
+        let compatible_versions = hub_registry
+            .get_compatible_versions(&hub_package, DBT_CORE_FIXED_VERSION, version_check)
+            .await?;
+        let mut package_candidates = Vec::with_capacity(compatible_versions.len());
+        for version in compatible_versions {
+            let metadata = &hub_package.versions[&version];
+            let mut dependencies = Vec::new();
+            for entry in &metadata.packages {
+                // Hub packages can only depend on other hub packages
+                if let DbtPackageEntry::Hub(hub_dependency) = entry {
+                    let unpinned_dependency = HubUnpinnedPackage::try_from(hub_dependency.clone())?;
+                    queue.push_back(unpinned_dependency.package.clone());
+                    dependencies.push(HubRequirement::new(
+                        &unpinned_dependency,
+                        format!("{package}@{version}"),
+                    ));
+                }
+            }
+            package_candidates.push(HubCandidate {
+                spec: VersionSpecifier::from_str(&version)?,
+                version,
+                dependencies,
+                unsatisfied_dbt_version: if version_check {
+                    HubClient::unsatisfied_dbt_version(metadata)
+                } else {
+                    None
+                },
+            });
+        }
+        // Newest first, so that the solver prefers the latest versions
+        package_candidates.sort_by(|a, b| b.spec.cmp(&a.spec));
+        names.insert(package.clone(), hub_package.name.clone());
+        candidates.insert(package, package_candidates);
+    }
+
+    let solution = match solve(&candidates, requirements) {
+        Ok(solution) => solution,
+        Err(explanation) => return err!(ErrorCode::InvalidConfig, "{}", explanation),
+    };
+    let packages = solution
+        .versions
+        .into_iter()
+        .map(|(package, version)| {
+            let package_candidates = &candidates[&package];
+            let version_latest = package_candidates
+                .iter()
+                .find(|c| !c.spec.is_prerelease())
+                .map_or_else(|| version.clone(), |c| c.version.clone());
+            HubPinnedPackage {
+                name: names[&package].clone(),
+                package,
+                version,
+                version_latest,
+            }
+        })
+        .collect();
+    Ok(HubResolution {
+        packages,
+        warnings: solution.warnings,
+    })
+}
+
+/// Selects one version for every required package, or explains why that is impossible.
+/// Candidates must be ordered by preference.
+pub fn solve(
+    candidates: &HashMap<String, Vec<HubCandidate>>,
+    requirements: &[HubRequirement],
+) -> Result<Solution, String> {
+    let mut solver = Solver {
+        candidates,
+        constraints: BTreeMap::new(),
+        decisions: Vec::new(),
+        conflicts: Vec::new(),
+    };
+    for requirement in requirements {
+        solver.add_constraint(requirement, None);
+    }
+    match solver.solve() {
+        Ok(()) => Ok(Solution {
+            versions: solver
+                .decisions
+                .iter()
+                .map(|(package, index)| {
+                    (package.clone(), candidates[package][*index].version.clone())
+                })
+                .collect(),
+            warnings: solver.dbt_version_warnings(),
+        }),
+        Err(_) => {
+            let mut explanation =
+                "Could not find a set of hub package versions that satisfies all requirements:"
+                    .to_string();
+            for conflict in solver.conflicts.iter().take(MAX_REPORTED_CONFLICTS) {
+                explanation.push_str("\n  - ");
+                explanation.push_str(conflict);
+            }
+            Err(explanation)
+        }
+    }
+}
+
+/// A requirement, tagged with the decision level that introduced it
+/// (`None` for requirements that do not come from a selected hub package)
+struct Constraint {
+    requirement: HubRequirement,
+    level: Option<usize>,
+}
+
+struct Solver<'a> {
+    candidates: &'a HashMap<String, Vec<HubCandidate>>,
+    constraints: BTreeMap<String, Vec<Constraint>>,
+    /// Selected (package, candidate index), in selection order
+    decisions: Vec<(String, usize)>,
+    /// Distinct explanations of the dead ends hit while searching
+    conflicts: Vec<String>,
+}
+
+impl Solver<'_> {
+    fn add_constraint(&mut self, requirement: &HubRequirement, level: Option<usize>) {
+        self.constraints
+            .entry(requirement.package.clone())
+            .or_default()
+            .push(Constraint {
+                requirement: requirement.clone(),
+                level,
+            });
+    }
+
+    fn decision_level(&self, package: &str) -> Option<usize> {
+        self.decisions.iter().position(|(p, _)| p == package)
+    }
+
+    /// Candidates of `package` that satisfy all of its current constraints
+    fn compatible_candidates(&self, package: &str) -> Vec<usize> {
+        self.candidates
+            .get(package)
+            .map(|package_candidates| {
+                package_candidates
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, candidate)| {
+                        candidate.unsatisfied_dbt_version.is_none()
+                            && self.matches_constraints(package, candidate)
+                    })
+                    .map(|(index, _)| index)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Whether `candidate` satisfies every range on `package`, ignoring require-dbt-version
+    fn matches_constraints(&self, package: &str, candidate: &HubCandidate) -> bool {
+        let constraints = &self.constraints[package];
+        let allow_prerelease = constraints.iter().any(|c| c.requirement.install_prerelease);
+        (allow_prerelease || !candidate.spec.is_prerelease())
+            && constraints
+                .iter()
+                .all(|c| c.requirement.allows(&candidate.spec))
+    }
+
+    /// For every selected package, the newest version that was passed over
+    /// only because it does not support the running dbt
+    fn dbt_version_warnings(&self) -> Vec<String> {
+        self.decisions
+            .iter()
+            .filter_map(|(package, index)| {
+                let package_candidates = &self.candidates[package];
+                let skipped = package_candidates[..*index].iter().find(|candidate| {
+                    candidate.unsatisfied_dbt_version.is_some()
+                        && self.matches_constraints(package, candidate)
+                })?;
+                Some(format!(
+                    "Package '{}' version {} requires dbt version {}, but current version is {}. \
+                     Using version {} instead.",
+                    package,
+                    skipped.version,
+                    skipped
+                        .unsatisfied_dbt_version
+                        .as_deref()
+                        .unwrap_or_default(),
+                    env!("CARGO_PKG_VERSION"),
+                    package_candidates[*index].version
+                ))
+            })
+            .collect()
+    }
+
+    /// The undecided package with the fewest compatible candidates; failing
+    /// early on the most constrained package keeps the search small
+    fn next_package(&self) -> Option<(String, Vec<usize>)> {
+        self.constraints
+            .iter()
+            .filter(|(package, constraints)| {
+                !constraints.is_empty() && self.decision_level(package).is_none()
+            })
+            .map(|(package, _)| (package.clone(), self.compatible_candidates(package)))
+            .min_by_key(|(_, compatible)| compatible.len())
+    }
+
+    /// Returns `Ok` once every constrained package is decided. On failure,
+    /// returns the decision levels responsible for the conflict.
+    fn solve(&mut self) -> Result<(), BTreeSet<usize>> {
+        let Some((package, compatible)) = self.next_package() else {
+            return Ok(());
+        };
+        let mut conflict: BTreeSet<usize> = self.constraints[&package]
+            .iter()
+            .filter_map(|c| c.level)
+            .collect();
+        if compatible.is_empty() {
+            self.record_unsatisfiable(&package);
+            return Err(conflict);
+        }
+
+        let candidates = self.candidates;
+        let level = self.decisions.len();
+        for index in compatible {
+            let candidate = &candidates[&package][index];
+            self.decisions.push((package.clone(), index));
+            for dependency in &candidate.dependencies {
+                self.add_constraint(dependency, Some(level));
+            }
+            let result = match self.check_decided(&package, candidate) {
+                Ok(()) => self.solve(),
+                Err(culprit) => Err(BTreeSet::from([level, culprit])),
+            };
+            match result {
+                Ok(()) => return Ok(()),
+                Err(cause) => {
+                    self.decisions.pop();
+                    for constraints in self.constraints.values_mut() {
+                        constraints.retain(|c| c.level != Some(level));
+                    }
+                    if !cause.contains(&level) {
+                        // This selection played no part in the conflict, so
+                        // trying its alternatives cannot help
+                        return Err(cause);
+                    }
+                    conflict.extend(cause.into_iter().filter(|l| *l != level));
+                }
+            }
+        }
+        Err(conflict)
+    }
+
+    /// Checks the dependencies of a new selection against the packages that
+    /// are already decided, returning the level of a decision it conflicts with
+    fn check_decided(&mut self, package: &str, candidate: &HubCandidate) -> Result<(), usize> {
+        for dependency in &candidate.dependencies {
+            let Some(level) = self.decision_level(&dependency.package) else {
+                continue;
+            };
+            let (decided_package, index) = &self.decisions[level];
+            let decided = &self.candidates[decided_package][*index];
+            if !dependency.allows(&decided.spec) {
+                let explanation = format!(
+                    "{}@{} requires {} {}, but {}@{} is selected",
+                    package,
+                    candidate.version,
+                    dependency.package,
+                    display_versions(&dependency.versions),
+                    decided_package,
+                    decided.version
+                );
+                self.record_conflict(explanation);
+                return Err(level);
+            }
+        }
+        Ok(())
+    }
+
+    fn record_unsatisfiable(&mut self, package: &str) {
+        let constraints = &self.constraints[package];
+        let mut explanation = format!("no version of '{package}' satisfies all requirements:");
+        for constraint in constraints {
+            explanation.push_str(&format!(
+                "\n      {} (required by {})",
+                display_versions(&constraint.requirement.versions),
+                constraint.requirement.required_by
+            ));
+        }
+        // Versions that match every range but not the running dbt
+        let dbt_mismatches = self
+            .candidates
+            .get(package)
+            .into_iter()
+            .flatten()
+            .filter(|candidate| {
+                constraints
+                    .iter()
+                    .all(|c| c.requirement.allows(&candidate.spec))
+            })
+            .filter_map(|candidate| {
+                candidate
+                    .unsatisfied_dbt_version
+                    .as_ref()
+                    .map(|required| format!("{} requires dbt {}", candidate.version, required))
+            })
+            .collect::<Vec<_>>();
+        if !dbt_mismatches.is_empty() {
+            explanation.push_str(&format!(
+                "\n      matching versions do not support dbt {}: {}",
+                env!("CARGO_PKG_VERSION"),
+                dbt_mismatches.join(", ")
+            ));
+        }
+        self.record_conflict(explanation);
+    }
+
+    fn record_conflict(&mut self, explanation: String) {
+        if !self.conflicts.contains(&explanation) {
+            self.conflicts.push(explanation);
+        }
+    }
+}
+
+fn display_versions(versions: &[Version]) -> String {
+    let bounds = versions
+        .iter()
+        .filter_map(|version| match version {
+            Version::Spec(spec) if spec.is_unbounded() => None,
+            Version::Spec(spec) => Some(spec.to_string()),
+            Version::Range(range) => Some(range.to_string()),
+            Version::String(value) => Some(value.clone()),
+        })
+        .collect::<Vec<_>>();
+    if bounds.is_empty() {
+        "any version".to_string()
+    } else {
+        bounds.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn versions(range: &[&str]) -> Vec<Version> {
+        range
+            .iter()
+            .map(|v| Version::Spec(VersionSpecifier::from_str(v).unwrap()))
+            .collect()
+    }
+
+    fn requirement(package: &str, range: &[&str], required_by: &str) -> HubRequirement {
+        HubRequirement {
+            package: package.to_string(),
+            versions: versions(range),
+            install_prerelease: false,
+            required_by: required_by.to_string(),
+        }
+    }
+
+    /// Registers a package version; versions must be added newest first
+    fn publish(
+        candidates: &mut HashMap<String, Vec<HubCandidate>>,
+        package: &str,
+        version: &str,
+        dependencies: &[(&str, &[&str])],
+    ) {
+        candidates
+            .entry(package.to_string())
+            .or_default()
+            .push(HubCandidate {
+                version: version.to_string(),
+                spec: VersionSpecifier::from_str(version).unwrap(),
+                dependencies: dependencies
+                    .iter()
+                    .map(|(dependency, range)| {
+                        requirement(dependency, range, &format!("{package}@{version}"))
+                    })
+                    .collect(),
+                unsatisfied_dbt_version: None,
+            });
+    }
+
+    #[test]
+    fn test_solve_picks_latest_versions() {
+        let mut candidates = HashMap::new();
+        publish(&mut candidates, "dbt-labs/dbt_utils", "1.3.0", &[]);
+        publish(&mut candidates, "dbt-labs/dbt_utils", "1.2.0", &[]);
+        publish(
+            &mut candidates,
+            "dbt-labs/codegen",
+            "0.13.0",
+            &[("dbt-labs/dbt_utils", &[">=1.0.0", "<2.0.0"])],
+        );
+
+        let solution = solve(
+            &candidates,
+            &[requirement("dbt-labs/codegen", &[], "your project")],
+        )
+        .unwrap();
+        assert_eq!(solution.versions["dbt-labs/codegen"], "0.13.0");
+        assert_eq!(solution.versions["dbt-labs/dbt_utils"], "1.3.0");
+    }
+
+    #[test]
+    fn test_solve_diamond_backtracks() {
+        // The latest codegen needs a newer dbt_utils than audit_helper allows,
+        // so the solver has to fall back to an older codegen
+        let mut candidates = HashMap::new();
+        publish(&mut candidates, "dbt-labs/dbt_utils", "1.3.0", &[]);
+        publish(&mut candidates, "dbt-labs/dbt_utils", "0.9.6", &[]);
+        publish(
+            &mut candidates,
+            "dbt-labs/codegen",
+            "0.13.0",
+            &[("dbt-labs/dbt_utils", &[">=1.0.0", "<2.0.0"])],
+        );
+        publish(
+            &mut candidates,
+            "dbt-labs/codegen",
+            "0.9.0",
+            &[("dbt-labs/dbt_utils", &[">=0.9.0", "<1.0.0"])],
+        );
+        publish(
+            &mut candidates,
+            "dbt-labs/audit_helper",
+            "0.9.0",
+            &[("dbt-labs/dbt_utils", &[">=0.9.0", "<1.0.0"])],
+        );
+
+        let solution = solve(
+            &candidates,
+            &[
+                requirement("dbt-labs/codegen", &[], "your project"),
+                requirement("dbt-labs/audit_helper", &[], "your project"),
+            ],
+        )
+        .unwrap();
+        assert_eq!(solution.versions["dbt-labs/codegen"], "0.9.0");
+        assert_eq!(solution.versions["dbt-labs/audit_helper"], "0.9.0");
+        assert_eq!(solution.versions["dbt-labs/dbt_utils"], "0.9.6");
+    }
+
+    #[test]
+    fn test_solve_explains_conflict() {
+        let mut candidates = HashMap::new();
+        publish(&mut candidates, "dbt-labs/dbt_utils", "1.3.0", &[]);
+        publish(&mut candidates, "dbt-labs/dbt_utils", "0.9.6", &[]);
+        publish(
+            &mut candidates,
+            "dbt-labs/audit_helper",
+            "0.9.0",
+            &[("dbt-labs/dbt_utils", &[">=0.9.0", "<1.0.0"])],
+        );
+
+        let explanation = solve(
+            &candidates,
+            &[
+                requirement("dbt-labs/dbt_utils", &[">=1.0.0"], "your project"),
+                requirement("dbt-labs/audit_helper", &[], "your project"),
+            ],
+        )
+        .unwrap_err();
+        assert!(explanation.contains("dbt-labs/dbt_utils"), "{explanation}");
+        assert!(
+            explanation.contains(">=1.0.0 (required by your project)")
+                || explanation.contains("but dbt-labs/dbt_utils@1.3.0 is selected"),
+            "{explanation}"
+        );
+        assert!(
+            explanation.contains("dbt-labs/audit_helper@0.9.0"),
+            "{explanation}"
+        );
+    }
+
+    #[test]
+    fn test_solve_skips_versions_for_other_dbt_versions() {
+        let mut candidates = HashMap::new();
+        publish(&mut candidates, "dbt-labs/dbt_utils", "1.3.0", &[]);
+        publish(&mut candidates, "dbt-labs/dbt_utils", "1.2.0", &[]);
+        candidates.get_mut("dbt-labs/dbt_utils").unwrap()[0].unsatisfied_dbt_version =
+            Some(">=100.0.0".to_string());
+
+        let solution = solve(
+            &candidates,
+            &[requirement("dbt-labs/dbt_utils", &[], "your project")],
+        )
+        .unwrap();
+        assert_eq!(solution.versions["dbt-labs/dbt_utils"], "1.2.0");
+        assert_eq!(solution.warnings.len(), 1);
+        assert!(
+            solution.warnings[0].contains("'dbt-labs/dbt_utils' version 1.3.0"),
+            "{:?}",
+            solution.warnings
+        );
+        assert!(
+            solution.warnings[0].contains(">=100.0.0"),
+            "{:?}",
+            solution.warnings
+        );
+
+        // A version excluded by the requirements is not reported as skipped
+        let solution = solve(
+            &candidates,
+            &[requirement(
+                "dbt-labs/dbt_utils",
+                &["<1.3.0"],
+                "your project",
+            )],
+        )
+        .unwrap();
+        assert!(solution.warnings.is_empty(), "{:?}", solution.warnings);
+
+        let explanation = solve(
+            &candidates,
+            &[requirement(
+                "dbt-labs/dbt_utils",
+                &[">=1.3.0"],
+                "your project",
+            )],
+        )
+        .unwrap_err();
+        assert!(
+            explanation.contains("1.3.0 requires dbt >=100.0.0"),
+            "{explanation}"
+        );
+    }
+}
@@ -1,15 +1,18 @@
 use dbt_common::cancellation::CancellationToken;
 use dbt_common::io_args::IoArgs;
+use dbt_common::tracing::emit::emit_warn_log_message;
 use dbt_common::{ErrorCode, FsResult, err, fs_err, stdfs};
 use dbt_jinja_utils::jinja_environment::JinjaEnv;
 use dbt_schemas::schemas::packages::{
-    DbtPackageLock, DbtPackages, DbtPackagesLock, GitPackageLock, HubPackageLock, LocalPackageLock,
-    PackageVersion, PrivatePackageLock, TarballPackageLock,
+    DbtPackageEntry, DbtPackageLock, DbtPackages, DbtPackagesLock, GitPackageLock, HubPackageLock,
+    LocalPackageLock, PackageVersion, PrivatePackageLock, TarballPackageLock,
 };
 use std::collections::{BTreeMap, HashSet};
 
 use crate::{
     package_listing::UnpinnedPackage,
+    registry::PackageRegistries,
+    solver::{HubRequirement, resolve_hub_packages},
     tarball_client::TarballClient,
     types::{GitPinnedPackage, LocalPinnedPackage, PrivatePinnedPackage, TarballPinnedPackage},
     utils::{handle_git_like_package, read_and_validate_dbt_project, sha1_hash_packages},
@@ -19,6 +22,9 @@ use crate::{hub_client::HubClient, package_listing::PackageListing};
 
 use super::load_dbt_packages;
 
+/// How requirements of the root project are attributed in conflict explanations
+const ROOT_PROJECT: &str = "your project";
+
 pub async fn compute_package_lock(
     io: &IoArgs,
     vars: &BTreeMap<String, dbt_serde_yaml::Value>,
@@ -29,35 +35,104 @@ pub async fn compute_package_lock(
     token: &CancellationToken,
 ) -> FsResult<DbtPackagesLock> {
     let sha1_hash = sha1_hash_packages(&dbt_packages.packages);
+    let registries = hub_registry.registries.clone();
     // First step, is to flatten into a single list of packages
     let mut dbt_packages_lock = DbtPackagesLock::default();
-    let mut package_listing = PackageListing::new(io.clone(), vars.clone());
+    let mut package_listing = PackageListing::new(io.clone(), vars.clone(), registries.clone());
     package_listing.hydrate_dbt_packages(dbt_packages, jinja_env)?;
-    let mut final_listing = PackageListing::new(io.clone(), vars.clone());
+    let mut final_listing = PackageListing::new(io.clone(), vars.clone(), registries.clone());
     hub_registry.hydrate_index().await?;
+    // Requirements on hub packages are collected while walking the other
+    // package types, and resolved together once they are all known
+    let mut hub_requirements = package_listing
+        .packages
+        .values()
+        .filter_map(|package| match package {
+            UnpinnedPackage::Hub(hub_unpinned_package) => {
+                Some(HubRequirement::new(hub_unpinned_package, ROOT_PROJECT))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
     resolve_packages(
         io,
         vars,
-        hub_registry,
+        &registries,
         &mut final_listing,
         &mut package_listing,
+        &mut hub_requirements,
         jinja_env,
-        version_check,
         token,
     )
     .await?;
+    let mut walked = final_listing
+        .packages
+        .keys()
+        .cloned()
+        .collect::<HashSet<_>>();
+    let hub_resolution = loop {
+        let hub_resolution =
+            resolve_hub_packages(hub_registry, &hub_requirements, version_check, token).await?;
+        // Anything other than hub packages that the selected versions depend on
+        // is walked like the packages of the root project, which may in turn
+        // add requirements on hub packages
+        let mut next_listing = PackageListing::new(io.clone(), vars.clone(), registries.clone());
+        for pinned_package in &hub_resolution.packages {
+            let hub_package = hub_registry
+                .get_hub_package(&pinned_package.package)
+                .await?;
+            let other_packages: Vec<_> = hub_package.versions[&pinned_package.version]
+                .packages
+                .iter()
+                .filter(|entry| !matches!(entry, DbtPackageEntry::Hub(_)))
+                .cloned()
+                .collect();
+            next_listing.update_from(&other_packages, jinja_env)?;
+        }
+        next_listing
+            .packages
+            .retain(|key, _| walked.insert(key.clone()));
+        if next_listing.packages.is_empty() {
+            break hub_resolution;
+        }
+        resolve_packages(
+            io,
+            vars,
+            &registries,
+            &mut final_listing,
+            &mut next_listing,
+            &mut hub_requirements,
+            jinja_env,
+            token,
+        )
+        .await?;
+    };
+    for warning in hub_resolution.warnings {
+        emit_warn_log_message(
+            ErrorCode::DependencyWarning,
+            warning,
+            io.status_reporter.as_ref(),
+        );
+    }
+    for pinned_package in hub_resolution.packages {
+        let hub_package = hub_registry
+            .get_hub_package(&pinned_package.package)
+            .await?;
+        hub_registry.check_package_deprecation(io, &hub_package);
+        dbt_packages_lock
+            .packages
+            .push(DbtPackageLock::Hub(HubPackageLock {
+                package: pinned_package.package,
+                name: pinned_package.name,
+                version: PackageVersion::String(pinned_package.version),
+                sha256: None,
+                tree_sha256: None,
+            }));
+    }
     for package in final_listing.packages.values() {
         match package {
-            UnpinnedPackage::Hub(hub_unpinned_package) => {
-                let pinned_package = hub_unpinned_package.resolved(hub_registry).await?;
-                dbt_packages_lock
-                    .packages
-                    .push(DbtPackageLock::Hub(HubPackageLock {
-                        package: pinned_package.package,
-                        name: pinned_package.name,
-                        version: PackageVersion::String(pinned_package.version),
-                    }));
-            }
+            // Hub packages were added from the solver's selection above
+            UnpinnedPackage::Hub(_) => {}
             UnpinnedPackage::Git(git_unpinned_package) => {
                 let pinned_package: GitPinnedPackage = git_unpinned_package.clone().try_into()?;
                 dbt_packages_lock
@@ -70,6 +145,7 @@ pub async fn compute_package_lock(
                         revision: pinned_package.revision,
                         warn_unpinned: pinned_package.warn_unpinned,
                         subdirectory: pinned_package.subdirectory,
+                        sha256: None,
                         __unrendered__: pinned_package.unrendered,
                     }));
             }
@@ -96,6 +172,7 @@ pub async fn compute_package_lock(
                         revision: pinned_package.revision,
                         warn_unpinned: pinned_package.warn_unpinned,
                         subdirectory: pinned_package.subdirectory,
+                        sha256: None,
                         __unrendered__: pinned_package.unrendered,
                     }));
             }
@@ -111,6 +188,8 @@ pub async fn compute_package_lock(
                     .push(DbtPackageLock::Tarball(TarballPackageLock {
                         tarball: tarball_unpinned_package.original_entry.tarball.clone(),
                         name: pinned_package.name,
+                        sha256: None,
+                        tree_sha256: None,
                         __unrendered__: unrendered,
                     }));
             }
@@ -142,37 +221,19 @@ pub async fn compute_package_lock(
 async fn resolve_packages(
     io: &IoArgs,
     vars: &BTreeMap<String, dbt_serde_yaml::Value>,
-    hub_registry: &mut HubClient,
+    registries: &PackageRegistries,
     final_listing: &mut PackageListing,
     package_listing: &mut PackageListing,
+    hub_requirements: &mut Vec<HubRequirement>,
     jinja_env: &JinjaEnv,
-    version_check: bool,
     token: &CancellationToken,
 ) -> FsResult<()> {
-    let mut next_listing = PackageListing::new(io.clone(), vars.clone());
+    let mut next_listing = PackageListing::new(io.clone(), vars.clone(), registries.clone());
     for unpinned_package in package_listing.packages.values_mut() {
         token.check_cancellation()?;
         match unpinned_package {
-            UnpinnedPackage::Hub(hub_unpinned_package) => {
-                let pinned_package = hub_unpinned_package.resolved(hub_registry).await?;
-                let hub_package = hub_registry
-                    .get_hub_package(&pinned_package.package)
-                    .await?;
-                hub_registry.check_package_deprecation(&package_listing.io_args, &hub_package);
-                let metadata = hub_package
-                    .versions
-                    .get(&pinned_package.version)
-                    .expect("Version should exist in package metadata");
-
-                if std::env::var("NEXTEST").is_err() && version_check {
-                    hub_registry.check_require_dbt_version(
-                        &package_listing.io_args,
-                        &hub_package.name,
-                        metadata,
-                    );
-                }
-                next_listing.update_from(&metadata.packages, jinja_env)?;
-            }
+            // Hub packages are resolved by the solver once all requirements are known
+            UnpinnedPackage::Hub(_) => {}
             UnpinnedPackage::Git(git_unpinned_package) => {
                 let (tmp_dir, checkout_path, commit_sha) = handle_git_like_package(
                     &git_unpinned_package.git,
@@ -183,17 +244,32 @@ async fn resolve_packages(
                 )?;
                 git_unpinned_package.revisions = vec![commit_sha];
                 let dbt_project = read_and_validate_dbt_project(io, &checkout_path, true)?;
-                git_unpinned_package.name = Some(dbt_project.name);
                 if let Some(dbt_packages) = load_dbt_packages(io, &checkout_path)?.0 {
-                    next_listing.update_from(&dbt_packages.packages, jinja_env)?;
+                    add_dependencies(
+                        &mut next_listing,
+                        hub_requirements,
+                        &dbt_packages.packages,
+                        &dbt_project.name,
+                        jinja_env,
+                    )?;
                 }
+                git_unpinned_package.name = Some(dbt_project.name);
                 // Keep tmp_dir alive until we're done with checkout_path
                 drop(tmp_dir);
             }
             UnpinnedPackage::Local(local_unpinned_package) => {
                 let (dbt_packages, _) = load_dbt_packages(io, &local_unpinned_package.local)?;
                 if let Some(dbt_packages) = dbt_packages {
-                    next_listing.update_from(&dbt_packages.packages, jinja_env)?;
+                    let required_by = local_unpinned_package.name.clone().unwrap_or_else(|| {
+                        local_unpinned_package.local.to_string_lossy().to_string()
+                    });
+                    add_dependencies(
+                        &mut next_listing,
+                        hub_requirements,
+                        &dbt_packages.packages,
+                        &required_by,
+                        jinja_env,
+                    )?;
                 }
             }
             UnpinnedPackage::Private(private_unpinned_package) => {
@@ -206,10 +282,16 @@ async fn resolve_packages(
                 )?;
                 private_unpinned_package.revisions = vec![commit_sha];
                 let dbt_project = read_and_validate_dbt_project(io, &checkout_path, true)?;
-                private_unpinned_package.name = Some(dbt_project.name);
                 if let Some(dbt_packages) = load_dbt_packages(io, &checkout_path)?.0 {
-                    next_listing.update_from(&dbt_packages.packages, jinja_env)?;
+                    add_dependencies(
+                        &mut next_listing,
+                        hub_requirements,
+                        &dbt_packages.packages,
+                        &dbt_project.name,
+                        jinja_env,
+                    )?;
                 }
+                private_unpinned_package.name = Some(dbt_project.name);
                 // Keep tmp_dir alive until we're done with checkout_path
                 drop(tmp_dir);
             }
@@ -225,7 +307,7 @@ async fn resolve_packages(
                     fs_err!(ErrorCode::IoError, "Failed to create untar dir: {}", e)
                 })?;
 
-                let mut tarball_client = TarballClient::new();
+                let mut tarball_client = TarballClient::new(registries.clone());
                 tarball_client
                     .download_and_extract_tarball(
                         &tarball_unpinned_package.tarball,
@@ -258,10 +340,16 @@ async fn resolve_packages(
 
                 let checkout_path = tar_contents[0].path();
                 let dbt_project = read_and_validate_dbt_project(io, &checkout_path, true)?;
-                tarball_unpinned_package.name = Some(dbt_project.name);
                 if let Some(dbt_packages) = load_dbt_packages(io, &checkout_path)?.0 {
-                    next_listing.update_from(&dbt_packages.packages, jinja_env)?;
+                    add_dependencies(
+                        &mut next_listing,
+                        hub_requirements,
+                        &dbt_packages.packages,
+                        &dbt_project.name,
+                        jinja_env,
+                    )?;
                 }
+                tarball_unpinned_package.name = Some(dbt_project.name);
             }
         }
         final_listing.incorporate_unpinned_package(unpinned_package)?;
@@ -270,14 +358,38 @@ async fn resolve_packages(
         Box::pin(resolve_packages(
             io,
             vars,
-            hub_registry,
+            registries,
             final_listing,
             &mut next_listing,
+            hub_requirements,
             jinja_env,
-            version_check,
             token,
         ))
         .await?;
     }
     Ok(())
 }
+
+/// Adds the packages that `required_by` depends on to `next_listing`, and
+/// records its requirements on hub packages for the solver
+fn add_dependencies(
+    next_listing: &mut PackageListing,
+    hub_requirements: &mut Vec<HubRequirement>,
+    packages: &Vec<DbtPackageEntry>,
+    required_by: &str,
+    jinja_env: &JinjaEnv,
+) -> FsResult<()> {
+    let mut dependencies = PackageListing::new(
+        next_listing.io_args.clone(),
+        next_listing.vars.clone(),
+        next_listing.registries.clone(),
+    );
+    dependencies.update_from(packages, jinja_env)?;
+    for package in dependencies.packages.values() {
+        if let UnpinnedPackage::Hub(hub_unpinned_package) = package {
+            hub_requirements.push(HubRequirement::new(hub_unpinned_package, required_by));
+        }
+        next_listing.incorporate_unpinned_package(package)?;
+    }
+    Ok(())
+}
@@ -11,7 +11,7 @@ use dbt_common::{
     show_progress, stdfs,
 };
 use dbt_jinja_utils::jinja_environment::JinjaEnv;
-use dbt_schemas::schemas::packages::DbtPackagesLock;
+use dbt_schemas::schemas::packages::{DbtPackageLock, DbtPackagesLock};
 use flate2::read::GzDecoder;
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
@@ -21,31 +21,29 @@ use crate::package_listing::UnpinnedPackage;
 
 use crate::{
     hub_client::HubClient,
+    package_cache::PackageCache,
     package_listing::PackageListing,
     tarball_client::TarballClient,
-    utils::{handle_git_like_package, read_and_validate_dbt_project, sanitize_git_url},
+    utils::{
+        handle_git_like_package, read_and_validate_dbt_project, sanitize_git_url, sha256_hash_tree,
+    },
 };
 
-#[allow(clippy::cognitive_complexity)]
+#[allow(clippy::cognitive_complexity, clippy::too_many_arguments)]
 pub async fn install_packages(
     io_args: &IoArgs,
     vars: &BTreeMap<String, dbt_serde_yaml::Value>,
     hub_registry: &mut HubClient,
     jinja_env: &JinjaEnv,
-    dbt_packages_lock: &DbtPackagesLock,
+    dbt_packages_lock: &mut DbtPackagesLock,
     packages_install_path: &Path,
+    package_cache: Option<&PackageCache>,
+    upgrade: bool,
+    offline: bool,
 ) -> FsResult<()> {
     // Cleanup package-lock.yml
-    let package_lock_str = dbt_serde_yaml::to_string(&dbt_packages_lock).unwrap();
+    write_package_lock(io_args, dbt_packages_lock)?;
     // Create tmp dir for tarball
-    let packages_lock_path = &io_args.in_dir.join(DBT_PACKAGES_LOCK_FILE);
-    std::fs::write(packages_lock_path, &package_lock_str).map_err(|e| {
-        fs_err!(
-            ErrorCode::IoError,
-            "Failed to write package-lock.yml file: {}",
-            e,
-        )
-    })?;
     let tarball_dir = tempfile::tempdir()
         .map_err(|e| fs_err!(ErrorCode::IoError, "Failed to create temp dir: {}", e,))?;
     if packages_install_path.exists() {
@@ -67,11 +65,50 @@ pub async fn install_packages(
     if dbt_packages_lock.packages.is_empty() {
         return Ok(());
     }
-    let mut package_listing = PackageListing::new(io_args.clone(), vars.clone());
+    let mut package_listing = PackageListing::new(
+        io_args.clone(),
+        vars.clone(),
+        hub_registry.registries.clone(),
+    );
 
     // Collect fusion-schema-compat upgrade suggestions
     let mut fusion_compat_suggestions: Vec<(String, String, String)> = Vec::new();
-    package_listing.hydrate_dbt_packages_lock(dbt_packages_lock, jinja_env)?;
+    // Packages found in the package cache are copied from there; only the
+    // rest is fetched
+    let mut pending_lock = DbtPackagesLock {
+        packages: Vec::new(),
+        sha1_hash: dbt_packages_lock.sha1_hash.clone(),
+    };
+    for package_lock in dbt_packages_lock.packages.iter() {
+        let package_name = package_lock.package_name();
+        if let Some(package_cache) = package_cache
+            && package_cache.restore(package_lock, &packages_install_path.join(&package_name))?
+        {
+            if std::env::var("NEXTEST").is_err() {
+                show_progress!(
+                    io_args,
+                    fsinfo!(INSTALLING.into(), format!("{package_name} (cached)"))
+                );
+            }
+            continue;
+        }
+        if offline && !matches!(package_lock, DbtPackageLock::Local(_)) {
+            return err!(
+                ErrorCode::InvalidConfig,
+                "Package '{}' is not in the package cache{}. Run deps without --offline to populate it.",
+                package_name,
+                if package_lock.sha256().is_none() {
+                    " (package-lock.yml records no sha256 for it)"
+                } else {
+                    ""
+                }
+            );
+        }
+        pending_lock.packages.push(package_lock.clone());
+    }
+    package_listing.hydrate_dbt_packages_lock(&pending_lock, jinja_env)?;
+    // Set when a digest is recorded for the first time (or replaced on upgrade)
+    let mut digests_updated = false;
 
     for package in package_listing.packages.values() {
         match package {
@@ -130,8 +167,8 @@ pub async fn install_packages(
                 let untar_path = tempfile::TempDir::new_in(packages_install_path).map_err(|e| {
                     fs_err!(ErrorCode::IoError, "Failed to create untar dir: {}", e)
                 })?;
-                let mut tarball_client = TarballClient::new();
-                tarball_client
+                let mut tarball_client = TarballClient::new(hub_registry.registries.clone());
+                let sha256 = tarball_client
                     .download_and_extract_tarball(
                         &tarball_url,
                         &tar_path,
@@ -139,10 +176,19 @@ pub async fn install_packages(
                         "hub_package",
                     )
                     .await?;
+                digests_updated |= verify_package_digest(
+                    io_args,
+                    dbt_packages_lock,
+                    &pinned_package.name,
+                    sha256,
+                    upgrade,
+                )?;
 
                 if let Some(common_prefix) = get_common_prefix(&tar_path)? {
                     let rename_path = packages_install_path.join(project_name);
                     stdfs::rename(untar_path.path().join(&common_prefix), &rename_path)?;
+                    digests_updated |=
+                        record_tree_digest(dbt_packages_lock, &pinned_package.name, &rename_path)?;
                 } else {
                     return err!(ErrorCode::IoError, "No common prefix for package found");
                 }
@@ -180,6 +226,13 @@ pub async fn install_packages(
                     false,
                 )?;
                 let project_name = dbt_project.name;
+                digests_updated |= verify_package_digest(
+                    io_args,
+                    dbt_packages_lock,
+                    &project_name,
+                    sha256_hash_tree(&checkout_path)?,
+                    upgrade,
+                )?;
                 stdfs::rename(&checkout_path, packages_install_path.join(&project_name))?;
                 // Keep tmp_dir alive until we're done with checkout_path
                 drop(tmp_dir);
@@ -239,6 +292,13 @@ pub async fn install_packages(
                     false,
                 )?;
                 let project_name = dbt_project.name;
+                digests_updated |= verify_package_digest(
+                    io_args,
+                    dbt_packages_lock,
+                    &project_name,
+                    sha256_hash_tree(&checkout_path)?,
+                    upgrade,
+                )?;
                 stdfs::rename(&checkout_path, packages_install_path.join(project_name))?;
                 // Keep tmp_dir alive until we're done with checkout_path
                 drop(tmp_dir);
@@ -272,8 +332,8 @@ pub async fn install_packages(
                 let untar_path = tempfile::TempDir::new_in(packages_install_path).map_err(|e| {
                     fs_err!(ErrorCode::IoError, "Failed to create untar dir: {}", e)
                 })?;
-                let mut tarball_client = TarballClient::new();
-                tarball_client
+                let mut tarball_client = TarballClient::new(hub_registry.registries.clone());
+                let sha256 = tarball_client
                     .download_and_extract_tarball(
                         &tarball_unpinned_package.tarball,
                         &tar_path,
@@ -312,7 +372,19 @@ pub async fn install_packages(
                     false,
                 )?;
                 let project_name = dbt_project.name;
+                digests_updated |= verify_package_digest(
+                    io_args,
+                    dbt_packages_lock,
+                    &project_name,
+                    sha256,
+                    upgrade,
+                )?;
                 stdfs::rename(&checkout_path, packages_install_path.join(&project_name))?;
+                digests_updated |= record_tree_digest(
+                    dbt_packages_lock,
+                    &project_name,
+                    &packages_install_path.join(&project_name),
+                )?;
 
                 if std::env::var("NEXTEST").is_err() {
                     show_progress!(
@@ -357,9 +429,102 @@ pub async fn install_packages(
         emit_info_log_message(msg);
     }
 
+    if digests_updated {
+        write_package_lock(io_args, dbt_packages_lock)?;
+    }
+
+    if let Some(package_cache) = package_cache {
+        for package_name in pending_lock.packages.iter().map(|p| p.package_name()) {
+            // Digests recorded during this install live on the main lock
+            let Some(package_lock) = dbt_packages_lock.get_by_name(&package_name) else {
+                continue;
+            };
+            if let Err(e) =
+                package_cache.store(package_lock, &packages_install_path.join(&package_name))
+            {
+                emit_warn_log_message(
+                    ErrorCode::IoError,
+                    format!("Failed to add package '{package_name}' to the package cache: {e}"),
+                    io_args.status_reporter.as_ref(),
+                );
+            }
+        }
+    }
+
     Ok(())
 }
 
+fn write_package_lock(io_args: &IoArgs, dbt_packages_lock: &DbtPackagesLock) -> FsResult<()> {
+    let package_lock_str = dbt_serde_yaml::to_string(dbt_packages_lock).unwrap();
+    let packages_lock_path = &io_args.in_dir.join(DBT_PACKAGES_LOCK_FILE);
+    std::fs::write(packages_lock_path, &package_lock_str).map_err(|e| {
+        fs_err!(
+            ErrorCode::IoError,
+            "Failed to write package-lock.yml file: {}",
+            e,
+        )
+    })
+}
+
+/// Checks the content digest of a downloaded package against the one recorded
+/// in package-lock.yml, before the package is moved into the install path.
+///
+/// A package without a recorded digest gets one. A mismatch is refused unless
+/// `upgrade` is set, in which case the new digest replaces the recorded one.
+/// Returns whether the lock was updated.
+fn verify_package_digest(
+    io_args: &IoArgs,
+    dbt_packages_lock: &mut DbtPackagesLock,
+    package_name: &str,
+    sha256: String,
+    upgrade: bool,
+) -> FsResult<bool> {
+    let Some(package_lock) = dbt_packages_lock.get_by_name_mut(package_name) else {
+        return Ok(false);
+    };
+    match package_lock.sha256() {
+        Some(recorded) if recorded == sha256 => return Ok(false),
+        Some(recorded) if !upgrade => {
+            return err!(
+                ErrorCode::PackageIntegrityError,
+                "Integrity check failed for package '{}': package-lock.yml records sha256 {} but the downloaded content hashes to {}. The package content changed upstream; re-run deps with --upgrade to accept it.",
+                package_name,
+                recorded,
+                sha256
+            );
+        }
+        Some(recorded) => emit_warn_log_message(
+            ErrorCode::PackageIntegrityError,
+            format!(
+                "Content of package '{package_name}' changed (sha256 {recorded} -> {sha256}), updating package-lock.yml"
+            ),
+            io_args.status_reporter.as_ref(),
+        ),
+        None => {}
+    }
+    package_lock.set_sha256(Some(sha256));
+    Ok(true)
+}
+
+/// Records the tree digest of a hub or tarball package installed from a
+/// tarball that passed [`verify_package_digest`], so the package cache can
+/// check its entries against the lock. Returns whether the lock was updated.
+fn record_tree_digest(
+    dbt_packages_lock: &mut DbtPackagesLock,
+    package_name: &str,
+    installed_path: &Path,
+) -> FsResult<bool> {
+    let Some(package_lock) = dbt_packages_lock.get_by_name_mut(package_name) else {
+        return Ok(false);
+    };
+    let tree_sha256 = sha256_hash_tree(installed_path)?;
+    if package_lock.tree_sha256() == Some(tree_sha256.as_str()) {
+        return Ok(false);
+    }
+    package_lock.set_tree_sha256(Some(tree_sha256));
+    Ok(true)
+}
+
 fn get_common_prefix(tar_path: &Path) -> FsResult<Option<PathBuf>> {
     // Open the tarball file
     let tar = File::open(tar_path)
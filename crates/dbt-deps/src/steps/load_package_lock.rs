@@ -56,6 +56,18 @@ pub fn try_load_valid_dbt_packages_lock(
     Ok(None)
 }
 
+/// Load the existing package-lock.yml, regardless of whether it still matches
+/// packages.yml, so the content digests it records can be carried over to a
+/// freshly computed lock. Unreadable or old-format lock files are ignored.
+pub fn load_recorded_dbt_packages_lock(io: &IoArgs) -> Option<DbtPackagesLock> {
+    let packages_lock_path = io.in_dir.join(DBT_PACKAGES_LOCK_FILE);
+    if !packages_lock_path.exists() {
+        return None;
+    }
+    let yml_str = try_read_yml_to_str(&packages_lock_path).ok()?;
+    from_yaml_raw(io, &yml_str, Some(&packages_lock_path), false, None).ok()
+}
+
 // This is a hack to support the old dbt_packages_lock.yml file format
 // In the future, we should not support just checking for directory names
 fn try_load_from_deprecated_dbt_packages_lock(
@@ -129,6 +141,8 @@ fn try_load_from_deprecated_dbt_packages_lock(
                                 package: package.to_string(),
                                 name: (*package_name).to_string(),
                                 version,
+                                sha256: None,
+                                tree_sha256: None,
                             }));
                         } else {
                             emit_warn_log_message(
@@ -160,6 +174,7 @@ fn try_load_from_deprecated_dbt_packages_lock(
                                 revision,
                                 warn_unpinned,
                                 subdirectory,
+                                sha256: None,
                                 __unrendered__: unrendered,
                             }));
                         } else {
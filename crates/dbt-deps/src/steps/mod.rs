@@ -7,5 +7,6 @@ pub use compute_package_lock::compute_package_lock;
 pub use install_packages::install_packages;
 pub use load_dbt_packages::load_dbt_packages;
 pub use load_package_lock::{
-    load_dbt_packages_lock_without_validation, try_load_valid_dbt_packages_lock,
+    load_dbt_packages_lock_without_validation, load_recorded_dbt_packages_lock,
+    try_load_valid_dbt_packages_lock,
 };
@@ -22,6 +22,8 @@ mod tests {
         let tarball_package_lock = TarballPackageLock {
             tarball: Verbatim::from("https://example.com/package.tar.gz".to_string()),
             name: "test-package".to_string(),
+            sha256: None,
+            tree_sha256: None,
             __unrendered__: HashMap::new(),
         };
 
@@ -37,6 +39,8 @@ mod tests {
         let tarball_package_lock = TarballPackageLock {
             tarball: Verbatim::from("https://example.com/package.tar.gz".to_string()),
             name: "test-package".to_string(),
+            sha256: None,
+            tree_sha256: None,
             __unrendered__: HashMap::new(),
         };
 
@@ -1,69 +1,69 @@
-use dbt_common::{ErrorCode, FsResult, fs_err};
-use reqwest::{Client, StatusCode};
-use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
-use reqwest_retry::{
-    RetryTransientMiddleware, policies::ExponentialBackoff as RetryExponentialBackoff,
-};
+use crate::registry::PackageRegistries;
+use dbt_common::{ErrorCode, FsResult, err, fs_err};
+use reqwest::StatusCode;
+use sha2::{Digest, Sha256};
 use std::{fs::File, io::Write, path::Path};
 
-const MAX_CLIENT_RETRIES: u32 = 3;
-
 pub struct TarballClient {
-    pub client: ClientWithMiddleware,
+    /// Tarballs served by a configured registry are downloaded with its credentials
+    pub registries: PackageRegistries,
 }
 
 impl TarballClient {
-    pub fn new() -> Self {
-        let retry_policy =
-            RetryExponentialBackoff::builder().build_with_max_retries(MAX_CLIENT_RETRIES);
-        let client = ClientBuilder::new(Client::new())
-            // Retry failed requests.
-            .with(RetryTransientMiddleware::new_with_policy(retry_policy))
-            .build();
-        Self { client }
+    pub fn new(registries: PackageRegistries) -> Self {
+        Self { registries }
     }
 
-    pub async fn download_tarball(&mut self, download_url: &str, out_path: &Path) -> FsResult<()> {
-        let tarball_res = self.client.get(download_url).send().await.map_err(|e| {
-            fs_err!(
-                ErrorCode::RuntimeError,
-                "Failed to get tarball from {download_url}; status: {}",
-                e.status().unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
-            )
-        })?;
-        if tarball_res.status().is_success() {
-            let mut file = File::create(out_path).map_err(|e| {
-                fs_err!(
-                    ErrorCode::IoError,
-                    "Failed to create file at {}; {}",
-                    out_path.display(),
-                    e
-                )
-            })?;
-            file.write_all(
-                tarball_res
-                    .bytes()
-                    .await
-                    .map_err(|e| {
-                        fs_err!(
-                            ErrorCode::RuntimeError,
-                            "Failed to write to file at {}; status: {}",
-                            out_path.display(),
-                            e
-                        )
-                    })?
-                    .as_ref(),
-            )
+    /// Downloads the tarball at `download_url` to `out_path` and returns the
+    /// SHA-256 of the downloaded bytes.
+    pub async fn download_tarball(
+        &mut self,
+        download_url: &str,
+        out_path: &Path,
+    ) -> FsResult<String> {
+        let tarball_res = self
+            .registries
+            .get(download_url)?
+            .send()
+            .await
             .map_err(|e| {
                 fs_err!(
-                    ErrorCode::IoError,
-                    "Failed to write to file at {}; {}",
-                    out_path.display(),
-                    e
+                    ErrorCode::RuntimeError,
+                    "Failed to get tarball from {download_url}; status: {}",
+                    e.status().unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
                 )
             })?;
+        if !tarball_res.status().is_success() {
+            return err!(
+                ErrorCode::RuntimeError,
+                "Failed to get tarball from {download_url}; status: {}",
+                tarball_res.status()
+            );
         }
-        Ok(())
+        let bytes = tarball_res.bytes().await.map_err(|e| {
+            fs_err!(
+                ErrorCode::RuntimeError,
+                "Failed to read tarball from {download_url}; {}",
+                e
+            )
+        })?;
+        let mut file = File::create(out_path).map_err(|e| {
+            fs_err!(
+                ErrorCode::IoError,
+                "Failed to create file at {}; {}",
+                out_path.display(),
+                e
+            )
+        })?;
+        file.write_all(bytes.as_ref()).map_err(|e| {
+            fs_err!(
+                ErrorCode::IoError,
+                "Failed to write to file at {}; {}",
+                out_path.display(),
+                e
+            )
+        })?;
+        Ok(format!("{:x}", Sha256::digest(&bytes)))
     }
 
     pub async fn download_and_extract_tarball(
@@ -72,9 +72,9 @@ impl TarballClient {
         tar_path: &Path,
         untar_path: &tempfile::TempDir,
         _package_type: &str,
-    ) -> FsResult<()> {
+    ) -> FsResult<String> {
         // Download the tarball
-        self.download_tarball(download_url, tar_path).await?;
+        let sha256 = self.download_tarball(download_url, tar_path).await?;
 
         // Extract the tarball
         let tar = File::open(tar_path)
@@ -86,6 +86,6 @@ impl TarballClient {
         tar.unpack(untar_path)
             .map_err(|e| fs_err!(ErrorCode::IoError, "Failed to unpack tar file: {}", e))?;
 
-        Ok(())
+        Ok(sha256)
     }
 }
@@ -1,7 +1,7 @@
 use std::path::{Path, PathBuf};
 
 use dbt_schemas::schemas::{
-    packages::{DbtPackageEntry, LocalPackage},
+    packages::{DbtPackageEntry, DbtPackageLock, DbtPackagesLock, LocalPackage},
     project::DbtProjectNameOnly,
 };
 use sha1::Digest;
@@ -35,6 +35,133 @@ pub fn sha1_hash_packages(packages: &[DbtPackageEntry]) -> String {
     )
 }
 
+/// Hashes an installed package directory into a single SHA-256 digest.
+///
+/// Files are visited in sorted path order and each contributes its relative
+/// path and content (or link target), so the digest only depends on what ends
+/// up in the packages install path. `.git` entries are skipped, since a clone
+/// carries metadata that differs from one checkout to the next.
+pub fn sha256_hash_tree(root: &Path) -> FsResult<String> {
+    let mut files = Vec::new();
+    collect_tree_files(root, root, &mut files)?;
+    files.sort();
+
+    let mut hasher = sha2::Sha256::new();
+    for relative_path in files {
+        let path = root.join(&relative_path);
+        let key = relative_path
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        hasher.update(key.as_bytes());
+        hasher.update([0]);
+        let metadata = std::fs::symlink_metadata(&path).map_err(|e| {
+            fs_err!(
+                ErrorCode::IoError,
+                "Failed to read metadata of {}: {}",
+                path.display(),
+                e
+            )
+        })?;
+        if metadata.file_type().is_symlink() {
+            let target = std::fs::read_link(&path).map_err(|e| {
+                fs_err!(
+                    ErrorCode::IoError,
+                    "Failed to read link {}: {}",
+                    path.display(),
+                    e
+                )
+            })?;
+            hasher.update(b"link:");
+            hasher.update(target.to_string_lossy().as_bytes());
+        } else {
+            let content = std::fs::read(&path).map_err(|e| {
+                fs_err!(
+                    ErrorCode::IoError,
+                    "Failed to read {}: {}",
+                    path.display(),
+                    e
+                )
+            })?;
+            hasher.update((content.len() as u64).to_le_bytes());
+            hasher.update(&content);
+        }
+        hasher.update([0]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+fn collect_tree_files(root: &Path, dir: &Path, files: &mut Vec<PathBuf>) -> FsResult<()> {
+    let entries = std::fs::read_dir(dir).map_err(|e| {
+        fs_err!(
+            ErrorCode::IoError,
+            "Failed to read directory {}: {}",
+            dir.display(),
+            e
+        )
+    })?;
+    for entry in entries {
+        let entry = entry
+            .map_err(|e| fs_err!(ErrorCode::IoError, "Failed to read directory entry: {}", e))?;
+        if entry.file_name() == ".git" {
+            continue;
+        }
+        let path = entry.path();
+        let file_type = entry.file_type().map_err(|e| {
+            fs_err!(
+                ErrorCode::IoError,
+                "Failed to read file type of {}: {}",
+                path.display(),
+                e
+            )
+        })?;
+        if file_type.is_dir() {
+            collect_tree_files(root, &path, files)?;
+        } else {
+            files.push(
+                path.strip_prefix(root)
+                    .expect("Entry should be inside the package root")
+                    .to_path_buf(),
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Copies the content digests recorded in `previous` onto the packages of
+/// `lock` that are still pinned to the same version, revision or URL, so that
+/// re-resolving packages.yml does not drop the digests of unchanged packages.
+pub fn carry_over_package_digests(previous: &DbtPackagesLock, lock: &mut DbtPackagesLock) {
+    for package in lock.packages.iter_mut() {
+        if let Some(previous) = previous
+            .packages
+            .iter()
+            .find(|p| same_package_pin(p, package))
+        {
+            package.set_sha256(previous.sha256().map(str::to_string));
+            package.set_tree_sha256(previous.tree_sha256().map(str::to_string));
+        }
+    }
+}
+
+fn same_package_pin(a: &DbtPackageLock, b: &DbtPackageLock) -> bool {
+    match (a, b) {
+        (DbtPackageLock::Hub(a), DbtPackageLock::Hub(b)) => {
+            a.package == b.package
+                && serde_json::to_value(&a.version).ok() == serde_json::to_value(&b.version).ok()
+        }
+        (DbtPackageLock::Git(a), DbtPackageLock::Git(b)) => {
+            *a.git == *b.git && a.revision == b.revision && a.subdirectory == b.subdirectory
+        }
+        (DbtPackageLock::Private(a), DbtPackageLock::Private(b)) => {
+            *a.private == *b.private && a.revision == b.revision && a.subdirectory == b.subdirectory
+        }
+        (DbtPackageLock::Tarball(a), DbtPackageLock::Tarball(b)) => *a.tarball == *b.tarball,
+        _ => false,
+    }
+}
+
 pub fn handle_git_like_package(
     repo_url: &str,
     revisions: &[String],
@@ -145,6 +272,9 @@ pub fn sanitize_git_url(url: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use dbt_schemas::schemas::packages::{HubPackageLock, PackageVersion, TarballPackageLock};
+    use dbt_serde_yaml::Verbatim;
+    use std::collections::HashMap;
 
     #[test]
     fn test_sanitize_git_url_basic_credentials() {
@@ -285,4 +415,68 @@ mod tests {
         let sanitized = sanitize_git_url(url);
         assert_eq!(sanitized, "https://github.com/dbt-labs/secret-project.git");
     }
+
+    #[test]
+    fn test_sha256_hash_tree() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("models/staging")).unwrap();
+        std::fs::write(root.join("dbt_project.yml"), "name: pkg\n").unwrap();
+        std::fs::write(root.join("models/staging/stg.sql"), "select 1").unwrap();
+        let digest = sha256_hash_tree(root).unwrap();
+        assert_eq!(digest.len(), 64);
+
+        // git metadata does not contribute to the digest
+        std::fs::create_dir_all(root.join(".git/objects")).unwrap();
+        std::fs::write(root.join(".git/HEAD"), "ref: refs/heads/main").unwrap();
+        assert_eq!(sha256_hash_tree(root).unwrap(), digest);
+
+        std::fs::write(root.join("models/staging/stg.sql"), "select 2").unwrap();
+        assert_ne!(sha256_hash_tree(root).unwrap(), digest);
+        std::fs::write(root.join("models/staging/stg.sql"), "select 1").unwrap();
+        assert_eq!(sha256_hash_tree(root).unwrap(), digest);
+
+        // moving content between files changes the digest
+        std::fs::write(root.join("models/staging/stg.sql"), "").unwrap();
+        std::fs::write(root.join("dbt_project.yml"), "name: pkg\nselect 1").unwrap();
+        assert_ne!(sha256_hash_tree(root).unwrap(), digest);
+    }
+
+    #[test]
+    fn test_carry_over_package_digests() {
+        let hub = |version: &str, sha256: Option<&str>| {
+            DbtPackageLock::Hub(HubPackageLock {
+                package: "dbt-labs/dbt_utils".to_string(),
+                name: "dbt_utils".to_string(),
+                version: PackageVersion::String(version.to_string()),
+                sha256: sha256.map(str::to_string),
+                tree_sha256: sha256.map(|sha256| format!("{sha256}-tree")),
+            })
+        };
+        let tarball = |sha256: Option<&str>| {
+            DbtPackageLock::Tarball(TarballPackageLock {
+                tarball: Verbatim::from("https://example.com/pkg.tar.gz".to_string()),
+                name: "pkg".to_string(),
+                sha256: sha256.map(str::to_string),
+                tree_sha256: sha256.map(|sha256| format!("{sha256}-tree")),
+                __unrendered__: HashMap::new(),
+            })
+        };
+        let previous = DbtPackagesLock {
+            packages: vec![hub("1.1.1", Some("aaaa")), tarball(Some("bbbb"))],
+            sha1_hash: "old".to_string(),
+        };
+        let mut lock = DbtPackagesLock {
+            packages: vec![hub("1.2.0", None), tarball(None)],
+            sha1_hash: "new".to_string(),
+        };
+        carry_over_package_digests(&previous, &mut lock);
+        assert_eq!(lock.get_by_name("dbt_utils").unwrap().sha256(), None);
+        assert_eq!(lock.get_by_name("dbt_utils").unwrap().tree_sha256(), None);
+        assert_eq!(lock.get_by_name("pkg").unwrap().sha256(), Some("bbbb"));
+        assert_eq!(
+            lock.get_by_name("pkg").unwrap().tree_sha256(),
+            Some("bbbb-tree")
+        );
+    }
 }
@@ -112,6 +112,7 @@ pub enum ErrorCode {
 
     GenericExecError = 1067,
     LicenseError = 1068,
+    PackageIntegrityError = 1069,
     // --------------------------------------------------------------------------------------------
     // Jinja
     MacroUnsupportedValueType = 1500,
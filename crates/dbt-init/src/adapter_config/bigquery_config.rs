@@ -122,6 +122,9 @@ pub fn setup_bigquery_profile(
         keyfile: None,
         quota_project: None,
         retries: None,
+        statement_retries: None,
+        statement_retry_backoff: None,
+        connection_pool: None,
         location: None,
         scopes: None,
         keyfile_json: None,
@@ -123,6 +123,9 @@ pub fn setup_databricks_profile(
         auth_type: None,
         compute: None,
         connect_retries: None,
+        statement_retries: None,
+        statement_retry_backoff: None,
+        connection_pool: None,
         connect_timeout: None,
         retry_all: None,
         connect_max_idle: None,
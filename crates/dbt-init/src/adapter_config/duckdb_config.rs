@@ -0,0 +1,81 @@
+use super::common::*;
+use crate::{ErrorCode, FsResult, fs_err};
+use dbt_schemas::schemas::profiles::DuckDBDbConfig;
+use dbt_schemas::schemas::serde::StringOrInteger;
+
+impl InteractiveSetup for DuckDBDbConfig {
+    fn get_fields() -> Vec<ConfigField> {
+        vec![
+            ConfigField {
+                name: "path".to_string(),
+                field_type: FieldType::Input {
+                    default: Some("dev.duckdb".to_string()),
+                },
+                condition: FieldCondition::Always,
+                prompt: "Database file path (:memory: for an in-memory database)".to_string(),
+                required: true,
+            },
+            ConfigField {
+                name: "schema".to_string(),
+                field_type: FieldType::Input {
+                    default: Some("main".to_string()),
+                },
+                condition: FieldCondition::Always,
+                prompt: "Schema (dbt schema)".to_string(),
+                required: true,
+            },
+        ]
+    }
+
+    fn set_field(&mut self, field_name: &str, value: FieldValue) -> FsResult<()> {
+        match field_name {
+            "path" => {
+                if let FieldValue::String(val) = value {
+                    self.path = Some(val);
+                }
+            }
+            "schema" => {
+                if let FieldValue::String(val) = value {
+                    self.schema = Some(val);
+                }
+            }
+            _ => {
+                return Err(fs_err!(
+                    ErrorCode::InvalidArgument,
+                    "Unknown field: {}",
+                    field_name
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn get_field(&self, field_name: &str) -> Option<FieldValue> {
+        match field_name {
+            "path" => self.path.as_ref().map(|v| FieldValue::String(v.clone())),
+            "schema" => self.schema.as_ref().map(|v| FieldValue::String(v.clone())),
+            _ => None,
+        }
+    }
+
+    fn is_field_set(&self, field_name: &str) -> bool {
+        match field_name {
+            "path" => self.path.is_some(),
+            "schema" => self.schema.is_some(),
+            _ => false,
+        }
+    }
+}
+
+pub fn setup_duckdb_profile(
+    existing_config: Option<&DuckDBDbConfig>,
+) -> FsResult<Box<DuckDBDbConfig>> {
+    let default_config = DuckDBDbConfig::default();
+    let mut config = ConfigProcessor::process_config(existing_config.or(Some(&default_config)))?;
+
+    if config.threads.is_none() {
+        config.threads = Some(StringOrInteger::Integer(1));
+    }
+
+    Ok(Box::new(config))
+}
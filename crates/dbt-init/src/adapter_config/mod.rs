@@ -1,12 +1,16 @@
 pub mod bigquery_config;
 pub mod common;
 pub mod databricks_config;
+pub mod duckdb_config;
 pub mod postgres_config;
 pub mod redshift_config;
 pub mod snowflake_config;
+pub mod trino_config;
 
 pub use bigquery_config::setup_bigquery_profile;
 pub use databricks_config::setup_databricks_profile;
+pub use duckdb_config::setup_duckdb_profile;
 pub use postgres_config::setup_postgres_profile;
 pub use redshift_config::setup_redshift_profile;
 pub use snowflake_config::setup_snowflake_profile;
+pub use trino_config::setup_trino_profile;
@@ -177,6 +177,9 @@ pub fn setup_redshift_profile(
         ra3_node: None,
         autocommit: None,
         retries: None,
+        statement_retries: None,
+        statement_retry_backoff: None,
+        connection_pool: None,
         method: None,
         host: None,
         user: None,
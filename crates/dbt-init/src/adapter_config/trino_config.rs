@@ -0,0 +1,167 @@
+use super::common::{ConfigField, ConfigProcessor, FieldValue, InteractiveSetup};
+use dbt_common::FsResult;
+use dbt_schemas::schemas::profiles::TrinoDbConfig;
+use dbt_schemas::schemas::serde::StringOrInteger;
+
+/// Values of `method`, in the order of the selection prompt
+const METHODS: [&str; 4] = ["none", "ldap", "jwt", "certificate"];
+
+impl InteractiveSetup for TrinoDbConfig {
+    fn get_fields() -> Vec<ConfigField> {
+        vec![
+            // Connection settings
+            ConfigField::input("host", "Host (hostname of the coordinator)"),
+            ConfigField::optional_input("port", "Port", Some("443")),
+            ConfigField::input("user", "User"),
+            ConfigField::input("database", "Catalog"),
+            ConfigField::input("schema", "Schema (dbt schema)"),
+            // Authentication
+            ConfigField::select(
+                "method",
+                "Which authentication method would you like to use?",
+                vec!["None", "LDAP (password)", "JWT", "Client certificate"],
+                1,
+            ),
+            ConfigField::password("password", "Password")
+                .when_field_equals("method", FieldValue::Integer(1)),
+            ConfigField::password("jwt_token", "JWT")
+                .when_field_equals("method", FieldValue::Integer(2)),
+            ConfigField::input("client_certificate", "Client certificate path")
+                .when_field_equals("method", FieldValue::Integer(3)),
+            ConfigField::input("client_private_key", "Client private key path")
+                .when_field_equals("method", FieldValue::Integer(3)),
+        ]
+    }
+
+    fn set_field(&mut self, field_name: &str, value: FieldValue) -> FsResult<()> {
+        match field_name {
+            "host" => {
+                if let FieldValue::String(s) = value {
+                    self.host = Some(s);
+                }
+            }
+            "port" => {
+                if let FieldValue::String(s) = value {
+                    if let Ok(port) = s.parse::<i64>() {
+                        self.port = Some(StringOrInteger::Integer(port));
+                    }
+                } else if let FieldValue::Integer(port) = value {
+                    self.port = Some(StringOrInteger::Integer(port));
+                }
+            }
+            "user" => {
+                if let FieldValue::String(s) = value {
+                    self.user = Some(s);
+                }
+            }
+            "database" => {
+                if let FieldValue::String(s) = value {
+                    self.database = Some(s);
+                }
+            }
+            "schema" => {
+                if let FieldValue::String(s) = value {
+                    self.schema = Some(s);
+                }
+            }
+            "method" => {
+                if let FieldValue::Integer(i) = value
+                    && let Some(method) = METHODS.get(i as usize)
+                {
+                    self.method = Some(method.to_string());
+                    // Plain HTTP is only reasonable for a local coordinator
+                    self.http_scheme = Some(if i == 0 { "http" } else { "https" }.to_string());
+                }
+            }
+            "password" => {
+                if let FieldValue::String(s) = value {
+                    self.password = Some(s);
+                }
+            }
+            "jwt_token" => {
+                if let FieldValue::String(s) = value {
+                    self.jwt_token = Some(s);
+                }
+            }
+            "client_certificate" => {
+                if let FieldValue::String(s) = value {
+                    self.client_certificate = Some(s);
+                }
+            }
+            "client_private_key" => {
+                if let FieldValue::String(s) = value {
+                    self.client_private_key = Some(s);
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn get_field(&self, field_name: &str) -> Option<FieldValue> {
+        match field_name {
+            "host" => self.host.as_ref().map(|s| FieldValue::String(s.clone())),
+            "port" => self.port.as_ref().map(|v| match v {
+                StringOrInteger::String(s) => FieldValue::String(s.clone()),
+                StringOrInteger::Integer(i) => FieldValue::Integer(*i),
+            }),
+            "user" => self.user.as_ref().map(|s| FieldValue::String(s.clone())),
+            "database" => self
+                .database
+                .as_ref()
+                .map(|s| FieldValue::String(s.clone())),
+            "schema" => self.schema.as_ref().map(|s| FieldValue::String(s.clone())),
+            "method" => self
+                .method
+                .as_ref()
+                .and_then(|m| METHODS.iter().position(|method| method == m))
+                .map(|i| FieldValue::Integer(i as i64)),
+            "password" => self
+                .password
+                .as_ref()
+                .map(|s| FieldValue::String(s.clone())),
+            "jwt_token" => self
+                .jwt_token
+                .as_ref()
+                .map(|s| FieldValue::String(s.clone())),
+            "client_certificate" => self
+                .client_certificate
+                .as_ref()
+                .map(|s| FieldValue::String(s.clone())),
+            "client_private_key" => self
+                .client_private_key
+                .as_ref()
+                .map(|s| FieldValue::String(s.clone())),
+            _ => None,
+        }
+    }
+
+    fn is_field_set(&self, field_name: &str) -> bool {
+        match field_name {
+            "host" => self.host.is_some(),
+            "port" => self.port.is_some(),
+            "user" => self.user.is_some(),
+            "database" => self.database.is_some(),
+            "schema" => self.schema.is_some(),
+            "method" => self.method.is_some(),
+            "password" => self.password.is_some(),
+            "jwt_token" => self.jwt_token.is_some(),
+            "client_certificate" => self.client_certificate.is_some(),
+            "client_private_key" => self.client_private_key.is_some(),
+            _ => false,
+        }
+    }
+}
+
+pub fn setup_trino_profile(
+    existing_config: Option<&TrinoDbConfig>,
+) -> FsResult<Box<TrinoDbConfig>> {
+    let default_config = TrinoDbConfig::default();
+    let mut config = ConfigProcessor::process_config(existing_config.or(Some(&default_config)))?;
+
+    if config.threads.is_none() {
+        config.threads = Some(StringOrInteger::Integer(8));
+    }
+
+    Ok(Box::new(config))
+}
@@ -186,6 +186,9 @@ fn create_merged_db_config(
                 keyfile: None,
                 quota_project: None,
                 retries: None,
+                statement_retries: None,
+                statement_retry_backoff: None,
+                connection_pool: None,
                 location: None,
                 scopes: None,
                 keyfile_json: None,
@@ -291,6 +294,9 @@ fn create_merged_db_config(
                     execution_project: bigquery.execution_project.clone(),
                     impersonate_service_account: bigquery.impersonate_service_account.clone(),
                     retries: bigquery.retries.map(|r| r as i64),
+                    statement_retries: None,
+                    statement_retry_backoff: None,
+                    connection_pool: None,
                     scopes: bigquery.scopes.clone(),
                     // Authentication details - these could be used to construct keyfile_json
                     client_id: Some(bigquery.client_id.clone()),
@@ -340,6 +346,9 @@ fn create_merged_db_config(
                     execution_project: bigquery_v1.execution_project.clone(),
                     impersonate_service_account: bigquery_v1.impersonate_service_account.clone(),
                     retries: bigquery_v1.retries.map(|r| r as i64),
+                    statement_retries: None,
+                    statement_retry_backoff: None,
+                    connection_pool: None,
                     scopes: bigquery_v1.scopes.clone(),
                     gcs_bucket: bigquery_v1.gcs_bucket.clone(),
                     dataproc_region: bigquery_v1.dataproc_region.clone(),
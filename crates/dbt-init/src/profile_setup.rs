@@ -1,13 +1,13 @@
 use crate::adapter_config::{
-    setup_bigquery_profile, setup_databricks_profile, setup_postgres_profile,
-    setup_redshift_profile, setup_snowflake_profile,
+    setup_bigquery_profile, setup_databricks_profile, setup_duckdb_profile, setup_postgres_profile,
+    setup_redshift_profile, setup_snowflake_profile, setup_trino_profile,
 };
 use crate::dbt_cloud_client::{CloudProject, DbtCloudClient, DbtCloudYml};
 use crate::yaml_utils::{has_top_level_key_parsed_file, remove_top_level_key_from_str};
 use dbt_common::adapter::AdapterType;
 use dbt_common::pretty_string::GREEN;
 use dbt_common::tracing::emit::{emit_info_log_message, emit_warn_log_message};
-use dbt_common::{ErrorCode, FsResult, fs_err, io_args::IoArgs};
+use dbt_common::{ErrorCode, FsResult, err, fs_err, io_args::IoArgs};
 use dbt_jinja_utils::phases::load::init::initialize_load_profile_jinja_environment;
 use dbt_jinja_utils::serde::{into_typed_with_jinja, value_from_file};
 use dbt_loader::{args::LoadArgs, load_profiles};
@@ -161,6 +161,8 @@ impl ProfileSetup {
             AdapterType::Bigquery,
             AdapterType::Postgres,
             AdapterType::Redshift,
+            AdapterType::DuckDB,
+            AdapterType::Trino,
         ]
     }
 
@@ -231,6 +233,26 @@ impl ProfileSetup {
                 };
                 todo!("setup_salesforce_profile")
             }
+            AdapterType::DuckDB => {
+                let duckdb_config = match existing_config {
+                    Some(DbConfig::DuckDB(config)) => Some(config),
+                    _ => None,
+                };
+                DbConfig::DuckDB(setup_duckdb_profile(duckdb_config.map(Box::as_ref))?)
+            }
+            AdapterType::Trino => {
+                let trino_config = match existing_config {
+                    Some(DbConfig::Trino(config)) => Some(config),
+                    _ => None,
+                };
+                DbConfig::Trino(setup_trino_profile(trino_config.map(Box::as_ref))?)
+            }
+            AdapterType::Generic => {
+                return err!(
+                    ErrorCode::NotSupported,
+                    "The generic adapter has no interactive setup, its profile has to be written by hand"
+                );
+            }
         };
 
         let mut outputs = HashMap::new();
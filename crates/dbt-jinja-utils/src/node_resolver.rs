@@ -53,6 +53,8 @@ pub struct NodeResolver {
     pub renaming: BTreeMap<String, (String, String, String)>,
     /// Whether this is a compile or test command
     pub compile_or_test: bool,
+    /// Identifier quote character declared by the profile, if any
+    pub quote_character: Option<char>,
 }
 
 impl NodeResolver {
@@ -60,6 +62,7 @@ impl NodeResolver {
     pub fn from_dbt_nodes(
         nodes: &Nodes,
         adapter_type: AdapterType,
+        quote_character: Option<char>,
         root_package_name: String,
         mantle_quoting: Option<DbtQuoting>,
         run_filter: RunFilter,
@@ -72,6 +75,7 @@ impl NodeResolver {
             run_filter,
             renaming,
             compile_or_test,
+            quote_character,
             ..Default::default()
         };
         for (_, node) in nodes.iter() {
@@ -223,7 +227,12 @@ impl NodeResolverTracker for NodeResolver {
         };
 
         let relation = RelationObject::new_with_filter(
-            create_relation_from_node(adapter_type, node, Some(self.run_filter.clone()))?,
+            create_relation_from_node(
+                adapter_type,
+                node,
+                Some(self.run_filter.clone()),
+                self.quote_character,
+            )?,
             self.run_filter.clone(),
             node.event_time(),
         )
@@ -310,7 +319,9 @@ impl NodeResolverTracker for NodeResolver {
         let unique_id = node.unique_id();
 
         // For functions, create a FunctionObject that renders function calls
-        let function_object = create_function_object_from_node(adapter_type, node)?.into_value();
+        let function_object =
+            create_function_object_from_node(adapter_type, node, self.quote_character)?
+                .into_value();
 
         // Lookup by function name
         let function_entry = self.functions.entry(function_name.clone()).or_default();
@@ -362,6 +373,7 @@ impl NodeResolverTracker for NodeResolver {
             Some(identifier),
             None,
             source.quoting(),
+            self.quote_character,
         )?;
         let relation = RelationObject::new_with_filter(
             base_rel,
@@ -661,7 +673,12 @@ impl NodeResolverTracker for NodeResolver {
         };
 
         let deferred_relation = RelationObject::new_with_filter(
-            create_relation_from_node(adapter_type, node, Some(self.run_filter.clone()))?,
+            create_relation_from_node(
+                adapter_type,
+                node,
+                Some(self.run_filter.clone()),
+                self.quote_character,
+            )?,
             self.run_filter.clone(),
             node.event_time(),
         )
@@ -986,6 +1003,7 @@ pub fn resolve_dependencies(
 pub fn create_function_object_from_node(
     adapter_type: AdapterType,
     node: &dyn InternalDbtNodeAttributes,
+    quote_character: Option<char>,
 ) -> FsResult<FunctionObject> {
     let relation = create_relation_internal(
         adapter_type,
@@ -994,6 +1012,7 @@ pub fn create_function_object_from_node(
         Some(node.base().alias.clone()),
         Some(RelationType::from(node.materialized())),
         node.quoting(),
+        quote_character,
     )?;
 
     // Create the qualified function name
@@ -49,6 +49,7 @@ where
     build_compile_node_context_inner(
         model,
         resolver_state.adapter_type,
+        resolver_state.dbt_profile.db_config.quote_character(),
         base_context,
         &resolver_state.root_project_name,
         resolver_state.node_resolver.clone(),
@@ -64,6 +65,7 @@ where
 pub fn build_compile_node_context_inner<T>(
     model: &T,
     adapter_type: AdapterType,
+    quote_character: Option<char>,
     base_context: &BTreeMap<String, MinijinjaValue>,
     root_project_name: &str,
     node_resolver: Arc<dyn NodeResolverTracker>,
@@ -119,6 +121,7 @@ where
                     Some(model.base().alias.clone()),
                     None,
                     model.base().quoting,
+                    quote_character,
                 )
                 .unwrap()
                 .as_value()
@@ -153,6 +156,7 @@ where
             Some(model.base().alias.clone()),
             None,
             model.base().quoting,
+            quote_character,
         )
         .unwrap()
         .as_value(),
@@ -70,6 +70,7 @@ impl Object for ParseExecute {
 pub fn build_resolve_model_context<T: DefaultTo<T> + 'static>(
     config: &T,
     adapter_type: AdapterType,
+    quote_character: Option<char>,
     database: &str,
     schema: &str,
     model_name: &str,
@@ -96,6 +97,7 @@ pub fn build_resolve_model_context<T: DefaultTo<T> + 'static>(
             package_quoting
                 .try_into()
                 .expect("Failed to convert quoting to resolved quoting"),
+            quote_character,
         )
         .unwrap()
         .as_value(),
@@ -114,6 +116,7 @@ pub fn build_resolve_model_context<T: DefaultTo<T> + 'static>(
         database: database.to_string(),
         schema: schema.to_string(),
         adapter_type,
+        quote_character,
         sql_resources: sql_resources_clone,
         runtime_config: runtime_config.clone(),
         package_quoting,
@@ -128,6 +131,7 @@ pub fn build_resolve_model_context<T: DefaultTo<T> + 'static>(
         schema: schema.to_string(),
         sql_resources: sql_resources.clone(),
         adapter_type,
+        quote_character,
         package_quoting,
     };
     let source_value = MinijinjaValue::from_object(source_function);
@@ -140,6 +144,7 @@ pub fn build_resolve_model_context<T: DefaultTo<T> + 'static>(
         schema: schema.to_string(),
         sql_resources: sql_resources.clone(),
         adapter_type,
+        quote_character,
         package_quoting,
     };
     let function_value = MinijinjaValue::from_object(function_function);
@@ -369,6 +374,7 @@ struct ResolveRefFunction<T: DefaultTo<T> + 'static> {
     database: String,
     schema: String,
     adapter_type: AdapterType,
+    quote_character: Option<char>,
     sql_resources: Arc<Mutex<Vec<SqlResource<T>>>>,
     runtime_config: Arc<DbtRuntimeConfig>,
     package_quoting: DbtQuoting,
@@ -442,6 +448,7 @@ impl<T: DefaultTo<T>> Object for ResolveRefFunction<T> {
             self.package_quoting
                 .try_into()
                 .expect("Failed to convert quoting to resolved quoting"),
+            self.quote_character,
         )
         .unwrap()
         .as_value();
@@ -455,6 +462,7 @@ struct ResolveSourceFunction<T: DefaultTo<T>> {
     database: String,
     schema: String,
     adapter_type: AdapterType,
+    quote_character: Option<char>,
     sql_resources: Arc<Mutex<Vec<SqlResource<T>>>>,
     package_quoting: DbtQuoting,
 }
@@ -506,6 +514,7 @@ impl<T: DefaultTo<T>> Object for ResolveSourceFunction<T> {
                 self.package_quoting
                     .try_into()
                     .expect("Failed to convert quoting to resolved quoting"),
+                self.quote_character,
             )
             .unwrap()
             .as_value())
@@ -523,6 +532,7 @@ struct ResolveFunctionFunction<T: DefaultTo<T>> {
     database: String,
     schema: String,
     adapter_type: AdapterType,
+    quote_character: Option<char>,
     sql_resources: Arc<Mutex<Vec<SqlResource<T>>>>,
     package_quoting: DbtQuoting,
 }
@@ -593,6 +603,7 @@ impl<T: DefaultTo<T>> Object for ResolveFunctionFunction<T> {
             self.package_quoting
                 .try_into()
                 .expect("Failed to convert quoting to resolved quoting"),
+            self.quote_character,
         )
         .unwrap();
 
@@ -866,6 +877,7 @@ mod test {
             schema: "test_schema".to_string(),
             sql_resources,
             adapter_type: AdapterType::Postgres,
+            quote_character: None,
             package_quoting: DEFAULT_DBT_QUOTING,
         };
         let source_value = MinijinjaValue::from_object(source_function);
@@ -46,6 +46,7 @@ async fn extend_with_model_context<S: Serialize>(
     base_attr: &NodeBaseAttributes,
     deprecated_config: &S,
     adapter_type: AdapterType,
+    quote_character: Option<char>,
     io_args: &IoArgs,
     resource_type: NodeType,
     sql_header: Option<MinijinjaValue>,
@@ -58,6 +59,7 @@ async fn extend_with_model_context<S: Serialize>(
         Some(base_attr.alias.clone()),
         None,
         base_attr.quoting,
+        quote_character,
     )
     .unwrap()
     .as_value();
@@ -218,6 +220,7 @@ pub async fn build_run_node_context<S: Serialize>(
     base_attr: &NodeBaseAttributes,
     deprecated_config: &S,
     adapter_type: AdapterType,
+    quote_character: Option<char>,
     agate_table: Option<AgateTable>,
     base_context: &BTreeMap<String, MinijinjaValue>,
     io_args: &IoArgs,
@@ -236,6 +239,7 @@ pub async fn build_run_node_context<S: Serialize>(
         base_attr,
         deprecated_config,
         adapter_type,
+        quote_character,
         io_args,
         resource_type,
         sql_header,
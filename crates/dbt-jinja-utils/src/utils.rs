@@ -453,6 +453,7 @@ pub fn generate_relation_name(
         Some(identifier.to_owned()),
         None, // relation_type
         quote_config,
+        parse_adapter.quote_character(),
     ) {
         Ok(relation) => Ok(relation.render_self_as_str()),
         Err(e) => Err(e),
@@ -304,7 +304,7 @@ pub fn get_adapter_prefixes(dialect: &str) -> Vec<String> {
 
     // Add parent adapters
     match dialect {
-        "redshift" => prefixes.push("postgres".to_string()),
+        "redshift" | "duckdb" => prefixes.push("postgres".to_string()),
         "databricks" => prefixes.push("spark".to_string()),
         // Add other adapter hierarchies as needed
         _ => {}
@@ -324,7 +324,7 @@ pub fn get_internal_packages(dialect: &str) -> Vec<String> {
 
     // Add parent packages
     match dialect {
-        "redshift" => internal_packages.push("dbt_postgres".to_string()),
+        "redshift" | "duckdb" => internal_packages.push("dbt_postgres".to_string()),
         "databricks" => internal_packages.push("dbt_spark".to_string()),
         // Add other adapter hierarchies as needed
         _ => {}
@@ -34,6 +34,10 @@ pub struct LoadArgs {
     pub upgrade: bool,
     /// generate lock file only
     pub lock: bool,
+    /// install packages from the package cache only
+    pub offline: bool,
+    /// directory to vendor locked packages into
+    pub vendor: Option<PathBuf>,
     // Whether to load only profiles
     pub debug_profile: bool,
     /// Whether to check package version requirements
@@ -62,6 +66,8 @@ impl LoadArgs {
             add_package: arg.add_package.clone(),
             upgrade: arg.upgrade,
             lock: arg.lock,
+            offline: arg.offline,
+            vendor: arg.vendor.clone(),
             vars: arg.vars.clone(),
             from_main: arg.from_main,
             threads: arg.num_threads,
@@ -0,0 +1,191 @@
+{% macro duckdb__create_table_as(temporary, relation, sql) -%}
+  {%- set sql_header = config.get('sql_header', none) -%}
+
+  {{ sql_header if sql_header is not none }}
+
+  create {% if temporary -%}
+    temporary
+  {%- endif %} table {{ relation.include(database=(not temporary), schema=(not temporary)) }}
+  {% set contract_config = config.get('contract') %}
+  {% if contract_config.enforced and (not temporary) %}
+    {{ get_assert_columns_equivalent(sql) }}
+    {{ get_table_columns_and_constraints() }} ;
+    insert into {{ relation }} (
+      {{ adapter.dispatch('get_column_names', 'dbt')() }}
+    )
+    {%- set sql = get_select_subquery(sql) %}
+  {% else %}
+    as
+  {% endif %}
+  (
+    {{ sql }}
+  );
+{%- endmacro %}
+
+{% macro duckdb__create_view_as(relation, sql) -%}
+  {%- set sql_header = config.get('sql_header', none) -%}
+
+  {{ sql_header if sql_header is not none }}
+  create view {{ relation }} as (
+    {{ sql }}
+  );
+{%- endmacro %}
+
+{% macro duckdb__create_schema(relation) -%}
+  {%- call statement('create_schema') -%}
+    create schema if not exists {{ relation.without_identifier() }}
+  {%- endcall -%}
+{% endmacro %}
+
+{% macro duckdb__drop_schema(relation) -%}
+  {%- call statement('drop_schema') -%}
+    drop schema if exists {{ relation.without_identifier() }} cascade
+  {%- endcall -%}
+{% endmacro %}
+
+{% macro duckdb__get_columns_in_relation(relation) -%}
+  {% call statement('get_columns_in_relation', fetch_result=True) %}
+      select
+          column_name,
+          data_type,
+          character_maximum_length,
+          numeric_precision,
+          numeric_scale
+
+      from system.information_schema.columns
+      where table_name = '{{ relation.identifier }}'
+      {% if relation.schema %}
+      and lower(table_schema) = lower('{{ relation.schema }}')
+      {% endif %}
+      {% if relation.database %}
+      and lower(table_catalog) = lower('{{ relation.database }}')
+      {% endif %}
+      order by ordinal_position
+  {% endcall %}
+  {% set table = load_result('get_columns_in_relation').table %}
+  {{ return(sql_convert_columns_in_relation(table)) }}
+{% endmacro %}
+
+{% macro duckdb__list_relations_without_caching(schema_relation) %}
+  {% call statement('list_relations_without_caching', fetch_result=True) -%}
+    select
+      table_catalog as database,
+      table_name as name,
+      table_schema as schema,
+      case
+        when table_type = 'BASE TABLE' then 'table'
+        when table_type = 'VIEW' then 'view'
+        when table_type = 'LOCAL TEMPORARY' then 'table'
+      end as type
+    from system.information_schema.tables
+    where lower(table_schema) = lower('{{ schema_relation.schema }}')
+      and lower(table_catalog) = lower('{{ schema_relation.database }}')
+  {% endcall %}
+  {{ return(load_result('list_relations_without_caching').table) }}
+{% endmacro %}
+
+{% macro duckdb__information_schema_name(database) -%}
+  {%- if database -%}
+    system.information_schema
+  {%- else -%}
+    information_schema
+  {%- endif -%}
+{%- endmacro %}
+
+{% macro duckdb__list_schemas(database) -%}
+  {% call statement('list_schemas', fetch_result=True, auto_begin=False) %}
+    select schema_name
+    from system.information_schema.schemata
+    {% if database is not none %}
+    where lower(catalog_name) = lower('{{ database | replace('"', '') }}')
+    {% endif %}
+  {% endcall %}
+  {{ return(load_result('list_schemas').table) }}
+{% endmacro %}
+
+{% macro duckdb__check_schema_exists(information_schema, schema) -%}
+  {% call statement('check_schema_exists', fetch_result=True, auto_begin=False) %}
+    select count(*)
+    from system.information_schema.schemata
+    where lower(schema_name) = lower('{{ schema }}')
+    and lower(catalog_name) = lower('{{ information_schema.database }}')
+  {% endcall %}
+  {{ return(load_result('check_schema_exists').table) }}
+{% endmacro %}
+
+{#
+  DuckDB does not limit the length of identifiers, so unlike Postgres the
+  base relation name is never truncated.
+#}
+
+{% macro duckdb__make_temp_relation(base_relation, suffix) %}
+    {% set dt = modules.datetime.datetime.now() %}
+    {% set dtstring = dt.strftime("%H%M%S%f") %}
+    {% set tmp_identifier = base_relation.identifier ~ suffix ~ dtstring %}
+    {{ return(base_relation.incorporate(path={"identifier": tmp_identifier, "schema": none, "database": none})) }}
+{% endmacro %}
+
+{% macro duckdb__make_intermediate_relation(base_relation, suffix) %}
+    {{ return(base_relation.incorporate(path={"identifier": base_relation.identifier ~ suffix})) }}
+{% endmacro %}
+
+{% macro duckdb__make_backup_relation(base_relation, backup_relation_type, suffix) %}
+    {% set backup_relation = base_relation.incorporate(path={"identifier": base_relation.identifier ~ suffix}) %}
+    {{ return(backup_relation.incorporate(type=backup_relation_type)) }}
+{% endmacro %}
+
+{% macro duckdb__rename_relation(from_relation, to_relation) -%}
+  {% set target_name = adapter.quote_as_configured(to_relation.identifier, 'identifier') %}
+  {% call statement('rename_relation') -%}
+    alter {{ from_relation.type }} {{ from_relation }} rename to {{ target_name }}
+  {%- endcall %}
+{% endmacro %}
+
+{% macro duckdb__alter_relation_comment(relation, comment) %}
+  {% set escaped_comment = comment | replace("'", "''") %}
+  comment on {{ relation.type }} {{ relation }} is '{{ escaped_comment }}';
+{% endmacro %}
+
+{% macro duckdb__alter_column_comment(relation, column_dict) %}
+  {% set existing_columns = adapter.get_columns_in_relation(relation) | map(attribute="name") | list %}
+  {% for column_name in column_dict if (column_name in existing_columns) %}
+    {% set comment = column_dict[column_name]['description'] | replace("'", "''") %}
+    {% set column = adapter.quote(column_name) if column_dict[column_name]['quote'] else column_name %}
+    comment on column {{ relation }}.{{ column }} is '{{ comment }}';
+  {% endfor %}
+{% endmacro %}
+
+{%- macro duckdb__get_show_grant_sql(relation) -%}
+  {# DuckDB has no access control #}
+  select '' as grantee, '' as privilege_type where false
+{%- endmacro -%}
+
+{% macro duckdb__copy_grants() %}
+    {{ return(False) }}
+{% endmacro %}
+
+{% macro duckdb__get_create_index_sql(relation, index_dict) -%}
+  {%- set index_config = adapter.parse_index(index_dict) -%}
+  {%- set comma_separated_columns = ", ".join(index_config.columns) -%}
+  {%- set index_name = index_config.render(relation) -%}
+
+  create {% if index_config.unique -%}
+    unique
+  {%- endif %} index
+  "{{ index_name }}"
+  on {{ relation }}
+  ({{ comma_separated_columns }})
+{%- endmacro %}
+
+{% macro duckdb__current_timestamp() -%}
+  now()
+{%- endmacro %}
+
+{% macro duckdb__snapshot_string_as_time(timestamp) -%}
+    {%- set result = "'" ~ timestamp ~ "'::timestamp" -%}
+    {{ return(result) }}
+{%- endmacro %}
+
+{% macro duckdb__snapshot_get_time() -%}
+  {{ current_timestamp() }}::timestamp
+{%- endmacro %}
@@ -0,0 +1,66 @@
+
+{% macro duckdb__get_catalog_relations(dbschema, relations) -%}
+  {%- call statement('catalog', fetch_result=True) -%}
+    with relations as (
+      select
+        t.database_name,
+        t.schema_name,
+        t.table_name,
+        case when t.temporary then 'LOCAL TEMPORARY' else 'BASE TABLE' end as table_type,
+        t.comment
+      from duckdb_tables() t
+      union all
+      select
+        v.database_name,
+        v.schema_name,
+        v.view_name as table_name,
+        'VIEW' as table_type,
+        v.comment
+      from duckdb_views() v
+      where not v.internal
+    )
+    select
+        r.database_name as table_database,
+        r.schema_name as table_schema,
+        r.table_name,
+        r.table_type,
+        coalesce(r.comment, '') as table_comment,
+        c.column_name,
+        c.column_index::decimal(38, 0) as column_index,
+        c.data_type as column_type,
+        coalesce(c.comment, '') as column_comment,
+        '' as table_owner
+    from relations r
+    join duckdb_columns() c
+      on r.database_name = c.database_name
+      and r.schema_name = c.schema_name
+      and r.table_name = c.table_name
+    where lower(r.database_name) = lower('{{ dbschema.database }}')
+      and (
+      {%- for relation in relations -%}
+        {%- if relation.identifier -%}
+          (lower(r.schema_name) = lower('{{ relation.schema }}') and
+           lower(r.table_name) = lower('{{ relation.identifier }}'))
+        {%- else-%}
+          lower(r.schema_name) = lower('{{ relation.schema }}')
+        {%- endif -%}
+        {%- if not loop.last %} or {% endif -%}
+      {%- endfor -%}
+      )
+    order by
+        r.schema_name,
+        r.table_name,
+        c.column_index
+  {%- endcall -%}
+
+  {{ return(load_result('catalog').table) }}
+{%- endmacro %}
+
+
+{% macro duckdb__get_catalog(dbschema, schemas) -%}
+  {%- set relations = [] -%}
+  {%- for schema in schemas -%}
+    {%- set dummy = relations.append({'schema': schema}) -%}
+  {%- endfor -%}
+  {{ return(duckdb__get_catalog_relations(dbschema, relations)) }}
+{%- endmacro %}
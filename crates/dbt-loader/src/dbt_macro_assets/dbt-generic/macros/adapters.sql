@@ -0,0 +1,39 @@
+{#
+  The generic adapter knows nothing about the SQL dialect of its driver beyond
+  what the `dialect` section of the profile declares. `adapter.dialect_query`
+  returns a query declared there with its `{database}`, `{schema}` and
+  `{identifier}` placeholders replaced, single quotes doubled.
+#}
+
+{% macro generic__get_columns_in_relation(relation) -%}
+  {% set sql = adapter.dialect_query('list_columns_sql', {
+    'database': relation.database,
+    'schema': relation.schema,
+    'identifier': relation.identifier,
+  }) %}
+  {% call statement('get_columns_in_relation', fetch_result=True) %}
+    {{ sql }}
+  {% endcall %}
+  {% set table = load_result('get_columns_in_relation').table %}
+  {{ return(sql_convert_columns_in_relation(table)) }}
+{% endmacro %}
+
+{% macro generic__list_relations_without_caching(schema_relation) %}
+  {% set sql = adapter.dialect_query('list_relations_sql', {
+    'database': schema_relation.database,
+    'schema': schema_relation.schema,
+  }) %}
+  {% call statement('list_relations_without_caching', fetch_result=True) -%}
+    {{ sql }}
+  {% endcall %}
+  {{ return(load_result('list_relations_without_caching').table) }}
+{% endmacro %}
+
+{% macro generic__list_schemas(database) -%}
+  {% set sql = adapter.dialect_query('list_schemas_sql', {'database': database}) %}
+  {{ return(run_query(sql)) }}
+{% endmacro %}
+
+{% macro generic__current_timestamp() -%}
+  current_timestamp
+{%- endmacro %}
@@ -0,0 +1,178 @@
+{#
+  Trino has no temporary tables: temporary relations are regular tables in the
+  schema of the model, named after it, and dropped by the materializations.
+  Each catalog has its own information_schema, so metadata queries are always
+  qualified with the database of the relation.
+#}
+
+{% macro trino__create_table_as(temporary, relation, sql) -%}
+  {%- set sql_header = config.get('sql_header', none) -%}
+
+  {{ sql_header if sql_header is not none }}
+
+  {% set contract_config = config.get('contract') %}
+  {% if contract_config.enforced and (not temporary) %}
+    {{ get_assert_columns_equivalent(sql) }}
+    create table {{ relation }}
+    {{ get_table_columns_and_constraints() }};
+    insert into {{ relation }} (
+      {{ adapter.dispatch('get_column_names', 'dbt')() }}
+    )
+    {%- set sql = get_select_subquery(sql) %}
+    {{ sql }}
+  {% else %}
+    create table {{ relation }} as (
+      {{ sql }}
+    )
+  {% endif %}
+{%- endmacro %}
+
+{% macro trino__create_view_as(relation, sql) -%}
+  {%- set sql_header = config.get('sql_header', none) -%}
+
+  {{ sql_header if sql_header is not none }}
+  create or replace view {{ relation }} as
+    {{ sql }}
+{%- endmacro %}
+
+{% macro trino__create_schema(relation) -%}
+  {%- call statement('create_schema') -%}
+    create schema if not exists {{ relation.without_identifier() }}
+  {%- endcall -%}
+{% endmacro %}
+
+{% macro trino__drop_schema(relation) -%}
+  {%- call statement('drop_schema') -%}
+    drop schema if exists {{ relation.without_identifier() }} cascade
+  {%- endcall -%}
+{% endmacro %}
+
+{% macro trino__get_columns_in_relation(relation) -%}
+  {% call statement('get_columns_in_relation', fetch_result=True) %}
+      select
+          column_name,
+          data_type,
+          null as character_maximum_length,
+          null as numeric_precision,
+          null as numeric_scale
+
+      from {{ relation.information_schema('columns') }}
+      where table_name = '{{ relation.identifier | lower }}'
+      {% if relation.schema %}
+      and table_schema = '{{ relation.schema | lower }}'
+      {% endif %}
+      order by ordinal_position
+  {% endcall %}
+  {% set table = load_result('get_columns_in_relation').table %}
+  {{ return(sql_convert_columns_in_relation(table)) }}
+{% endmacro %}
+
+{% macro trino__list_relations_without_caching(schema_relation) %}
+  {% call statement('list_relations_without_caching', fetch_result=True) -%}
+    select
+      table_catalog as database,
+      table_name as name,
+      table_schema as schema,
+      case
+        when table_type = 'BASE TABLE' then 'table'
+        when table_type = 'VIEW' then 'view'
+        else table_type
+      end as type
+    from {{ schema_relation.information_schema('tables') }}
+    where table_schema = '{{ schema_relation.schema | lower }}'
+  {% endcall %}
+  {{ return(load_result('list_relations_without_caching').table) }}
+{% endmacro %}
+
+{% macro trino__list_schemas(database) -%}
+  {% call statement('list_schemas', fetch_result=True, auto_begin=False) %}
+    select distinct schema_name
+    from {{ adapter.quote(database) }}.information_schema.schemata
+  {% endcall %}
+  {{ return(load_result('list_schemas').table) }}
+{% endmacro %}
+
+{% macro trino__check_schema_exists(information_schema, schema) -%}
+  {% call statement('check_schema_exists', fetch_result=True, auto_begin=False) %}
+    select count(*)
+    from {{ information_schema }}.schemata
+    where schema_name = '{{ schema | lower }}'
+  {% endcall %}
+  {{ return(load_result('check_schema_exists').table) }}
+{% endmacro %}
+
+{#- Trino has no cascade for tables and views -#}
+{%- macro trino__get_drop_sql(relation) -%}
+  {%- if relation.is_view -%}
+    {{ drop_view(relation) }}
+  {%- else -%}
+    {{ drop_table(relation) }}
+  {%- endif -%}
+{%- endmacro -%}
+
+{% macro trino__drop_relation(relation) -%}
+  {% call statement('drop_relation', auto_begin=False) -%}
+    {{ get_drop_sql(relation) }}
+  {%- endcall %}
+{% endmacro %}
+
+{% macro trino__drop_table(relation) -%}
+  drop table if exists {{ relation.render() }}
+{%- endmacro %}
+
+{% macro trino__drop_view(relation) -%}
+  drop view if exists {{ relation.render() }}
+{%- endmacro %}
+
+{% macro trino__make_temp_relation(base_relation, suffix) %}
+    {% set tmp_identifier = base_relation.identifier ~ suffix %}
+    {{ return(base_relation.incorporate(path={"identifier": tmp_identifier})) }}
+{% endmacro %}
+
+{% macro trino__rename_relation(from_relation, to_relation) -%}
+  {% call statement('rename_relation') -%}
+    alter {{ from_relation.type }} {{ from_relation }} rename to {{ to_relation }}
+  {%- endcall %}
+{% endmacro %}
+
+{% macro trino__alter_relation_comment(relation, comment) %}
+  {% set escaped_comment = comment | replace("'", "''") %}
+  comment on {{ relation.type }} {{ relation }} is '{{ escaped_comment }}'
+{% endmacro %}
+
+{% macro trino__alter_column_comment(relation, column_dict) %}
+  {% set existing_columns = adapter.get_columns_in_relation(relation) | map(attribute="name") | list %}
+  {% for column_name in column_dict if (column_name in existing_columns) %}
+    {% set comment = column_dict[column_name]['description'] | replace("'", "''") %}
+    {% set column = adapter.quote(column_name) if column_dict[column_name]['quote'] else column_name %}
+    {% call statement('alter_column_comment') -%}
+      comment on column {{ relation }}.{{ column }} is '{{ comment }}'
+    {%- endcall %}
+  {% endfor %}
+{% endmacro %}
+
+{%- macro trino__get_show_grant_sql(relation) -%}
+  select
+    grantee,
+    lower(privilege_type) as privilege_type
+  from {{ relation.information_schema('table_privileges') }}
+  where table_schema = '{{ relation.schema | lower }}'
+    and table_name = '{{ relation.identifier | lower }}'
+{%- endmacro -%}
+
+{% macro trino__copy_grants() %}
+    {{ return(False) }}
+{% endmacro %}
+
+{% macro trino__current_timestamp() -%}
+  current_timestamp
+{%- endmacro %}
+
+{% macro trino__snapshot_string_as_time(timestamp) -%}
+    {%- set result = "timestamp '" ~ timestamp ~ "'" -%}
+    {{ return(result) }}
+{%- endmacro %}
+
+{% macro trino__snapshot_get_time() -%}
+  cast({{ current_timestamp() }} as timestamp(6) with time zone)
+{%- endmacro %}
@@ -0,0 +1,49 @@
+{% macro trino__get_catalog_relations(dbschema, relations) -%}
+  {%- call statement('catalog', fetch_result=True) -%}
+    select
+        t.table_catalog as table_database,
+        t.table_schema,
+        t.table_name,
+        t.table_type,
+        coalesce(tc.comment, '') as table_comment,
+        c.column_name,
+        cast(c.ordinal_position as decimal(38, 0)) as column_index,
+        c.data_type as column_type,
+        coalesce(c.comment, '') as column_comment,
+        '' as table_owner
+    from {{ adapter.quote(dbschema.database) }}.information_schema.tables t
+    join {{ adapter.quote(dbschema.database) }}.information_schema.columns c
+      on t.table_schema = c.table_schema
+      and t.table_name = c.table_name
+    left join system.metadata.table_comments tc
+      on tc.catalog_name = t.table_catalog
+      and tc.schema_name = t.table_schema
+      and tc.table_name = t.table_name
+    where (
+      {%- for relation in relations -%}
+        {%- if relation.identifier -%}
+          (t.table_schema = '{{ relation.schema | lower }}' and
+           t.table_name = '{{ relation.identifier | lower }}')
+        {%- else-%}
+          t.table_schema = '{{ relation.schema | lower }}'
+        {%- endif -%}
+        {%- if not loop.last %} or {% endif -%}
+      {%- endfor -%}
+      )
+    order by
+        t.table_schema,
+        t.table_name,
+        c.ordinal_position
+  {%- endcall -%}
+
+  {{ return(load_result('catalog').table) }}
+{%- endmacro %}
+
+
+{% macro trino__get_catalog(dbschema, schemas) -%}
+  {%- set relations = [] -%}
+  {%- for schema in schemas -%}
+    {%- set dummy = relations.append({'schema': schema}) -%}
+  {%- endfor -%}
+  {{ return(trino__get_catalog_relations(dbschema, relations)) }}
+{%- endmacro %}
@@ -0,0 +1,117 @@
+{#
+  Trino runs delete and merge through the connector of the catalog, so these
+  strategies need a connector that supports row-level changes (e.g. Iceberg or
+  Delta Lake). DELETE takes no alias in Trino, the target is referred to by
+  its name.
+#}
+
+{% macro trino__get_incremental_default_sql(arg_dict) %}
+  {% do return(get_incremental_append_sql(arg_dict)) %}
+{% endmacro %}
+
+{% macro trino__get_delete_insert_merge_sql(target, source, unique_key, dest_columns, incremental_predicates) -%}
+  {%- set dest_cols_csv = get_quoted_csv(dest_columns | map(attribute="name")) -%}
+
+  {% if unique_key %}
+    {% if unique_key is string %}
+      {% set unique_key = [unique_key] %}
+    {% endif %}
+
+    delete from {{ target }}
+    where exists (
+      select 1
+      from {{ source }}
+      where
+      {% for key in unique_key %}
+        {{ target }}.{{ key }} = {{ source }}.{{ key }}
+        {%- if not loop.last %} and {% endif %}
+      {% endfor %}
+    )
+    {%- if incremental_predicates %}
+      {% for predicate in incremental_predicates %}
+        and {{ predicate | replace("DBT_INTERNAL_DEST.", target ~ ".") }}
+      {% endfor %}
+    {%- endif -%};
+
+  {% endif %}
+
+  insert into {{ target }} ({{ dest_cols_csv }})
+  (
+    select {{ dest_cols_csv }}
+    from {{ source }}
+  )
+{%- endmacro %}
+
+{% macro trino__get_merge_sql(target, source, unique_key, dest_columns, incremental_predicates=none) -%}
+  {%- set predicates = [] if incremental_predicates is none else [] + incremental_predicates -%}
+  {%- set dest_cols_csv = get_quoted_csv(dest_columns | map(attribute="name")) -%}
+  {%- set merge_update_columns = config.get('merge_update_columns') -%}
+  {%- set merge_exclude_columns = config.get('merge_exclude_columns') -%}
+  {%- set update_columns = get_merge_update_columns(merge_update_columns, merge_exclude_columns, dest_columns) -%}
+  {%- set sql_header = config.get('sql_header', none) -%}
+
+  {% if unique_key %}
+    {% if unique_key is string %}
+      {% set unique_key = [unique_key] %}
+    {% endif %}
+    {% for key in unique_key %}
+      {% do predicates.append("DBT_INTERNAL_SOURCE." ~ key ~ " = DBT_INTERNAL_DEST." ~ key) %}
+    {% endfor %}
+  {% else %}
+    {% do predicates.append('false') %}
+  {% endif %}
+
+  {{ sql_header if sql_header is not none }}
+
+  merge into {{ target }} as DBT_INTERNAL_DEST
+    using {{ source }} as DBT_INTERNAL_SOURCE
+    on {{ "(" ~ predicates | join(") and (") ~ ")" }}
+
+  {% if unique_key %}
+  when matched then update set
+    {% for column_name in update_columns -%}
+      {{ column_name }} = DBT_INTERNAL_SOURCE.{{ column_name }}
+      {%- if not loop.last %}, {%- endif %}
+    {%- endfor %}
+  {% endif %}
+
+  when not matched then insert
+    ({{ dest_cols_csv }})
+  values
+    ({% for column_name in dest_columns | map(attribute="name") -%}
+      DBT_INTERNAL_SOURCE.{{ adapter.quote(column_name) }}
+      {%- if not loop.last %}, {% endif %}
+    {%- endfor %})
+{%- endmacro %}
+
+{% macro trino__get_incremental_microbatch_sql(arg_dict) %}
+  {%- set target = arg_dict["target_relation"] -%}
+  {%- set source = arg_dict["temp_relation"] -%}
+  {%- set dest_columns = arg_dict["dest_columns"] -%}
+  {%- set predicates = [] -%}
+  {%- for predicate in arg_dict.get('incremental_predicates') or [] -%}
+    {% do predicates.append(predicate | replace("DBT_INTERNAL_DEST.", target ~ ".")) %}
+  {%- endfor -%}
+
+  {% if not model.batch or (not model.batch.event_time_start or not model.batch.event_time_end) -%}
+    {% do exceptions.raise_compiler_error('dbt could not compute the start and end timestamps for the running batch') %}
+  {% endif %}
+
+  {#-- Replace the rows of the batch --#}
+  {% do predicates.append(model.config.event_time ~ " >= timestamp '" ~ model.batch.event_time_start ~ "'") %}
+  {% do predicates.append(model.config.event_time ~ " < timestamp '" ~ model.batch.event_time_end ~ "'") %}
+
+  delete from {{ target }}
+  where (
+  {% for predicate in predicates %}
+    {%- if not loop.first %}and {% endif -%} {{ predicate }}
+  {% endfor %}
+  );
+
+  {%- set dest_cols_csv = get_quoted_csv(dest_columns | map(attribute="name")) -%}
+  insert into {{ target }} ({{ dest_cols_csv }})
+  (
+    select {{ dest_cols_csv }}
+    from {{ source }}
+  )
+{% endmacro %}
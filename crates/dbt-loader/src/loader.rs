@@ -226,6 +226,8 @@ pub async fn load(
         arg.add_package.clone(),
         arg.upgrade,
         arg.lock,
+        arg.offline,
+        arg.vendor.as_deref(),
         arg.vars.clone(),
         arg.version_check,
         token,
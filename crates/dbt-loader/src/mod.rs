@@ -16,3 +16,4 @@ pub mod args;
 pub mod clean;
 pub mod dbt_project_yml_loader;
 pub mod utils;
+pub mod watch;
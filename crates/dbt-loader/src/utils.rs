@@ -115,13 +115,20 @@ pub fn get_db_config(
             target_name,
         )
     })?;
-    let db_config: DbConfig = dbt_serde_yaml::from_value(db_config_yml.clone()).map_err(|e| {
-        fs_err!(
-            ErrorCode::InvalidConfig,
-            "Failed to parse profiles.yml: {}",
-            e
-        )
-    })?;
+    let mut db_config: DbConfig =
+        dbt_serde_yaml::from_value(db_config_yml.clone()).map_err(|e| {
+            fs_err!(
+                ErrorCode::InvalidConfig,
+                "Failed to parse profiles.yml: {}",
+                e
+            )
+        })?;
+    // DuckDB names the catalog after the database file
+    if let DbConfig::DuckDB(duckdb_config) = &mut db_config
+        && duckdb_config.database.is_none()
+    {
+        duckdb_config.database = Some(duckdb_config.default_database());
+    }
 
     // if !db_config.ignored_properties().is_empty() {
     //     show_warning!(
@@ -0,0 +1,234 @@
+//! Polling file watcher behind `parse --watch`.
+//!
+//! The watcher keeps the modification times of all project, package and
+//! profile files, and classifies a set of changes by how much of the project
+//! has to be reloaded for them.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use dbt_common::constants::{
+    DBT_CONFIG_DIR, DBT_INTERNAL_PACKAGES_DIR_NAME, DBT_PACKAGES_LOCK_FILE, DBT_PACKAGES_YML,
+    DBT_PROFILES_YML, DBT_PROJECT_YML,
+};
+use dirs::home_dir;
+use walkdir::WalkDir;
+
+/// Files which change how the whole project (and its packages) is loaded
+const PROJECT_CONFIG_FILES: [&str; 4] = [
+    DBT_PROJECT_YML,
+    DBT_PACKAGES_YML,
+    "dependencies.yml",
+    DBT_PACKAGES_LOCK_FILE,
+];
+
+/// How much of the project needs to be reloaded after some files changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReloadScope {
+    /// Only files of the root project changed; the packages can be reused
+    RootProject,
+    /// The project configuration, a package or the profiles changed
+    Full,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FileChanges {
+    /// Added, modified and removed files
+    pub paths: BTreeSet<PathBuf>,
+    pub scope: Option<ReloadScope>,
+}
+
+impl FileChanges {
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+}
+
+pub struct ProjectWatcher {
+    project_dir: PathBuf,
+    /// Directories inside the project which are never watched (target, logs, ...)
+    ignored_dirs: Vec<PathBuf>,
+    /// Installed packages, a change in any of them requires a full reload
+    package_dirs: Vec<PathBuf>,
+    profile_paths: Vec<PathBuf>,
+    snapshot: BTreeMap<PathBuf, SystemTime>,
+}
+
+impl ProjectWatcher {
+    /// Watch the project in `project_dir`, its installed packages and the
+    /// profiles.yml files the project could be loaded with.
+    pub fn new(
+        project_dir: &Path,
+        out_dir: &Path,
+        package_roots: impl IntoIterator<Item = PathBuf>,
+        profiles_dir: Option<&Path>,
+    ) -> Self {
+        let package_dirs = package_roots
+            .into_iter()
+            .filter(|path| path != project_dir)
+            .collect::<Vec<_>>();
+        let internal_packages_dirs = package_dirs
+            .iter()
+            .filter_map(|path| path.parent())
+            .map(|path| path.with_file_name(DBT_INTERNAL_PACKAGES_DIR_NAME));
+        let ignored_dirs = [
+            out_dir.to_path_buf(),
+            project_dir.join("logs"),
+            project_dir.join(DBT_INTERNAL_PACKAGES_DIR_NAME),
+        ]
+        .into_iter()
+        .chain(internal_packages_dirs)
+        .collect();
+
+        let mut profile_paths = vec![project_dir.join(DBT_PROFILES_YML)];
+        if let Some(profiles_dir) = profiles_dir {
+            profile_paths.push(profiles_dir.join(DBT_PROFILES_YML));
+        }
+        if let Some(home_dir) = home_dir() {
+            profile_paths.push(home_dir.join(DBT_CONFIG_DIR).join(DBT_PROFILES_YML));
+        }
+
+        let mut watcher = Self {
+            project_dir: project_dir.to_path_buf(),
+            ignored_dirs,
+            package_dirs,
+            profile_paths,
+            snapshot: BTreeMap::new(),
+        };
+        watcher.snapshot = watcher.scan();
+        watcher
+    }
+
+    /// Rescan all watched files and return what changed since the last call
+    pub fn poll(&mut self) -> FileChanges {
+        let snapshot = self.scan();
+        let mut paths = BTreeSet::new();
+        for (path, modified) in &snapshot {
+            if self.snapshot.get(path) != Some(modified) {
+                paths.insert(path.clone());
+            }
+        }
+        for path in self.snapshot.keys() {
+            if !snapshot.contains_key(path) {
+                paths.insert(path.clone());
+            }
+        }
+        self.snapshot = snapshot;
+
+        let scope = if paths.is_empty() {
+            None
+        } else if paths.iter().any(|path| self.requires_full_reload(path)) {
+            Some(ReloadScope::Full)
+        } else {
+            Some(ReloadScope::RootProject)
+        };
+        FileChanges { paths, scope }
+    }
+
+    fn requires_full_reload(&self, path: &Path) -> bool {
+        self.profile_paths.iter().any(|profile| profile == path)
+            || self.package_dirs.iter().any(|dir| path.starts_with(dir))
+            || path.parent() == Some(self.project_dir.as_path())
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| PROJECT_CONFIG_FILES.contains(&name))
+    }
+
+    fn scan(&self) -> BTreeMap<PathBuf, SystemTime> {
+        let mut snapshot = BTreeMap::new();
+        let roots = std::iter::once(&self.project_dir).chain(
+            self.package_dirs
+                .iter()
+                .filter(|dir| !dir.starts_with(&self.project_dir)),
+        );
+        for root in roots {
+            let walker = WalkDir::new(root).into_iter().filter_entry(|entry| {
+                let path = entry.path();
+                let hidden =
+                    entry.depth() > 0 && entry.file_name().to_string_lossy().starts_with('.');
+                !hidden && !self.ignored_dirs.iter().any(|dir| path == dir)
+            });
+            for entry in walker.filter_map(Result::ok) {
+                if entry.file_type().is_file()
+                    && is_watched_file(entry.path())
+                    && let Ok(modified) = entry.metadata().and_then(|m| m.modified())
+                {
+                    snapshot.insert(entry.into_path(), modified);
+                }
+            }
+        }
+        for path in &self.profile_paths {
+            if let Ok(modified) = path.metadata().and_then(|m| m.modified()) {
+                snapshot.insert(path.clone(), modified);
+            }
+        }
+        snapshot
+    }
+}
+
+fn is_watched_file(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|ext| ext.to_str()),
+        Some("sql" | "yml" | "yaml" | "md" | "csv" | "py" | "jinja")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::time::Duration;
+
+    fn touch(path: &Path, contents: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+        // make sure the modification time moves on coarse grained file systems
+        let modified = path.metadata().unwrap().modified().unwrap() + Duration::from_secs(2);
+        fs::File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_poll_classifies_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let project_dir = dir.path().join("project");
+        let package_dir = project_dir.join("dbt_packages").join("utils");
+        touch(&project_dir.join(DBT_PROJECT_YML), "name: proj");
+        touch(&project_dir.join("models").join("a.sql"), "select 1");
+        touch(&project_dir.join("target").join("manifest.json"), "{}");
+        touch(&package_dir.join("macros").join("m.sql"), "");
+
+        let mut watcher = ProjectWatcher::new(
+            &project_dir,
+            &project_dir.join("target"),
+            [project_dir.clone(), package_dir.clone()],
+            None,
+        );
+        assert!(watcher.poll().is_empty());
+
+        touch(&project_dir.join("models").join("a.sql"), "select 2");
+        touch(
+            &project_dir.join("target").join("manifest.json"),
+            "{\"a\": 1}",
+        );
+        touch(&project_dir.join("models").join("notes.txt"), "");
+        let changes = watcher.poll();
+        assert_eq!(
+            changes.paths,
+            BTreeSet::from([project_dir.join("models").join("a.sql")])
+        );
+        assert_eq!(changes.scope, Some(ReloadScope::RootProject));
+
+        fs::remove_file(package_dir.join("macros").join("m.sql")).unwrap();
+        assert_eq!(watcher.poll().scope, Some(ReloadScope::Full));
+
+        touch(&project_dir.join(DBT_PROJECT_YML), "name: proj2");
+        assert_eq!(watcher.poll().scope, Some(ReloadScope::Full));
+    }
+}
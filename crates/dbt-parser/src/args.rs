@@ -1,5 +1,6 @@
 //! Module defines the input arguments required for resolution
 
+use crate::partial_parse::PartialParseCache;
 use dbt_common::FsResult;
 use dbt_common::io_args::IoArgs;
 use dbt_common::{
@@ -8,6 +9,7 @@ use dbt_common::{
 };
 use dbt_schemas::filter::RunFilter;
 use std::collections::BTreeMap;
+use std::sync::Arc;
 
 /// Args to be passed into the resolution phase
 #[derive(Clone, Default, Debug)]
@@ -36,6 +38,11 @@ pub struct ResolveArgs {
     pub sample_config: RunFilter,
     /// For remapping unique_is to (database, schema, table) when sampling is enabled
     pub sample_renaming: BTreeMap<String, (String, String, String)>,
+    /// Reuse (and persist) rendered sql files across invocations
+    pub partial_parse: bool,
+    /// The partial parsing cache; set up by the resolver when `partial_parse` is
+    /// enabled, or supplied by the caller to carry it across parses
+    pub partial_parse_cache: Option<Arc<PartialParseCache>>,
 }
 
 impl ResolveArgs {
@@ -54,6 +61,8 @@ impl ResolveArgs {
             replay: arg.replay.clone(),
             sample_config: RunFilter::try_from(arg.empty, arg.sample.clone())?,
             sample_renaming: arg.sample_renaming.clone(),
+            partial_parse: arg.partial_parse,
+            partial_parse_cache: None,
         })
     }
 }
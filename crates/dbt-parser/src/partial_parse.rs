@@ -0,0 +1,706 @@
+//! Persistent cache of rendered sql files, so that a project does not have to
+//! be rendered from scratch on every invocation.
+//!
+//! Every successfully rendered sql file is stored in the target directory,
+//! keyed by the file contents, the config the file was rendered with, and the
+//! macros, vars and env vars it can reach. Editing a macro or a var only
+//! invalidates the files which use it. Inputs which can affect the rendering
+//! of any file (the target, the packages and their dispatch and quoting
+//! configs) are combined into an environment hash: when it changes, the whole
+//! cache is discarded. The errors and warnings reported while rendering a file
+//! are stored with it, and reported again when the file is restored.
+//!
+//! Parsed properties (yml) files are cached too, by their contents. They keep
+//! the source spans of their values, which the on-disk cache cannot store, so
+//! they are only reused when the cache is carried over to the next parse of
+//! the same process, as `parse --watch` does.
+
+use crate::sql_file_info::SqlFileInfo;
+use dbt_common::adapter::AdapterType;
+use dbt_common::io_args::IoArgs;
+use dbt_common::io_utils::RecordingStatusReporter;
+use dbt_common::tracing::emit::{emit_error_log_message, emit_warn_log_message};
+use dbt_common::{ErrorCode, FsResult, stdfs};
+use dbt_schemas::schemas::project::DefaultTo;
+use dbt_schemas::schemas::properties::DbtPropertiesFileValues;
+use dbt_schemas::state::{DbtState, Macros, ModelStatus};
+use minijinja::MacroSpans;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, RwLock};
+
+/// Name of the partial parsing cache in the target directory
+pub const PARTIAL_PARSE_FILE_NAME: &str = "partial_parse.json";
+
+/// Bumped whenever the layout of the cache or of [SqlFileInfo] changes
+const PARTIAL_PARSE_VERSION: u32 = 3;
+
+static ENV_VAR_CALL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"env_var\(\s*['"]([^'"]+)['"]"#).unwrap());
+
+/// A `var(...)` call, with its first argument
+static VAR_CALL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"\bvar\(\s*(?:['"]([^'"]+)['"]|[^)\s])"#).unwrap());
+
+static IDENTIFIER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"[A-Za-z_][A-Za-z0-9_]*").unwrap());
+
+#[derive(Default, Serialize, Deserialize)]
+struct PartialParseFile {
+    version: u32,
+    environment_hash: String,
+    files: BTreeMap<String, CachedRender>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedRender {
+    render_key: String,
+    status: ModelStatus,
+    sql_file_info: serde_json::Value,
+    rendered_sql: String,
+    macro_spans: MacroSpans,
+    diagnostics: Vec<CachedDiagnostic>,
+}
+
+/// An error or warning reported while rendering a file
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedDiagnostic {
+    error: bool,
+    code: u16,
+    message: String,
+}
+
+#[derive(Clone)]
+struct CachedProperties {
+    contents_hash: String,
+    values: DbtPropertiesFileValues,
+}
+
+/// A rendered sql file restored from the cache
+pub struct CachedSqlFile<T: DefaultTo<T>> {
+    /// The file info collected while rendering the file
+    pub sql_file_info: SqlFileInfo<T>,
+    /// The rendered SQL
+    pub rendered_sql: String,
+    /// The macro spans for the rendered SQL
+    pub macro_spans: MacroSpans,
+    /// The status of the node
+    pub status: ModelStatus,
+    diagnostics: Vec<CachedDiagnostic>,
+}
+
+impl<T: DefaultTo<T>> CachedSqlFile<T> {
+    /// Report again the errors and warnings of the render this file was
+    /// restored from
+    pub fn replay_diagnostics(&self, io: &IoArgs) {
+        for diagnostic in &self.diagnostics {
+            let code = ErrorCode::try_from(diagnostic.code).unwrap_or_default();
+            if diagnostic.error {
+                emit_error_log_message(code, &diagnostic.message, io.status_reporter.as_ref());
+            } else {
+                emit_warn_log_message(code, &diagnostic.message, io.status_reporter.as_ref());
+            }
+        }
+    }
+}
+
+/// The [IoArgs] to render a file with, recording the errors and warnings it
+/// reports so that they can be stored with the render
+pub fn recording_io_args(io: &IoArgs) -> (IoArgs, Arc<RecordingStatusReporter>) {
+    let recorder = Arc::new(RecordingStatusReporter::new(io.status_reporter.clone()));
+    let io = IoArgs {
+        status_reporter: Some(recorder.clone()),
+        ..io.clone()
+    };
+    (io, recorder)
+}
+
+/// What a macro can pull into the rendering of a file that calls it
+struct MacroInputs {
+    sql_hash: String,
+    /// Every identifier in the macro, i.e. the macros it can call
+    identifiers: BTreeSet<String>,
+    vars: VarRefs,
+    env_vars: BTreeSet<String>,
+}
+
+/// The vars read by a file or a macro
+#[derive(Default)]
+struct VarRefs {
+    names: BTreeSet<String>,
+    /// Whether a var is read by a name only known when rendering
+    dynamic: bool,
+}
+
+impl VarRefs {
+    fn from_source(source: &str) -> Self {
+        let mut refs = Self::default();
+        for captures in VAR_CALL.captures_iter(source) {
+            match captures.get(1) {
+                Some(name) => {
+                    refs.names.insert(name.as_str().to_string());
+                }
+                None => refs.dynamic = true,
+            }
+        }
+        refs
+    }
+
+    fn extend(&mut self, other: &VarRefs) {
+        self.names.extend(other.names.iter().cloned());
+        self.dynamic |= other.dynamic;
+    }
+}
+
+/// The macros and vars of a project, to find the ones a sql file depends on
+#[derive(Default)]
+pub struct RenderInputs {
+    /// Macros by name; packages can define macros with the same name
+    macros: BTreeMap<String, Vec<MacroInputs>>,
+    /// Names of the dispatched implementations (`<adapter>__<name>`) of a macro name
+    dispatched: BTreeMap<String, BTreeSet<String>>,
+    /// The values of every var, by name, in all packages and on the command line
+    vars: BTreeMap<String, String>,
+}
+
+impl RenderInputs {
+    /// Collect the macros of every package and the vars of `dbt_state`
+    pub fn new(dbt_state: &DbtState, macros: &Macros) -> Self {
+        let mut vars = BTreeMap::<String, Vec<String>>::new();
+        for (package_name, package_vars) in &dbt_state.vars {
+            for (name, value) in package_vars {
+                let value = serde_json::to_string(value).unwrap_or_default();
+                vars.entry(name.clone())
+                    .or_default()
+                    .push(format!("{package_name}:{value}"));
+            }
+        }
+        for (name, value) in &dbt_state.cli_vars {
+            let value = serde_json::to_string(value).unwrap_or_default();
+            vars.entry(name.clone())
+                .or_default()
+                .push(format!(":{value}"));
+        }
+        let vars = vars
+            .into_iter()
+            .map(|(name, values)| (name, sha256(&values.join("\n"))))
+            .collect();
+        Self::with_vars(macros, vars)
+    }
+
+    /// `vars` maps the name of every var to a hash of its values
+    fn with_vars(macros: &Macros, vars: BTreeMap<String, String>) -> Self {
+        let mut inputs = BTreeMap::<String, Vec<MacroInputs>>::new();
+        let mut dispatched = BTreeMap::<String, BTreeSet<String>>::new();
+        for dbt_macro in macros.macros.values() {
+            if let Some((_, name)) = dbt_macro.name.split_once("__") {
+                dispatched
+                    .entry(name.to_string())
+                    .or_default()
+                    .insert(dbt_macro.name.clone());
+            }
+            inputs
+                .entry(dbt_macro.name.clone())
+                .or_default()
+                .push(MacroInputs {
+                    sql_hash: sha256(&dbt_macro.macro_sql),
+                    identifiers: identifiers(&dbt_macro.macro_sql),
+                    vars: VarRefs::from_source(&dbt_macro.macro_sql),
+                    env_vars: referenced_env_vars(&dbt_macro.macro_sql),
+                });
+        }
+        Self {
+            macros: inputs,
+            dispatched,
+            vars,
+        }
+    }
+
+    /// Hash the macros `sql` can call, directly or through other macros, and
+    /// the vars and env vars it and these macros read
+    fn dependencies_hash(&self, sql: &str) -> String {
+        let mut used_macros = BTreeSet::new();
+        let mut vars = VarRefs::from_source(sql);
+        let mut env_vars = referenced_env_vars(sql);
+        let mut pending = identifiers(sql).into_iter().collect::<Vec<_>>();
+        while let Some(identifier) = pending.pop() {
+            let dispatched = self.dispatched.get(&identifier).into_iter().flatten();
+            for name in std::iter::once(&identifier).chain(dispatched) {
+                let Some(macro_inputs) = self.macros.get(name) else {
+                    continue;
+                };
+                if !used_macros.insert(name.clone()) {
+                    continue;
+                }
+                for inputs in macro_inputs {
+                    pending.extend(inputs.identifiers.iter().cloned());
+                    vars.extend(&inputs.vars);
+                    env_vars.extend(inputs.env_vars.iter().cloned());
+                }
+            }
+        }
+
+        let mut hasher = Sha256::new();
+        for name in &used_macros {
+            hash_part(&mut hasher, name);
+            for inputs in &self.macros[name] {
+                hash_part(&mut hasher, &inputs.sql_hash);
+            }
+        }
+        // A var read by a computed name could be any of them
+        let var_names: Box<dyn Iterator<Item = &String>> = if vars.dynamic {
+            Box::new(self.vars.keys())
+        } else {
+            Box::new(vars.names.iter())
+        };
+        for name in var_names {
+            hash_part(&mut hasher, name);
+            hash_part(
+                &mut hasher,
+                self.vars.get(name).map(String::as_str).unwrap_or_default(),
+            );
+        }
+        hash_env_vars(&mut hasher, env_vars);
+        hex::encode(hasher.finalize())
+    }
+}
+
+/// What a parse can reuse from the previous one
+#[derive(Default)]
+struct Generation {
+    environment_hash: String,
+    inputs: RenderInputs,
+    previous: BTreeMap<String, CachedRender>,
+    previous_properties: BTreeMap<PathBuf, CachedProperties>,
+}
+
+/// Rendered sql files and parsed properties files of the previous parse, and
+/// the ones of the current parse which will be reused by the next one.
+pub struct PartialParseCache {
+    path: PathBuf,
+    generation: RwLock<Generation>,
+    current: Mutex<BTreeMap<String, CachedRender>>,
+    current_properties: Mutex<BTreeMap<PathBuf, CachedProperties>>,
+    hits: AtomicUsize,
+}
+
+impl fmt::Debug for PartialParseCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let generation = self.generation.read().unwrap();
+        f.debug_struct("PartialParseCache")
+            .field("path", &self.path)
+            .field("environment_hash", &generation.environment_hash)
+            .field("previous", &generation.previous.len())
+            .field("previous_properties", &generation.previous_properties.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialParseCache {
+    /// An empty cache persisted in `out_dir`; call [Self::prepare] before
+    /// each parse
+    pub fn new(out_dir: &Path) -> Self {
+        Self {
+            path: out_dir.join(PARTIAL_PARSE_FILE_NAME),
+            generation: RwLock::new(Generation::default()),
+            current: Mutex::new(BTreeMap::new()),
+            current_properties: Mutex::new(BTreeMap::new()),
+            hits: AtomicUsize::new(0),
+        }
+    }
+
+    /// Start a new parse. The files rendered or reused by the previous parse
+    /// of this cache become reusable; for the first parse, or when the
+    /// `environment_hash` changed, they are loaded from the target directory
+    /// instead, discarding entries written for a different environment (or
+    /// by a different version).
+    pub fn prepare(&self, environment_hash: String, inputs: RenderInputs) {
+        let mut generation = self.generation.write().unwrap();
+        let mut current = self.current.lock().unwrap();
+        let mut current_properties = self.current_properties.lock().unwrap();
+        let (previous, previous_properties) = if generation.environment_hash == environment_hash {
+            (
+                std::mem::take(&mut *current),
+                std::mem::take(&mut *current_properties),
+            )
+        } else {
+            current.clear();
+            current_properties.clear();
+            (self.read(&environment_hash), BTreeMap::new())
+        };
+        *generation = Generation {
+            environment_hash,
+            inputs,
+            previous,
+            previous_properties,
+        };
+        self.hits.store(0, Ordering::Relaxed);
+    }
+
+    fn read(&self, environment_hash: &str) -> BTreeMap<String, CachedRender> {
+        stdfs::read_to_string(&self.path)
+            .ok()
+            .and_then(|contents| serde_json::from_str::<PartialParseFile>(&contents).ok())
+            .filter(|file| {
+                file.version == PARTIAL_PARSE_VERSION && file.environment_hash == environment_hash
+            })
+            .map(|file| file.files)
+            .unwrap_or_default()
+    }
+
+    /// Hash the inputs which can change the rendering of any sql file
+    pub fn environment_hash(dbt_state: &DbtState, adapter_type: AdapterType) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(PARTIAL_PARSE_VERSION.to_le_bytes());
+        hasher.update(env!("CARGO_PKG_VERSION"));
+        hash_part(&mut hasher, adapter_type.as_ref());
+        hash_part(&mut hasher, &dbt_state.dbt_profile.blake3_hash());
+        for package in &dbt_state.packages {
+            let dbt_project = &package.dbt_project;
+            hash_part(&mut hasher, &dbt_project.name);
+            hash_part(
+                &mut hasher,
+                &serde_json::to_string(&(
+                    &dbt_project.dispatch,
+                    &dbt_project.flags,
+                    &*dbt_project.quoting,
+                ))
+                .unwrap_or_default(),
+            );
+        }
+        hex::encode(hasher.finalize())
+    }
+
+    /// Key for a single file, from its contents, the config it is rendered
+    /// with, and the macros, vars and env vars it depends on
+    pub fn render_key<T: Serialize>(&self, sql: &str, config: &T) -> String {
+        let dependencies_hash = self
+            .generation
+            .read()
+            .unwrap()
+            .inputs
+            .dependencies_hash(sql);
+        let mut hasher = Sha256::new();
+        hash_part(&mut hasher, std::any::type_name::<T>());
+        hash_part(&mut hasher, sql);
+        hash_part(
+            &mut hasher,
+            &serde_json::to_string(config).unwrap_or_default(),
+        );
+        hash_part(&mut hasher, &dependencies_hash);
+        hex::encode(hasher.finalize())
+    }
+
+    /// Returns the cached render of `path` if it was rendered with the same
+    /// `render_key`, and keeps it for the next parse
+    pub fn get<T: DefaultTo<T>>(&self, path: &Path, render_key: &str) -> Option<CachedSqlFile<T>> {
+        let entry_key = entry_key::<T>(path);
+        let generation = self.generation.read().unwrap();
+        let cached = generation
+            .previous
+            .get(&entry_key)
+            .filter(|cached| cached.render_key == render_key)?;
+        let sql_file_info = serde_json::from_value(cached.sql_file_info.clone()).ok()?;
+        self.current
+            .lock()
+            .unwrap()
+            .insert(entry_key, cached.clone());
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        Some(CachedSqlFile {
+            sql_file_info,
+            rendered_sql: cached.rendered_sql.clone(),
+            macro_spans: cached.macro_spans.clone(),
+            status: cached.status,
+            diagnostics: cached.diagnostics.clone(),
+        })
+    }
+
+    /// Store a freshly rendered file, with the errors and warnings reported
+    /// to `recorder` while rendering it
+    #[allow(clippy::too_many_arguments)]
+    pub fn insert<T: DefaultTo<T>>(
+        &self,
+        path: &Path,
+        render_key: &str,
+        sql_file_info: &SqlFileInfo<T>,
+        rendered_sql: &str,
+        macro_spans: &MacroSpans,
+        status: ModelStatus,
+        recorder: &RecordingStatusReporter,
+    ) {
+        let Ok(sql_file_info) = serde_json::to_value(sql_file_info) else {
+            return;
+        };
+        self.current.lock().unwrap().insert(
+            entry_key::<T>(path),
+            CachedRender {
+                render_key: render_key.to_string(),
+                status,
+                sql_file_info,
+                rendered_sql: rendered_sql.to_string(),
+                macro_spans: macro_spans.clone(),
+                diagnostics: recorder
+                    .take_diagnostics()
+                    .into_iter()
+                    .map(|(error, code, message)| CachedDiagnostic {
+                        error,
+                        code: code as u16,
+                        message,
+                    })
+                    .collect(),
+            },
+        );
+    }
+
+    /// Returns the parsed properties file at `path` if it had the same
+    /// `contents`, and keeps it for the next parse
+    pub fn get_properties(&self, path: &Path, contents: &str) -> Option<DbtPropertiesFileValues> {
+        let contents_hash = sha256(contents);
+        let generation = self.generation.read().unwrap();
+        let cached = generation
+            .previous_properties
+            .get(path)
+            .filter(|cached| cached.contents_hash == contents_hash)?;
+        self.current_properties
+            .lock()
+            .unwrap()
+            .insert(path.to_path_buf(), cached.clone());
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        Some(cached.values.clone())
+    }
+
+    /// Store a freshly parsed properties file
+    pub fn insert_properties(&self, path: &Path, contents: &str, values: &DbtPropertiesFileValues) {
+        self.current_properties.lock().unwrap().insert(
+            path.to_path_buf(),
+            CachedProperties {
+                contents_hash: sha256(contents),
+                values: values.clone(),
+            },
+        );
+    }
+
+    /// Number of files restored from the cache by the current parse
+    pub fn hits(&self) -> usize {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Persist the sql files rendered or reused by the current parse. Files
+    /// which were deleted or failed to render are dropped from the cache.
+    pub fn write(&self) -> FsResult<()> {
+        let file = PartialParseFile {
+            version: PARTIAL_PARSE_VERSION,
+            environment_hash: self.generation.read().unwrap().environment_hash.clone(),
+            files: self.current.lock().unwrap().clone(),
+        };
+        let tmp_path = self.path.with_extension("json.tmp");
+        stdfs::create_dir_all(self.path.parent().unwrap())?;
+        stdfs::write(&tmp_path, serde_json::to_string(&file)?)?;
+        stdfs::rename(&tmp_path, &self.path)
+    }
+}
+
+fn entry_key<T>(path: &Path) -> String {
+    format!("{}:{}", std::any::type_name::<T>(), path.display())
+}
+
+fn sha256(source: &str) -> String {
+    hex::encode(Sha256::digest(source))
+}
+
+fn hash_part(hasher: &mut Sha256, part: &str) {
+    hasher.update((part.len() as u64).to_le_bytes());
+    hasher.update(part);
+}
+
+fn hash_env_vars(hasher: &mut Sha256, names: BTreeSet<String>) {
+    for name in names {
+        hash_part(hasher, &name);
+        hash_part(hasher, &std::env::var(&name).unwrap_or_default());
+    }
+}
+
+fn identifiers(source: &str) -> BTreeSet<String> {
+    IDENTIFIER
+        .find_iter(source)
+        .map(|m| m.as_str().to_string())
+        .collect()
+}
+
+fn referenced_env_vars(source: &str) -> BTreeSet<String> {
+    ENV_VAR_CALL
+        .captures_iter(source)
+        .map(|captures| captures[1].to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbt_schemas::schemas::macros::DbtMacro;
+    use dbt_schemas::schemas::project::ModelConfig;
+
+    fn render_inputs(macros: &[(&str, &str)], vars: &[(&str, &str)]) -> RenderInputs {
+        let macros = Macros {
+            macros: macros
+                .iter()
+                .map(|(name, sql)| {
+                    let unique_id = format!("macro.test.{name}");
+                    let dbt_macro = DbtMacro {
+                        name: name.to_string(),
+                        unique_id: unique_id.clone(),
+                        macro_sql: sql.to_string(),
+                        ..Default::default()
+                    };
+                    (unique_id, dbt_macro)
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let vars = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), sha256(value)))
+            .collect();
+        RenderInputs::with_vars(&macros, vars)
+    }
+
+    fn cache(dir: &Path, environment_hash: &str) -> PartialParseCache {
+        let cache = PartialParseCache::new(dir);
+        cache.prepare(environment_hash.to_string(), render_inputs(&[], &[]));
+        cache
+    }
+
+    #[test]
+    fn test_render_key_and_roundtrip() {
+        let config = ModelConfig {
+            enabled: Some(true),
+            ..Default::default()
+        };
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("models").join("a.sql");
+        let cache = cache(dir.path(), "environment");
+        let key = cache.render_key("select 1", &config);
+        assert_eq!(key, cache.render_key("select 1", &config));
+        assert_ne!(key, cache.render_key("select 2", &config));
+        assert_ne!(key, cache.render_key("select 1", &ModelConfig::default()));
+
+        let sql_file_info = SqlFileInfo::<ModelConfig> {
+            refs: vec![("b".to_string(), None, None, Default::default())],
+            config: Box::new(config),
+            ..Default::default()
+        };
+        let (io, recorder) = recording_io_args(&IoArgs::default());
+        emit_warn_log_message(
+            ErrorCode::Generic,
+            "deprecated",
+            io.status_reporter.as_ref(),
+        );
+        cache.insert(
+            &path,
+            &key,
+            &sql_file_info,
+            "select 1",
+            &MacroSpans::default(),
+            ModelStatus::Enabled,
+            &recorder,
+        );
+        cache.write().unwrap();
+
+        let cache = self::cache(dir.path(), "environment");
+        assert!(cache.get::<ModelConfig>(&path, "other").is_none());
+        let cached = cache.get::<ModelConfig>(&path, &key).unwrap();
+        assert_eq!(cached.sql_file_info.refs[0].0, "b");
+        assert_eq!(cached.sql_file_info.config.enabled, Some(true));
+        assert_eq!(cached.status, ModelStatus::Enabled);
+        assert_eq!(cache.hits(), 1);
+
+        // the warnings of the render are reported again
+        let (io, recorder) = recording_io_args(&IoArgs::default());
+        cached.replay_diagnostics(&io);
+        assert_eq!(
+            recorder.take_diagnostics(),
+            vec![(false, ErrorCode::Generic, "deprecated".to_string())]
+        );
+
+        // the entries reused by a parse are kept for the next parse
+        cache.prepare("environment".to_string(), render_inputs(&[], &[]));
+        assert_eq!(cache.hits(), 0);
+        assert!(cache.get::<ModelConfig>(&path, &key).is_some());
+
+        // a different environment hash discards all entries
+        let cache = self::cache(dir.path(), "changed");
+        assert!(cache.get::<ModelConfig>(&path, &key).is_none());
+    }
+
+    #[test]
+    fn test_render_key_only_depends_on_used_macros_and_vars() {
+        let sql = "select {{ cents_to_dollars('amount') }} from {{ var('orders') }}";
+        let config = ModelConfig::default();
+        let macros = [
+            (
+                "cents_to_dollars",
+                "{{ return(adapter.dispatch('cents_to_dollars')(column)) }}",
+            ),
+            (
+                "default__cents_to_dollars",
+                "{{ column }} / {{ divisor() }}",
+            ),
+            ("divisor", "100"),
+            ("unrelated", "{{ var('other') }}"),
+        ];
+        let vars = [("orders", "raw.orders"), ("other", "1")];
+        let dir = tempfile::tempdir().unwrap();
+        let cache = PartialParseCache::new(dir.path());
+        let key_with = |macros: &[(&str, &str)], vars: &[(&str, &str)]| {
+            cache.prepare("environment".to_string(), render_inputs(macros, vars));
+            cache.render_key(sql, &config)
+        };
+        let key = key_with(&macros, &vars);
+        let edited = |name: &str, sql: &'static str| {
+            macros
+                .iter()
+                .map(|(macro_name, macro_sql)| {
+                    (
+                        *macro_name,
+                        if *macro_name == name { sql } else { *macro_sql },
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // editing a macro or var the file does not use keeps the key
+        assert_eq!(key, key_with(&edited("unrelated", "{{ var('x') }}"), &vars));
+        assert_eq!(
+            key,
+            key_with(&macros, &[("orders", "raw.orders"), ("other", "2")])
+        );
+        // editing a called, dispatched or transitively called macro changes it
+        assert_ne!(key, key_with(&edited("cents_to_dollars", "1"), &vars));
+        assert_ne!(
+            key,
+            key_with(&edited("default__cents_to_dollars", "1"), &vars)
+        );
+        assert_ne!(key, key_with(&edited("divisor", "1000"), &vars));
+        // and so does a var the file reads
+        assert_ne!(
+            key,
+            key_with(&macros, &[("orders", "stg.orders"), ("other", "1")])
+        );
+    }
+
+    #[test]
+    fn test_var_refs() {
+        let refs = VarRefs::from_source("{{ var('a') }} {{ var(\"b\", 1) }}");
+        assert_eq!(
+            refs.names,
+            BTreeSet::from(["a".to_string(), "b".to_string()])
+        );
+        assert!(!refs.dynamic);
+        assert!(VarRefs::from_source("{{ var(name) }}").dynamic);
+    }
+}
@@ -2,6 +2,7 @@
 use crate::args::ResolveArgs;
 use crate::dbt_namespace::DbtNamespace;
 use crate::dbt_project_config::DbtProjectConfig;
+use crate::partial_parse::recording_io_args;
 use crate::resolve::resolve_properties::MinimalPropertiesEntry;
 use crate::sql_file_info::SqlFileInfo;
 use crate::utils::{get_node_fqn, register_duplicate_resource, trigger_duplicate_errors};
@@ -145,6 +146,7 @@ pub async fn render_unresolved_sql_files_sequentially<
         root_project_name,
         package_name,
         adapter_type,
+        quote_character,
         database,
         schema,
         local_project_config,
@@ -227,6 +229,30 @@ pub async fn render_unresolved_sql_files_sequentially<
         let absolute_path = dbt_asset.base_path.join(&dbt_asset.path);
         let sql = read_to_string(&absolute_path).await.map_err(|e| *e)?;
 
+        let partial_parse = args
+            .partial_parse_cache
+            .as_ref()
+            .map(|cache| (cache, cache.render_key(&sql, &properties_config)));
+        if let Some((cache, render_key)) = &partial_parse
+            && let Some(cached) = cache.get::<T>(&absolute_path, render_key)
+        {
+            cached.replay_diagnostics(&args.io);
+            model_sql_resources_map.push(SqlFileRenderResult {
+                asset: dbt_asset.clone(),
+                sql_file_info: cached.sql_file_info,
+                rendered_sql: cached.rendered_sql,
+                macro_spans: cached.macro_spans,
+                properties: maybe_model,
+                status: cached.status,
+                patch_path: node_properties
+                    .get(ref_name)
+                    .map(|mpe| mpe.relative_path.clone()),
+            });
+            continue;
+        }
+
+        // The errors and warnings of the render are stored with it, to be reported on cache hits
+        let (io, recorder) = recording_io_args(&args.io);
         let sql_resources = Arc::new(Mutex::new(Vec::new()));
         let execute_exists = Arc::new(AtomicBool::new(false));
 
@@ -239,6 +265,7 @@ pub async fn render_unresolved_sql_files_sequentially<
         resolve_model_context.extend(build_resolve_model_context(
             &properties_config,
             *adapter_type,
+            *quote_character,
             database,
             schema,
             &model_name,
@@ -250,7 +277,7 @@ pub async fn render_unresolved_sql_files_sequentially<
             sql_resources.clone(),
             execute_exists.clone(),
             &display_path,
-            &args.io,
+            &io,
         ));
         show_progress!(
             args.io,
@@ -261,7 +288,7 @@ pub async fn render_unresolved_sql_files_sequentially<
             && let Some(unique_id) = unique_id.as_str()
         {
             let _ = dbt_jinja_utils::typecheck::typecheck(
-                &args.io,
+                &io,
                 jinja_env.clone(),
                 &HashMap::new(),
                 jinja_type_checking_event_listener_factory.clone(),
@@ -309,7 +336,7 @@ pub async fn render_unresolved_sql_files_sequentially<
                     &*temp_sql_file_info.config,
                     jinja_env.clone(),
                     &display_path, // path to sql file, might not be path to hooks
-                    args.io.clone(),
+                    io.clone(),
                     &resolve_model_context,
                     jinja_type_checking_event_listener_factory.clone(),
                 )?;
@@ -338,6 +365,17 @@ pub async fn render_unresolved_sql_files_sequentially<
                 };
 
                 let macro_spans = listener_factory.drain_macro_spans(&display_path);
+                if let Some((cache, render_key)) = &partial_parse {
+                    cache.insert(
+                        &absolute_path,
+                        render_key,
+                        &sql_file_info,
+                        &rendered_sql_except_node_resolver,
+                        &macro_spans,
+                        status,
+                        &recorder,
+                    );
+                }
                 model_sql_resources_map.push(SqlFileRenderResult {
                     asset: dbt_asset.clone(),
                     sql_file_info,
@@ -423,6 +461,8 @@ pub struct RenderCtxInner<T: DefaultTo<T>> {
     pub package_name: String,
     /// The type of the adapter
     pub adapter_type: AdapterType,
+    /// The identifier quote character declared by the profile, if any
+    pub quote_character: Option<char>,
     /// The database name
     pub database: String,
     /// The schema name
@@ -534,6 +574,7 @@ pub async fn render_unresolved_sql_files<
                     root_project_name,
                     package_name,
                     adapter_type,
+                    quote_character,
                     database,
                     schema,
                     local_project_config,
@@ -615,6 +656,32 @@ pub async fn render_unresolved_sql_files<
 
                     let absolute_path = dbt_asset.base_path.join(&dbt_asset.path);
                     let sql = read_to_string(&absolute_path).await.map_err(|e| *e)?;
+
+                    let partial_parse = args
+                        .partial_parse_cache
+                        .as_ref()
+                        .map(|cache| (cache, cache.render_key(&sql, &properties_config)));
+                    if let Some((cache, render_key)) = &partial_parse
+                        && let Some(cached) = cache.get::<T>(&absolute_path, render_key)
+                    {
+                        cached.replay_diagnostics(&args.io);
+                        local_results.push(SqlFileRenderResult {
+                            asset: dbt_asset.clone(),
+                            sql_file_info: cached.sql_file_info,
+                            rendered_sql: cached.rendered_sql,
+                            macro_spans: cached.macro_spans,
+                            properties: maybe_model,
+                            status: cached.status,
+                            patch_path: chunk_node_properties
+                                .get(ref_name)
+                                .map(|mpe| mpe.relative_path.clone()),
+                        });
+                        continue;
+                    }
+
+                    // The errors and warnings of the render are stored with it, to be reported on
+                    // cache hits
+                    let (io, recorder) = recording_io_args(&args.io);
                     let sql_resources = Arc::new(Mutex::new(Vec::new()));
                     // when `load`, using `Ordering::Relax` is enough since no threads should be writing to it
                     // as long as the read is after `render_sql` is done within this scope
@@ -629,6 +696,7 @@ pub async fn render_unresolved_sql_files<
                     resolve_model_context.extend(build_resolve_model_context(
                         &properties_config,
                         *adapter_type,
+                        *quote_character,
                         database,
                         schema,
                         &model_name,
@@ -640,7 +708,7 @@ pub async fn render_unresolved_sql_files<
                         sql_resources.clone(),
                         execute_exists.clone(),
                         &display_path,
-                        &args.io,
+                        &io,
                     ));
                     show_progress!(
                         args.io,
@@ -651,7 +719,7 @@ pub async fn render_unresolved_sql_files<
                         && let Some(unique_id) = unique_id.as_str()
                     {
                         let _ = dbt_jinja_utils::typecheck::typecheck(
-                            &args.io,
+                            &io,
                             jinja_env.clone(),
                             &HashMap::new(),
                             jinja_type_checking_event_listener_factory.clone(),
@@ -703,7 +771,7 @@ pub async fn render_unresolved_sql_files<
                                 &*temp_sql_file_info.config,
                                 jinja_env.clone(),
                                 &display_path, // path to sql file, might not be path to hooks
-                                args.io.clone(),
+                                io.clone(),
                                 &resolve_model_context,
                                 jinja_type_checking_event_listener_factory.clone(),
                             )
@@ -729,6 +797,17 @@ pub async fn render_unresolved_sql_files<
                             };
 
                             let macro_spans = listener_factory.drain_macro_spans(&display_path);
+                            if let Some((cache, render_key)) = &partial_parse {
+                                cache.insert(
+                                    &absolute_path,
+                                    render_key,
+                                    &sql_file_info,
+                                    &rendered_sql_except_node_resolver,
+                                    &macro_spans,
+                                    status,
+                                    &recorder,
+                                );
+                            }
                             local_results.push(SqlFileRenderResult {
                                 asset: dbt_asset.clone(),
                                 sql_file_info,
@@ -957,6 +1036,7 @@ async fn process_model_chunk_for_unsafe_detection<T: InternalDbtNodeAttributes +
         let (mut render_resolved_context, _) = build_compile_node_context_inner(
             &model,
             adapter_type,
+            node_resolver.quote_character,
             &render_base_context,
             &root_project_name,
             Arc::new(node_resolver.clone()),
@@ -81,6 +81,8 @@ mod tests {
             replay: None,
             sample_config: RunFilter::default(),
             sample_renaming: BTreeMap::new(),
+            partial_parse: false,
+            partial_parse_cache: None,
         };
 
         // Create base context with minimal required values
@@ -98,6 +100,7 @@ mod tests {
                 root_project_name: "root_project".to_string(),
                 package_name: "test_package".to_string(), // Different from root - this triggers the override logic
                 adapter_type: AdapterType::Postgres,
+                quote_character: None,
                 database: "test_db".to_string(),
                 schema: "default_schema".to_string(),
                 local_project_config: package_config,
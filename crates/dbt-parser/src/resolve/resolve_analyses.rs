@@ -47,6 +47,7 @@ pub async fn resolve_analyses(
     database: &str,
     schema: &str,
     adapter_type: AdapterType,
+    quote_character: Option<char>,
     package_name: &str,
     env: Arc<JinjaEnv>,
     base_ctx: &BTreeMap<String, minijinja::Value>,
@@ -85,6 +86,7 @@ pub async fn resolve_analyses(
             base_ctx: base_ctx.clone(),
             package_name: package_name.to_string(),
             adapter_type,
+            quote_character,
             database: database.to_string(),
             schema: schema.to_string(),
             local_project_config,
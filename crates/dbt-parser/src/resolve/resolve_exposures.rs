@@ -41,6 +41,7 @@ pub async fn resolve_exposures(
     database: &str,
     schema: &str,
     adapter_type: AdapterType,
+    quote_character: Option<char>,
     package_name: &str,
     env: &JinjaEnv,
     base_ctx: &BTreeMap<String, MinijinjaValue>,
@@ -130,6 +131,7 @@ pub async fn resolve_exposures(
                     database,
                     schema,
                     adapter_type,
+                    quote_character,
                     package_name,
                     &root_project.name,
                     fqn.clone(),
@@ -218,6 +220,7 @@ pub fn resolve_yaml_depends_on(
     database: &str,
     schema: &str,
     adapter_type: AdapterType,
+    quote_character: Option<char>,
     package_name: &str,
     root_project_name: &str,
     fqn: Vec<String>,
@@ -237,6 +240,7 @@ pub fn resolve_yaml_depends_on(
         resolve_model_context.extend(build_resolve_model_context(
             exposure_config,
             adapter_type,
+            quote_character,
             database,
             schema,
             &fqn.join("."),
@@ -46,6 +46,7 @@ pub async fn resolve_functions(
     database: &str,
     schema: &str,
     adapter_type: AdapterType,
+    quote_character: Option<char>,
     package_name: &str,
     env: Arc<JinjaEnv>,
     base_ctx: &BTreeMap<String, minijinja::Value>,
@@ -83,6 +84,7 @@ pub async fn resolve_functions(
             base_ctx: base_ctx.clone(),
             package_name: package_name.to_string(),
             adapter_type,
+            quote_character,
             database: database.to_string(),
             schema: schema.to_string(),
             local_project_config: local_project_config.clone(),
@@ -44,6 +44,7 @@ pub fn resolve_operations(
     jinja_env: &Arc<JinjaEnv>,
     io: &IoArgs,
     adapter_type: AdapterType,
+    quote_character: Option<char>,
     database: &str,
     schema: &str,
     root_project_quoting: DbtQuoting,
@@ -63,6 +64,7 @@ pub fn resolve_operations(
             jinja_env,
             io,
             adapter_type,
+            quote_character,
             database,
             schema,
             &root_project_quoting,
@@ -81,6 +83,7 @@ pub fn resolve_operations(
             jinja_env,
             io,
             adapter_type,
+            quote_character,
             database,
             schema,
             &root_project_quoting,
@@ -101,6 +104,7 @@ fn new_operation(
     jinja_env: &Arc<JinjaEnv>,
     io: &IoArgs,
     adapter_type: AdapterType,
+    quote_character: Option<char>,
     database: &str,
     schema: &str,
     root_project_quoting: &DbtQuoting,
@@ -150,6 +154,7 @@ fn new_operation(
             operation_ctx.extend(build_resolve_model_context(
                 &OperationEmptyConfig,
                 adapter_type,
+                quote_character,
                 database,
                 schema,
                 &operation.__common_attr__.name,
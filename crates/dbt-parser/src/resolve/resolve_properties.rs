@@ -582,13 +582,26 @@ pub fn resolve_minimal_properties(
 
         let input = try_read_yml_to_str(&absolute_path)?;
 
-        match from_yaml_raw::<DbtPropertiesFileValues>(
-            &arg.io,
-            &input,
-            Some(&absolute_path),
-            true,
-            dependency_package_name,
-        ) {
+        let cached = arg
+            .partial_parse_cache
+            .as_ref()
+            .and_then(|cache| cache.get_properties(&absolute_path, &input));
+        let parsed = match cached {
+            Some(properties_file_values) => Ok(properties_file_values),
+            None => from_yaml_raw::<DbtPropertiesFileValues>(
+                &arg.io,
+                &input,
+                Some(&absolute_path),
+                true,
+                dependency_package_name,
+            )
+            .inspect(|properties_file_values| {
+                if let Some(cache) = &arg.partial_parse_cache {
+                    cache.insert_properties(&absolute_path, &input, properties_file_values);
+                }
+            }),
+        };
+        match parsed {
             Ok(properties_file_values) => {
                 let properties_path = &dbt_asset.path;
                 minimal_resolved_properties.extend_from_minimal_properties_file(
@@ -56,6 +56,7 @@ pub async fn resolve_snapshots(
     database: &str,
     schema: &str,
     adapter_type: AdapterType,
+    quote_character: Option<char>,
     jinja_env: Arc<JinjaEnv>,
     base_ctx: &BTreeMap<String, MinijinjaValue>,
     runtime_config: Arc<DbtRuntimeConfig>,
@@ -201,6 +202,7 @@ pub async fn resolve_snapshots(
             base_ctx: base_ctx.clone(),
             package_name: package_name.to_string(),
             adapter_type,
+            quote_character,
             database: database.to_string(),
             schema: schema.to_string(),
             local_project_config,
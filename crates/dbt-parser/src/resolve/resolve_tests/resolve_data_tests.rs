@@ -122,6 +122,7 @@ pub async fn resolve_data_tests(
     database: &str,
     schema: &str,
     adapter_type: AdapterType,
+    quote_character: Option<char>,
     env: Arc<JinjaEnv>,
     base_ctx: &BTreeMap<String, minijinja::Value>,
     runtime_config: Arc<DbtRuntimeConfig>,
@@ -182,6 +183,7 @@ pub async fn resolve_data_tests(
             base_ctx: base_ctx.clone(),
             package_name: package_name.to_string(),
             adapter_type,
+            quote_character,
             database: database.to_string(),
             schema: schema.to_string(),
             local_project_config,
@@ -30,6 +30,7 @@ use tracing::Instrument as _;
 
 use crate::args::ResolveArgs;
 use crate::dbt_project_config::{RootProjectConfigs, build_root_project_configs};
+use crate::partial_parse::{PartialParseCache, RenderInputs};
 use crate::resolve::resolve_groups::resolve_groups;
 use crate::resolve::resolve_operations::resolve_operations;
 use crate::resolve::resolve_query_comment::resolve_query_comment;
@@ -116,6 +117,22 @@ pub async fn resolve(
             )
         })?;
 
+    // Reuse the files rendered and parsed by the previous parse where possible
+    let partial_parse_cache = arg.partial_parse_cache.clone().or_else(|| {
+        arg.partial_parse
+            .then(|| Arc::new(PartialParseCache::new(&arg.io.out_dir)))
+    });
+    if let Some(cache) = &partial_parse_cache {
+        cache.prepare(
+            PartialParseCache::environment_hash(&dbt_state, adapter_type),
+            RenderInputs::new(&dbt_state, &macros),
+        );
+    }
+    let arg = &ResolveArgs {
+        partial_parse_cache,
+        ..arg.clone()
+    };
+
     // Build the root project config
     let root_project_quoting =
         resolve_package_quoting(*dbt_state.root_project().quoting, adapter_type);
@@ -165,6 +182,7 @@ pub async fn resolve(
     let mut node_resolver = NodeResolver::from_dbt_nodes(
         &nodes,
         adapter_type,
+        dbt_state.dbt_profile.db_config.quote_character(),
         root_project_name.to_string(),
         None,
         arg.sample_config.clone(),
@@ -271,6 +289,7 @@ pub async fn resolve(
             &jinja_env,
             &arg.io,
             adapter_type,
+            dbt_state.dbt_profile.db_config.quote_character(),
             &dbt_state.dbt_profile.database,
             &dbt_state.dbt_profile.schema,
             DbtQuoting {
@@ -298,6 +317,18 @@ pub async fn resolve(
     // Check access
     check_access(arg, &nodes, &all_runtime_configs);
 
+    if let Some(cache) = &arg.partial_parse_cache {
+        tracing::debug!(
+            "Reused {} rendered sql and properties files from partial parsing",
+            cache.hits()
+        );
+        if arg.partial_parse
+            && let Err(err) = cache.write()
+        {
+            emit_warn_log_from_fs_error(&err, arg.io.status_reporter.as_ref());
+        }
+    }
+
     Ok((
         ResolverState {
             root_project_name: root_project_name.to_string(),
@@ -437,6 +468,7 @@ pub async fn resolve_inner(
     let schema = &dbt_state.dbt_profile.schema;
 
     let package_quoting = resolve_package_quoting(*package.dbt_project.quoting, adapter_type);
+    let quote_character = dbt_state.dbt_profile.db_config.quote_character();
 
     let base_ctx = build_resolve_context(
         root_package_name,
@@ -554,6 +586,7 @@ pub async fn resolve_inner(
         database,
         schema,
         adapter_type,
+        quote_character,
         jinja_env.clone(),
         &base_ctx,
         runtime_config.clone(),
@@ -576,6 +609,7 @@ pub async fn resolve_inner(
         database,
         schema,
         adapter_type,
+        quote_character,
         package_name,
         jinja_env.clone(),
         &base_ctx,
@@ -599,6 +633,7 @@ pub async fn resolve_inner(
         database,
         schema,
         adapter_type,
+        quote_character,
         package_name,
         jinja_env.clone(),
         &base_ctx,
@@ -619,6 +654,7 @@ pub async fn resolve_inner(
         database,
         schema,
         adapter_type,
+        quote_character,
         package_name,
         jinja_env.clone(),
         &base_ctx,
@@ -638,6 +674,7 @@ pub async fn resolve_inner(
         database,
         schema,
         adapter_type,
+        quote_character,
         package_name,
         &jinja_env,
         &base_ctx,
@@ -706,6 +743,7 @@ pub async fn resolve_inner(
         database,
         schema,
         adapter_type,
+        quote_character,
         jinja_env.clone(),
         &base_ctx,
         runtime_config.clone(),
@@ -4,9 +4,10 @@ use dbt_frontend_common::error::CodeLocation;
 use dbt_jinja_utils::phases::parse::sql_resource::SqlResource;
 use dbt_schemas::schemas::{common::DbtChecksum, project::DefaultTo};
 use minijinja::{ArgSpec, machinery::Span};
+use serde::{Deserialize, Serialize};
 
 /// Collected details about processed sql files
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SqlFileInfo<T: DefaultTo<T>> {
     /// e.g. source('a', 'b')
     pub sources: Vec<(String, String, CodeLocation)>,
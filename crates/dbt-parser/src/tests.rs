@@ -45,6 +45,7 @@ mod tests {
         let mut context = build_resolve_model_context(
             init_config,
             AdapterType::Postgres,
+            None,
             "db",
             "schema",
             "my_model",
@@ -8,15 +8,17 @@ use dbt_serde_yaml::Value;
 use log::LevelFilter;
 use serde::{Deserialize, Serialize};
 use std::sync::LazyLock;
+use std::time::Duration;
 use std::{
     collections::{BTreeMap, HashSet},
     path::{Path, PathBuf},
 };
 use strum::{Display, IntoEnumIterator};
 
+use dbt_common::adapter::AdapterType;
 use dbt_common::io_args::{
-    ClapResourceType, DisplayFormat, EvalArgs, FsCommand, IoArgs, JsonSchemaTypes, Phases,
-    ShowOptions, SystemArgs, check_selector, check_var,
+    ClapResourceType, DisplayFormat, EvalArgs, FsCommand, GraphFormat, GraphLayer, IoArgs,
+    JsonSchemaTypes, Phases, ReplayMode, ShowOptions, SystemArgs, check_selector, check_var,
 };
 use dbt_common::row_limit::RowLimit;
 
@@ -82,9 +84,33 @@ pub enum Commands {
     /// List selected nodes (alias for list)
     Ls(ListArgs),
 
+    /// Export the dependency graph of the selected nodes
+    Graph(GraphArgs),
+
+    /// Compile selected models, running their introspective queries against the warehouse or a recording
+    Compile(CompileArgs),
+
+    /// Compare the project against a previous state or git revision
+    Diff(DiffArgs),
+
+    /// Generate a static HTML documentation site for the project
+    Docs(DocsArgs),
+
     /// Remove target directories
     Clean(CleanArgs),
 
+    /// Inspect or prune the cache of warehouse query results
+    Cache(CacheArgs),
+
+    /// Bundle ADBC drivers for air-gapped machines, or install them from a bundle or mirror
+    Drivers(DriversArgs),
+
+    /// Compare or redact recordings written by `compile --record`
+    Recording(RecordingArgs),
+
+    /// Run SQL interactively against the target of the profile
+    Repl(ReplArgs),
+
     /// Create reference documentation (json schema for artifacts)
     Man(ManArgs),
 }
@@ -99,6 +125,30 @@ pub enum ProjectTemplate {
     MomsFlowerShop,
 }
 
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, Default, Display, ValueEnum, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum DepsFormat {
+    /// Human readable table or tree
+    #[default]
+    Table,
+    /// JSON, for scripts
+    Json,
+}
+
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, Default, Display, ValueEnum, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffFormat {
+    /// Human readable summary
+    #[default]
+    Text,
+    /// The full diff as JSON
+    Json,
+}
+
 // ----------------------------------------------------------------------------------------------
 // Command Args
 #[derive(Parser, Debug, Default, Clone, Serialize, Deserialize)]
@@ -128,14 +178,48 @@ pub struct DepsArgs {
     pub upgrade: bool,
     #[arg(long)]
     pub lock: bool,
+    /// Install packages from the package cache only, without network access
+    #[arg(long)]
+    pub offline: bool,
+    /// Also copy all locked packages into this directory, e.g. to commit them
+    #[arg(long)]
+    pub vendor: Option<PathBuf>,
+
+    /// Inspect the locked packages instead of installing them
+    #[command(subcommand)]
+    pub command: Option<DepsCommand>,
+
+    /// The format of `deps tree`, `deps why` and `deps outdated`
+    #[arg(global = true, long, default_value = "table")]
+    pub output: DepsFormat,
 
     // Flattened Common args
     #[clap(flatten)]
     pub common_args: CommonArgs,
 }
 
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize)]
+pub enum DepsCommand {
+    /// Print the dependency graph of the locked packages
+    Tree,
+    /// Show which packages of the project pull in a package, and through which others
+    Why(DepsWhyArgs),
+    /// List locked hub packages that have newer versions, or that are deprecated or moved
+    Outdated,
+}
+
+#[derive(Parser, Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DepsWhyArgs {
+    /// The name of the package
+    pub package: String,
+}
+
 #[derive(Parser, Debug, Default, Clone, Serialize, Deserialize)]
 pub struct ParseArgs {
+    /// Keep running and reparse the project whenever one of its files changes
+    #[arg(long)]
+    pub watch: bool,
+
     // Flattened Common args
     #[clap(flatten)]
     pub common_args: CommonArgs,
@@ -168,6 +252,141 @@ pub struct ListArgs {
     pub exclude_resource_type: Option<ClapResourceType>,
 }
 
+#[derive(Parser, Debug, Default, Clone, Serialize, Deserialize)]
+pub struct GraphArgs {
+    // Flattened Common args
+    #[clap(flatten)]
+    pub common_args: CommonArgs,
+
+    /// The format of the exported graph
+    #[arg(long, default_value = "dot")]
+    pub output: GraphFormat,
+
+    /// Write the graph to this file instead of stdout
+    #[arg(long)]
+    pub output_path: Option<PathBuf>,
+
+    /// Space-separated optional layers to add to the graph (e.g. --layers exposures metrics)
+    #[arg(long, num_args(1..), value_delimiter = ' ')]
+    pub layers: Vec<GraphLayer>,
+
+    /// Select nodes of a specific type;
+    #[arg(long)]
+    pub resource_type: Option<ClapResourceType>,
+
+    /// Exclude nodes of a specific type;
+    #[arg(long)]
+    pub exclude_resource_type: Option<ClapResourceType>,
+}
+
+#[derive(Parser, Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CompileArgs {
+    // Flattened Common args
+    #[clap(flatten)]
+    pub common_args: CommonArgs,
+
+    /// Record every warehouse query and its result into this directory
+    #[arg(long, conflicts_with = "replay")]
+    pub record: Option<PathBuf>,
+
+    /// Answer warehouse queries from a directory written by --record instead of connecting
+    #[arg(long)]
+    pub replay: Option<PathBuf>,
+
+    /// Answer repeated warehouse queries from a local cache of earlier results
+    #[arg(long, conflicts_with = "replay")]
+    pub beta_use_query_cache: bool,
+
+    /// How long cached query results are served, e.g. 30m or 12h [default: 24h]
+    #[arg(long, requires = "beta_use_query_cache", value_parser = humantime::parse_duration)]
+    pub query_cache_ttl: Option<Duration>,
+
+    /// Bound on the total size of cached query results, e.g. 500M or 2G [default: 1G]
+    #[arg(long, requires = "beta_use_query_cache", value_parser = parse_size)]
+    pub query_cache_max_size: Option<u64>,
+}
+
+#[derive(Parser, Debug, Clone, Serialize, Deserialize)]
+pub struct CacheArgs {
+    #[command(subcommand)]
+    pub command: CacheCommand,
+
+    /// The query cache directory [default: <target-path>/query_cache]
+    #[arg(global = true, long)]
+    pub cache_dir: Option<PathBuf>,
+
+    // Flattened Common args
+    #[clap(flatten)]
+    pub common_args: CommonArgs,
+}
+
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize)]
+pub enum CacheCommand {
+    /// Remove expired results, the least recently used results beyond a size bound, and
+    /// files the cache no longer knows about
+    Prune(CachePruneArgs),
+    /// Show the number and size of the cached results and the hit rate
+    Stats,
+}
+
+#[derive(Parser, Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CachePruneArgs {
+    /// Remove results older than this, e.g. 30m or 12h
+    #[arg(long, value_parser = humantime::parse_duration)]
+    pub ttl: Option<Duration>,
+
+    /// Remove the least recently used results until the cache fits, e.g. 500M or 2G
+    #[arg(long, value_parser = parse_size)]
+    pub max_size: Option<u64>,
+}
+
+/// Parse a size in bytes with an optional K, M or G (binary) suffix
+fn parse_size(s: &str) -> Result<u64, String> {
+    let s = s.trim();
+    let (digits, shift) = match s.char_indices().last() {
+        Some((i, 'K' | 'k')) => (&s[..i], 10),
+        Some((i, 'M' | 'm')) => (&s[..i], 20),
+        Some((i, 'G' | 'g')) => (&s[..i], 30),
+        _ => (s, 0),
+    };
+    digits
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .and_then(|n| n.checked_mul(1 << shift))
+        .ok_or_else(|| format!("invalid size '{s}', expected e.g. 1048576, 500M or 2G"))
+}
+
+#[derive(Parser, Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DocsArgs {
+    // Flattened Common args
+    #[clap(flatten)]
+    pub common_args: CommonArgs,
+
+    /// The directory to write the site to [default: <target-path>/docs]
+    #[arg(long)]
+    pub output_path: Option<PathBuf>,
+
+    /// Path of a catalog.json to render column types and table stats from [default: <target-path>/catalog.json, if present]
+    #[arg(long)]
+    pub catalog_path: Option<PathBuf>,
+}
+
+#[derive(Parser, Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DiffArgs {
+    // Flattened Common args
+    #[clap(flatten)]
+    pub common_args: CommonArgs,
+
+    /// Compare against the project as of this git revision instead of the manifest in --state
+    #[arg(long)]
+    pub base_rev: Option<String>,
+
+    /// The format of the diff
+    #[arg(long, default_value = "text")]
+    pub output: DiffFormat,
+}
+
 #[derive(Parser, Debug, Default, Clone, Serialize, Deserialize)]
 pub struct CleanArgs {
     /// Clean the target directory specified by file or --target-path
@@ -179,6 +398,105 @@ pub struct CleanArgs {
     pub common_args: CommonArgs,
 }
 
+#[derive(Parser, Debug, Clone, Serialize, Deserialize)]
+pub struct DriversArgs {
+    #[command(subcommand)]
+    pub command: DriversCommand,
+
+    // Flattened Common args
+    #[clap(flatten)]
+    pub common_args: CommonArgs,
+}
+
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize)]
+pub enum DriversCommand {
+    /// Fetch drivers into a tarball that `drivers install` or DBT_ADBC_DRIVER_SOURCE can install from
+    Bundle(DriversBundleArgs),
+    /// Install the drivers for this machine, replacing the installed ones
+    Install(DriversInstallArgs),
+}
+
+#[derive(Parser, Debug, Clone, Serialize, Deserialize)]
+pub struct DriversBundleArgs {
+    /// The tarball to write
+    #[arg(long, short = 'o')]
+    pub output: PathBuf,
+
+    /// The adapters to bundle drivers for [default: all adapters with published drivers]
+    #[arg(long = "adapter", value_parser = parse_adapter_type)]
+    pub adapters: Vec<AdapterType>,
+
+    /// The operating systems to bundle drivers for: linux, macos or windows [default: this machine's]
+    #[arg(long)]
+    pub os: Vec<String>,
+
+    /// The architectures to bundle drivers for, e.g. x86_64 or aarch64 [default: this machine's]
+    #[arg(long)]
+    pub arch: Vec<String>,
+
+    /// Fetch the drivers from this mirror URL or directory instead of the dbt Labs CDN
+    #[arg(long, env = "DBT_ADBC_DRIVER_SOURCE")]
+    pub source: Option<String>,
+}
+
+#[derive(Parser, Debug, Clone, Serialize, Deserialize)]
+pub struct DriversInstallArgs {
+    /// A bundle (.tar), mirror URL or directory to install the drivers from
+    #[arg(long, env = "DBT_ADBC_DRIVER_SOURCE")]
+    pub source: String,
+
+    /// The adapters to install drivers for [default: all adapters with published drivers]
+    #[arg(long = "adapter", value_parser = parse_adapter_type)]
+    pub adapters: Vec<AdapterType>,
+}
+
+#[derive(Parser, Debug, Clone, Serialize, Deserialize)]
+pub struct RecordingArgs {
+    #[command(subcommand)]
+    pub command: RecordingCommand,
+
+    // Flattened Common args
+    #[clap(flatten)]
+    pub common_args: CommonArgs,
+}
+
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize)]
+pub enum RecordingCommand {
+    /// Show the statements added, removed or changed between two recordings; exits with 1 if any
+    Diff(RecordingDiffArgs),
+    /// Replace every recorded result value with synthetic data of the same type
+    Redact(RecordingRedactArgs),
+}
+
+#[derive(Parser, Debug, Clone, Serialize, Deserialize)]
+pub struct RecordingDiffArgs {
+    /// The recording to compare against
+    pub old: PathBuf,
+    /// The recording to compare
+    pub new: PathBuf,
+}
+
+#[derive(Parser, Debug, Clone, Serialize, Deserialize)]
+pub struct RecordingRedactArgs {
+    /// The recording to redact
+    pub path: PathBuf,
+
+    /// Write the redacted recording here instead of redacting in place
+    #[arg(long, short = 'o')]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Parser, Debug, Clone, Serialize, Deserialize)]
+pub struct ReplArgs {
+    // Flattened Common args
+    #[clap(flatten)]
+    pub common_args: CommonArgs,
+}
+
+fn parse_adapter_type(s: &str) -> Result<AdapterType, String> {
+    s.parse().map_err(|_| format!("unknown adapter '{s}'"))
+}
+
 #[derive(Parser, Debug, Default, Clone, Serialize, Deserialize)]
 pub struct ManArgs {
     // Flattened IO args
@@ -242,6 +560,10 @@ pub struct CommonArgs {
     #[arg(global = true, long, env = "DBT_INDIRECT_SELECTION")]
     pub indirect_selection: Option<IndirectSelection>,
 
+    /// Use the artifacts (manifest.json, run_results.json, sources.json) in this directory for state comparison
+    #[arg(global = true, long, env = "DBT_STATE")]
+    pub state: Option<PathBuf>,
+
     /// Suppress all non-error logging to stdout. Does not affect {{ print() }} macro calls.
     #[arg(global = true, long, env = "DBT_QUIET", short = 'q')]
     pub quiet: bool,
@@ -261,6 +583,12 @@ pub struct CommonArgs {
     #[arg(global = true,long,action = ArgAction::SetTrue,  default_value_t=false, value_parser = BoolishValueParser::new(),hide = true)]
     pub no_write_json: bool,
 
+    /// Reuse rendered sql files of the previous invocation stored in the target directory [env: DBT_PARTIAL_PARSE=]. Off by default.
+    #[arg(global = true, long, default_value_t = false, action = ArgAction::SetTrue, env = "DBT_PARTIAL_PARSE", value_parser = BoolishValueParser::new())]
+    pub partial_parse: bool,
+    #[arg(global = true, long, action = ArgAction::SetTrue, default_value_t = false, value_parser = BoolishValueParser::new(), hide = true)]
+    pub no_partial_parse: bool,
+
     /// Set 'log-path' for the current run, overriding 'DBT_LOG_PATH'.
     #[arg(global = true, long, env = "DBT_LOG_PATH")]
     pub log_path: Option<PathBuf>,
@@ -321,7 +649,10 @@ impl Cli {
         // Some commands operate without project context, while others must be run in a project directory.
         let (in_dir, out_dir) = {
             match &self.command {
-                Commands::Man(_) | Commands::Init(_) => {
+                Commands::Man(_)
+                | Commands::Init(_)
+                | Commands::Drivers(_)
+                | Commands::Recording(_) => {
                     // These commands do not require a project directory
                     (PathBuf::from("."), PathBuf::from("."))
                 }
@@ -363,7 +694,17 @@ impl Cli {
             Commands::List(args) => args.to_eval_args(system_arg, &in_dir, &out_dir),
             Commands::Parse(args) => args.to_eval_args(system_arg, &in_dir, &out_dir),
             Commands::Ls(args) => args.to_eval_args(system_arg, &in_dir, &out_dir),
+            Commands::Graph(args) => args.to_eval_args(system_arg, &in_dir, &out_dir),
+            Commands::Compile(args) => args.to_eval_args(system_arg, &in_dir, &out_dir)?,
+            Commands::Diff(args) => args.to_eval_args(system_arg, &in_dir, &out_dir),
+            Commands::Docs(args) => args.to_eval_args(system_arg, &in_dir, &out_dir),
             Commands::Clean(args) => args.to_eval_args(system_arg, &in_dir, &out_dir),
+            Commands::Cache(args) => args.common_args.to_eval_args(system_arg, &in_dir, &out_dir),
+            Commands::Drivers(args) => args.common_args.to_eval_args(system_arg, &in_dir, &out_dir),
+            Commands::Recording(args) => {
+                args.common_args.to_eval_args(system_arg, &in_dir, &out_dir)
+            }
+            Commands::Repl(args) => args.common_args.to_eval_args(system_arg, &in_dir, &out_dir),
             Commands::Man(args) => args.to_eval_args(system_arg, &in_dir, &out_dir),
         };
         arg.from_main = from_main;
@@ -377,8 +718,16 @@ impl Cli {
             Commands::Deps(args) => args.common_args.clone(),
             Commands::List(args) => args.common_args.clone(),
             Commands::Ls(args) => args.common_args.clone(),
+            Commands::Graph(args) => args.common_args.clone(),
+            Commands::Compile(args) => args.common_args.clone(),
+            Commands::Diff(args) => args.common_args.clone(),
+            Commands::Docs(args) => args.common_args.clone(),
             Commands::Parse(args) => args.common_args.clone(),
             Commands::Clean(args) => args.common_args.clone(),
+            Commands::Cache(args) => args.common_args.clone(),
+            Commands::Drivers(args) => args.common_args.clone(),
+            Commands::Recording(args) => args.common_args.clone(),
+            Commands::Repl(args) => args.common_args.clone(),
             Commands::Man(args) => args.common_args.clone(),
         }
     }
@@ -399,7 +748,15 @@ impl Cli {
             Commands::Parse(..) => FsCommand::Parse,
             Commands::List(..) => FsCommand::List,
             Commands::Ls(..) => FsCommand::List,
+            Commands::Graph(..) => FsCommand::Extension("graph"),
+            Commands::Compile(..) => FsCommand::Compile,
+            Commands::Diff(..) => FsCommand::Extension("diff"),
+            Commands::Docs(..) => FsCommand::Extension("docs"),
             Commands::Clean(..) => FsCommand::Clean,
+            Commands::Cache(..) => FsCommand::Extension("cache"),
+            Commands::Drivers(..) => FsCommand::Extension("drivers"),
+            Commands::Recording(..) => FsCommand::Extension("recording"),
+            Commands::Repl(..) => FsCommand::Extension("repl"),
             Commands::Man(..) => FsCommand::Man,
         }
     }
@@ -408,10 +765,18 @@ impl Cli {
 impl DepsArgs {
     pub fn to_eval_args(&self, arg: SystemArgs, in_dir: &Path, out_dir: &Path) -> EvalArgs {
         let mut eval_args = self.common_args.to_eval_args(arg, in_dir, out_dir);
-        eval_args.phase = Phases::Deps;
+        // Inspecting packages loads the project like `debug` does, without
+        // reinstalling them
+        eval_args.phase = if self.command.is_some() {
+            Phases::Debug
+        } else {
+            Phases::Deps
+        };
         eval_args.add_package = self.add_package.clone();
         eval_args.upgrade = self.upgrade;
         eval_args.lock = self.lock;
+        eval_args.offline = self.offline;
+        eval_args.vendor = self.vendor.clone();
         eval_args
     }
 }
@@ -448,6 +813,57 @@ impl ListArgs {
     }
 }
 
+impl GraphArgs {
+    pub fn to_eval_args(&self, arg: SystemArgs, in_dir: &Path, out_dir: &Path) -> EvalArgs {
+        let mut eval_args = self.common_args.to_eval_args(arg, in_dir, out_dir);
+        eval_args.phase = Phases::Lineage;
+        if let Some(resource_type) = self.resource_type {
+            eval_args.resource_types = vec![resource_type];
+        }
+        if let Some(exclude_resource_type) = self.exclude_resource_type {
+            eval_args.exclude_resource_types = vec![exclude_resource_type];
+        }
+        eval_args
+    }
+}
+
+impl CompileArgs {
+    pub fn to_eval_args(
+        &self,
+        arg: SystemArgs,
+        in_dir: &Path,
+        out_dir: &Path,
+    ) -> FsResult<EvalArgs> {
+        let mut eval_args = self.common_args.to_eval_args(arg, in_dir, out_dir);
+        eval_args.phase = Phases::Compile;
+        eval_args.io.beta_use_query_cache = self.beta_use_query_cache;
+        // recordings are looked up while the current directory may have changed,
+        // so relative paths are pinned to where the command was invoked
+        eval_args.replay = match (&self.record, &self.replay) {
+            (Some(record), _) => Some(ReplayMode::FsRecord(std::path::absolute(record)?)),
+            (None, Some(replay)) => Some(ReplayMode::FsReplay(std::path::absolute(replay)?)),
+            (None, None) => None,
+        };
+        Ok(eval_args)
+    }
+}
+
+impl DiffArgs {
+    pub fn to_eval_args(&self, arg: SystemArgs, in_dir: &Path, out_dir: &Path) -> EvalArgs {
+        let mut eval_args = self.common_args.to_eval_args(arg, in_dir, out_dir);
+        eval_args.phase = Phases::Parse;
+        eval_args
+    }
+}
+
+impl DocsArgs {
+    pub fn to_eval_args(&self, arg: SystemArgs, in_dir: &Path, out_dir: &Path) -> EvalArgs {
+        let mut eval_args = self.common_args.to_eval_args(arg, in_dir, out_dir);
+        eval_args.phase = Phases::Parse;
+        eval_args
+    }
+}
+
 impl ManArgs {
     pub fn to_eval_args(&self, arg: SystemArgs, in_dir: &Path, out_dir: &Path) -> EvalArgs {
         let eval_args = self.common_args.to_eval_args(arg, in_dir, out_dir);
@@ -604,6 +1020,7 @@ impl CommonArgs {
                 .map(|s| parse_model_specifiers(&s).unwrap()),
             indirect_selection: self.indirect_selection,
             selector: self.selector.clone(),
+            state: self.state.clone(),
             log_format_file: self.log_format_file,
             log_format: self.log_format,
             log_level_file: match (self.debug, self.log_level_file) {
@@ -624,6 +1041,7 @@ impl CommonArgs {
             } else {
                 self.write_json
             },
+            partial_parse: self.partial_parse && !self.no_partial_parse,
             target_path: self.target_path.clone(),
             send_anonymous_usage_stats: self.get_send_anonymous_usage_stats(),
             ..Default::default()
@@ -1,43 +1,103 @@
-use crate::dbt_sa_clap::{Cli, Commands, ProjectTemplate};
+use crate::dbt_sa_clap::{
+    CacheArgs, CacheCommand, Cli, Commands, CompileArgs, DepsArgs, DepsCommand, DepsFormat,
+    DiffArgs, DiffFormat, DocsArgs, DriversArgs, DriversCommand, GraphArgs, ProjectTemplate,
+    RecordingArgs, RecordingCommand,
+};
+use dbt_adapter::SqlEngine;
+use dbt_adapter::base_adapter::{AdapterFactory, backend_of};
+use dbt_adapter::bridge_adapter::release_tlocal_connection;
+use dbt_adapter::errors::adapter_error_to_adbc_error;
+use dbt_adapter::factory::DefaultAdapterFactory;
+use dbt_adapter::query_cache::{
+    DEFAULT_QUERY_CACHE_MAX_SIZE, DEFAULT_QUERY_CACHE_TTL, QUERY_CACHE_DIR_NAME, QueryCache,
+    QueryCacheConfig, QueryCacheImpl, QueryCacheIndex, QueryCacheMode,
+};
+use dbt_adapter::recording::{diff_recordings, redact_recording};
+use dbt_common::adapter::AdapterType;
 use dbt_common::cancellation::CancellationToken;
 use dbt_common::create_root_info_span;
 use dbt_common::io_utils::checkpoint_maybe_exit;
-use dbt_common::tracing::emit::{emit_error_log_from_fs_error, emit_info_log_message};
+use dbt_common::tokiofs::read_to_string;
+use dbt_common::tracing::emit::{
+    emit_error_log_from_fs_error, emit_info_event, emit_info_log_message, println,
+};
 use dbt_common::tracing::invocation::create_invocation_attributes;
 use dbt_common::tracing::metrics::get_exit_code_from_error_counter;
 use dbt_init::init;
 use dbt_jinja_utils::invocation_args::InvocationArgs;
-use dbt_jinja_utils::listener::DefaultJinjaTypeCheckEventListenerFactory;
+use dbt_jinja_utils::jinja_environment::JinjaEnv;
+use dbt_jinja_utils::listener::{
+    DefaultJinjaTypeCheckEventListenerFactory, DefaultRenderingEventListenerFactory,
+};
+use dbt_jinja_utils::phases::compile::build_compile_node_context_inner;
+use dbt_jinja_utils::phases::load::init::initialize_load_jinja_environment;
+use dbt_jinja_utils::phases::{
+    build_compile_and_run_base_context, configure_compile_and_run_jinja_environment,
+};
+use dbt_jinja_utils::utils::render_sql;
 use dbt_loader::clean::execute_clean_command;
+use dbt_schemas::dbt_utils::resolve_package_quoting;
 use dbt_schemas::man::execute_man_command;
 
-use dbt_common::io_args::{EvalArgs, EvalArgsBuilder};
+use dbt_common::io_args::{DisplayFormat, EvalArgs, EvalArgsBuilder, ListOutputFormat};
+use dbt_common::pretty_table::pretty_vec_table;
 use dbt_common::{
     ErrorCode, FsResult,
-    constants::{DBT_MANIFEST_JSON, INSTALLING, VALIDATING},
-    fs_err, fsinfo,
-    io_args::{Phases, SystemArgs},
+    constants::{
+        DBT_CATALOG_JSON, DBT_COMPILED_DIR_NAME, DBT_INTERNAL_PACKAGES_DIR_NAME, DBT_MANIFEST_JSON,
+        DBT_PACKAGES_DIR_NAME, DBT_TARGET_DIR_NAME, INSTALLING, VALIDATING,
+    },
+    err, fs_err, fsinfo,
+    io_args::{Phases, StaticAnalysisKind, SystemArgs},
     logging::init_logger,
     pretty_string::GREEN,
     show_progress, show_result_with_default_title, stdfs,
     tracing::span_info::record_span_status,
 };
 
-use dbt_schemas::schemas::Nodes;
-use dbt_schemas::state::Macros;
+use dbt_dag::docs_site::DocsSite;
+use dbt_dag::graph_export::LineageGraph;
+use dbt_dag::schedule::Schedule;
+use dbt_dag::selector::NodeSelector;
+use dbt_frontend_common::Dialect;
+use dbt_schemas::schemas::legacy_catalog::DbtCatalog;
+use dbt_schemas::schemas::serde::typed_struct_from_json_file;
+use dbt_schemas::schemas::{
+    FreshnessResultsArtifact, InternalDbtNodeAttributes, Nodes, PreviousState,
+};
+use dbt_schemas::state::{DbtState, Macros, ResolverState};
+use dbt_telemetry::ListItemOutput;
+use dbt_xdbc::query_ctx::ExecutionPhase;
+use dbt_xdbc::repl::{ReplConnector, run_repl};
+use dbt_xdbc::{
+    Backend, DriverSource, cdn_target_os, create_driver_bundle, install_driver_from_source,
+    installable_drivers,
+};
 #[allow(unused_imports)]
 use git_version::git_version;
+use minijinja::Value as MinijinjaValue;
+use minijinja::constants::{TARGET_PACKAGE_NAME, TARGET_UNIQUE_ID};
 
-use dbt_schemas::schemas::manifest::build_manifest;
+use dbt_schemas::schemas::manifest::{DbtManifest, build_manifest};
 use tracing::Instrument;
 
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::process::Command;
 use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 
+use dbt_loader::watch::{ProjectWatcher, ReloadScope};
 use dbt_loader::{args::LoadArgs, load};
-use dbt_parser::{args::ResolveArgs, resolver::resolve};
+use dbt_parser::{args::ResolveArgs, partial_parse::PartialParseCache, resolver::resolve};
+use fs_deps::inspect::{PackageGraph, outdated_packages};
+use fs_deps::load_recorded_dbt_packages_lock;
 
 use serde_json::to_string_pretty;
 
+/// How often `parse --watch` checks the project for changes
+const WATCH_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
 // ------------------------------------------------------------------------------------------------
 
 pub async fn execute_fs(
@@ -79,6 +139,24 @@ async fn do_execute_fs(eval_arg: &EvalArgs, cli: Cli, token: CancellationToken)
                 Ok(1)
             }
         };
+    } else if let Commands::Drivers(drivers_args) = &cli.command {
+        return match execute_drivers(eval_arg, drivers_args) {
+            Ok(()) => Ok(0),
+            Err(e) => {
+                emit_error_log_from_fs_error(&e, eval_arg.io.status_reporter.as_ref());
+
+                Ok(1)
+            }
+        };
+    } else if let Commands::Recording(recording_args) = &cli.command {
+        return match execute_recording(recording_args) {
+            Ok(code) => Ok(code),
+            Err(e) => {
+                emit_error_log_from_fs_error(&e, eval_arg.io.status_reporter.as_ref());
+
+                Ok(1)
+            }
+        };
     } else if let Commands::Init(init_args) = &cli.command {
         // Handle init command
         use dbt_init::init::run_init_workflow;
@@ -183,6 +261,24 @@ async fn execute_setup_and_all_phases(
             Err(e) => {
                 emit_error_log_from_fs_error(&e, eval_arg.io.status_reporter.as_ref());
 
+                Ok(1)
+            }
+        }
+    } else if let Commands::Cache(ref cache_args) = cli.command {
+        match execute_cache(eval_arg, cache_args) {
+            Ok(()) => Ok(0),
+            Err(e) => {
+                emit_error_log_from_fs_error(&e, eval_arg.io.status_reporter.as_ref());
+
+                Ok(1)
+            }
+        }
+    } else if let Commands::Repl(_) = cli.command {
+        match execute_repl(eval_arg, token).await {
+            Ok(()) => Ok(0),
+            Err(e) => {
+                emit_error_log_from_fs_error(&e, eval_arg.io.status_reporter.as_ref());
+
                 Ok(1)
             }
         }
@@ -200,11 +296,7 @@ async fn execute_setup_and_all_phases(
 }
 
 #[allow(clippy::cognitive_complexity)]
-async fn execute_all_phases(
-    arg: &EvalArgs,
-    _cli: &Cli,
-    token: &CancellationToken,
-) -> FsResult<i32> {
+async fn execute_all_phases(arg: &EvalArgs, cli: &Cli, token: &CancellationToken) -> FsResult<i32> {
     // Loads all .yml files + collects all included files
     let load_args = LoadArgs::from_eval_args(arg);
     let invocation_args = InvocationArgs::from_eval_args(arg);
@@ -220,6 +312,13 @@ async fn execute_all_phases(
 
     show_result_with_default_title!(&arg.io, ShowOptions::InputFiles, &dbt_state.to_string());
 
+    if let Commands::Deps(deps_args) = &cli.command
+        && let Some(deps_command) = &deps_args.command
+    {
+        execute_deps(&arg, deps_args, deps_command, &dbt_state).await?;
+        return Ok(get_exit_code_from_error_counter());
+    }
+
     // This also exits the init command b/c init `to_eval_args` sets the phase to debug
     if let Some(exit_code) = checkpoint_maybe_exit(&arg, Phases::Debug) {
         return Ok(exit_code);
@@ -229,12 +328,18 @@ async fn execute_all_phases(
     }
 
     // Parses (dbt parses) all .sql files with execute == false
-    let resolve_args = ResolveArgs::try_from_eval_args(&arg)?;
+    let mut resolve_args = ResolveArgs::try_from_eval_args(&arg)?;
+    let watch = matches!(&cli.command, Commands::Parse(parse_args) if parse_args.watch);
+    if watch {
+        // reparses only render the files whose contents or dependencies changed
+        resolve_args.partial_parse_cache = Some(Arc::new(PartialParseCache::new(&arg.io.out_dir)));
+    }
     let invocation_args = InvocationArgs::from_eval_args(&arg);
-    let (resolved_state, _jinja_env) = resolve(
+    let dbt_state = Arc::new(dbt_state);
+    let (resolved_state, jinja_env) = resolve(
         &resolve_args,
         &invocation_args,
-        Arc::new(dbt_state),
+        dbt_state.clone(),
         Macros::default(),
         Nodes::default(),
         token,
@@ -242,12 +347,23 @@ async fn execute_all_phases(
     )
     .await?;
 
-    let dbt_manifest = build_manifest(&arg.io.invocation_id.to_string(), &resolved_state);
+    let mut dbt_manifest = build_manifest(&arg.io.invocation_id.to_string(), &resolved_state);
+
+    if let Commands::Compile(compile_args) = &cli.command {
+        execute_compile(
+            &arg,
+            compile_args,
+            &dbt_state,
+            &resolved_state,
+            &jinja_env,
+            &mut dbt_manifest,
+            token,
+        )
+        .await?;
+    }
 
     if arg.write_json {
-        let dbt_manifest_path = arg.io.out_dir.join(DBT_MANIFEST_JSON);
-        stdfs::create_dir_all(dbt_manifest_path.parent().unwrap())?;
-        stdfs::write(dbt_manifest_path, serde_json::to_string(&dbt_manifest)?)?;
+        write_manifest(&arg, &dbt_manifest)?;
     }
 
     show_result_with_default_title!(
@@ -256,5 +372,886 @@ async fn execute_all_phases(
         to_string_pretty(&dbt_manifest)?
     );
 
+    if arg.phase == Phases::List {
+        execute_list(&arg, &resolved_state)?;
+    }
+    if let Commands::Graph(graph_args) = &cli.command {
+        execute_graph(&arg, graph_args, &resolved_state)?;
+    }
+    if let Commands::Diff(diff_args) = &cli.command {
+        execute_diff(&arg, diff_args, &resolved_state, token).await?;
+    }
+    if let Commands::Docs(docs_args) = &cli.command {
+        execute_docs(&arg, docs_args, &dbt_manifest)?;
+    }
+    if let Some(partial_parse_cache) = resolve_args.partial_parse_cache.filter(|_| watch) {
+        return watch_project(&arg, dbt_state, partial_parse_cache, token).await;
+    }
+
     Ok(get_exit_code_from_error_counter())
 }
+
+/// Write the manifest through a temporary file, so that tools reading
+/// manifest.json never observe a partially written file.
+fn write_manifest(arg: &EvalArgs, dbt_manifest: &DbtManifest) -> FsResult<()> {
+    let dbt_manifest_path = arg.io.out_dir.join(DBT_MANIFEST_JSON);
+    let tmp_path = dbt_manifest_path.with_extension("json.tmp");
+    stdfs::create_dir_all(&arg.io.out_dir)?;
+    stdfs::write(&tmp_path, serde_json::to_string(dbt_manifest)?)?;
+    stdfs::rename(&tmp_path, &dbt_manifest_path)
+}
+
+/// Keep reparsing the project whenever one of its files, packages or profiles
+/// changes, until the invocation is cancelled. Parse errors are reported and
+/// the previous manifest stays in place until the project parses again.
+///
+/// Changes within the root project only reload the root project, and
+/// `partial_parse_cache` carries the rendered sql files and parsed properties
+/// files over from the previous parse, so that only the changed files and
+/// the files depending on changed macros or vars are parsed again.
+async fn watch_project(
+    arg: &EvalArgs,
+    mut dbt_state: Arc<DbtState>,
+    partial_parse_cache: Arc<PartialParseCache>,
+    token: &CancellationToken,
+) -> FsResult<i32> {
+    let mut watcher = project_watcher(arg, &dbt_state);
+    emit_info_log_message(format!("Watching {} for changes", arg.io.in_dir.display()));
+
+    loop {
+        let changes = loop {
+            if token.is_cancelled() {
+                return Ok(get_exit_code_from_error_counter());
+            }
+            tokio::time::sleep(WATCH_POLL_INTERVAL).await;
+            let changes = watcher.poll();
+            if !changes.is_empty() {
+                break changes;
+            }
+        };
+        let prev_dbt_state = match changes.scope {
+            Some(ReloadScope::RootProject) => Some(dbt_state.clone()),
+            _ => None,
+        };
+        emit_info_log_message(format!(
+            "Detected {} changed file(s), reparsing",
+            changes.paths.len()
+        ));
+
+        let reparsed = resolve_project(
+            arg,
+            prev_dbt_state,
+            Some(partial_parse_cache.clone()),
+            token,
+        )
+        .await
+        .and_then(|(new_dbt_state, resolved_state)| {
+            let dbt_manifest = build_manifest(&arg.io.invocation_id.to_string(), &resolved_state);
+            if arg.write_json {
+                write_manifest(arg, &dbt_manifest)?;
+            }
+            Ok((new_dbt_state, resolved_state.nodes.iter().count()))
+        });
+        match reparsed {
+            Ok((new_dbt_state, node_count)) => {
+                dbt_state = new_dbt_state;
+                if changes.scope == Some(ReloadScope::Full) {
+                    // packages may have been (re)installed or moved
+                    watcher = project_watcher(arg, &dbt_state);
+                }
+                emit_info_log_message(format!("Parsed {node_count} nodes, watching for changes"));
+            }
+            Err(e) if token.is_cancelled() => {
+                return Err(e);
+            }
+            Err(e) => {
+                emit_error_log_from_fs_error(&e, arg.io.status_reporter.as_ref());
+            }
+        }
+    }
+}
+
+/// Evaluate the resolved selectors against the parsed graph, comparing against
+/// the previous state (--state) for the state: and source_status: methods
+fn select_nodes(arg: &EvalArgs, resolved_state: &ResolverState) -> FsResult<Schedule<String>> {
+    let previous_state = arg
+        .state
+        .as_deref()
+        .map(|state_path| PreviousState::try_new(state_path, resolved_state.root_project_quoting))
+        .transpose()?;
+    let mut selector = NodeSelector::new(&resolved_state.nodes, &resolved_state.root_project_name);
+    // the latest `source freshness` results, compared against the previous state by source_status:
+    let current_source_freshness = previous_state.as_ref().and_then(|_| {
+        typed_struct_from_json_file::<FreshnessResultsArtifact>(
+            &arg.io.out_dir.join("sources.json"),
+        )
+        .ok()
+    });
+    if let Some(previous_state) = &previous_state {
+        selector = selector.with_previous_state(previous_state, &resolved_state.macros.macros);
+    }
+    if let Some(current_source_freshness) = &current_source_freshness {
+        selector = selector.with_current_source_freshness(current_source_freshness);
+    }
+    selector.schedule(
+        resolved_state.resolved_selectors.include.as_ref(),
+        resolved_state.resolved_selectors.exclude.as_ref(),
+        &arg.resource_types,
+        &arg.exclude_resource_types,
+    )
+}
+
+/// Evaluate the resolved selectors against the parsed graph and print the selected nodes
+fn execute_list(arg: &EvalArgs, resolved_state: &ResolverState) -> FsResult<()> {
+    let schedule = select_nodes(arg, resolved_state)?;
+
+    let output_format = ListOutputFormat::try_from(arg.format).unwrap_or_default();
+    for item in schedule.show_dbt_nodes(&resolved_state.nodes, output_format, &arg.output_keys) {
+        emit_info_event(
+            ListItemOutput {
+                output_format: dbt_telemetry::ListOutputFormat::from(output_format) as i32,
+                content: item.content,
+                unique_id: Some(item.unique_id),
+            },
+            None,
+        );
+    }
+    Ok(())
+}
+
+/// Render the selected models and analyses with `execute` set, answering their
+/// introspective queries from the warehouse or, with --replay, from a recording.
+/// The compiled code is written below the compiled directory and into the manifest.
+async fn execute_compile(
+    arg: &EvalArgs,
+    compile_args: &CompileArgs,
+    dbt_state: &DbtState,
+    resolved_state: &ResolverState,
+    jinja_env: &JinjaEnv,
+    dbt_manifest: &mut DbtManifest,
+    token: &CancellationToken,
+) -> FsResult<()> {
+    let schedule = select_nodes(arg, resolved_state)?;
+
+    let db_config = dbt_state.dbt_profile.db_config.to_mapping()?;
+    let query_cache = arg.io.beta_use_query_cache.then(|| {
+        let config = QueryCacheConfig::new(
+            QueryCacheMode::ReadWrite,
+            arg.io.out_dir.join(QUERY_CACHE_DIR_NAME),
+            Some(
+                compile_args
+                    .query_cache_ttl
+                    .unwrap_or(DEFAULT_QUERY_CACHE_TTL),
+            ),
+            vec![
+                ExecutionPhase::Render,
+                ExecutionPhase::Analyze,
+                ExecutionPhase::Run,
+            ],
+        )
+        .with_max_size(Some(
+            compile_args
+                .query_cache_max_size
+                .unwrap_or(DEFAULT_QUERY_CACHE_MAX_SIZE),
+        ))
+        .with_key_scope(
+            resolved_state.adapter_type,
+            &dbt_state.dbt_profile.target,
+            db_config.clone(),
+        );
+        Arc::new(QueryCacheImpl::new(config))
+    });
+    let adapter = DefaultAdapterFactory::default().create_adapter(
+        resolved_state.adapter_type,
+        db_config,
+        arg.replay.clone(),
+        dbt_state.root_project_flags(),
+        None,
+        query_cache
+            .clone()
+            .map(|query_cache| query_cache as Arc<dyn QueryCache>),
+        resolved_state.root_project_quoting,
+        (*dbt_state.root_project().query_comment).clone(),
+        token.clone(),
+    )?;
+    let mut jinja_env = jinja_env.clone();
+    configure_compile_and_run_jinja_environment(&mut jinja_env, adapter);
+
+    let mut base_context = build_compile_and_run_base_context(
+        resolved_state.node_resolver.clone(),
+        &resolved_state.root_project_name,
+        &resolved_state.nodes,
+        resolved_state.runtime_config.clone(),
+    );
+
+    let nodes = resolved_state
+        .nodes
+        .models
+        .values()
+        .map(|model| model.as_ref() as &dyn InternalDbtNodeAttributes)
+        .chain(
+            resolved_state
+                .nodes
+                .analyses
+                .values()
+                .map(|analysis| analysis.as_ref() as &dyn InternalDbtNodeAttributes),
+        )
+        .filter(|node| schedule.selected_nodes.contains(&node.common().unique_id));
+
+    // node paths are relative to the root of the package that defines them
+    let package_roots: HashMap<&str, &Path> = dbt_state
+        .packages
+        .iter()
+        .map(|package| {
+            (
+                package.dbt_project.name.as_str(),
+                package.package_root_path.as_path(),
+            )
+        })
+        .collect();
+    // manifest paths are relative to the project, like the other node paths
+    let compiled_dir = arg
+        .io
+        .out_dir
+        .strip_prefix(&arg.io.in_dir)
+        .unwrap_or(&arg.io.out_dir)
+        .join(DBT_COMPILED_DIR_NAME);
+    let mut num_compiled = 0;
+    for node in nodes {
+        token.check_cancellation()?;
+        let common = node.common();
+        let package_root = package_roots
+            .get(common.package_name.as_str())
+            .copied()
+            .unwrap_or(arg.io.in_dir.as_path());
+        let sql = read_to_string(
+            &arg.io
+                .in_dir
+                .join(package_root)
+                .join(&common.original_file_path),
+        )
+        .await?;
+
+        base_context.insert(
+            TARGET_PACKAGE_NAME.to_string(),
+            MinijinjaValue::from(common.package_name.clone()),
+        );
+        base_context.insert(
+            TARGET_UNIQUE_ID.to_string(),
+            MinijinjaValue::from(common.unique_id.clone()),
+        );
+        let (node_context, _) = build_compile_node_context_inner(
+            node,
+            resolved_state.adapter_type,
+            resolved_state.dbt_profile.db_config.quote_character(),
+            &base_context,
+            &resolved_state.root_project_name,
+            resolved_state.node_resolver.clone(),
+            resolved_state.runtime_config.clone(),
+            StaticAnalysisKind::On.into(),
+            false,
+        );
+        // a query missing from the recording surfaces here as a rendering error
+        let compiled_code = render_sql(
+            &sql,
+            &jinja_env,
+            &node_context,
+            &DefaultRenderingEventListenerFactory::default(),
+            &common.original_file_path,
+        );
+        // the node is done with its connection, give it back to the pool
+        release_tlocal_connection();
+        let compiled_code = compiled_code?;
+
+        // target/compiled/<package>/<original_file_path>, as dbt lays it out
+        let compiled_path = compiled_dir
+            .join(&common.package_name)
+            .join(&common.original_file_path);
+        let absolute_path = arg.io.in_dir.join(&compiled_path);
+        if let Some(parent) = absolute_path.parent() {
+            stdfs::create_dir_all(parent)?;
+        }
+        stdfs::write(&absolute_path, &compiled_code)?;
+        dbt_manifest.set_compiled_code(
+            &common.unique_id,
+            compiled_path.display().to_string(),
+            compiled_code,
+        );
+        num_compiled += 1;
+    }
+    if let Some(query_cache) = &query_cache {
+        query_cache.flush().map_err(|e| {
+            fs_err!(
+                ErrorCode::IoError,
+                "Failed to write the query cache index: {}",
+                e
+            )
+        })?;
+    }
+
+    emit_info_log_message(format!(
+        "Compiled {num_compiled} nodes to {}",
+        arg.io.out_dir.join(DBT_COMPILED_DIR_NAME).display()
+    ));
+    Ok(())
+}
+
+/// Export the dependency graph of the selected nodes
+fn execute_graph(
+    arg: &EvalArgs,
+    graph_args: &GraphArgs,
+    resolved_state: &ResolverState,
+) -> FsResult<()> {
+    let schedule = select_nodes(arg, resolved_state)?;
+    let graph = LineageGraph::build(
+        &resolved_state.nodes,
+        Some(&schedule.selected_nodes),
+        &graph_args.layers,
+    );
+    let rendered = graph.render(graph_args.output)?;
+    match &graph_args.output_path {
+        Some(output_path) => {
+            if let Some(parent) = output_path.parent()
+                && !parent.as_os_str().is_empty()
+            {
+                stdfs::create_dir_all(parent)?;
+            }
+            stdfs::write(output_path, rendered)?;
+        }
+        None => println(rendered),
+    }
+    Ok(())
+}
+
+/// Generate the documentation site, including the catalog if one was generated
+fn execute_docs(arg: &EvalArgs, docs_args: &DocsArgs, dbt_manifest: &DbtManifest) -> FsResult<()> {
+    let catalog_path = docs_args
+        .catalog_path
+        .clone()
+        .unwrap_or_else(|| arg.io.out_dir.join(DBT_CATALOG_JSON));
+    let catalog = if catalog_path.exists() {
+        Some(typed_struct_from_json_file::<DbtCatalog>(&catalog_path)?)
+    } else if docs_args.catalog_path.is_some() {
+        return err!(
+            ErrorCode::InvalidArgument,
+            "Catalog not found: {}",
+            catalog_path.display()
+        );
+    } else {
+        None
+    };
+
+    let output_path = docs_args
+        .output_path
+        .clone()
+        .unwrap_or_else(|| arg.io.out_dir.join("docs"));
+    DocsSite::build(dbt_manifest, catalog.as_ref())?.write(&output_path)?;
+    emit_info_log_message(format!(
+        "Wrote documentation site to {}",
+        output_path.join("index.html").display()
+    ));
+    Ok(())
+}
+
+/// Print the package graph, why a package is installed, or which hub packages are outdated
+async fn execute_deps(
+    arg: &EvalArgs,
+    deps_args: &DepsArgs,
+    deps_command: &DepsCommand,
+    dbt_state: &DbtState,
+) -> FsResult<()> {
+    let dbt_packages_lock = load_recorded_dbt_packages_lock(&arg.io).unwrap_or_default();
+    let dependencies = dbt_state
+        .packages
+        .iter()
+        .map(|package| {
+            (
+                package.dbt_project.name.clone(),
+                package.dependencies.clone(),
+            )
+        })
+        .collect::<BTreeMap<_, _>>();
+    let graph = PackageGraph::new(
+        dbt_state.root_project_name(),
+        &dependencies,
+        &dbt_packages_lock,
+    );
+    match deps_command {
+        DepsCommand::Tree => match deps_args.output {
+            DepsFormat::Table => println(graph.render_tree()),
+            DepsFormat::Json => println(to_string_pretty(&graph)?),
+        },
+        DepsCommand::Why(why_args) => {
+            let Some(why) = graph.why(&why_args.package) else {
+                return err!(
+                    ErrorCode::InvalidArgument,
+                    "Package '{}' is not in package-lock.yml",
+                    why_args.package
+                );
+            };
+            match deps_args.output {
+                DepsFormat::Table => {
+                    let rows = why
+                        .paths
+                        .iter()
+                        .map(|path| vec![path[1].clone(), path.join(" -> ")])
+                        .collect::<Vec<_>>();
+                    println(pretty_vec_table(
+                        &format!("Why is '{}' installed?", why.package),
+                        "",
+                        &["Required by".to_string(), "Path".to_string()],
+                        &rows,
+                        DisplayFormat::Table,
+                        None,
+                        false,
+                        false,
+                    )?);
+                }
+                DepsFormat::Json => println(to_string_pretty(&why)?),
+            }
+        }
+        DepsCommand::Outdated => {
+            let outdated = outdated_packages(&arg.io, &dbt_packages_lock).await?;
+            match deps_args.output {
+                DepsFormat::Table if outdated.is_empty() => {
+                    emit_info_log_message("All hub packages are up to date");
+                }
+                DepsFormat::Table => {
+                    let rows = outdated
+                        .iter()
+                        .map(|package| {
+                            let mut notes = Vec::new();
+                            if package.deprecated {
+                                notes.push("deprecated".to_string());
+                            }
+                            if let Some(moved_to) = &package.moved_to {
+                                notes.push(format!("moved to {moved_to}"));
+                            }
+                            vec![
+                                package.package.clone(),
+                                package.locked.clone(),
+                                package.latest_compatible.clone().unwrap_or_default(),
+                                package.latest.clone().unwrap_or_default(),
+                                notes.join(", "),
+                            ]
+                        })
+                        .collect::<Vec<_>>();
+                    let column_names =
+                        ["Package", "Locked", "Compatible", "Latest", "Notes"].map(str::to_string);
+                    println(pretty_vec_table(
+                        "",
+                        "",
+                        &column_names,
+                        &rows,
+                        DisplayFormat::Table,
+                        None,
+                        false,
+                        false,
+                    )?);
+                }
+                DepsFormat::Json => println(to_string_pretty(&outdated)?),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Show the statistics of the query cache, or prune it
+fn execute_cache(arg: &EvalArgs, cache_args: &CacheArgs) -> FsResult<()> {
+    let cache_dir = cache_args
+        .cache_dir
+        .clone()
+        .unwrap_or_else(|| arg.io.out_dir.join(QUERY_CACHE_DIR_NAME));
+    let mut index = QueryCacheIndex::load(&cache_dir);
+    match &cache_args.command {
+        CacheCommand::Prune(prune_args) => {
+            let summary = index.prune(
+                &cache_dir,
+                prune_args.ttl,
+                prune_args.max_size,
+                SystemTime::now(),
+            );
+            index.save(&cache_dir).map_err(|e| {
+                fs_err!(
+                    ErrorCode::IoError,
+                    "Failed to write the query cache index in {}: {}",
+                    cache_dir.display(),
+                    e
+                )
+            })?;
+            emit_info_log_message(format!(
+                "Removed {} cached results and {} unindexed files, freeing {} bytes",
+                summary.removed_entries, summary.removed_files, summary.removed_bytes
+            ));
+        }
+        CacheCommand::Stats => {
+            let stats = &index.stats;
+            let lookups = stats.hits + stats.misses;
+            let hit_rate = if lookups == 0 {
+                0.0
+            } else {
+                stats.hits as f64 * 100.0 / lookups as f64
+            };
+            let lines = [
+                format!("Query cache: {}", cache_dir.display()),
+                format!("  results:     {}", index.entries.len()),
+                format!("  size:        {} bytes", index.total_size()),
+                format!("  hits:        {}", stats.hits),
+                format!("  misses:      {}", stats.misses),
+                format!("  hit rate:    {hit_rate:.1}%"),
+                format!("  expirations: {}", stats.expirations),
+                format!("  evictions:   {}", stats.evictions),
+            ];
+            emit_info_log_message(lines.join("\n"));
+        }
+    }
+    Ok(())
+}
+
+/// Connects the REPL through the SQL engine of the adapter of the target, so that
+/// its connection is set up and its statements are commented as in `dbt run`
+struct AdapterReplConnector {
+    engine: Arc<SqlEngine>,
+    /// The query comment of the project, resolved once for the session
+    comment: String,
+}
+
+impl ReplConnector for AdapterReplConnector {
+    fn connect(&self) -> adbc_core::error::Result<Box<dyn dbt_xdbc::Connection>> {
+        self.engine
+            .new_connection(None, None)
+            .map_err(adapter_error_to_adbc_error)
+    }
+
+    fn prepare_sql(&self, sql: &str) -> String {
+        self.engine.query_comment().add_comment(sql, &self.comment)
+    }
+}
+
+/// Connect to the target of the profile through its adapter and run the REPL on it
+async fn execute_repl(arg: &EvalArgs, token: &CancellationToken) -> FsResult<()> {
+    let load_args = LoadArgs::from_eval_args(arg);
+    let invocation_args = InvocationArgs::from_eval_args(arg);
+    let (dbt_state, _dbt_cloud_config) = load(&load_args, &invocation_args, token).await?;
+    let dbt_profile = &dbt_state.dbt_profile;
+    let Some(adapter_type) = dbt_profile.db_config.adapter_type_if_supported() else {
+        return err!(
+            ErrorCode::NotSupported,
+            "The REPL does not support the adapter of target '{}'",
+            dbt_profile.target
+        );
+    };
+    let root_project = dbt_state.root_project();
+    let adapter = DefaultAdapterFactory::default().create_adapter(
+        adapter_type,
+        dbt_profile.db_config.to_mapping()?,
+        None,
+        dbt_state.root_project_flags(),
+        None,
+        None,
+        resolve_package_quoting(*root_project.quoting, adapter_type).try_into()?,
+        (*root_project.query_comment).clone(),
+        token.clone(),
+    )?;
+
+    // The REPL runs outside of any node
+    let env = initialize_load_jinja_environment(
+        &dbt_profile.profile,
+        &dbt_profile.target,
+        dbt_profile.db_config.adapter_type(),
+        dbt_profile.db_config.clone(),
+        dbt_state.run_started_at,
+        &invocation_args.to_dict(),
+        arg.io.clone(),
+        token.clone(),
+        dbt_state.catalogs.clone(),
+    )?;
+    let engine = adapter.engine().clone();
+    let comment = env.render_str(
+        engine.query_comment().comment(),
+        BTreeMap::from([
+            ("node", MinijinjaValue::from(())),
+            ("connection_name", MinijinjaValue::from("repl")),
+        ]),
+        &[],
+    )?;
+
+    let name = format!("{}.{}", dbt_profile.profile, dbt_profile.target);
+    let connector = Box::new(AdapterReplConnector { engine, comment });
+    run_repl(connector, Dialect::from(adapter_type), &name)
+        .await
+        .map_err(|e| fs_err!(ErrorCode::IoError, "{}", e.message))
+}
+
+/// Compare two recordings, or redact one
+fn execute_recording(recording_args: &RecordingArgs) -> FsResult<i32> {
+    match &recording_args.command {
+        RecordingCommand::Diff(diff_args) => {
+            let diff = diff_recordings(&diff_args.old, &diff_args.new)
+                .map_err(|e| fs_err!(ErrorCode::IoError, "{}", e.message))?;
+            emit_info_log_message(diff.to_string());
+            Ok(if diff.is_empty() { 0 } else { 1 })
+        }
+        RecordingCommand::Redact(redact_args) => {
+            let output = redact_args.output.as_ref().unwrap_or(&redact_args.path);
+            let num_redacted = redact_recording(&redact_args.path, output)
+                .map_err(|e| fs_err!(ErrorCode::IoError, "{}", e.message))?;
+            emit_info_log_message(format!(
+                "Redacted {num_redacted} results into {}",
+                output.display()
+            ));
+            Ok(0)
+        }
+    }
+}
+
+/// Bundle ADBC drivers for offline installation, or install them from a bundle or mirror
+fn execute_drivers(arg: &EvalArgs, drivers_args: &DriversArgs) -> FsResult<()> {
+    match &drivers_args.command {
+        DriversCommand::Bundle(bundle_args) => {
+            let source = bundle_args
+                .source
+                .as_deref()
+                .map(DriverSource::parse)
+                .unwrap_or(DriverSource::Cdn);
+            let backends = driver_backends(&bundle_args.adapters)?;
+            let oses = if bundle_args.os.is_empty() {
+                vec![std::env::consts::OS.to_string()]
+            } else {
+                bundle_args.os.clone()
+            };
+            let arches = if bundle_args.arch.is_empty() {
+                vec![std::env::consts::ARCH.to_string()]
+            } else {
+                bundle_args.arch.clone()
+            };
+            let mut targets = Vec::new();
+            for os in &oses {
+                let Some(target_os) = cdn_target_os(os) else {
+                    return err!(
+                        ErrorCode::InvalidArgument,
+                        "Unsupported operating system '{}', expected linux, macos or windows",
+                        os
+                    );
+                };
+                for arch in &arches {
+                    targets.push((target_os, arch.as_str()));
+                }
+            }
+            let entries =
+                create_driver_bundle(&source, &backends, &targets, &bundle_args.output)
+                    .map_err(|e| fs_err!(ErrorCode::IoError, "Failed to bundle drivers: {}", e))?;
+            emit_info_log_message(format!(
+                "Wrote {} drivers to {}",
+                entries.len(),
+                bundle_args.output.display()
+            ));
+        }
+        DriversCommand::Install(install_args) => {
+            let source = DriverSource::parse(&install_args.source);
+            for backend in driver_backends(&install_args.adapters)? {
+                show_progress!(
+                    &arg.io,
+                    fsinfo!(INSTALLING.into(), format!("{backend} driver"))
+                );
+                install_driver_from_source(&source, backend).map_err(|e| {
+                    fs_err!(
+                        ErrorCode::IoError,
+                        "Failed to install the {} driver: {}",
+                        backend,
+                        e
+                    )
+                })?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The driver backends of the given adapters, or all installable ones if none are given
+fn driver_backends(adapters: &[AdapterType]) -> FsResult<Vec<Backend>> {
+    if adapters.is_empty() {
+        return Ok(installable_drivers().to_vec());
+    }
+    adapters
+        .iter()
+        .map(|&adapter_type| {
+            let backend = backend_of(adapter_type);
+            if installable_drivers().contains(&backend) {
+                Ok(backend)
+            } else {
+                err!(
+                    ErrorCode::InvalidArgument,
+                    "No ADBC driver is published for the {} adapter",
+                    adapter_type
+                )
+            }
+        })
+        .collect()
+}
+
+/// Compare the resolved project against the manifest in --state or the project
+/// as of a git revision
+async fn execute_diff(
+    arg: &EvalArgs,
+    diff_args: &DiffArgs,
+    resolved_state: &ResolverState,
+    token: &CancellationToken,
+) -> FsResult<()> {
+    let previous_state = match (&diff_args.base_rev, &arg.state) {
+        (Some(base_rev), _) => resolve_git_revision(arg, base_rev, token).await?,
+        (None, Some(state_path)) => {
+            PreviousState::try_new(state_path, resolved_state.root_project_quoting)?
+        }
+        (None, None) => {
+            return err!(
+                ErrorCode::InvalidArgument,
+                "Nothing to compare against, use --state <dir> or --base-rev <rev>"
+            );
+        }
+    };
+    if previous_state.nodes.is_none() {
+        return err!(
+            ErrorCode::InvalidArgument,
+            "Could not load a manifest from {}",
+            previous_state.state_path.display()
+        );
+    }
+
+    let diff = previous_state.diff(&resolved_state.nodes, &resolved_state.macros.macros);
+    match diff_args.output {
+        DiffFormat::Text => println(diff.to_string().trim_end()),
+        DiffFormat::Json => println(to_string_pretty(&diff)?),
+    }
+    Ok(())
+}
+
+/// Parse the project as of `base_rev`, checked out into a temporary git worktree.
+/// The packages installed for the current project are reused.
+async fn resolve_git_revision(
+    arg: &EvalArgs,
+    base_rev: &str,
+    token: &CancellationToken,
+) -> FsResult<PreviousState> {
+    let repo_root = git(&arg.io.in_dir, &["rev-parse", "--show-toplevel"])?;
+    let repo_root = stdfs::canonicalize(Path::new(&repo_root))?;
+    let project_subdir = arg.io.in_dir.strip_prefix(&repo_root).map_err(|e| {
+        fs_err!(
+            ErrorCode::IoError,
+            "Project is not inside its git repository: {}",
+            e
+        )
+    })?;
+
+    let temp_dir = tempfile::tempdir()?;
+    let worktree = temp_dir.path().join("worktree");
+    git(
+        &repo_root,
+        &[
+            "worktree",
+            "add",
+            "--detach",
+            &worktree.to_string_lossy(),
+            base_rev,
+        ],
+    )?;
+
+    let mut base_arg = arg.clone();
+    base_arg.io.in_dir = worktree.join(project_subdir);
+    base_arg.io.out_dir = temp_dir.path().join(DBT_TARGET_DIR_NAME);
+    base_arg.write_json = false;
+    base_arg.packages_install_path = Some(match &arg.packages_install_path {
+        Some(path) if path.is_relative() => arg.io.in_dir.join(path),
+        Some(path) => path.clone(),
+        None => arg.io.in_dir.join(DBT_PACKAGES_DIR_NAME),
+    });
+    base_arg.internal_packages_install_path =
+        Some(temp_dir.path().join(DBT_INTERNAL_PACKAGES_DIR_NAME));
+    let resolved = resolve_project(&base_arg, None, None, token).await;
+
+    let _ = git(
+        &repo_root,
+        &["worktree", "remove", "--force", &worktree.to_string_lossy()],
+    );
+    let (_, resolved_state) = resolved?;
+    Ok(PreviousState {
+        nodes: Some(resolved_state.nodes),
+        macros: Some(resolved_state.macros.macros),
+        run_results: None,
+        source_freshness_results: None,
+        state_path: PathBuf::from(base_rev),
+    })
+}
+
+fn project_watcher(arg: &EvalArgs, dbt_state: &DbtState) -> ProjectWatcher {
+    let package_roots = dbt_state
+        .packages
+        .iter()
+        .map(|package| package.package_root_path.clone());
+    ProjectWatcher::new(
+        &arg.io.in_dir,
+        &arg.io.out_dir,
+        package_roots,
+        arg.profiles_dir.as_deref(),
+    )
+}
+
+/// Load and resolve the project in `arg.io.in_dir` without writing any artifacts.
+/// With a `prev_dbt_state` only the root project is reloaded and its packages
+/// and vars are reused; with a `partial_parse_cache` the files which did not
+/// change since its previous parse are reused.
+async fn resolve_project(
+    arg: &EvalArgs,
+    prev_dbt_state: Option<Arc<DbtState>>,
+    partial_parse_cache: Option<Arc<PartialParseCache>>,
+    token: &CancellationToken,
+) -> FsResult<(Arc<DbtState>, ResolverState)> {
+    stdfs::create_dir_all(&arg.io.out_dir)?;
+    let mut load_args = LoadArgs::from_eval_args(arg);
+    load_args.prev_dbt_state = prev_dbt_state;
+    let invocation_args = InvocationArgs::from_eval_args(arg);
+    let (dbt_state, _dbt_cloud_config) = load(&load_args, &invocation_args, token).await?;
+    let arg = EvalArgsBuilder::from_eval_args(arg)
+        .with_additional(
+            dbt_state.dbt_profile.target.to_string(),
+            dbt_state.dbt_profile.threads,
+            dbt_state.dbt_profile.db_config.adapter_type_if_supported(),
+        )
+        .build();
+
+    let resolve_args = ResolveArgs {
+        partial_parse_cache,
+        ..ResolveArgs::try_from_eval_args(&arg)?
+    };
+    let invocation_args = InvocationArgs::from_eval_args(&arg);
+    let dbt_state = Arc::new(dbt_state);
+    let (resolved_state, _jinja_env) = resolve(
+        &resolve_args,
+        &invocation_args,
+        dbt_state.clone(),
+        Macros::default(),
+        Nodes::default(),
+        token,
+        Arc::new(DefaultJinjaTypeCheckEventListenerFactory::default()),
+    )
+    .await?;
+    Ok((dbt_state, resolved_state))
+}
+
+fn git(dir: &Path, args: &[&str]) -> FsResult<String> {
+    let output = Command::new("git")
+        .current_dir(dir)
+        .env("LC_ALL", "C")
+        .args(args)
+        .output()
+        .map_err(|e| fs_err!(ErrorCode::RuntimeError, "Error running git: {e}"))?;
+    if !output.status.success() {
+        return err!(
+            ErrorCode::RuntimeError,
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
@@ -177,10 +177,14 @@ where
 
 #[derive(Deserialize, Serialize, Debug, Clone, JsonSchema, PartialEq, Eq)]
 #[allow(non_camel_case_types)]
+#[serde(rename_all = "snake_case")]
 pub enum FreshnessStatus {
     Pass,
     Warn,
     Error,
+    /// The freshness query itself failed
+    #[serde(rename = "runtime error")]
+    RuntimeError,
 }
 
 impl std::fmt::Display for FreshnessStatus {
@@ -189,6 +193,7 @@ impl std::fmt::Display for FreshnessStatus {
             Self::Pass => "pass",
             Self::Warn => "warn",
             Self::Error => "error",
+            Self::RuntimeError => "runtime error",
         };
         write!(f, "{s}")
     }
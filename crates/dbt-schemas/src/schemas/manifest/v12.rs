@@ -56,6 +56,28 @@ impl DbtManifestV12 {
             })
             .collect::<HashMap<_, _>>()
     }
+
+    /// Record the compiled code of a node and where it was written.
+    /// Returns false if the manifest has no compilable node with this id.
+    pub fn set_compiled_code(
+        &mut self,
+        unique_id: &str,
+        compiled_path: String,
+        compiled_code: String,
+    ) -> bool {
+        let base_attr = match self.nodes.get_mut(unique_id) {
+            Some(DbtNode::Model(model)) => &mut model.__base_attr__,
+            Some(DbtNode::Test(test)) => &mut test.__base_attr__,
+            Some(DbtNode::Snapshot(snapshot)) => &mut snapshot.__base_attr__,
+            Some(DbtNode::Seed(seed)) => &mut seed.__base_attr__,
+            Some(DbtNode::Analysis(analysis)) => &mut analysis.__base_attr__,
+            Some(DbtNode::Operation(_)) | Some(DbtNode::Function(_)) | None => return false,
+        };
+        base_attr.compiled_path = Some(compiled_path);
+        base_attr.compiled = Some(true);
+        base_attr.compiled_code = Some(compiled_code);
+        true
+    }
 }
 
 impl Serialize for DbtManifestV12 {
@@ -2395,7 +2395,10 @@ impl AdapterAttr {
                     transient: config.transient,
                 })))
             }
-            AdapterType::Postgres => AdapterAttr::default(),
+            AdapterType::Postgres
+            | AdapterType::DuckDB
+            | AdapterType::Trino
+            | AdapterType::Generic => AdapterAttr::default(),
             AdapterType::Bigquery => {
                 AdapterAttr::default().with_bigquery_attr(Some(Box::new(BigQueryAttr {
                     description: config.description.clone(),
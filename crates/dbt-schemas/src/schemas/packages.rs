@@ -163,6 +163,10 @@ impl DbtPackagesLock {
         self.packages.iter().find(|p| p.package_name() == name)
     }
 
+    pub fn get_by_name_mut(&mut self, name: &str) -> Option<&mut DbtPackageLock> {
+        self.packages.iter_mut().find(|p| p.package_name() == name)
+    }
+
     pub fn has_duplicate_package_names(&self) -> bool {
         let mut seen = std::collections::HashSet::new();
         self.packages.iter().any(|p| !seen.insert(p.package_name()))
@@ -216,6 +220,53 @@ impl DbtPackageLock {
         }
     }
 
+    /// SHA-256 of the installed package content as recorded by `deps`.
+    /// Local packages are symlinked rather than installed and carry no digest.
+    pub fn sha256(&self) -> Option<&str> {
+        match self {
+            DbtPackageLock::Hub(hub_package_lock) => hub_package_lock.sha256.as_deref(),
+            DbtPackageLock::Git(git_package_lock) => git_package_lock.sha256.as_deref(),
+            DbtPackageLock::Local(_) => None,
+            DbtPackageLock::Private(private_package_lock) => private_package_lock.sha256.as_deref(),
+            DbtPackageLock::Tarball(tarball_package_lock) => tarball_package_lock.sha256.as_deref(),
+        }
+    }
+
+    pub fn set_sha256(&mut self, sha256: Option<String>) {
+        match self {
+            DbtPackageLock::Hub(hub_package_lock) => hub_package_lock.sha256 = sha256,
+            DbtPackageLock::Git(git_package_lock) => git_package_lock.sha256 = sha256,
+            DbtPackageLock::Local(_) => {}
+            DbtPackageLock::Private(private_package_lock) => private_package_lock.sha256 = sha256,
+            DbtPackageLock::Tarball(tarball_package_lock) => tarball_package_lock.sha256 = sha256,
+        }
+    }
+
+    /// SHA-256 of the installed package tree. Git and private packages are
+    /// locked by their tree digest; hub and tarball packages are locked by the
+    /// digest of their tarball and record the tree digest alongside it.
+    pub fn tree_sha256(&self) -> Option<&str> {
+        match self {
+            DbtPackageLock::Hub(hub_package_lock) => hub_package_lock.tree_sha256.as_deref(),
+            DbtPackageLock::Git(git_package_lock) => git_package_lock.sha256.as_deref(),
+            DbtPackageLock::Local(_) => None,
+            DbtPackageLock::Private(private_package_lock) => private_package_lock.sha256.as_deref(),
+            DbtPackageLock::Tarball(tarball_package_lock) => {
+                tarball_package_lock.tree_sha256.as_deref()
+            }
+        }
+    }
+
+    pub fn set_tree_sha256(&mut self, tree_sha256: Option<String>) {
+        match self {
+            DbtPackageLock::Hub(hub_package_lock) => hub_package_lock.tree_sha256 = tree_sha256,
+            DbtPackageLock::Tarball(tarball_package_lock) => {
+                tarball_package_lock.tree_sha256 = tree_sha256
+            }
+            DbtPackageLock::Git(_) | DbtPackageLock::Local(_) | DbtPackageLock::Private(_) => {}
+        }
+    }
+
     pub fn entry_type(&self) -> String {
         match self {
             DbtPackageLock::Hub(_) => "hub".to_string(),
@@ -233,6 +284,10 @@ pub struct HubPackageLock {
     pub name: String,
     #[serde(rename = "version")]
     pub version: PackageVersion,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tree_sha256: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -244,6 +299,8 @@ pub struct GitPackageLock {
     pub warn_unpinned: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subdirectory: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
     pub __unrendered__: HashMap<String, YmlValue>,
 }
 
@@ -264,6 +321,8 @@ pub struct PrivatePackageLock {
     pub warn_unpinned: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subdirectory: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
     pub __unrendered__: HashMap<String, YmlValue>,
 }
 
@@ -271,6 +330,10 @@ pub struct PrivatePackageLock {
 pub struct TarballPackageLock {
     pub tarball: Verbatim<String>,
     pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tree_sha256: Option<String>,
     pub __unrendered__: HashMap<String, YmlValue>,
 }
 
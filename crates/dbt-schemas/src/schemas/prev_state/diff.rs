@@ -0,0 +1,450 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use super::{ModificationType, PreviousState};
+use crate::schemas::macros::DbtMacro;
+use crate::schemas::{InternalDbtNode, InternalDbtNodeAttributes, Nodes};
+
+/// The categories reported per modified node, in the order they are checked
+const MODIFICATION_TYPES: [ModificationType; 6] = [
+    ModificationType::Body,
+    ModificationType::Configs,
+    ModificationType::Relation,
+    ModificationType::PersistedDescriptions,
+    ModificationType::Macros,
+    ModificationType::Contract,
+];
+
+/// Structured difference between the nodes of a previous state and the current project
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ManifestDiff {
+    pub added: Vec<NodeSummary>,
+    pub removed: Vec<NodeSummary>,
+    pub modified: Vec<NodeDiff>,
+    /// Dependencies of current nodes which don't exist (anymore)
+    pub broken_refs: Vec<BrokenRef>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NodeSummary {
+    pub unique_id: String,
+    pub resource_type: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NodeDiff {
+    pub unique_id: String,
+    pub resource_type: String,
+    pub modifications: Vec<ModificationType>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub columns: Vec<ColumnChange>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub configs: Vec<ConfigChange>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub added_refs: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub removed_refs: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum ColumnChange {
+    Added {
+        name: String,
+        data_type: Option<String>,
+    },
+    Removed {
+        name: String,
+        data_type: Option<String>,
+    },
+    TypeChanged {
+        name: String,
+        previous: Option<String>,
+        current: Option<String>,
+    },
+}
+
+/// A top level config key whose value changed; `None` if the key is not set
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfigChange {
+    pub key: String,
+    pub previous: Option<Value>,
+    pub current: Option<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BrokenRef {
+    pub unique_id: String,
+    pub missing: String,
+}
+
+impl ManifestDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.modified.is_empty()
+            && self.broken_refs.is_empty()
+    }
+}
+
+impl PreviousState {
+    /// Compare the nodes of the current project against the ones of the previous state
+    pub fn diff(
+        &self,
+        current_nodes: &Nodes,
+        current_macros: &BTreeMap<String, DbtMacro>,
+    ) -> ManifestDiff {
+        let empty = Nodes::default();
+        let previous_nodes = self.nodes.as_ref().unwrap_or(&empty);
+        let mut diff = ManifestDiff::default();
+
+        for (unique_id, current_node) in current_nodes.iter() {
+            let Some(previous_node) = previous_nodes.get_node(unique_id) else {
+                diff.added.push(summary(current_node));
+                continue;
+            };
+            let node_diff = self.diff_node(current_node, previous_node, current_macros);
+            if !node_diff.modifications.is_empty()
+                || !node_diff.columns.is_empty()
+                || !node_diff.configs.is_empty()
+                || !node_diff.added_refs.is_empty()
+                || !node_diff.removed_refs.is_empty()
+            {
+                diff.modified.push(node_diff);
+            }
+        }
+        diff.removed = previous_nodes
+            .iter()
+            .filter(|(unique_id, _)| !current_nodes.contains(unique_id))
+            .map(|(_, node)| summary(node))
+            .collect();
+        diff.broken_refs = current_nodes
+            .iter()
+            .flat_map(|(unique_id, node)| {
+                node.base()
+                    .depends_on
+                    .nodes
+                    .iter()
+                    .filter(|dependency| !current_nodes.contains(dependency))
+                    .map(|dependency| BrokenRef {
+                        unique_id: unique_id.clone(),
+                        missing: dependency.clone(),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        diff
+    }
+
+    fn diff_node(
+        &self,
+        current_node: &dyn InternalDbtNodeAttributes,
+        previous_node: &dyn InternalDbtNodeAttributes,
+        current_macros: &BTreeMap<String, DbtMacro>,
+    ) -> NodeDiff {
+        let modifications = MODIFICATION_TYPES
+            .into_iter()
+            .filter(|modification_type| {
+                self.is_modified(
+                    current_node,
+                    Some(modification_type.clone()),
+                    current_macros,
+                )
+            })
+            .collect();
+
+        let dependencies = |node: &dyn InternalDbtNodeAttributes| {
+            node.base()
+                .depends_on
+                .nodes
+                .iter()
+                .cloned()
+                .collect::<BTreeSet<_>>()
+        };
+        let (current_deps, previous_deps) =
+            (dependencies(current_node), dependencies(previous_node));
+
+        NodeDiff {
+            unique_id: current_node.common().unique_id.clone(),
+            resource_type: current_node.resource_type().as_ref().to_string(),
+            modifications,
+            columns: column_changes(current_node, previous_node),
+            configs: config_changes(current_node, previous_node),
+            added_refs: current_deps.difference(&previous_deps).cloned().collect(),
+            removed_refs: previous_deps.difference(&current_deps).cloned().collect(),
+        }
+    }
+}
+
+fn summary(node: &dyn InternalDbtNodeAttributes) -> NodeSummary {
+    NodeSummary {
+        unique_id: node.common().unique_id.clone(),
+        resource_type: node.resource_type().as_ref().to_string(),
+    }
+}
+
+fn column_changes(
+    current_node: &dyn InternalDbtNode,
+    previous_node: &dyn InternalDbtNode,
+) -> Vec<ColumnChange> {
+    let column_types = |node: &dyn InternalDbtNode| {
+        node.base()
+            .columns
+            .iter()
+            .map(|column| (column.name.clone(), column.data_type.clone()))
+            .collect::<BTreeMap<_, _>>()
+    };
+    let (current, previous) = (column_types(current_node), column_types(previous_node));
+
+    let mut changes = vec![];
+    for (name, data_type) in &current {
+        match previous.get(name) {
+            None => changes.push(ColumnChange::Added {
+                name: name.clone(),
+                data_type: data_type.clone(),
+            }),
+            Some(previous_type) if previous_type != data_type => {
+                changes.push(ColumnChange::TypeChanged {
+                    name: name.clone(),
+                    previous: previous_type.clone(),
+                    current: data_type.clone(),
+                })
+            }
+            Some(_) => {}
+        }
+    }
+    for (name, data_type) in &previous {
+        if !current.contains_key(name) {
+            changes.push(ColumnChange::Removed {
+                name: name.clone(),
+                data_type: data_type.clone(),
+            });
+        }
+    }
+    changes
+}
+
+fn config_changes(
+    current_node: &dyn InternalDbtNode,
+    previous_node: &dyn InternalDbtNode,
+) -> Vec<ConfigChange> {
+    let config = |node: &dyn InternalDbtNode| match serde_json::to_value(node.serialized_config()) {
+        Ok(Value::Object(config)) => config,
+        _ => serde_json::Map::new(),
+    };
+    let (current, previous) = (config(current_node), config(previous_node));
+
+    let keys = current
+        .keys()
+        .chain(previous.keys())
+        .collect::<BTreeSet<_>>();
+    keys.into_iter()
+        .filter(|key| current.get(*key) != previous.get(*key))
+        .map(|key| ConfigChange {
+            key: key.clone(),
+            previous: previous.get(key).cloned(),
+            current: current.get(key).cloned(),
+        })
+        .collect()
+}
+
+fn show_value(value: &Option<Value>) -> String {
+    match value {
+        Some(value) => value.to_string(),
+        None => "(unset)".to_string(),
+    }
+}
+
+impl fmt::Display for ManifestDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No changes");
+        }
+        if !self.added.is_empty() {
+            writeln!(f, "Added ({}):", self.added.len())?;
+            for node in &self.added {
+                writeln!(f, "  + {}", node.unique_id)?;
+            }
+        }
+        if !self.removed.is_empty() {
+            writeln!(f, "Removed ({}):", self.removed.len())?;
+            for node in &self.removed {
+                writeln!(f, "  - {}", node.unique_id)?;
+            }
+        }
+        if !self.modified.is_empty() {
+            writeln!(f, "Modified ({}):", self.modified.len())?;
+            for node in &self.modified {
+                write!(f, "  ~ {}", node.unique_id)?;
+                if !node.modifications.is_empty() {
+                    let modifications = node
+                        .modifications
+                        .iter()
+                        .map(|modification| modification.to_string())
+                        .collect::<Vec<_>>();
+                    write!(f, " [{}]", modifications.join(", "))?;
+                }
+                writeln!(f)?;
+                for column in &node.columns {
+                    match column {
+                        ColumnChange::Added { name, data_type } => writeln!(
+                            f,
+                            "      column added: {name}{}",
+                            data_type
+                                .as_ref()
+                                .map(|data_type| format!(" ({data_type})"))
+                                .unwrap_or_default()
+                        )?,
+                        ColumnChange::Removed { name, .. } => {
+                            writeln!(f, "      column removed: {name}")?
+                        }
+                        ColumnChange::TypeChanged {
+                            name,
+                            previous,
+                            current,
+                        } => writeln!(
+                            f,
+                            "      column type changed: {name} ({} -> {})",
+                            previous.as_deref().unwrap_or("unknown"),
+                            current.as_deref().unwrap_or("unknown")
+                        )?,
+                    }
+                }
+                for config in &node.configs {
+                    writeln!(
+                        f,
+                        "      config {}: {} -> {}",
+                        config.key,
+                        show_value(&config.previous),
+                        show_value(&config.current)
+                    )?;
+                }
+                for dependency in &node.added_refs {
+                    writeln!(f, "      ref added: {dependency}")?;
+                }
+                for dependency in &node.removed_refs {
+                    writeln!(f, "      ref removed: {dependency}")?;
+                }
+            }
+        }
+        if !self.broken_refs.is_empty() {
+            writeln!(f, "Broken refs ({}):", self.broken_refs.len())?;
+            for broken_ref in &self.broken_refs {
+                writeln!(f, "  ! {} -> {}", broken_ref.unique_id, broken_ref.missing)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schemas::DbtModel;
+    use crate::schemas::common::DbtChecksum;
+    use crate::schemas::dbt_column::DbtColumn;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    fn model(name: &str, deps: &[&str], columns: &[(&str, &str)]) -> DbtModel {
+        let mut model = DbtModel::default();
+        model.__common_attr__.unique_id = format!("model.proj.{name}");
+        model.__common_attr__.name = name.to_string();
+        model.__base_attr__.depends_on.nodes = deps.iter().map(|d| d.to_string()).collect();
+        model.__base_attr__.columns = columns
+            .iter()
+            .map(|(name, data_type)| {
+                Arc::new(DbtColumn {
+                    name: name.to_string(),
+                    data_type: Some(data_type.to_string()),
+                    ..Default::default()
+                })
+            })
+            .collect();
+        model
+    }
+
+    fn nodes(models: Vec<DbtModel>) -> Nodes {
+        let mut nodes = Nodes::default();
+        for model in models {
+            nodes
+                .models
+                .insert(model.__common_attr__.unique_id.clone(), Arc::new(model));
+        }
+        nodes
+    }
+
+    #[test]
+    fn test_diff() {
+        let previous_state = PreviousState {
+            nodes: Some(nodes(vec![
+                model("a", &[], &[("id", "int"), ("amount", "int")]),
+                model("b", &["model.proj.a"], &[]),
+                model("old", &[], &[]),
+            ])),
+            macros: Some(BTreeMap::new()),
+            run_results: None,
+            source_freshness_results: None,
+            state_path: PathBuf::from("prod"),
+        };
+
+        let mut a = model(
+            "a",
+            &[],
+            &[("id", "int"), ("amount", "numeric"), ("note", "text")],
+        );
+        a.__common_attr__.checksum = DbtChecksum::hash(b"select 2");
+        let current = nodes(vec![
+            a,
+            model("b", &["model.proj.a", "model.proj.new"], &[]),
+            model("c", &["model.proj.old"], &[]),
+            model("new", &[], &[]),
+        ]);
+
+        let diff = previous_state.diff(&current, &BTreeMap::new());
+        let ids = |nodes: &[NodeSummary]| {
+            nodes
+                .iter()
+                .map(|node| node.unique_id.as_str())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(&diff.added), ["model.proj.c", "model.proj.new"]);
+        assert_eq!(ids(&diff.removed), ["model.proj.old"]);
+        assert_eq!(
+            diff.broken_refs,
+            [BrokenRef {
+                unique_id: "model.proj.c".to_string(),
+                missing: "model.proj.old".to_string(),
+            }]
+        );
+
+        assert_eq!(diff.modified.len(), 2);
+        let a = &diff.modified[0];
+        assert_eq!(a.modifications, [ModificationType::Body]);
+        assert_eq!(
+            a.columns,
+            [
+                ColumnChange::TypeChanged {
+                    name: "amount".to_string(),
+                    previous: Some("int".to_string()),
+                    current: Some("numeric".to_string()),
+                },
+                ColumnChange::Added {
+                    name: "note".to_string(),
+                    data_type: Some("text".to_string()),
+                },
+            ]
+        );
+        let b = &diff.modified[1];
+        assert!(b.modifications.is_empty());
+        assert_eq!(b.added_refs, ["model.proj.new"]);
+
+        let text = diff.to_string();
+        assert!(text.contains("  ~ model.proj.a [body]\n"));
+        assert!(text.contains("      column type changed: amount (int -> numeric)\n"));
+        assert!(text.contains("  ! model.proj.c -> model.proj.old\n"));
+    }
+}
@@ -1,21 +1,35 @@
-use super::{RunResultsArtifact, manifest::DbtManifest, sources::FreshnessResultsArtifact};
+mod diff;
+
+pub use diff::{BrokenRef, ColumnChange, ConfigChange, ManifestDiff, NodeDiff, NodeSummary};
+
+use super::{
+    RunResultsArtifact,
+    manifest::DbtManifest,
+    sources::{FreshnessResultsArtifact, FreshnessResultsNode},
+};
 use crate::schemas::common::{DbtQuoting, ResolvedQuoting};
+use crate::schemas::macros::DbtMacro;
 use crate::schemas::manifest::nodes_from_dbt_manifest;
 use crate::schemas::serde::typed_struct_from_json_file;
-use crate::schemas::{InternalDbtNode, Nodes};
+use crate::schemas::{DbtModel, InternalDbtNode, Nodes};
 use dbt_common::{FsResult, constants::DBT_MANIFEST_JSON};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fmt;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 #[derive(Debug, Clone)]
 pub struct PreviousState {
     pub nodes: Option<Nodes>,
+    pub macros: Option<BTreeMap<String, DbtMacro>>,
     pub run_results: Option<RunResultsArtifact>,
     pub source_freshness_results: Option<FreshnessResultsArtifact>,
     pub state_path: PathBuf,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ModificationType {
     Body,
     Configs,
@@ -26,6 +40,38 @@ pub enum ModificationType {
     Any,
 }
 
+impl FromStr for ModificationType {
+    type Err = ();
+
+    /// Parses the sub-selector of `state:modified.<type>`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "body" => Ok(Self::Body),
+            "configs" => Ok(Self::Configs),
+            "relation" => Ok(Self::Relation),
+            "persisted_descriptions" => Ok(Self::PersistedDescriptions),
+            "macros" => Ok(Self::Macros),
+            "contract" => Ok(Self::Contract),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for ModificationType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Body => "body",
+            Self::Configs => "configs",
+            Self::Relation => "relation",
+            Self::PersistedDescriptions => "persisted_descriptions",
+            Self::Macros => "macros",
+            Self::Contract => "contract",
+            Self::Any => "any",
+        };
+        write!(f, "{s}")
+    }
+}
+
 impl fmt::Display for PreviousState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "PreviousState from {}", self.state_path.display())
@@ -34,7 +80,7 @@ impl fmt::Display for PreviousState {
 impl PreviousState {
     pub fn try_new(state_path: &Path, root_project_quoting: ResolvedQuoting) -> FsResult<Self> {
         // Try to load manifest.json, but make it optional
-        let nodes = if let Ok(manifest) =
+        let (nodes, macros) = if let Ok(manifest) =
             typed_struct_from_json_file::<DbtManifest>(&state_path.join(DBT_MANIFEST_JSON))
         {
             let dbt_quoting = DbtQuoting {
@@ -49,13 +95,18 @@ impl PreviousState {
             } else {
                 dbt_quoting
             };
-            Some(nodes_from_dbt_manifest(manifest, quoting))
+            let macros = manifest.macros.clone();
+            (
+                Some(nodes_from_dbt_manifest(manifest, quoting)),
+                Some(macros),
+            )
         } else {
-            None
+            (None, None)
         };
 
         Ok(Self {
             nodes,
+            macros,
             run_results: RunResultsArtifact::from_file(&state_path.join("run_results.json")).ok(),
             source_freshness_results: typed_struct_from_json_file(&state_path.join("sources.json"))
                 .ok(),
@@ -80,11 +131,31 @@ impl PreviousState {
         !self.exists(node)
     }
 
-    // Check if a node has been modified, optionally checking for a specific type of modification
+    // The status of each node of the previous run_results.json, by unique_id
+    pub fn run_result_statuses(&self) -> HashMap<&str, &str> {
+        self.run_results
+            .iter()
+            .flat_map(|run_results| &run_results.results)
+            .map(|result| (result.unique_id.as_str(), result.status.as_str()))
+            .collect()
+    }
+
+    // The results of the previous freshness check of the sources, from sources.json
+    pub fn source_freshness_by_unique_id(&self) -> HashMap<&str, &FreshnessResultsNode> {
+        self.source_freshness_results
+            .as_ref()
+            .map(FreshnessResultsArtifact::results_by_unique_id)
+            .unwrap_or_default()
+    }
+
+    // Check if a node has been modified, optionally checking for a specific type of modification.
+    // `current_macros` are the macros of the current project, used to detect changes in the
+    // (transitive) macro dependencies of the node.
     pub fn is_modified(
         &self,
         node: &dyn InternalDbtNode,
         modification_type: Option<ModificationType>,
+        current_macros: &BTreeMap<String, DbtMacro>,
     ) -> bool {
         // If it's new, it's also considered modified
         if self.is_new(node) {
@@ -95,32 +166,34 @@ impl PreviousState {
             // TODO chenyu: confirm with product if it is okay to merge the body check with content check
             Some(ModificationType::Body) => self.check_modified_content(node),
             Some(ModificationType::Configs) => self.check_configs_modified(node),
-            // Some(ModificationType::Relation) => self.check_relation_modified(node, unique_id),
-            // Some(ModificationType::PersistedDescriptions) => {
-            //     self.check_persisted_descriptions_modified(node, unique_id)
-            // }
-            // Some(ModificationType::Macros) => self.check_macros_modified(node, unique_id),
-            // Some(ModificationType::Contract) => self.check_contract_modified(node, unique_id),
-            // Some(ModificationType::Any) | None => {
-            //     self.check_body_modified(node, unique_id)
-            //         || self.check_configs_modified(node, unique_id)
-            //         || self.check_relation_modified(node, unique_id)
-            //         || self.check_persisted_descriptions_modified(node, unique_id)
-            //         || self.check_macros_modified(node, unique_id)
-            //         || self.check_contract_modified(node, unique_id)
-            // }
-            _ => self.check_modified_content(node),
+            Some(ModificationType::Relation) => self.check_relation_modified(node),
+            Some(ModificationType::PersistedDescriptions) => {
+                self.check_persisted_descriptions_modified(node)
+            }
+            Some(ModificationType::Macros) => self.check_macros_modified(node, current_macros),
+            Some(ModificationType::Contract) => self.check_contract_modified(node),
+            Some(ModificationType::Any) | None => {
+                self.check_modified_content(node)
+                    || self.check_configs_modified(node)
+                    || self.check_relation_modified(node)
+                    || self.check_persisted_descriptions_modified(node)
+                    || self.check_macros_modified(node, current_macros)
+                    || self.check_contract_modified(node)
+            }
         }
     }
 
+    fn previous_node(&self, current_node: &dyn InternalDbtNode) -> Option<&dyn InternalDbtNode> {
+        self.nodes
+            .as_ref()
+            .and_then(|nodes| nodes.get_node(current_node.common().unique_id.as_str()))
+            .map(|node| node as &dyn InternalDbtNode)
+    }
+
     // Private helper methods to check specific types of modifications
     fn check_modified_content(&self, current_node: &dyn InternalDbtNode) -> bool {
         // Get the previous node from the manifest
-        let previous_node = match self
-            .nodes
-            .as_ref()
-            .and_then(|nodes| nodes.get_node(current_node.common().unique_id.as_str()))
-        {
+        let previous_node = match self.previous_node(current_node) {
             Some(node) => node,
             // TODO test is currently ignored in the state selector because fusion generate test name different from dbt-mantle.
             None => return !current_node.is_test(), // If previous node doesn't exist, consider it modified
@@ -131,14 +204,109 @@ impl PreviousState {
 
     fn check_configs_modified(&self, current_node: &dyn InternalDbtNode) -> bool {
         // Get the previous node from the manifest
-        let previous_node = match self
-            .nodes
-            .as_ref()
-            .and_then(|nodes| nodes.get_node(current_node.common().unique_id.as_str()))
-        {
+        let previous_node = match self.previous_node(current_node) {
             Some(node) => node,
-            None => return true, // If previous node doesn't exist, consider it modified
+            None => return !current_node.is_test(), // If previous node doesn't exist, consider it modified
+        };
+        !current_node.has_same_config(previous_node)
+    }
+
+    fn check_relation_modified(&self, current_node: &dyn InternalDbtNode) -> bool {
+        let Some(previous_node) = self.previous_node(current_node) else {
+            return !current_node.is_test();
+        };
+        let (current, previous) = (current_node.base(), previous_node.base());
+        current.database != previous.database
+            || current.schema != previous.schema
+            || current.alias != previous.alias
+    }
+
+    fn check_persisted_descriptions_modified(&self, current_node: &dyn InternalDbtNode) -> bool {
+        let Some(previous_node) = self.previous_node(current_node) else {
+            return !current_node.is_test();
+        };
+        let Some(persist_docs) = &current_node.base().persist_docs else {
+            return false;
+        };
+        if persist_docs.relation.unwrap_or(false)
+            && current_node.common().description != previous_node.common().description
+        {
+            return true;
+        }
+        if persist_docs.columns.unwrap_or(false) {
+            let column_descriptions = |node: &dyn InternalDbtNode| {
+                node.base()
+                    .columns
+                    .iter()
+                    .map(|column| (column.name.clone(), column.description.clone()))
+                    .collect::<BTreeMap<_, _>>()
+            };
+            return column_descriptions(current_node) != column_descriptions(previous_node);
+        }
+        false
+    }
+
+    /// A node's macros are modified if any macro it (transitively) depends on
+    /// was added or has a different body than in the previous state.
+    fn check_macros_modified(
+        &self,
+        current_node: &dyn InternalDbtNode,
+        current_macros: &BTreeMap<String, DbtMacro>,
+    ) -> bool {
+        let Some(previous_macros) = &self.macros else {
+            return false;
+        };
+        let mut visited = BTreeSet::new();
+        let mut stack = current_node.base().depends_on.macros.clone();
+        while let Some(macro_id) = stack.pop() {
+            if !visited.insert(macro_id.clone()) {
+                continue;
+            }
+            let Some(current_macro) = current_macros.get(&macro_id) else {
+                continue;
+            };
+            match previous_macros.get(&macro_id) {
+                Some(previous_macro) if previous_macro.macro_sql == current_macro.macro_sql => {}
+                _ => return true,
+            }
+            stack.extend(current_macro.depends_on.macros.iter().cloned());
+        }
+        false
+    }
+
+    /// A contract is modified if its enforcement changed, or if it is enforced
+    /// and the name or data type of any column changed.
+    fn check_contract_modified(&self, current_node: &dyn InternalDbtNode) -> bool {
+        let Some(current_model) = current_node.as_any().downcast_ref::<DbtModel>() else {
+            return false;
+        };
+        let Some(previous_model) = self
+            .previous_node(current_node)
+            .and_then(|node| node.as_any().downcast_ref::<DbtModel>())
+        else {
+            return true;
+        };
+        let enforced = |model: &DbtModel| {
+            model
+                .__model_attr__
+                .contract
+                .as_ref()
+                .is_some_and(|contract| contract.enforced)
+        };
+        if enforced(current_model) != enforced(previous_model) {
+            return true;
+        }
+        if !enforced(current_model) {
+            return false;
+        }
+        let column_types = |model: &DbtModel| {
+            model
+                .__base_attr__
+                .columns
+                .iter()
+                .map(|column| (column.name.clone(), column.data_type.clone()))
+                .collect::<BTreeMap<_, _>>()
         };
-        current_node.has_same_config(previous_node)
+        column_types(current_model) != column_types(previous_model)
     }
 }
@@ -9,10 +9,10 @@ use dbt_serde_yaml::UntaggedEnumDeserialize;
 use merge::Merge;
 use serde_derive::Deserialize;
 use serde_derive::Serialize;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::convert::TryFrom;
 use std::fmt::{self, Debug, Display};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 type YmlValue = dbt_serde_yaml::Value;
 
@@ -47,6 +47,8 @@ pub enum DbConfig {
     // Spark,
     Databricks(Box<DatabricksDbConfig>),
     Salesforce(Box<SalesforceDbConfig>),
+    DuckDB(Box<DuckDBDbConfig>),
+    Generic(Box<GenericDbConfig>),
     // Hive,
     // Exasol,
     // Oracle,
@@ -69,7 +71,6 @@ pub enum DbConfig {
     // #[serde(rename = "layer_bigquery")]
     // LayerBigquery,
     // Iomete,
-    // DuckDB,
     // SQLite,
     // MySQL,
     // IBMDB2,
@@ -100,6 +101,8 @@ impl_from_db_config!(Bigquery, BigqueryDbConfig);
 impl_from_db_config!(Trino, TrinoDbConfig);
 impl_from_db_config!(Datafusion, DatafusionDbConfig);
 impl_from_db_config!(Databricks, DatabricksDbConfig);
+impl_from_db_config!(DuckDB, DuckDBDbConfig);
+impl_from_db_config!(Generic, GenericDbConfig);
 
 impl DbConfig {
     pub fn get_unique_field(&self) -> Option<&String> {
@@ -112,6 +115,8 @@ impl DbConfig {
             DbConfig::Redshift(config) => config.host.as_ref(),
             DbConfig::Databricks(config) => config.host.as_ref(),
             DbConfig::Salesforce(config) => config.client_id.as_ref(),
+            DbConfig::DuckDB(config) => config.path.as_ref(),
+            DbConfig::Generic(config) => config.uri.as_ref().or(config.driver.as_ref()),
         }
     }
 
@@ -214,8 +219,10 @@ impl DbConfig {
             DbConfig::Databricks(_) => &["host", "http_path", "schema"],
             // TODO: Salesforce connection keys
             DbConfig::Salesforce(_) => &["login_url", "database", "data_transform_run_timeout"],
-            // TODO: Trino and Datafusion connection keys
-            DbConfig::Trino(_) => &[],
+            DbConfig::DuckDB(_) => &["path", "database", "schema", "extensions", "attach"],
+            DbConfig::Generic(_) => &["driver", "uri", "database", "schema"],
+            DbConfig::Trino(_) => &["host", "port", "user", "database", "schema", "method"],
+            // TODO: Datafusion connection keys
             DbConfig::Datafusion(_) => &[],
         }
     }
@@ -249,6 +256,8 @@ impl DbConfig {
             DbConfig::Redshift(config) => dbt_serde_yaml::to_value(config),
             DbConfig::Databricks(config) => dbt_serde_yaml::to_value(config),
             DbConfig::Salesforce(config) => dbt_serde_yaml::to_value(config),
+            DbConfig::DuckDB(config) => dbt_serde_yaml::to_value(config),
+            DbConfig::Generic(config) => dbt_serde_yaml::to_value(config),
         }
     }
 
@@ -263,6 +272,8 @@ impl DbConfig {
             DbConfig::Datafusion(..) => "datafusion",
             DbConfig::Databricks(..) => "databricks",
             DbConfig::Salesforce(..) => "salesforce",
+            DbConfig::DuckDB(..) => "duckdb",
+            DbConfig::Generic(..) => "generic",
         }
     }
 
@@ -272,10 +283,24 @@ impl DbConfig {
             DbConfig::Snowflake(..) => Some(AdapterType::Snowflake),
             DbConfig::Postgres(..) => Some(AdapterType::Postgres),
             DbConfig::Bigquery(..) => Some(AdapterType::Bigquery),
-            DbConfig::Trino(..) => None,
+            DbConfig::Trino(..) => Some(AdapterType::Trino),
             DbConfig::Datafusion(..) => None,
             DbConfig::Databricks(..) => Some(AdapterType::Databricks),
             DbConfig::Salesforce(..) => Some(AdapterType::Salesforce),
+            DbConfig::DuckDB(..) => Some(AdapterType::DuckDB),
+            DbConfig::Generic(..) => Some(AdapterType::Generic),
+        }
+    }
+
+    /// The identifier quote character declared by the dialect of a generic
+    /// profile; other adapters quote as their relation types do
+    pub fn quote_character(&self) -> Option<char> {
+        match self {
+            DbConfig::Generic(config) => config
+                .dialect
+                .as_ref()
+                .map(|dialect| dialect.quote_character()),
+            _ => None,
         }
     }
 
@@ -289,6 +314,8 @@ impl DbConfig {
             DbConfig::Datafusion(config) => config.database.as_ref(),
             DbConfig::Databricks(config) => config.database.as_ref(),
             DbConfig::Salesforce(config) => config.database.as_ref(),
+            DbConfig::DuckDB(config) => config.database.as_ref(),
+            DbConfig::Generic(config) => config.database.as_ref(),
         }
     }
 
@@ -302,6 +329,8 @@ impl DbConfig {
             DbConfig::Datafusion(config) => config.schema.as_ref(),
             DbConfig::Databricks(config) => config.schema.as_ref(),
             DbConfig::Salesforce(_) => None,
+            DbConfig::DuckDB(config) => config.schema.as_ref(),
+            DbConfig::Generic(config) => config.schema.as_ref(),
         }
     }
 
@@ -315,6 +344,8 @@ impl DbConfig {
             DbConfig::Trino(config) => config.threads.as_ref(),
             DbConfig::Datafusion(_) => None,
             DbConfig::Salesforce(_) => None,
+            DbConfig::DuckDB(config) => config.threads.as_ref(),
+            DbConfig::Generic(config) => config.threads.as_ref(),
         }
     }
 
@@ -328,6 +359,8 @@ impl DbConfig {
             DbConfig::Redshift(config) => config.threads = threads,
             DbConfig::Datafusion(_) => (),
             DbConfig::Salesforce(_) => (),
+            DbConfig::DuckDB(config) => config.threads = threads,
+            DbConfig::Generic(config) => config.threads = threads,
         }
     }
 
@@ -447,6 +480,13 @@ pub struct RedshiftDbConfig {
     pub autocommit: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub retries: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_retries: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_retry_backoff: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[merge(strategy = merge_strategies_extend::overwrite_always)]
+    pub connection_pool: Option<ConnectionPoolConfig>,
     // Authentication Parameters (Password)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub method: Option<String>,
@@ -498,6 +538,13 @@ pub struct SnowflakeDbConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub connect_retries: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_retries: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_retry_backoff: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[merge(strategy = merge_strategies_extend::overwrite_always)]
+    pub connection_pool: Option<ConnectionPoolConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub connect_timeout: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub request_timeout: Option<i64>,
@@ -566,6 +613,13 @@ pub struct PostgresDbConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub retries: Option<StringOrInteger>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_retries: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_retry_backoff: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[merge(strategy = merge_strategies_extend::overwrite_always)]
+    pub connection_pool: Option<ConnectionPoolConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub search_path: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub role: Option<String>,
@@ -577,6 +631,13 @@ pub struct PostgresDbConfig {
     pub sslkey: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sslrootcert: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connect_timeout: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_name: Option<String>,
+    /// Command-line options sent to the server at connection start, as in libpq
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<String>,
     // Authentication Parameters (Password)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub method: Option<String>,
@@ -627,6 +688,13 @@ pub struct BigqueryDbConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub retries: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_retries: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_retry_backoff: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[merge(strategy = merge_strategies_extend::overwrite_always)]
+    pub connection_pool: Option<ConnectionPoolConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub location: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scopes: Option<Vec<String>>,
@@ -656,7 +724,7 @@ pub struct BigqueryDbConfig {
     pub target_name: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, Merge)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default, JsonSchema, Merge)]
 #[merge(strategy = merge_strategies_extend::overwrite_option)]
 #[serde(rename_all = "snake_case")]
 pub struct TrinoDbConfig {
@@ -664,6 +732,7 @@ pub struct TrinoDbConfig {
     pub port: Option<StringOrInteger>, // Setting as Option but required as of dbt 1.7.1
     pub user: Option<String>,          // Setting as Option but required as of dbt 1.7.1
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "catalog")]
     pub database: Option<String>,
     pub host: Option<String>, // Setting as Option but required as of dbt 1.7.1
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -674,6 +743,37 @@ pub struct TrinoDbConfig {
     pub password: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub role: Option<String>,
+    /// `none`, `ldap` (user and password), `jwt` or `certificate`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    /// `http` or `https`, defaults to `https` unless `method` is `none`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_scheme: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jwt_token: Option<String>,
+    /// Path of the PEM client certificate for the `certificate` method
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_certificate: Option<String>,
+    /// Path of the PEM private key for the `certificate` method
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_private_key: Option<String>,
+    /// Path of the PEM bundle of certificate authorities trusted for TLS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cert: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[merge(strategy = merge_strategies_extend::overwrite_always)]
+    pub session_properties: Option<BTreeMap<String, YmlValue>>,
+    /// Catalog roles, keyed by catalog name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[merge(strategy = merge_strategies_extend::overwrite_always)]
+    pub roles: Option<BTreeMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_retries: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_retry_backoff: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[merge(strategy = merge_strategies_extend::overwrite_always)]
+    pub connection_pool: Option<ConnectionPoolConfig>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, Merge)]
@@ -732,6 +832,13 @@ pub struct DatabricksDbConfig {
     pub connect_max_idle: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub threads: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_retries: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_retry_backoff: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[merge(strategy = merge_strategies_extend::overwrite_always)]
+    pub connection_pool: Option<ConnectionPoolConfig>,
 }
 
 fn default_databricks_database() -> Option<String> {
@@ -765,6 +872,180 @@ fn default_data_transform_run_timeout() -> Option<i64> {
     Some(180000) // 3 mins
 }
 
+/// The path of an in-memory DuckDB database
+pub const DUCKDB_IN_MEMORY_PATH: &str = ":memory:";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default, JsonSchema, Merge)]
+#[merge(strategy = merge_strategies_extend::overwrite_option)]
+#[serde(rename_all = "snake_case")]
+pub struct DuckDBDbConfig {
+    /// Path of the database file, `:memory:` for an in-memory database
+    #[serde(default = "default_duckdb_path")]
+    pub path: Option<String>,
+    /// Defaults to the file stem of `path` (`memory` for in-memory databases)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database: Option<String>,
+    #[serde(default = "default_duckdb_schema")]
+    pub schema: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threads: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_retries: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_retry_backoff: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[merge(strategy = merge_strategies_extend::overwrite_always)]
+    pub connection_pool: Option<ConnectionPoolConfig>,
+    /// Extensions installed and loaded on every connection, e.g. `httpfs`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Vec<String>>,
+    /// Additional databases attached on every connection
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attach: Option<Vec<DuckDBAttachment>>,
+    /// Settings applied with `SET` on every connection, e.g. `memory_limit`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[merge(strategy = merge_strategies_extend::overwrite_always)]
+    pub settings: Option<HashMap<String, YmlValue>>,
+}
+
+impl DuckDBDbConfig {
+    /// The catalog name DuckDB gives to the database at `path`
+    pub fn default_database(&self) -> String {
+        match self.path.as_deref() {
+            None | Some("") | Some(DUCKDB_IN_MEMORY_PATH) => "memory".to_string(),
+            Some(path) => Path::new(path)
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_else(|| "memory".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct DuckDBAttachment {
+    pub path: String,
+    /// Defaults to the file stem of `path`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    /// Storage type of the attached database, e.g. `sqlite` or `postgres`
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+}
+
+/// Connection pool settings of a target, see `dbt_xdbc::pool::PoolConfig`
+///
+/// Durations are in seconds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ConnectionPoolConfig {
+    /// Connections opened up front and kept open even when idle, 0 by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_size: Option<u32>,
+    /// Maximum number of open connections, unbounded by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_size: Option<u32>,
+    /// Query checking that an idle connection is alive before it is reused,
+    /// `select 1` by default. An empty string disables the check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation_query: Option<String>,
+    /// Idle connections beyond `min_size` are closed after this long, 600 by
+    /// default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idle_timeout: Option<f64>,
+    /// Connections are closed once they have been open for this long
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_lifetime: Option<f64>,
+    /// How long to wait for a connection when `max_size` are in use, 300 by
+    /// default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checkout_timeout: Option<f64>,
+    /// Replace connections whose session was lost (e.g. broken pipe) before
+    /// retrying, true by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reconnect: Option<bool>,
+}
+
+fn default_duckdb_path() -> Option<String> {
+    Some(DUCKDB_IN_MEMORY_PATH.to_string())
+}
+
+fn default_duckdb_schema() -> Option<String> {
+    Some("main".to_string())
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default, JsonSchema, Merge)]
+#[merge(strategy = merge_strategies_extend::overwrite_option)]
+#[serde(rename_all = "snake_case")]
+pub struct GenericDbConfig {
+    /// Name of the ADBC driver library without prefix or suffix, e.g.
+    /// `adbc_driver_sqlite`
+    pub driver: Option<String>,
+    /// Entrypoint of the driver library, if it is not `AdbcDriverInit`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entrypoint: Option<String>,
+    /// Passed to the driver as the `uri` database option
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+    /// Other database options, passed to the driver as they are
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[merge(strategy = merge_strategies_extend::overwrite_always)]
+    pub driver_options: Option<BTreeMap<String, YmlValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database: Option<String>,
+    pub schema: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threads: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_retries: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_retry_backoff: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[merge(strategy = merge_strategies_extend::overwrite_always)]
+    pub connection_pool: Option<ConnectionPoolConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[merge(strategy = merge_strategies_extend::overwrite_always)]
+    pub dialect: Option<GenericDialect>,
+}
+
+/// Declarative description of the SQL dialect spoken by a generic ADBC driver
+///
+/// In the metadata queries, `{database}`, `{schema}` and `{identifier}` are
+/// replaced by the corresponding names, with single quotes doubled so that
+/// they can be used inside string literals.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct GenericDialect {
+    /// Character used to quote identifiers, `"` by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_character: Option<char>,
+    /// SQL type names for the types dbt creates columns with, keyed by
+    /// `integer`, `float`, `decimal`, `boolean`, `timestamp`, `date`, `time`
+    /// and `text`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub types: Option<BTreeMap<String, String>>,
+    /// Query listing the relations of `{database}`.`{schema}`, returning the
+    /// columns `database`, `schema`, `name` and `type` (`table` or `view`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub list_relations_sql: Option<String>,
+    /// Query listing the columns of `{database}`.`{schema}`.`{identifier}`,
+    /// returning `column_name` and `data_type` in column order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub list_columns_sql: Option<String>,
+    /// Query listing the schemas of `{database}`, returning `schema_name`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub list_schemas_sql: Option<String>,
+}
+
+impl GenericDialect {
+    /// The quote character for identifiers
+    pub fn quote_character(&self) -> char {
+        self.quote_character.unwrap_or('"')
+    }
+}
+
 #[derive(Serialize, JsonSchema)]
 #[serde(untagged)]
 #[serde(rename_all = "snake_case")]
@@ -778,6 +1059,8 @@ pub enum TargetContext {
     Databricks(DatabricksTargetEnv),
     Redshift(RedshiftTargetEnv),
     Salesforce(SalesforceTargetEnv),
+    DuckDB(DuckDBTargetEnv),
+    Generic(GenericTargetEnv),
     // Add other variants as needed
 }
 
@@ -921,6 +1204,20 @@ pub struct SalesforceTargetEnv {
     pub __common__: CommonTargetContext,
 }
 
+#[derive(Serialize, JsonSchema)]
+pub struct DuckDBTargetEnv {
+    pub path: String,
+    pub extensions: Vec<String>,
+    pub __common__: CommonTargetContext,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct GenericTargetEnv {
+    pub driver: String,
+    pub dialect: GenericDialect,
+    pub __common__: CommonTargetContext,
+}
+
 fn missing(field: &str) -> String {
     format!("In file `profiles.yml`, field `{field}` is required.")
 }
@@ -1134,6 +1431,36 @@ impl TryFrom<DbConfig> for TargetContext {
                     threads: None,
                 },
             })),
+
+            DbConfig::DuckDB(config) => {
+                let database = config
+                    .database
+                    .clone()
+                    .unwrap_or_else(|| config.default_database());
+                Ok(TargetContext::DuckDB(DuckDBTargetEnv {
+                    path: config
+                        .path
+                        .unwrap_or_else(|| DUCKDB_IN_MEMORY_PATH.to_string()),
+                    extensions: config.extensions.unwrap_or_default(),
+                    __common__: CommonTargetContext {
+                        database,
+                        schema: config.schema.ok_or_else(|| missing("schema"))?,
+                        type_: adapter_type,
+                        threads: None,
+                    },
+                }))
+            }
+
+            DbConfig::Generic(config) => Ok(TargetContext::Generic(GenericTargetEnv {
+                driver: config.driver.ok_or_else(|| missing("driver"))?,
+                dialect: config.dialect.unwrap_or_default(),
+                __common__: CommonTargetContext {
+                    database: config.database.ok_or_else(|| missing("database"))?,
+                    schema: config.schema.ok_or_else(|| missing("schema"))?,
+                    type_: adapter_type,
+                    threads: None,
+                },
+            })),
         }
     }
 }
@@ -1189,4 +1516,118 @@ mod tests {
             panic!("Expected DbConfig::Bigquery, got {config:?}",);
         }
     }
+
+    #[test]
+    fn test_duckdb_adapter_config_parsing() {
+        let config: DbConfig = dbt_serde_yaml::from_str(
+            "type: duckdb\n\
+             path: /tmp/jaffle_shop.duckdb\n\
+             extensions: [httpfs, parquet]\n\
+             attach:\n  - path: /tmp/raw.sqlite\n    type: sqlite\n    read_only: true\n\
+             settings:\n  memory_limit: 4GB",
+        )
+        .unwrap();
+        assert_eq!(
+            config.adapter_type_if_supported(),
+            Some(AdapterType::DuckDB)
+        );
+        assert_eq!(config.get_schema().map(String::as_str), Some("main"));
+        let duckdb_config = match config {
+            DbConfig::DuckDB(duckdb_config) => duckdb_config,
+            config => panic!("Expected DbConfig::DuckDB, got {config:?}"),
+        };
+        assert_eq!(duckdb_config.default_database(), "jaffle_shop");
+        assert_eq!(
+            duckdb_config.extensions,
+            Some(vec!["httpfs".to_string(), "parquet".to_string()])
+        );
+        let attach = duckdb_config.attach.as_ref().unwrap();
+        assert_eq!(attach[0].type_.as_deref(), Some("sqlite"));
+        assert_eq!(attach[0].read_only, Some(true));
+        assert!(duckdb_config.settings.unwrap().contains_key("memory_limit"));
+
+        let in_memory: DbConfig = dbt_serde_yaml::from_str("type: duckdb").unwrap();
+        let in_memory = match in_memory {
+            DbConfig::DuckDB(in_memory) => in_memory,
+            config => panic!("Expected DbConfig::DuckDB, got {config:?}"),
+        };
+        assert_eq!(in_memory.path.as_deref(), Some(DUCKDB_IN_MEMORY_PATH));
+        assert_eq!(in_memory.default_database(), "memory");
+    }
+
+    #[test]
+    fn test_trino_adapter_config_parsing() {
+        let config: DbConfig = dbt_serde_yaml::from_str(
+            "type: trino\n\
+             method: jwt\n\
+             host: trino.example.com\n\
+             port: 443\n\
+             user: dbt\n\
+             catalog: hive\n\
+             schema: analytics\n\
+             jwt_token: secret\n\
+             session_properties:\n  query_max_run_time: 4h\n\
+             roles:\n  hive: admin",
+        )
+        .unwrap();
+        assert_eq!(config.adapter_type_if_supported(), Some(AdapterType::Trino));
+        assert_eq!(config.get_schema().map(String::as_str), Some("analytics"));
+        let trino_config = match config {
+            DbConfig::Trino(trino_config) => trino_config,
+            config => panic!("Expected DbConfig::Trino, got {config:?}"),
+        };
+        assert_eq!(trino_config.database.as_deref(), Some("hive"));
+        assert_eq!(trino_config.method.as_deref(), Some("jwt"));
+        assert_eq!(
+            trino_config.roles.unwrap().get("hive").map(String::as_str),
+            Some("admin")
+        );
+        assert!(
+            trino_config
+                .session_properties
+                .unwrap()
+                .contains_key("query_max_run_time")
+        );
+    }
+
+    #[test]
+    fn test_generic_adapter_config_parsing() {
+        let config: DbConfig = dbt_serde_yaml::from_str(
+            "type: generic\n\
+             driver: adbc_driver_sqlite\n\
+             uri: ':memory:'\n\
+             database: main\n\
+             schema: main\n\
+             driver_options:\n  adbc.sqlite.query.batch_rows: 1024\n\
+             dialect:\n  quote_character: '`'\n  types:\n    text: varchar\n\
+             \x20 list_relations_sql: select name from sqlite_master where '{schema}' = 'main'",
+        )
+        .unwrap();
+        assert_eq!(
+            config.adapter_type_if_supported(),
+            Some(AdapterType::Generic)
+        );
+        assert_eq!(
+            config.get_unique_field().map(String::as_str),
+            Some(":memory:")
+        );
+        let generic_config = match config {
+            DbConfig::Generic(generic_config) => generic_config,
+            config => panic!("Expected DbConfig::Generic, got {config:?}"),
+        };
+        let dialect = generic_config.dialect.unwrap();
+        assert_eq!(dialect.quote_character(), '`');
+        assert_eq!(
+            dialect.types.unwrap().get("text").map(String::as_str),
+            Some("varchar")
+        );
+        assert!(dialect.list_relations_sql.unwrap().contains("'{schema}'"));
+        assert!(
+            generic_config
+                .driver_options
+                .unwrap()
+                .contains_key("adbc.sqlite.query.batch_rows")
+        );
+        assert_eq!(GenericDialect::default().quote_character(), '"');
+    }
 }
@@ -1,7 +1,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 
 use super::{
     TimingInfo,
@@ -28,15 +28,25 @@ pub struct FreshnessResultsMetadata {
 #[serde(rename_all = "snake_case")]
 pub struct FreshnessResultsNode {
     pub unique_id: String,
+    // Results with a `runtime error` status only carry the unique_id, status and error
+    #[serde(default)]
     pub max_loaded_at: DateTime<Utc>,
+    #[serde(default)]
     pub snapshotted_at: DateTime<Utc>,
+    #[serde(default)]
     pub max_loaded_at_time_ago_in_s: f64,
     pub status: FreshnessStatus,
+    #[serde(default)]
     pub criteria: FreshnessDefinition,
+    #[serde(default)]
     pub adapter_response: BTreeMap<String, String>,
+    #[serde(default)]
     pub timing: Vec<TimingInfo>,
+    #[serde(default)]
     pub thread_id: String,
+    #[serde(default)]
     pub execution_time: f64,
+    pub error: Option<String>,
 }
 
 /// Represents the structure of the sources.json artifact.
@@ -51,3 +61,13 @@ pub struct FreshnessResultsArtifact {
     /// Total elapsed time for the entire dbt invocation in seconds.
     pub elapsed_time: f64,
 }
+
+impl FreshnessResultsArtifact {
+    /// The freshness results of the sources, by unique_id
+    pub fn results_by_unique_id(&self) -> HashMap<&str, &FreshnessResultsNode> {
+        self.results
+            .iter()
+            .map(|result| (result.unique_id.as_str(), result))
+            .collect()
+    }
+}
@@ -909,7 +909,7 @@ impl Object for VarProvider {
     }
 }
 /// Represents the status of a model
-#[derive(Debug, Clone, PartialEq, Copy)]
+#[derive(Debug, Clone, PartialEq, Copy, Serialize, Deserialize)]
 pub enum ModelStatus {
     /// Model is enabled and successfully parsed
     Enabled,
@@ -7,9 +7,9 @@ use super::traits::AnyTelemetryEvent;
 use crate::{
     attributes::traits::ArrowSerializableTelemetryEvent,
     schemas::{
-        ArtifactWritten, CallTrace, CompiledCodeInline, Invocation, ListItemOutput, LogMessage,
-        NodeEvaluated, OnboardingScreenShown, PhaseExecuted, Process, QueryExecuted,
-        ShowDataOutput, Unknown,
+        ArtifactWritten, CallTrace, CompiledCodeInline, ConnectionPoolStats, Invocation,
+        ListItemOutput, LogMessage, NodeEvaluated, OnboardingScreenShown, PhaseExecuted, Process,
+        QueryExecuted, QueryRetried, ShowDataOutput, Unknown,
     },
     serialize::arrow::ArrowAttributes,
 };
@@ -233,6 +233,18 @@ static PUBLIC_TELEMETRY_EVENT_REGISTRY: LazyLock<TelemetryEventTypeRegistry> = L
             #[cfg(any(test, feature = "test-utils"))]
             faker_for_type::<ListItemOutput>,
         );
+        registry.register(
+            QueryRetried::FULL_NAME,
+            arrow_deserialize_for_type::<QueryRetried>,
+            #[cfg(any(test, feature = "test-utils"))]
+            faker_for_type::<QueryRetried>,
+        );
+        registry.register(
+            ConnectionPoolStats::FULL_NAME,
+            arrow_deserialize_for_type::<ConnectionPoolStats>,
+            #[cfg(any(test, feature = "test-utils"))]
+            faker_for_type::<ConnectionPoolStats>,
+        );
         registry.register(
             ShowDataOutput::FULL_NAME,
             arrow_deserialize_for_type::<ShowDataOutput>,
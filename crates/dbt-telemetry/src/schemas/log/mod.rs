@@ -2,10 +2,12 @@
 pub(crate) mod artifact;
 pub(crate) mod list_item;
 pub(crate) mod log_message;
+pub(crate) mod query;
 pub(crate) mod show_data;
 
 // Re-export all schemas from proto_rust directly for the outside world
 pub use artifact::*;
 pub use list_item::*;
 pub use log_message::*;
+pub use query::*;
 pub use show_data::*;
@@ -0,0 +1,208 @@
+use prost::Name as _;
+pub use proto_rust::v1::public::events::fusion::query::{ConnectionPoolStats, QueryRetried};
+use serde_with::skip_serializing_none;
+use std::borrow::Cow;
+
+use crate::{
+    ArrowSerializableTelemetryEvent, ProtoTelemetryEvent, TelemetryContext, TelemetryEventRecType,
+    TelemetryOutputFlags, serialize::arrow::ArrowAttributes,
+};
+
+impl ProtoTelemetryEvent for QueryRetried {
+    const RECORD_CATEGORY: TelemetryEventRecType = TelemetryEventRecType::Log;
+    const OUTPUT_FLAGS: TelemetryOutputFlags = TelemetryOutputFlags::ALL;
+
+    fn event_display_name(&self) -> String {
+        format!("Query retried ({}/{})", self.retry, self.max_retries)
+    }
+
+    fn has_sensitive_data(&self) -> bool {
+        // Only the hash of the SQL is carried, never the SQL itself.
+        false
+    }
+
+    fn with_context(&mut self, context: &TelemetryContext) {
+        // Inject phase if not set and provided by context
+        if self.phase.is_none()
+            && let Some(p) = context.phase
+        {
+            self.phase = Some(p as i32);
+        }
+    }
+}
+
+/// Internal struct used for serializing/deserializing subset of
+/// QueryRetried fields as JSON payload in ArrowAttributes.
+#[skip_serializing_none]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Debug)]
+struct QueryRetriedJsonPayload {
+    retry: u32,
+    max_retries: u32,
+    backoff_ms: u32,
+    query_error_adapter_message: Option<String>,
+}
+
+impl ArrowSerializableTelemetryEvent for QueryRetried {
+    fn to_arrow_record(&self) -> ArrowAttributes<'_> {
+        ArrowAttributes {
+            // Well-known fields for easier querying
+            content_hash: Some(Cow::from(self.sql_hash.as_str())),
+            adapter_type: Some(Cow::from(self.adapter_type.as_str())),
+            unique_id: self.unique_id.as_deref().map(Cow::Borrowed),
+            phase: self.phase.map(|_| self.phase()),
+            query_error_vendor_code: self
+                .query_error_vendor_code
+                .map(|_| self.query_error_vendor_code()),
+            // The rest of the data is serialized as JSON payload
+            json_payload: serde_json::to_string(&QueryRetriedJsonPayload {
+                retry: self.retry,
+                max_retries: self.max_retries,
+                backoff_ms: self.backoff_ms,
+                query_error_adapter_message: self.query_error_adapter_message.clone(),
+            })
+            .unwrap_or_else(|_| {
+                panic!(
+                    "Failed to serialize data in event type \"{}\" to JSON",
+                    Self::full_name()
+                )
+            })
+            .into(),
+            ..Default::default()
+        }
+    }
+
+    fn from_arrow_record(record: &ArrowAttributes) -> Result<Self, String> {
+        let json_payload: QueryRetriedJsonPayload =
+            serde_json::from_str(record.json_payload.as_ref().ok_or_else(|| {
+                format!(
+                    "Missing json payload for event type \"{}\"",
+                    Self::full_name()
+                )
+            })?)
+            .map_err(|e| {
+                format!(
+                    "Failed to deserialize data of event type \"{}\" from JSON payload: {}",
+                    Self::full_name(),
+                    e
+                )
+            })?;
+
+        Ok(Self {
+            sql_hash: record
+                .content_hash
+                .as_deref()
+                .map(str::to_string)
+                .ok_or_else(|| {
+                    format!(
+                        "Missing `content_hash` for event type \"{}\"",
+                        Self::full_name()
+                    )
+                })?,
+            adapter_type: record
+                .adapter_type
+                .as_deref()
+                .map(str::to_string)
+                .ok_or_else(|| {
+                    format!(
+                        "Missing `adapter_type` for event type \"{}\"",
+                        Self::full_name()
+                    )
+                })?,
+            unique_id: record.unique_id.as_deref().map(str::to_string),
+            retry: json_payload.retry,
+            max_retries: json_payload.max_retries,
+            backoff_ms: json_payload.backoff_ms,
+            phase: record.phase.map(|v| v as i32),
+            query_error_adapter_message: json_payload.query_error_adapter_message,
+            query_error_vendor_code: record.query_error_vendor_code,
+        })
+    }
+}
+
+impl ProtoTelemetryEvent for ConnectionPoolStats {
+    const RECORD_CATEGORY: TelemetryEventRecType = TelemetryEventRecType::Log;
+    const OUTPUT_FLAGS: TelemetryOutputFlags = TelemetryOutputFlags::ALL;
+
+    fn event_display_name(&self) -> String {
+        format!("Connection pool stats ({})", self.adapter_type)
+    }
+
+    fn has_sensitive_data(&self) -> bool {
+        false
+    }
+}
+
+/// Internal struct used for serializing/deserializing the counters of
+/// ConnectionPoolStats as JSON payload in ArrowAttributes.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Debug)]
+struct ConnectionPoolStatsJsonPayload {
+    checkouts: u64,
+    waits: u64,
+    wait_time_ms: u64,
+    connects: u64,
+    reconnects: u64,
+    validation_failures: u64,
+    expired: u64,
+}
+
+impl ArrowSerializableTelemetryEvent for ConnectionPoolStats {
+    fn to_arrow_record(&self) -> ArrowAttributes<'_> {
+        ArrowAttributes {
+            adapter_type: Some(Cow::from(self.adapter_type.as_str())),
+            json_payload: serde_json::to_string(&ConnectionPoolStatsJsonPayload {
+                checkouts: self.checkouts,
+                waits: self.waits,
+                wait_time_ms: self.wait_time_ms,
+                connects: self.connects,
+                reconnects: self.reconnects,
+                validation_failures: self.validation_failures,
+                expired: self.expired,
+            })
+            .unwrap_or_else(|_| {
+                panic!(
+                    "Failed to serialize data in event type \"{}\" to JSON",
+                    Self::full_name()
+                )
+            })
+            .into(),
+            ..Default::default()
+        }
+    }
+
+    fn from_arrow_record(record: &ArrowAttributes) -> Result<Self, String> {
+        let json_payload: ConnectionPoolStatsJsonPayload =
+            serde_json::from_str(record.json_payload.as_ref().ok_or_else(|| {
+                format!(
+                    "Missing json payload for event type \"{}\"",
+                    Self::full_name()
+                )
+            })?)
+            .map_err(|e| {
+                format!(
+                    "Failed to deserialize data of event type \"{}\" from JSON payload: {}",
+                    Self::full_name(),
+                    e
+                )
+            })?;
+
+        Ok(Self {
+            adapter_type: record
+                .adapter_type
+                .as_deref()
+                .map(str::to_string)
+                .ok_or_else(|| {
+                    format!(
+                        "Missing `adapter_type` for event type \"{}\"",
+                        Self::full_name()
+                    )
+                })?,
+            checkouts: json_payload.checkouts,
+            waits: json_payload.waits,
+            wait_time_ms: json_payload.wait_time_ms,
+            connects: json_payload.connects,
+            reconnects: json_payload.reconnects,
+            validation_failures: json_payload.validation_failures,
+            expired: json_payload.expired,
+        })
+    }
+}
@@ -1,11 +1,22 @@
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use dbt_test_containers::container::docker::{
     ContainerConfig, PortBinding, fetch_container_logs, initialize_container, shutdown_container,
 };
 use std::collections::HashMap;
 
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Database {
+    /// Postgres on port 5499
+    Postgres,
+    /// Trino on port 8099, with the `memory` and `tpch` catalogs
+    Trino,
+}
+
 #[derive(Parser, Debug, Clone)]
 pub struct StartDbArgs {
+    /// The database to start
+    #[arg(long, value_enum, default_value_t = Database::Postgres)]
+    pub db: Database,
     /// Keep the database alive after the command completes
     #[arg(long)]
     pub no_keep_alive: bool,
@@ -17,25 +28,29 @@ pub struct StartDbArgs {
     pub stop: bool,
 }
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let args = StartDbArgs::parse();
+fn port_binding(
+    container_port: &str,
+    host_port: &str,
+) -> HashMap<String, Option<Vec<PortBinding>>> {
+    HashMap::from_iter([(
+        format!("{container_port}/tcp"),
+        Some(vec![PortBinding {
+            host_ip: Some("0.0.0.0".to_string()),
+            host_port: Some(format!("{host_port}/tcp")),
+        }]),
+    )])
+}
 
-    let config = ContainerConfig {
+fn postgres_config(reuse_latest: bool) -> ContainerConfig {
+    ContainerConfig {
         image_name_base: "postgres-fs-tests".to_string(),
         image_uri: None,
         dockerfile_path: Some("fs/sa/crates/dbt-test-containers/docker/postgres/Dockerfile".into()),
         ro_mount_paths: vec![],
         rw_mount_path: None,
-        port_bindings: HashMap::from_iter([(
-            "5432/tcp".to_string(),
-            Some(vec![PortBinding {
-                host_ip: Some("0.0.0.0".to_string()),
-                host_port: Some("5499/tcp".to_string()),
-            }]),
-        )]),
+        port_bindings: port_binding("5432", "5499"),
         network_mode: None,
-        reuse_latest: !args.no_reuse_latest,
+        reuse_latest,
         container_id: None,
         cmd: None,
         env: vec![
@@ -45,6 +60,36 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         ],
         build_args: vec![],
         bind_user: false,
+    }
+}
+
+/// The official image runs a single node cluster without authentication, so
+/// tests connect with `http://<any user>@localhost:8099?catalog=memory`.
+fn trino_config(reuse_latest: bool) -> ContainerConfig {
+    ContainerConfig {
+        image_name_base: "trino-fs-tests".to_string(),
+        image_uri: Some("trinodb/trino:latest".to_string()),
+        dockerfile_path: None,
+        ro_mount_paths: vec![],
+        rw_mount_path: None,
+        port_bindings: port_binding("8080", "8099"),
+        network_mode: None,
+        reuse_latest,
+        container_id: None,
+        cmd: None,
+        env: vec![],
+        build_args: vec![],
+        bind_user: false,
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = StartDbArgs::parse();
+
+    let config = match args.db {
+        Database::Postgres => postgres_config(!args.no_reuse_latest),
+        Database::Trino => trino_config(!args.no_reuse_latest),
     };
     let container = initialize_container(config).await?;
     if args.stop || args.no_keep_alive {
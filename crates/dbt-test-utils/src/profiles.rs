@@ -38,6 +38,12 @@ pub fn load_db_config_from_test_profile_with_database(
         DbConfig::Databricks(db) => {
             db.database = Some(database.to_string());
         }
+        DbConfig::DuckDB(duckdb) => {
+            duckdb.database = Some(database.to_string());
+        }
+        DbConfig::Trino(trino) => {
+            trino.database = Some(database.to_string());
+        }
         _ => {}
     }
 
@@ -91,6 +97,12 @@ pub fn load_db_config<P: AsRef<Path>>(
         DbConfig::Databricks(db) => {
             db.schema = Some(schema.to_string());
         }
+        DbConfig::DuckDB(duckdb) => {
+            duckdb.schema = Some(schema.to_string());
+        }
+        DbConfig::Trino(trino) => {
+            trino.schema = Some(schema.to_string());
+        }
         _ => {}
     }
 
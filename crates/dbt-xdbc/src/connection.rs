@@ -9,7 +9,7 @@ use std::sync::Arc;
 use adbc_core::options;
 use adbc_core::{
     Connection as _, Optionable,
-    error::Result,
+    error::{Error, Result, Status},
     options::{OptionConnection, OptionValue},
 };
 use adbc_driver_manager::ManagedConnection as ManagedAdbcConnection;
@@ -45,6 +45,25 @@ pub trait Connection: Send {
     /// Updates the node_id associated with this [Connection]
     fn update_node_id(&mut self, node_id: Option<String>);
 
+    /// Report that the session of this [Connection] was lost (e.g. the warehouse
+    /// dropped it while it was idle).
+    ///
+    /// Connections checked out of a [Pool](crate::pool::Pool) are replaced before
+    /// their next statement and never go back to the pool. Other connections
+    /// ignore this.
+    fn invalidate(&mut self) {}
+
+    /// Replace the lost session of this [Connection] with a new one.
+    ///
+    /// Only connections checked out of a [Pool](crate::pool::Pool) can open a new
+    /// session, other connections report [Status::NotImplemented].
+    fn reconnect(&mut self) -> Result<()> {
+        Err(Error::with_message_and_status(
+            "This connection cannot reconnect",
+            Status::NotImplemented,
+        ))
+    }
+
     /// Cancel the in-progress operation on a connection.
     fn cancel(&mut self) -> Result<()>;
 
@@ -18,8 +18,15 @@ use libloading;
 use parking_lot::RwLockUpgradableReadGuard;
 use std::sync::Arc;
 use std::{
-    collections::HashMap, env, ffi::c_int, fmt, fmt::Display, hash::Hash, path::Path,
-    path::PathBuf, sync::LazyLock,
+    collections::{HashMap, HashSet},
+    env,
+    ffi::c_int,
+    fmt,
+    fmt::Display,
+    hash::Hash,
+    path::Path,
+    path::PathBuf,
+    sync::{LazyLock, Mutex},
 };
 
 mod builder;
@@ -47,6 +54,8 @@ pub enum Backend {
     Salesforce,
     /// DuckDB driver implementation (ADBC).
     DuckDB,
+    /// Trino driver implementation (ADBC).
+    Trino,
     /// Databricks driver implementation (ODBC).
     DatabricksODBC,
     /// Redshift driver implementation (ODBC).
@@ -76,6 +85,7 @@ impl Display for Backend {
             Backend::Databricks => write!(f, "Databricks"),
             Backend::Redshift => write!(f, "Redshift"),
             Backend::DuckDB => write!(f, "DuckDB"),
+            Backend::Trino => write!(f, "Trino"),
             Backend::DatabricksODBC => write!(f, "Databricks"),
             Backend::RedshiftODBC => write!(f, "Redshift"),
             Backend::Salesforce => write!(f, "Salesforce"),
@@ -85,6 +95,17 @@ impl Display for Backend {
 }
 
 impl Backend {
+    /// A [`Backend::Generic`] loading the driver `library_name`.
+    ///
+    /// Like the drivers loaded from them, the names are kept for the lifetime
+    /// of the process, once per distinct name.
+    pub fn generic(library_name: &str, entrypoint: Option<&str>) -> Self {
+        Backend::Generic {
+            library_name: intern_driver_name(library_name),
+            entrypoint: entrypoint.map(|entrypoint| intern_driver_name(entrypoint).as_bytes()),
+        }
+    }
+
     pub fn adbc_library_name(&self) -> Option<&'static str> {
         match self {
             Backend::Snowflake => Some("adbc_driver_snowflake"),
@@ -94,6 +115,7 @@ impl Backend {
             Backend::Salesforce => Some("adbc_driver_salesforce"),
             Backend::Redshift => Some("adbc_driver_redshift"),
             Backend::DuckDB => Some("duckdb"),
+            Backend::Trino => Some("adbc_driver_trino"),
             Backend::DatabricksODBC | Backend::RedshiftODBC => None, // these use ODBC
             Backend::Generic { library_name, .. } => Some(library_name),
         }
@@ -120,6 +142,7 @@ impl Backend {
             | Backend::Redshift
             | Backend::Salesforce
             | Backend::DuckDB
+            | Backend::Trino
             | Backend::Generic { .. } => FFIProtocol::Adbc,
             Backend::DatabricksODBC | Backend::RedshiftODBC => FFIProtocol::Odbc,
         }
@@ -255,6 +278,20 @@ fn find_adbc_libs_directory() -> Option<PathBuf> {
     None
 }
 
+/// Library and entrypoint names of the generic drivers, see [`Backend::generic`].
+static GENERIC_DRIVER_NAMES: LazyLock<Mutex<HashSet<&'static str>>> =
+    LazyLock::new(|| Mutex::new(HashSet::new()));
+
+fn intern_driver_name(name: &str) -> &'static str {
+    let mut names = GENERIC_DRIVER_NAMES.lock().unwrap();
+    if let Some(interned) = names.get(name) {
+        return interned;
+    }
+    let interned: &'static str = Box::leak(name.to_owned().into_boxed_str());
+    names.insert(interned);
+    interned
+}
+
 /// Directory used by [`AdbcDriver::load_dynamic_from_name`].
 static ADBC_LIBS_DIRECTORY: LazyLock<Option<PathBuf>> = LazyLock::new(find_adbc_libs_directory);
 /// All loaded ADBC drivers are cached in `LOADED_ADBC_DRIVERS`, no matter the loading strategy used.
@@ -362,7 +399,7 @@ impl AdbcDriver {
                 Self::try_load_driver_through_cdn_cache(backend, adbc_version)
             }
             // Drivers that are not published to the dbt Labs CDN.
-            Backend::Generic { .. } => Self::try_load_driver_from_name(
+            Backend::Trino | Backend::Generic { .. } => Self::try_load_driver_from_name(
                 backend.adbc_library_name().unwrap(),
                 backend.adbc_driver_entrypoint(),
                 adbc_version,
@@ -545,6 +582,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn generic_backend_interns_names() {
+        let a = Backend::generic("adbc_driver_sqlite", Some("AdbcDriverSqliteInit"));
+        let b = Backend::generic(&String::from("adbc_driver_sqlite"), None);
+        let (Some(a_name), Some(b_name)) = (a.adbc_library_name(), b.adbc_library_name()) else {
+            panic!("generic backends have a library name");
+        };
+        assert_eq!(a_name, "adbc_driver_sqlite");
+        assert!(std::ptr::eq(a_name, b_name));
+        assert_eq!(
+            a.adbc_driver_entrypoint(),
+            Some(&b"AdbcDriverSqliteInit"[..])
+        );
+        assert_eq!(b.adbc_driver_entrypoint(), None);
+    }
+
     #[cfg(feature = "odbc")]
     #[test_with::env(ODBC_DATABRICKS_TESTS)]
     #[test]
@@ -0,0 +1,11 @@
+// Names of Database options for the DuckDB ADBC Driver
+// Based on the C++ implementation at duckdb/src/common/adbc/adbc.cpp
+
+/// Path of the database file
+pub const PATH: &str = "path";
+
+/// The path DuckDB interprets as an in-memory database
+pub const IN_MEMORY_PATH: &str = ":memory:";
+
+/// The catalog name DuckDB gives to an in-memory database
+pub const IN_MEMORY_DATABASE: &str = "memory";
@@ -1,6 +1,6 @@
 use core::fmt;
 use std::ffi::OsString;
-use std::io::{Read as _, Write as _};
+use std::io::{Read, Write as _};
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 use std::{env, io};
@@ -44,8 +44,16 @@ pub enum InstallError {
     SyncFile(io::Error),
     /// Error while atomically renaming the driver file to its final name.
     RenameFile(io::Error),
-    /// SHA256 checksum mismatch: expected {}, got {} (URL: {}).
+    /// SHA256 checksum mismatch: expected {}, got {} (source: {}).
+    ///
+    /// The source is the URL, path or bundle entry of the driver file.
     ChecksumMismatch(String, String, String),
+    /// No SHA256 checksum is known for the driver file at the given location.
+    MissingChecksum(String),
+    /// The driver bundle at the given path has no entry for the driver file.
+    BundleEntryNotFound(String, PathBuf),
+    /// The backend, OS or architecture has no driver published on the CDN.
+    Unsupported(String),
 }
 
 impl fmt::Display for InstallError {
@@ -77,12 +85,27 @@ impl fmt::Display for InstallError {
             InstallError::WriteFile(error) => write!(f, "Unable to write file: {error}"),
             InstallError::SyncFile(error) => write!(f, "Unable to sync file: {error}"),
             InstallError::RenameFile(error) => write!(f, "Unable to rename file: {error}"),
-            InstallError::ChecksumMismatch(expected, got, url) => {
+            InstallError::ChecksumMismatch(expected, got, source) => {
+                write!(
+                    f,
+                    "SHA-256 checksum mismatch: expected {expected}, got {got} (source: {source})"
+                )
+            }
+            InstallError::MissingChecksum(location) => {
+                write!(
+                    f,
+                    "No known SHA-256 checksum for {location}, refusing to install it"
+                )
+            }
+            InstallError::BundleEntryNotFound(entry, path_buf) => {
                 write!(
                     f,
-                    "SHA-256 checksum mismatch: expected {expected}, got {got} (URL: {url})"
+                    "Driver bundle {} does not contain {}",
+                    path_buf.display(),
+                    entry
                 )
             }
+            InstallError::Unsupported(what) => write!(f, "No driver is published for {what}"),
         }
     }
 }
@@ -167,32 +190,121 @@ impl InstallError {
             InstallError::SyncFile(_) => Status::IO,
             InstallError::RenameFile(_) => Status::IO,
             InstallError::ChecksumMismatch(_, _, _) => Status::InvalidData,
+            InstallError::MissingChecksum(_) => Status::InvalidData,
+            InstallError::BundleEntryNotFound(_, _) => Status::NotFound,
+            InstallError::Unsupported(_) => Status::InvalidArguments,
         };
         let message = format!("Driver installation error: {self}");
         Error::with_message_and_status(message, status)
     }
 }
 
-pub fn format_driver_url(backend_name: &str, version: &str, os: &str) -> String {
-    const PUBLIC_DBT_CDN: &str = "public.cdn.getdbt.com";
+const PUBLIC_DBT_CDN_BASE_URL: &str = "https://public.cdn.getdbt.com/fs/adbc";
+
+/// Environment variable selecting where drivers are installed from, see [DriverSource::parse].
+pub const DRIVER_SOURCE_ENV_VAR_NAME: &str = "DBT_ADBC_DRIVER_SOURCE";
+
+/// Where compressed driver files are fetched from.
+///
+/// Every source but [DriverSource::Cdn] has the layout of the CDN: the file
+/// for a driver is at `<backend>/<file name>` (see [format_driver_file_name]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DriverSource {
+    /// The public dbt Labs CDN.
+    Cdn,
+    /// A mirror of the CDN, given by its base URL.
+    Mirror(String),
+    /// A local directory, e.g. a copy of the CDN on a shared drive.
+    Directory(PathBuf),
+    /// A tarball created by [create_driver_bundle].
+    Bundle(PathBuf),
+}
+
+impl DriverSource {
+    /// `http://` and `https://` URLs are mirrors, `.tar` files are bundles and
+    /// anything else is a local directory.
+    pub fn parse(source: &str) -> Self {
+        if source.starts_with("https://") || source.starts_with("http://") {
+            DriverSource::Mirror(source.trim_end_matches('/').to_string())
+        } else if source.ends_with(".tar") {
+            DriverSource::Bundle(PathBuf::from(source))
+        } else {
+            DriverSource::Directory(PathBuf::from(source))
+        }
+    }
 
-    // %-encode most non-alphanumeric characters in the version string
+    /// The source configured with [DRIVER_SOURCE_ENV_VAR_NAME], the CDN by default.
+    pub fn from_env() -> Self {
+        match env::var(DRIVER_SOURCE_ENV_VAR_NAME) {
+            Ok(source) if !source.is_empty() => Self::parse(&source),
+            _ => DriverSource::Cdn,
+        }
+    }
+}
+
+/// The file name of a compressed driver, e.g.
+/// `adbc_driver_snowflake-0.17.0+dbt0.2.0-x86_64-manylinux_2_17-linux-gnu.so.zst`.
+pub fn format_driver_file_name(backend_name: &str, version: &str, arch: &str, os: &str) -> String {
+    format!(
+        "adbc_driver_{}-{}-{}-{}{}.zst",
+        backend_name,
+        version,
+        arch,
+        os,
+        dll_suffix(os)
+    )
+}
+
+/// The path of a compressed driver relative to the root of a [DriverSource].
+fn driver_file_relpath(backend_name: &str, version: &str, arch: &str, os: &str) -> String {
+    format!(
+        "{}/{}",
+        backend_name,
+        format_driver_file_name(backend_name, version, arch, os)
+    )
+}
+
+fn format_driver_url_with_base(
+    base_url: &str,
+    backend_name: &str,
+    version: &str,
+    arch: &str,
+    os: &str,
+) -> String {
+    // %-encode most non-alphanumeric characters in the file name (e.g. the + in the version)
     const NON_ALPHANUMERIC: &AsciiSet = &percent_encoding::NON_ALPHANUMERIC
         .remove(b'-')
         .remove(b'.')
         .remove(b'_');
+    let file_name = format_driver_file_name(backend_name, version, arch, os);
     format!(
-        "https://{}/fs/adbc/{}/adbc_driver_{}-{}-{}-{}{}.zst",
-        PUBLIC_DBT_CDN,
+        "{}/{}/{}",
+        base_url,
         backend_name,
+        percent_encoding::utf8_percent_encode(&file_name, NON_ALPHANUMERIC),
+    )
+}
+
+pub fn format_driver_url(backend_name: &str, version: &str, os: &str) -> String {
+    format_driver_url_with_base(
+        PUBLIC_DBT_CDN_BASE_URL,
         backend_name,
-        percent_encoding::utf8_percent_encode(version, NON_ALPHANUMERIC),
+        version,
         env::consts::ARCH,
         os,
-        env::consts::DLL_SUFFIX
     )
 }
 
+/// The suffix of dynamic libraries on a target OS of the CDN.
+fn dll_suffix(os: &str) -> &'static str {
+    match os {
+        LINUX_TARGET_OS => ".so",
+        MACOS_TARGET_OS => ".dylib",
+        WINDOWS_TARGET_OS => ".dll",
+        _ => env::consts::DLL_SUFFIX,
+    }
+}
+
 /// Format the full path to the driver file in the OS cache directory.
 ///
 /// Examples for each platform:
@@ -249,17 +361,39 @@ pub fn pre_install_all_drivers() -> Result<()> {
     Ok(())
 }
 
+/// Install the driver for the given backend and the current platform from the given source,
+/// replacing the driver already installed, if any.
+pub fn install_driver_from_source(source: &DriverSource, backend: Backend) -> Result<()> {
+    if !is_installable_driver(backend) {
+        return Err(InstallError::Unsupported(backend.to_string()));
+    }
+    let (backend_name, version, target_os) = driver_parameters(backend);
+    install_driver_from(source, backend_name, version, target_os)
+}
+
 pub fn is_installable_driver(backend: Backend) -> bool {
     INSTALLABLE_DRIVERS.contains(&backend)
 }
 
-#[allow(dead_code)]
+/// All backends whose drivers are published on the CDN.
+pub fn installable_drivers() -> &'static [Backend] {
+    INSTALLABLE_DRIVERS
+}
+
 const LINUX_TARGET_OS: &str = "manylinux_2_17-linux-gnu";
-#[allow(dead_code)]
 const MACOS_TARGET_OS: &str = "apple-darwin";
-#[allow(dead_code)]
 const WINDOWS_TARGET_OS: &str = "pc-windows-msvc";
 
+/// The target OS of the CDN for `linux`, `macos` or `windows`.
+pub fn cdn_target_os(os: &str) -> Option<&'static str> {
+    match os {
+        "linux" => Some(LINUX_TARGET_OS),
+        "macos" => Some(MACOS_TARGET_OS),
+        "windows" => Some(WINDOWS_TARGET_OS),
+        _ => None,
+    }
+}
+
 pub fn driver_parameters(
     backend: Backend,
 ) -> (
@@ -283,7 +417,10 @@ pub fn driver_parameters(
         Backend::Redshift => ("redshift", REDSHIFT_DRIVER_VERSION),
         Backend::Salesforce => ("salesforce", SALESFORCE_DRIVER_VERSION),
         Backend::DuckDB => ("duckdb", DUCKDB_DRIVER_VERSION),
-        Backend::DatabricksODBC | Backend::RedshiftODBC | Backend::Generic { .. } => {
+        Backend::DatabricksODBC
+        | Backend::RedshiftODBC
+        | Backend::Trino
+        | Backend::Generic { .. } => {
             unreachable!("driver_parameters() called with backend={:?}", backend)
         }
     };
@@ -316,10 +453,156 @@ fn find_expected_checksum(backend_name: &str, version: &str, os: &str) -> Option
 }
 
 pub fn install_driver_internal(backend_name: &str, version: &str, target_os: &str) -> Result<()> {
+    install_driver_from(&DriverSource::from_env(), backend_name, version, target_os)
+}
+
+fn install_driver_from(
+    source: &DriverSource,
+    backend_name: &str,
+    version: &str,
+    target_os: &str,
+) -> Result<()> {
     let full_driver_path = format_driver_path(backend_name, version, target_os)?;
-    let url = format_driver_url(backend_name, version, target_os);
     let checksum = find_expected_checksum(backend_name, version, target_os);
-    download_zst_driver_file(&url, &full_driver_path, checksum)
+    if *source == DriverSource::Cdn {
+        let url = format_driver_url(backend_name, version, target_os);
+        return download_zst_driver_file(&url, &full_driver_path, checksum);
+    }
+    let (reader, location) =
+        open_driver_file(source, backend_name, version, env::consts::ARCH, target_os)?;
+    // Drivers from anywhere but the CDN are only trusted when they can be verified
+    let checksum = checksum.ok_or_else(|| InstallError::MissingChecksum(location.clone()))?;
+    write_zst_driver_file(reader, &location, &full_driver_path, Some(checksum))
+}
+
+/// Open the compressed driver file in the given source.
+///
+/// Returns a reader for the file and a description of its location for error messages.
+fn open_driver_file(
+    source: &DriverSource,
+    backend_name: &str,
+    version: &str,
+    arch: &str,
+    os: &str,
+) -> Result<(Box<dyn Read>, String)> {
+    match source {
+        DriverSource::Cdn | DriverSource::Mirror(_) => {
+            let base_url = match source {
+                DriverSource::Mirror(base_url) => base_url.as_str(),
+                _ => PUBLIC_DBT_CDN_BASE_URL,
+            };
+            let url = format_driver_url_with_base(base_url, backend_name, version, arch, os);
+            let response = http_agent().get(&url).call().map_err(InstallError::Http)?;
+            Ok((Box::new(response.into_body().into_reader()), url))
+        }
+        DriverSource::Directory(dir) => {
+            let path = dir.join(driver_file_relpath(backend_name, version, arch, os));
+            let file = std::fs::File::open(&path).map_err(InstallError::Io)?;
+            Ok((Box::new(file), path.display().to_string()))
+        }
+        DriverSource::Bundle(bundle) => {
+            let entry_name = driver_file_relpath(backend_name, version, arch, os);
+            let file = std::fs::File::open(bundle).map_err(InstallError::Io)?;
+            let mut archive = tar::Archive::new(file);
+            for entry in archive.entries().map_err(InstallError::Io)? {
+                let mut entry = entry.map_err(InstallError::Io)?;
+                let path = entry.path().map_err(InstallError::Io)?;
+                if path.to_str() == Some(entry_name.as_str()) {
+                    // Entries borrow the archive, so the (already compressed) file is buffered
+                    let mut data = Vec::with_capacity(entry.size() as usize);
+                    entry.read_to_end(&mut data).map_err(InstallError::Io)?;
+                    let location = format!("{}:{}", bundle.display(), entry_name);
+                    return Ok((Box::new(io::Cursor::new(data)), location));
+                }
+            }
+            let bundle = bundle.clone();
+            Err(InstallError::BundleEntryNotFound(entry_name, bundle))
+        }
+    }
+}
+
+/// Create a driver bundle: a tarball with the compressed drivers of the given backends for every
+/// `(os, arch)` target, fetched from `source` and laid out like the CDN.
+///
+/// Every driver is checked against the known SHA-256 checksums before it is added, so that a
+/// bundle only ever contains drivers that can be installed from it. Returns the bundle entries.
+pub fn create_driver_bundle(
+    source: &DriverSource,
+    backends: &[Backend],
+    targets: &[(&str, &str)],
+    destination: &Path,
+) -> Result<Vec<String>> {
+    let parent = match destination.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    std::fs::create_dir_all(&parent).map_err(|e| InstallError::CreateDir(e, parent.clone()))?;
+    let tmp_name = tmpname(".", 15, ".tar").map_err(InstallError::GetRandom)?;
+    let tmp_path = parent.join(tmp_name);
+    let tmp = std::fs::File::create(&tmp_path)
+        .map_err(|e| InstallError::CreateFIle(e, tmp_path.clone()))?;
+
+    let result = (|| {
+        let mut builder = tar::Builder::new(tmp);
+        let mut entries = Vec::new();
+        for &backend in backends {
+            if !is_installable_driver(backend) {
+                return Err(InstallError::Unsupported(backend.to_string()));
+            }
+            let (backend_name, version, _) = driver_parameters(backend);
+            for &(os, arch) in targets {
+                let (mut reader, location) =
+                    open_driver_file(source, backend_name, version, arch, os)?;
+                let mut data = Vec::new();
+                reader.read_to_end(&mut data).map_err(InstallError::Io)?;
+
+                let expected = find_expected_checksum_internal(backend_name, version, os, arch)
+                    .ok_or_else(|| InstallError::MissingChecksum(location.clone()))?;
+                let got = to_hex(&Sha256::digest(&data));
+                if got != expected {
+                    return Err(InstallError::ChecksumMismatch(
+                        expected.to_string(),
+                        got,
+                        location,
+                    ));
+                }
+
+                let entry_name = driver_file_relpath(backend_name, version, arch, os);
+                let mut header = tar::Header::new_gnu();
+                header.set_size(data.len() as u64);
+                header.set_mode(0o644);
+                builder
+                    .append_data(&mut header, &entry_name, data.as_slice())
+                    .map_err(InstallError::WriteFile)?;
+                entries.push(entry_name);
+            }
+        }
+        let tmp = builder.into_inner().map_err(InstallError::WriteFile)?;
+        tmp.sync_data().map_err(InstallError::SyncFile)?;
+        Ok(entries)
+    })();
+
+    match result {
+        Ok(entries) => {
+            std::fs::rename(&tmp_path, destination).map_err(InstallError::RenameFile)?;
+            Ok(entries)
+        }
+        Err(e) => {
+            let _ = std::fs::remove_file(&tmp_path);
+            Err(e)
+        }
+    }
+}
+
+/// Lowercase hex encoding of a SHA-256 digest.
+fn to_hex(bytes: &[u8]) -> String {
+    const HEX: &[u8] = b"0123456789abcdef";
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        hex.push(HEX[(b >> 4) as usize] as char);
+        hex.push(HEX[(b & 0xf) as usize] as char);
+    }
+    hex
 }
 
 /// Unguessable temporary file name generator.
@@ -379,36 +662,53 @@ unsafe impl zstd_safe::WriteBuf for ZstdWriteBuffer {
 
 const DRIVER_DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(60);
 
+fn http_agent() -> ureq::Agent {
+    // Use Rustls as the TLS provider but on the OS for the root certificates.
+    //
+    // [1]: https://github.com/dbt-labs/dbt-fusion/issues/147
+    let tls_config = TlsConfig::builder()
+        .provider(TlsProvider::Rustls)
+        .root_certs(RootCerts::PlatformVerifier)
+        .build();
+    let http_config = ureq::Agent::config_builder()
+        .tls_config(tls_config)
+        .timeout_global(Some(DRIVER_DOWNLOAD_TIMEOUT))
+        .build();
+    ureq::Agent::new_with_config(http_config)
+}
+
 /// Download a Zstandard-compressed file from the given URL and save (atomically and durably)
 /// it to the fully-qualified destination path.
 pub fn download_zst_driver_file<P: AsRef<Path>>(
     url: &str,
     destination: P,
     expected_sha256sum: Option<&str>,
+) -> Result<()> {
+    let mut response = http_agent().get(url).call().map_err(InstallError::Http)?;
+    write_zst_driver_file(
+        response.body_mut().as_reader(),
+        url,
+        destination,
+        expected_sha256sum,
+    )
+}
+
+/// Decompress a Zstandard-compressed driver file and save (atomically and durably) it to the
+/// fully-qualified destination path.
+///
+/// The SHA-256 checksum is computed over the compressed data. `location` only appears in error
+/// messages.
+fn write_zst_driver_file<P: AsRef<Path>>(
+    mut download_stream: impl Read,
+    location: &str,
+    destination: P,
+    expected_sha256sum: Option<&str>,
 ) -> Result<()> {
     debug_assert!(
         destination.as_ref().is_absolute(),
         "destination path must be absolute"
     );
 
-    // Configure the HTTP agent
-    let http_agent = {
-        // Use Rustls as the TLS provider but on the OS for the root certificates.
-        //
-        // [1]: https://github.com/dbt-labs/dbt-fusion/issues/147
-        let tls_config = TlsConfig::builder()
-            .provider(TlsProvider::Rustls)
-            .root_certs(RootCerts::PlatformVerifier)
-            .build();
-        let http_config = ureq::Agent::config_builder()
-            .tls_config(tls_config)
-            .timeout_global(Some(DRIVER_DOWNLOAD_TIMEOUT))
-            .build();
-        ureq::Agent::new_with_config(http_config)
-    };
-
-    let mut response = http_agent.get(url).call().map_err(InstallError::Http)?;
-
     // Generate a random file name and create an empty temporary file
     let tmp_path = {
         let tmp_name = tmpname(".", 15, ".download").map_err(InstallError::GetRandom)?;
@@ -477,7 +777,6 @@ pub fn download_zst_driver_file<P: AsRef<Path>>(
         Ok(next_insize_hint)
     };
 
-    let mut download_stream = response.body_mut().as_reader();
     loop {
         if download_buffer.len() < zstd_insize_hint {
             // Ensure there is `zstd_insize_hint` bytes of capacity in `download_buffer`.
@@ -546,18 +845,15 @@ pub fn download_zst_driver_file<P: AsRef<Path>>(
             expected.len() == 64,
             "expected SHA-256 checksum must be 64 hex characters"
         );
-        const HEX: &[u8] = b"0123456789abcdef";
-        let mut got = [0; 64];
-        for (i, b) in sha256sum.iter().enumerate() {
-            got[i * 2] = HEX[(b >> 4) as usize];
-            got[i * 2 + 1] = HEX[(b & 0xf) as usize];
-        }
-        let got = std::str::from_utf8(&got).unwrap();
+        let got = to_hex(&sha256sum);
         if got != expected {
+            // Never leave an unverified driver behind
+            drop(tmp);
+            let _ = std::fs::remove_file(&tmp_path);
             return Err(InstallError::ChecksumMismatch(
                 expected.to_string(),
-                got.to_string(),
-                url.to_string(),
+                got,
+                location.to_string(),
             ));
         }
     }
@@ -579,9 +875,8 @@ mod tests {
         assert_eq!(
             url,
             format!(
-                "https://public.cdn.getdbt.com/fs/adbc/snowflake/adbc_driver_snowflake-0.17.0%2Bdbt0.2.0-{}-manylinux_2_17-linux-gnu{}.zst",
+                "https://public.cdn.getdbt.com/fs/adbc/snowflake/adbc_driver_snowflake-0.17.0%2Bdbt0.2.0-{}-manylinux_2_17-linux-gnu.so.zst",
                 env::consts::ARCH,
-                env::consts::DLL_SUFFIX
             )
         );
     }
@@ -612,6 +907,111 @@ mod tests {
         assert_eq!(path, expected);
     }
 
+    #[test]
+    fn test_driver_source_parse() {
+        assert_eq!(
+            DriverSource::parse("https://mirror.internal/adbc/"),
+            DriverSource::Mirror("https://mirror.internal/adbc".to_string())
+        );
+        assert_eq!(
+            DriverSource::parse("/mnt/drivers/bundle.tar"),
+            DriverSource::Bundle(PathBuf::from("/mnt/drivers/bundle.tar"))
+        );
+        assert_eq!(
+            DriverSource::parse("/mnt/drivers"),
+            DriverSource::Directory(PathBuf::from("/mnt/drivers"))
+        );
+    }
+
+    #[test]
+    fn test_format_mirror_driver_url() {
+        let url = format_driver_url_with_base(
+            "https://mirror.internal/adbc",
+            "postgresql",
+            "0.21.0+dbt0.21.0",
+            "aarch64",
+            MACOS_TARGET_OS,
+        );
+        assert_eq!(
+            url,
+            "https://mirror.internal/adbc/postgresql/adbc_driver_postgresql-0.21.0%2Bdbt0.21.0-aarch64-apple-darwin.dylib.zst"
+        );
+    }
+
+    #[test]
+    fn test_create_driver_bundle_rejects_checksum_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let relpath = driver_file_relpath(
+            "postgresql",
+            POSTGRES_DRIVER_VERSION,
+            "x86_64",
+            LINUX_TARGET_OS,
+        );
+        std::fs::create_dir_all(dir.path().join("postgresql")).unwrap();
+        std::fs::write(dir.path().join(&relpath), b"not a driver").unwrap();
+
+        let bundle = dir.path().join("bundle.tar");
+        let res = create_driver_bundle(
+            &DriverSource::Directory(dir.path().to_path_buf()),
+            &[Backend::Postgres],
+            &[(LINUX_TARGET_OS, "x86_64")],
+            &bundle,
+        );
+        assert!(matches!(res, Err(InstallError::ChecksumMismatch(..))));
+        assert!(!bundle.exists());
+    }
+
+    #[test]
+    fn test_install_from_bundle_rejects_checksum_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let relpath =
+            driver_file_relpath("duckdb", DUCKDB_DRIVER_VERSION, "x86_64", LINUX_TARGET_OS);
+        let bundle = dir.path().join("bundle.tar");
+        {
+            let mut compressed = Vec::with_capacity(zstd_safe::compress_bound(12));
+            zstd_safe::compress(&mut compressed, b"not a driver", 3).unwrap();
+            let mut builder = tar::Builder::new(std::fs::File::create(&bundle).unwrap());
+            let mut header = tar::Header::new_gnu();
+            header.set_size(compressed.len() as u64);
+            builder
+                .append_data(&mut header, &relpath, compressed.as_slice())
+                .unwrap();
+            builder.finish().unwrap();
+        }
+        let source = DriverSource::Bundle(bundle.clone());
+
+        let (reader, location) = open_driver_file(
+            &source,
+            "duckdb",
+            DUCKDB_DRIVER_VERSION,
+            "x86_64",
+            LINUX_TARGET_OS,
+        )
+        .unwrap();
+        let destination = dir.path().join("out").join("libadbc_driver_duckdb.so");
+        let res = write_zst_driver_file(
+            reader,
+            &location,
+            &destination,
+            Some("0".repeat(64).as_str()),
+        );
+        assert!(matches!(res, Err(InstallError::ChecksumMismatch(..))));
+        assert!(!destination.exists());
+        assert_eq!(
+            std::fs::read_dir(dir.path().join("out")).unwrap().count(),
+            0
+        );
+
+        let res = open_driver_file(
+            &source,
+            "duckdb",
+            DUCKDB_DRIVER_VERSION,
+            "aarch64",
+            LINUX_TARGET_OS,
+        );
+        assert!(matches!(res, Err(InstallError::BundleEntryNotFound(..))));
+    }
+
     /// Check that the expected SHA-256 checksum is found for each backend, version, target_os and
     /// arch combinations.
     ///
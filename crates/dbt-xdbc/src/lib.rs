@@ -44,6 +44,10 @@ pub use statement::Statement;
 pub mod query_ctx;
 pub use query_ctx::QueryCtx;
 
+pub mod params;
+pub use params::QueryParams;
+
+pub mod pool;
 pub mod semaphore;
 pub mod sql;
 
@@ -60,9 +64,11 @@ pub(crate) mod install;
 // Constants for different backends
 pub mod bigquery;
 pub mod databricks;
+pub mod duckdb;
 pub mod redshift;
 pub mod salesforce;
 pub mod snowflake;
+pub mod trino;
 
 // REPL for ADBC drivers
 #[cfg(feature = "repl")]
@@ -92,6 +98,10 @@ pub const SALESFORCE_DRIVER_VERSION: &str = "0.21.0+dbt0.21.1";
 
 pub use install::pre_install_all_drivers;
 pub use install::pre_install_driver;
+pub use install::{
+    DRIVER_SOURCE_ENV_VAR_NAME, DriverSource, InstallError, cdn_target_os, create_driver_bundle,
+    install_driver_from_source, installable_drivers,
+};
 
 /// A function that creates a new connection to the database.
 type NewConnectionF<Error> = Box<dyn Fn() -> Result<Box<dyn Connection>, Error> + Send + Sync>;
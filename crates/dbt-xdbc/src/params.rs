@@ -0,0 +1,163 @@
+//! Positional parameters of a SQL statement
+//!
+//! Parameters are bound to a statement with [Statement::bind] as a record batch with a single
+//! row and one column per parameter, in the order of the placeholders in the SQL text. The values
+//! travel separately from the SQL text, so they never have to be quoted or escaped.
+//!
+//! [Statement::bind]: crate::Statement::bind
+
+use std::sync::Arc;
+
+use adbc_core::error::Result;
+use arrow_array::{ArrayRef, BooleanArray, Int64Array, RecordBatch, StringArray};
+use arrow_schema::{DataType, Field, Schema};
+
+use crate::Backend;
+
+/// The value of a single parameter
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParamValue {
+    Bool(bool),
+    Int64(i64),
+    Utf8(String),
+}
+
+impl ParamValue {
+    fn data_type(&self) -> DataType {
+        match self {
+            ParamValue::Bool(_) => DataType::Boolean,
+            ParamValue::Int64(_) => DataType::Int64,
+            ParamValue::Utf8(_) => DataType::Utf8,
+        }
+    }
+
+    fn to_array(&self) -> ArrayRef {
+        match self {
+            ParamValue::Bool(value) => Arc::new(BooleanArray::from(vec![*value])),
+            ParamValue::Int64(value) => Arc::new(Int64Array::from(vec![*value])),
+            ParamValue::Utf8(value) => Arc::new(StringArray::from(vec![value.as_str()])),
+        }
+    }
+}
+
+impl From<bool> for ParamValue {
+    fn from(value: bool) -> Self {
+        ParamValue::Bool(value)
+    }
+}
+
+impl From<i64> for ParamValue {
+    fn from(value: i64) -> Self {
+        ParamValue::Int64(value)
+    }
+}
+
+impl From<&str> for ParamValue {
+    fn from(value: &str) -> Self {
+        ParamValue::Utf8(value.to_string())
+    }
+}
+
+impl From<String> for ParamValue {
+    fn from(value: String) -> Self {
+        ParamValue::Utf8(value)
+    }
+}
+
+/// Positional parameters of a statement, together with the placeholder syntax of the backend
+/// the statement is sent to.
+///
+/// ```ignore
+/// let mut params = QueryParams::new(backend);
+/// let sql = format!("select * from t where schema = {}", params.push(schema));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryParams {
+    backend: Backend,
+    values: Vec<ParamValue>,
+}
+
+impl QueryParams {
+    pub fn new(backend: Backend) -> Self {
+        Self {
+            backend,
+            values: Vec::new(),
+        }
+    }
+
+    /// Add a parameter and return the placeholder to put in the SQL text in its place
+    pub fn push(&mut self, value: impl Into<ParamValue>) -> String {
+        self.values.push(value.into());
+        placeholder(self.backend, self.values.len())
+    }
+
+    pub fn values(&self) -> &[ParamValue] {
+        &self.values
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The record batch to bind to the statement.
+    ///
+    /// Columns are left unnamed so that drivers accepting both named and positional
+    /// parameters (e.g. BigQuery) treat them as positional.
+    pub fn to_record_batch(&self) -> Result<RecordBatch> {
+        let fields = self
+            .values
+            .iter()
+            .map(|value| Field::new("", value.data_type(), false))
+            .collect::<Vec<_>>();
+        let columns = self.values.iter().map(ParamValue::to_array).collect();
+        let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?;
+        Ok(batch)
+    }
+}
+
+/// The placeholder of the `index`-th parameter (starting at 1) in SQL sent to the backend
+pub fn placeholder(backend: Backend, index: usize) -> String {
+    match backend {
+        Backend::Postgres | Backend::Redshift => format!("${index}"),
+        Backend::Snowflake
+        | Backend::BigQuery
+        | Backend::Databricks
+        | Backend::Salesforce
+        | Backend::DuckDB
+        | Backend::Trino
+        | Backend::DatabricksODBC
+        | Backend::RedshiftODBC
+        | Backend::Generic { .. } => "?".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_placeholders_and_record_batch() {
+        let mut params = QueryParams::new(Backend::Postgres);
+        assert_eq!(params.push("it's"), "$1");
+        assert_eq!(params.push(10_i64), "$2");
+
+        let batch = params.to_record_batch().unwrap();
+        assert_eq!(batch.num_rows(), 1);
+        assert_eq!(batch.num_columns(), 2);
+        let value = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap()
+            .value(0);
+        assert_eq!(value, "it's");
+
+        let mut params = QueryParams::new(Backend::Snowflake);
+        assert_eq!(params.push(true), "?");
+        assert_eq!(params.push("x"), "?");
+    }
+}
@@ -0,0 +1,915 @@
+//! A pool of [Connection]s with health checks, idle reaping and a bound on
+//! the number of open connections.
+//!
+//! Connections are checked out with [Pool::checkout] and go back to the pool
+//! when the returned [PooledConnection] is dropped. An idle connection is
+//! validated with a cheap query before it is handed out again, and connections
+//! that stayed idle for too long or outlived their maximum lifetime are closed
+//! instead of being reused. Warehouses (and the load balancers in front of
+//! them) drop idle sessions, so without these checks a long run fails on the
+//! first statement sent on a connection that is already gone.
+//!
+//! There is no background thread: idle connections are reaped whenever a
+//! connection goes back to the pool, or when [Pool::reap] is called, and the
+//! first checkout opens the [PoolConfig::min_size] connections.
+
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use adbc_core::{
+    error::{Error, Result, Status},
+    options::{self, OptionConnection, OptionValue},
+};
+use arrow_array::RecordBatchReader;
+use arrow_schema::Schema;
+use parking_lot::{Condvar, Mutex, MutexGuard};
+use tracy_client::span;
+
+use crate::{Connection, QueryCtx, Statement};
+
+/// Query used to check that an idle connection is still alive
+pub const DEFAULT_VALIDATION_QUERY: &str = "select 1";
+/// Idle connections beyond the minimum size are closed after this long
+pub const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+/// How long a checkout waits for a connection when the pool is at its maximum size
+pub const DEFAULT_CHECKOUT_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// A function that opens a new connection to the database.
+pub type ConnectF = Box<dyn Fn() -> Result<Box<dyn Connection>> + Send + Sync>;
+
+/// Sizing, health check and expiration settings of a [Pool].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoolConfig {
+    /// Number of connections opened by the first [Pool::checkout] and kept
+    /// open even when they are idle for longer than [PoolConfig::idle_timeout].
+    pub min_size: usize,
+    /// Maximum number of open connections, checked out or idle.
+    pub max_size: usize,
+    /// Query run on an idle connection before it is handed out again.
+    /// `None` disables validation.
+    pub validation_query: Option<String>,
+    /// Idle connections beyond [PoolConfig::min_size] are closed after this
+    /// long. `None` keeps them open.
+    pub idle_timeout: Option<Duration>,
+    /// Connections are closed, instead of going back to the pool, once they
+    /// have been open for this long. `None` keeps them open.
+    pub max_lifetime: Option<Duration>,
+    /// How long [Pool::checkout] waits for a connection to go back to the
+    /// pool when [PoolConfig::max_size] connections are open.
+    pub checkout_timeout: Duration,
+    /// Whether an [invalidated](Connection::invalidate) connection is replaced
+    /// by a new one before its next statement. Otherwise the statements sent
+    /// on it keep failing until it is dropped.
+    pub reconnect: bool,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            min_size: 0,
+            max_size: usize::MAX,
+            validation_query: Some(DEFAULT_VALIDATION_QUERY.to_string()),
+            idle_timeout: Some(DEFAULT_IDLE_TIMEOUT),
+            max_lifetime: None,
+            checkout_timeout: DEFAULT_CHECKOUT_TIMEOUT,
+            reconnect: true,
+        }
+    }
+}
+
+/// Counters of the activity of a [Pool] since it was created.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Connections currently open, checked out or idle
+    pub open: usize,
+    /// Connections currently idle in the pool
+    pub idle: usize,
+    /// Calls to [Pool::checkout]
+    pub checkouts: u64,
+    /// Checkouts that had to wait for a connection to go back to the pool
+    pub waits: u64,
+    /// Total time spent waiting in those checkouts
+    pub wait_time: Duration,
+    /// Connections opened, including reconnects
+    pub connects: u64,
+    /// Invalidated connections replaced by a new one
+    pub reconnects: u64,
+    /// Idle connections that failed the validation query
+    pub validation_failures: u64,
+    /// Connections closed for being idle or old for too long
+    pub expired: u64,
+}
+
+impl fmt::Display for PoolStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} checkouts ({} waited {:.1}s in total), {} connects, {} reconnects, \
+             {} failed validations, {} expired, {} open ({} idle)",
+            self.checkouts,
+            self.waits,
+            self.wait_time.as_secs_f64(),
+            self.connects,
+            self.reconnects,
+            self.validation_failures,
+            self.expired,
+            self.open,
+            self.idle,
+        )
+    }
+}
+
+#[derive(Default)]
+struct Metrics {
+    checkouts: AtomicU64,
+    waits: AtomicU64,
+    wait_time_us: AtomicU64,
+    connects: AtomicU64,
+    reconnects: AtomicU64,
+    validation_failures: AtomicU64,
+    expired: AtomicU64,
+}
+
+struct IdleConnection {
+    conn: Box<dyn Connection>,
+    created_at: Instant,
+    idle_since: Instant,
+}
+
+struct PoolState {
+    /// Idle connections, the most recently used at the back.
+    idle: VecDeque<IdleConnection>,
+    /// Number of open connections, checked out or idle.
+    open: usize,
+}
+
+struct PoolInner {
+    config: PoolConfig,
+    connect_f: ConnectF,
+    state: Mutex<PoolState>,
+    /// Signaled when a connection goes back to the pool or is closed.
+    available: Condvar,
+    /// Set once the first checkout opened the minimum number of connections.
+    filled: AtomicBool,
+    metrics: Metrics,
+}
+
+impl PoolInner {
+    fn connect(&self) -> Result<Box<dyn Connection>> {
+        let _span = span!("Pool::connect");
+        let conn = (self.connect_f)()?;
+        self.metrics.connects.fetch_add(1, Ordering::Relaxed);
+        Ok(conn)
+    }
+
+    fn is_too_old(&self, created_at: Instant, now: Instant) -> bool {
+        self.config
+            .max_lifetime
+            .is_some_and(|lifetime| now.duration_since(created_at) >= lifetime)
+    }
+
+    fn is_expired(&self, idle: &IdleConnection, now: Instant, can_shrink: bool) -> bool {
+        let idle_too_long = can_shrink
+            && self
+                .config
+                .idle_timeout
+                .is_some_and(|timeout| now.duration_since(idle.idle_since) >= timeout);
+        idle_too_long || self.is_too_old(idle.created_at, now)
+    }
+
+    /// Run the validation query on an idle connection.
+    fn validate(&self, conn: &mut dyn Connection) -> bool {
+        let Some(sql) = &self.config.validation_query else {
+            return true;
+        };
+        let _span = span!("Pool::validate");
+        let ctx = QueryCtx::default().with_desc("connection pool validation");
+        let res = conn.new_statement().and_then(|mut stmt| {
+            stmt.set_sql_query(&ctx, sql)?;
+            stmt.execute().map(|_| ())
+        });
+        match res {
+            Ok(()) => true,
+            Err(e) => {
+                self.metrics
+                    .validation_failures
+                    .fetch_add(1, Ordering::Relaxed);
+                tracing::debug!("Closing pooled connection that failed validation: {e}");
+                false
+            }
+        }
+    }
+
+    /// Remove the expired idle connections, keeping [PoolConfig::min_size]
+    /// connections open. The caller closes the returned connections once the
+    /// lock is released, as closing a connection talks to the warehouse.
+    fn take_expired(&self, state: &mut PoolState) -> Vec<IdleConnection> {
+        let now = Instant::now();
+        let mut expired = Vec::new();
+        let mut i = 0;
+        while i < state.idle.len() {
+            let can_shrink = state.open > self.config.min_size;
+            if self.is_expired(&state.idle[i], now, can_shrink) {
+                expired.extend(state.idle.remove(i));
+                state.open -= 1;
+            } else {
+                i += 1;
+            }
+        }
+        if !expired.is_empty() {
+            self.metrics
+                .expired
+                .fetch_add(expired.len() as u64, Ordering::Relaxed);
+            self.available.notify_all();
+        }
+        expired
+    }
+
+    /// Give back the slot of a connection that was closed.
+    fn release_slot(&self) {
+        self.state.lock().open -= 1;
+        self.available.notify_one();
+    }
+
+    fn checkin(&self, conn: Box<dyn Connection>, created_at: Instant) {
+        let now = Instant::now();
+        if self.is_too_old(created_at, now) {
+            self.metrics.expired.fetch_add(1, Ordering::Relaxed);
+            drop(conn);
+            self.release_slot();
+            return;
+        }
+        let expired = {
+            let mut state = self.state.lock();
+            state.idle.push_back(IdleConnection {
+                conn,
+                created_at,
+                idle_since: now,
+            });
+            self.available.notify_one();
+            self.take_expired(&mut state)
+        };
+        drop(expired);
+    }
+}
+
+/// A pool of connections to one database, see the [module](self) docs.
+///
+/// Cloning a [Pool] is cheap and the clones share the connections.
+#[derive(Clone)]
+pub struct Pool {
+    inner: Arc<PoolInner>,
+}
+
+impl fmt::Debug for Pool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pool")
+            .field("config", &self.inner.config)
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+impl Pool {
+    /// Create an empty pool that opens connections with `connect_f`.
+    pub fn new(config: PoolConfig, connect_f: ConnectF) -> Self {
+        debug_assert!(config.max_size > 0, "Pool must allow for one connection");
+        let inner = PoolInner {
+            config,
+            connect_f,
+            state: Mutex::new(PoolState {
+                idle: VecDeque::new(),
+                open: 0,
+            }),
+            available: Condvar::new(),
+            filled: AtomicBool::new(false),
+            metrics: Metrics::default(),
+        };
+        Self {
+            inner: Arc::new(inner),
+        }
+    }
+
+    pub fn config(&self) -> &PoolConfig {
+        &self.inner.config
+    }
+
+    /// Check out a connection, reusing an idle one if it passes validation.
+    ///
+    /// Blocks when [PoolConfig::max_size] connections are open until one goes
+    /// back to the pool, and fails after [PoolConfig::checkout_timeout].
+    pub fn checkout(&self) -> Result<PooledConnection> {
+        let _span = span!("Pool::checkout");
+        let inner = &self.inner;
+        inner.metrics.checkouts.fetch_add(1, Ordering::Relaxed);
+        if inner.config.min_size > 0
+            && !inner.filled.swap(true, Ordering::Relaxed)
+            && let Err(e) = self.fill()
+        {
+            inner.filled.store(false, Ordering::Relaxed);
+            return Err(e);
+        }
+
+        let start = Instant::now();
+        let deadline = start + inner.config.checkout_timeout;
+        let mut waited = false;
+        let mut state = inner.state.lock();
+        let res = loop {
+            // The most recently used connection is the most likely to be alive
+            if let Some(mut idle) = state.idle.pop_back() {
+                let can_shrink = state.open > inner.config.min_size;
+                // Validating and closing connections talk to the warehouse, so
+                // the lock is not held meanwhile
+                let alive = MutexGuard::unlocked(&mut state, || {
+                    if inner.is_expired(&idle, Instant::now(), can_shrink) {
+                        inner.metrics.expired.fetch_add(1, Ordering::Relaxed);
+                        false
+                    } else {
+                        inner.validate(idle.conn.as_mut())
+                    }
+                });
+                if alive {
+                    break Ok((idle.conn, idle.created_at));
+                }
+                state.open -= 1;
+                MutexGuard::unlocked(&mut state, || drop(idle));
+                continue;
+            }
+            if state.open < inner.config.max_size {
+                state.open += 1;
+                let res = MutexGuard::unlocked(&mut state, || inner.connect());
+                if res.is_err() {
+                    state.open -= 1;
+                    inner.available.notify_one();
+                }
+                break res.map(|conn| (conn, Instant::now()));
+            }
+            if !waited {
+                waited = true;
+                inner.metrics.waits.fetch_add(1, Ordering::Relaxed);
+            }
+            if inner.available.wait_until(&mut state, deadline).timed_out() {
+                break Err(Error::with_message_and_status(
+                    format!(
+                        "Timed out after {:.0}s waiting for a connection, all {} connections \
+                         of the pool are in use",
+                        inner.config.checkout_timeout.as_secs_f64(),
+                        inner.config.max_size
+                    ),
+                    Status::Timeout,
+                ));
+            }
+        };
+        drop(state);
+
+        if waited {
+            inner
+                .metrics
+                .wait_time_us
+                .fetch_add(start.elapsed().as_micros() as u64, Ordering::Relaxed);
+        }
+        let (conn, created_at) = res?;
+        Ok(PooledConnection {
+            pool: inner.clone(),
+            conn: Some(conn),
+            created_at,
+            invalidated: false,
+        })
+    }
+
+    /// Open idle connections until [PoolConfig::min_size] connections are open.
+    pub fn fill(&self) -> Result<()> {
+        let _span = span!("Pool::fill");
+        let inner = &self.inner;
+        loop {
+            {
+                let mut state = inner.state.lock();
+                if state.open >= inner.config.min_size {
+                    return Ok(());
+                }
+                state.open += 1;
+            }
+            match inner.connect() {
+                Ok(conn) => inner.checkin(conn, Instant::now()),
+                Err(e) => {
+                    inner.release_slot();
+                    return Err(e);
+                }
+            }
+        }
+    }
+
+    /// Close the idle connections that expired, keeping
+    /// [PoolConfig::min_size] connections open.
+    pub fn reap(&self) {
+        let expired = {
+            let mut state = self.inner.state.lock();
+            self.inner.take_expired(&mut state)
+        };
+        drop(expired);
+    }
+
+    /// A snapshot of the counters of the pool.
+    pub fn stats(&self) -> PoolStats {
+        let (open, idle) = {
+            let state = self.inner.state.lock();
+            (state.open, state.idle.len())
+        };
+        let metrics = &self.inner.metrics;
+        PoolStats {
+            open,
+            idle,
+            checkouts: metrics.checkouts.load(Ordering::Relaxed),
+            waits: metrics.waits.load(Ordering::Relaxed),
+            wait_time: Duration::from_micros(metrics.wait_time_us.load(Ordering::Relaxed)),
+            connects: metrics.connects.load(Ordering::Relaxed),
+            reconnects: metrics.reconnects.load(Ordering::Relaxed),
+            validation_failures: metrics.validation_failures.load(Ordering::Relaxed),
+            expired: metrics.expired.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// A [Connection] checked out of a [Pool].
+///
+/// Goes back to the pool when dropped, unless it was invalidated or outlived
+/// [PoolConfig::max_lifetime], in which case it is closed.
+pub struct PooledConnection {
+    pool: Arc<PoolInner>,
+    conn: Option<Box<dyn Connection>>,
+    created_at: Instant,
+    invalidated: bool,
+}
+
+fn lost_connection_error() -> Error {
+    Error::with_message_and_status(
+        "The pooled connection was lost and reconnecting failed",
+        Status::InvalidState,
+    )
+}
+
+impl PooledConnection {
+    fn conn(&self) -> Result<&dyn Connection> {
+        self.conn.as_deref().ok_or_else(lost_connection_error)
+    }
+
+    /// The connection, replacing it first if it was invalidated.
+    fn conn_mut(&mut self) -> Result<&mut dyn Connection> {
+        self.conn()?;
+        if self.invalidated && self.pool.config.reconnect {
+            self.reconnect()?;
+        }
+        Ok(self.conn.as_deref_mut().unwrap())
+    }
+
+    /// Replace the connection with a new one, closing the current one.
+    pub fn reconnect(&mut self) -> Result<()> {
+        let _span = span!("PooledConnection::reconnect");
+        // The slot of the pool is kept, the old connection is closed first so
+        // that the warehouse doesn't see one connection more than the limit
+        let Some(old) = self.conn.take() else {
+            return Err(lost_connection_error());
+        };
+        drop(old);
+        let res = self.pool.connect();
+        let conn = match res {
+            Ok(conn) => conn,
+            Err(e) => {
+                // Without a connection this is no longer part of the pool
+                self.pool.release_slot();
+                return Err(e);
+            }
+        };
+        self.pool.metrics.reconnects.fetch_add(1, Ordering::Relaxed);
+        tracing::debug!("Reconnected a pooled connection whose session was lost");
+        self.conn = Some(conn);
+        self.created_at = Instant::now();
+        self.invalidated = false;
+        Ok(())
+    }
+}
+
+impl Drop for PooledConnection {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            if self.invalidated {
+                drop(conn);
+                self.pool.release_slot();
+            } else {
+                self.pool.checkin(conn, self.created_at);
+            }
+        }
+    }
+}
+
+impl fmt::Debug for PooledConnection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PooledConnection")
+            .field("conn", &self.conn)
+            .field("invalidated", &self.invalidated)
+            .finish()
+    }
+}
+
+impl Connection for PooledConnection {
+    fn new_statement(&mut self) -> Result<Box<dyn Statement>> {
+        self.conn_mut()?.new_statement()
+    }
+
+    fn update_node_id(&mut self, node_id: Option<String>) {
+        if let Some(conn) = self.conn.as_mut() {
+            conn.update_node_id(node_id);
+        }
+    }
+
+    fn invalidate(&mut self) {
+        self.invalidated = true;
+        if let Some(conn) = self.conn.as_mut() {
+            conn.invalidate();
+        }
+    }
+
+    fn reconnect(&mut self) -> Result<()> {
+        PooledConnection::reconnect(self)
+    }
+
+    fn cancel(&mut self) -> Result<()> {
+        self.conn_mut()?.cancel()
+    }
+
+    fn get_info<'a>(
+        &'a self,
+        codes: Option<HashSet<options::InfoCode>>,
+    ) -> Result<Box<dyn RecordBatchReader + Send + 'a>> {
+        self.conn()?.get_info(codes)
+    }
+
+    fn get_objects<'a>(
+        &'a self,
+        depth: options::ObjectDepth,
+        catalog: Option<&'a str>,
+        db_schema: Option<&'a str>,
+        table_name: Option<&'a str>,
+        table_type: Option<Vec<&'a str>>,
+        column_name: Option<&'a str>,
+    ) -> Result<Box<dyn RecordBatchReader + Send + 'a>> {
+        self.conn()?.get_objects(
+            depth,
+            catalog,
+            db_schema,
+            table_name,
+            table_type,
+            column_name,
+        )
+    }
+
+    fn get_table_schema(
+        &self,
+        catalog: Option<&str>,
+        db_schema: Option<&str>,
+        table_name: &str,
+    ) -> Result<Schema> {
+        self.conn()?
+            .get_table_schema(catalog, db_schema, table_name)
+    }
+
+    fn get_table_types<'a>(&'a self) -> Result<Box<dyn RecordBatchReader + Send + 'a>> {
+        self.conn()?.get_table_types()
+    }
+
+    fn get_statistic_names<'a>(&'a self) -> Result<Box<dyn RecordBatchReader + Send + 'a>> {
+        self.conn()?.get_statistic_names()
+    }
+
+    fn get_statistics<'a>(
+        &'a self,
+        catalog: Option<&'a str>,
+        db_schema: Option<&'a str>,
+        table_name: Option<&'a str>,
+        approximate: bool,
+    ) -> Result<Box<dyn RecordBatchReader + Send + 'a>> {
+        self.conn()?
+            .get_statistics(catalog, db_schema, table_name, approximate)
+    }
+
+    fn commit(&mut self) -> Result<()> {
+        self.conn_mut()?.commit()
+    }
+
+    fn rollback(&mut self) -> Result<()> {
+        self.conn_mut()?.rollback()
+    }
+
+    fn read_partition<'a>(
+        &'a self,
+        partition: &'a [u8],
+    ) -> Result<Box<dyn RecordBatchReader + Send + 'a>> {
+        self.conn()?.read_partition(partition)
+    }
+
+    fn set_option(&mut self, key: OptionConnection, value: OptionValue) -> Result<()> {
+        self.conn_mut()?.set_option(key, value)
+    }
+
+    fn get_option_string(&self, key: OptionConnection) -> Result<String> {
+        self.conn()?.get_option_string(key)
+    }
+
+    fn get_option_bytes(&self, key: OptionConnection) -> Result<Vec<u8>> {
+        self.conn()?.get_option_bytes(key)
+    }
+
+    fn get_option_int(&self, key: OptionConnection) -> Result<i64> {
+        self.conn()?.get_option_int(key)
+    }
+
+    fn get_option_double(&self, key: OptionConnection) -> Result<f64> {
+        self.conn()?.get_option_double(key)
+    }
+
+    fn debug_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::AtomicUsize;
+    use std::thread;
+
+    use adbc_core::PartitionedResult;
+    use arrow_array::{RecordBatch, RecordBatchIterator};
+
+    /// A connection whose statements fail once `alive` is cleared, like a
+    /// session the warehouse dropped.
+    struct MockConnection {
+        id: usize,
+        alive: Arc<AtomicBool>,
+    }
+
+    struct MockStatement {
+        alive: Arc<AtomicBool>,
+    }
+
+    impl MockStatement {
+        fn check_alive(&self) -> Result<()> {
+            if self.alive.load(Ordering::SeqCst) {
+                Ok(())
+            } else {
+                Err(Error::with_message_and_status("broken pipe", Status::IO))
+            }
+        }
+    }
+
+    impl Statement for MockStatement {
+        fn bind(&mut self, _batch: RecordBatch) -> Result<()> {
+            unimplemented!()
+        }
+
+        fn bind_stream(&mut self, _reader: Box<dyn RecordBatchReader + Send>) -> Result<()> {
+            unimplemented!()
+        }
+
+        fn execute<'a>(&'a mut self) -> Result<Box<dyn RecordBatchReader + Send + 'a>> {
+            self.check_alive()?;
+            let reader = RecordBatchIterator::new(vec![], Arc::new(Schema::empty()));
+            Ok(Box::new(reader))
+        }
+
+        fn execute_update(&mut self) -> Result<Option<i64>> {
+            self.check_alive()?;
+            Ok(None)
+        }
+
+        fn execute_schema(&mut self) -> Result<Schema> {
+            unimplemented!()
+        }
+
+        fn execute_partitions(&mut self) -> Result<PartitionedResult> {
+            unimplemented!()
+        }
+
+        fn get_parameter_schema(&self) -> Result<Schema> {
+            unimplemented!()
+        }
+
+        fn prepare(&mut self) -> Result<()> {
+            unimplemented!()
+        }
+
+        fn set_sql_query(&mut self, _ctx: &QueryCtx, _sql: &str) -> Result<()> {
+            Ok(())
+        }
+
+        fn set_substrait_plan(&mut self, _plan: &[u8]) -> Result<()> {
+            unimplemented!()
+        }
+
+        fn cancel(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Connection for MockConnection {
+        fn new_statement(&mut self) -> Result<Box<dyn Statement>> {
+            let alive = self.alive.clone();
+            Ok(Box::new(MockStatement { alive }))
+        }
+
+        fn update_node_id(&mut self, _node_id: Option<String>) {}
+
+        fn cancel(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        fn commit(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        fn rollback(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        fn get_option_int(&self, _key: OptionConnection) -> Result<i64> {
+            Ok(self.id as i64)
+        }
+    }
+
+    /// A pool of mock connections, along with the liveness flags of all the
+    /// connections it opened.
+    fn mock_pool(config: PoolConfig) -> (Pool, Arc<Mutex<Vec<Arc<AtomicBool>>>>) {
+        let opened = Arc::new(Mutex::new(Vec::<Arc<AtomicBool>>::new()));
+        let next_id = AtomicUsize::new(0);
+        let connect_f: ConnectF = {
+            let opened = opened.clone();
+            Box::new(move || {
+                let alive = Arc::new(AtomicBool::new(true));
+                opened.lock().push(alive.clone());
+                let id = next_id.fetch_add(1, Ordering::SeqCst);
+                Ok(Box::new(MockConnection { id, alive }) as Box<dyn Connection>)
+            })
+        };
+        (Pool::new(config, connect_f), opened)
+    }
+
+    fn id_of(conn: &PooledConnection) -> i64 {
+        conn.get_option_int(OptionConnection::AutoCommit).unwrap()
+    }
+
+    fn execute(conn: &mut dyn Connection) -> Result<()> {
+        let mut stmt = conn.new_statement()?;
+        stmt.execute_update().map(|_| ())
+    }
+
+    #[test]
+    fn test_connections_are_reused() {
+        let (pool, _) = mock_pool(PoolConfig::default());
+        let first = pool.checkout().unwrap();
+        let second = pool.checkout().unwrap();
+        assert_ne!(id_of(&first), id_of(&second));
+        let first_id = id_of(&first);
+        drop(second);
+        drop(first);
+
+        let conn = pool.checkout().unwrap();
+        assert_eq!(id_of(&conn), first_id);
+        let stats = pool.stats();
+        assert_eq!(stats.checkouts, 3);
+        assert_eq!(stats.connects, 2);
+        assert_eq!(stats.open, 2);
+        assert_eq!(stats.idle, 1);
+    }
+
+    #[test]
+    fn test_dead_idle_connections_fail_validation() {
+        let (pool, opened) = mock_pool(PoolConfig::default());
+        drop(pool.checkout().unwrap());
+        // The warehouse drops the session while the connection is idle
+        opened.lock()[0].store(false, Ordering::SeqCst);
+
+        let mut conn = pool.checkout().unwrap();
+        assert_eq!(id_of(&conn), 1);
+        assert!(execute(&mut conn).is_ok());
+        let stats = pool.stats();
+        assert_eq!(stats.validation_failures, 1);
+        assert_eq!(stats.open, 1);
+    }
+
+    #[test]
+    fn test_invalidated_connections_reconnect() {
+        let (pool, opened) = mock_pool(PoolConfig::default());
+        let mut conn = pool.checkout().unwrap();
+        opened.lock()[0].store(false, Ordering::SeqCst);
+        assert!(execute(&mut conn).is_err());
+
+        conn.invalidate();
+        assert!(execute(&mut conn).is_ok());
+        assert_eq!(id_of(&conn), 1);
+        drop(conn);
+        let stats = pool.stats();
+        assert_eq!(stats.reconnects, 1);
+        assert_eq!(stats.open, 1);
+        assert_eq!(stats.idle, 1);
+
+        let (pool, opened) = mock_pool(PoolConfig {
+            reconnect: false,
+            ..PoolConfig::default()
+        });
+        let mut conn = pool.checkout().unwrap();
+        opened.lock()[0].store(false, Ordering::SeqCst);
+        conn.invalidate();
+        assert!(execute(&mut conn).is_err());
+        drop(conn);
+        // Invalidated connections never go back to the pool
+        assert_eq!(pool.stats().open, 0);
+    }
+
+    #[test]
+    fn test_reconnect_through_connection_trait() {
+        let (pool, opened) = mock_pool(PoolConfig {
+            reconnect: false,
+            ..PoolConfig::default()
+        });
+        let mut conn = pool.checkout().unwrap();
+        opened.lock()[0].store(false, Ordering::SeqCst);
+        conn.invalidate();
+        let dyn_conn: &mut dyn Connection = &mut conn;
+        assert!(dyn_conn.reconnect().is_ok());
+        assert!(execute(&mut conn).is_ok());
+        drop(conn);
+        let stats = pool.stats();
+        assert_eq!(stats.reconnects, 1);
+        assert_eq!(stats.idle, 1);
+    }
+
+    #[test]
+    fn test_idle_connections_are_reaped_down_to_min_size() {
+        let (pool, _) = mock_pool(PoolConfig {
+            min_size: 1,
+            idle_timeout: Some(Duration::ZERO),
+            ..PoolConfig::default()
+        });
+        let conns = (0..3).map(|_| pool.checkout().unwrap()).collect::<Vec<_>>();
+        drop(conns);
+        pool.reap();
+        let stats = pool.stats();
+        assert_eq!(stats.open, 1);
+        assert_eq!(stats.idle, 1);
+        assert_eq!(stats.expired, 2);
+    }
+
+    #[test]
+    fn test_first_checkout_opens_min_size() {
+        let (pool, _) = mock_pool(PoolConfig {
+            min_size: 3,
+            ..PoolConfig::default()
+        });
+        let conn = pool.checkout().unwrap();
+        let stats = pool.stats();
+        assert_eq!(stats.connects, 3);
+        assert_eq!(stats.open, 3);
+        assert_eq!(stats.idle, 2);
+        drop(conn);
+        drop(pool.checkout().unwrap());
+        assert_eq!(pool.stats().connects, 3);
+    }
+
+    #[test]
+    fn test_connections_past_max_lifetime_are_closed() {
+        let (pool, _) = mock_pool(PoolConfig {
+            max_lifetime: Some(Duration::ZERO),
+            ..PoolConfig::default()
+        });
+        drop(pool.checkout().unwrap());
+        let stats = pool.stats();
+        assert_eq!(stats.open, 0);
+        assert_eq!(stats.expired, 1);
+    }
+
+    #[test]
+    fn test_checkout_waits_for_max_size() {
+        let (pool, _) = mock_pool(PoolConfig {
+            max_size: 1,
+            checkout_timeout: Duration::from_millis(200),
+            ..PoolConfig::default()
+        });
+        let conn = pool.checkout().unwrap();
+        let err = pool.checkout().unwrap_err();
+        assert_eq!(err.status, Status::Timeout);
+
+        let checkout = {
+            let pool = pool.clone();
+            thread::spawn(move || pool.checkout().map(|conn| id_of(&conn)))
+        };
+        thread::sleep(Duration::from_millis(20));
+        drop(conn);
+        assert_eq!(checkout.join().unwrap().unwrap(), 0);
+        assert!(pool.stats().waits >= 1);
+        assert_eq!(pool.stats().connects, 1);
+    }
+}
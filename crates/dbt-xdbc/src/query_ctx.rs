@@ -46,6 +46,8 @@ pub struct QueryCtx {
     created_at: DateTime<Utc>,
     // Description (abribrary string) associated with the query
     desc: Option<String>,
+    // Whether the query reads the catalog of the warehouse rather than user data
+    introspection: bool,
 }
 
 impl Default for QueryCtx {
@@ -65,6 +67,7 @@ impl QueryCtx {
             phase,
             created_at: Utc::now(),
             desc,
+            introspection: false,
         }
     }
 
@@ -73,7 +76,10 @@ impl QueryCtx {
     pub fn with_node_id(self, node_unique_id: impl Into<String>) -> Self {
         // We never allow unique id to be reassigned
         assert!(self.node_unique_id.is_none());
-        Self::create(Some(node_unique_id.into()), self.phase, self.desc)
+        Self {
+            introspection: self.introspection,
+            ..Self::create(Some(node_unique_id.into()), self.phase, self.desc)
+        }
     }
 
     /// Create a new context by keeping other fields same and using
@@ -93,7 +99,20 @@ impl QueryCtx {
     /// Creates a new context by keeping other fields same and setting
     /// the given execution phase.
     pub fn with_phase(self, phase: ExecutionPhase) -> Self {
-        Self::create(self.node_unique_id, Some(phase), self.desc)
+        Self {
+            introspection: self.introspection,
+            ..Self::create(self.node_unique_id, Some(phase), self.desc)
+        }
+    }
+
+    /// Marks the query as an adapter call reading the catalog of the
+    /// warehouse (relations, columns, schemas) rather than user data.
+    /// Recordings keep the results of such queries when they are redacted.
+    pub fn with_introspection(self) -> Self {
+        Self {
+            introspection: true,
+            ..self
+        }
     }
 
     /// Return unique node id associated with this context
@@ -121,6 +140,11 @@ impl QueryCtx {
     pub fn phase(&self) -> Option<ExecutionPhase> {
         self.phase
     }
+
+    /// Returns whether the query reads the catalog of the warehouse
+    pub fn is_introspection(&self) -> bool {
+        self.introspection
+    }
 }
 
 #[cfg(test)]
@@ -133,6 +157,16 @@ mod tests {
         assert_eq!(query_ctx.desc().unwrap(), "this is a really good query");
     }
 
+    #[test]
+    fn test_introspection() {
+        assert!(!QueryCtx::default().is_introspection());
+        let query_ctx = QueryCtx::default()
+            .with_introspection()
+            .with_node_id("model.a")
+            .with_phase(ExecutionPhase::Run);
+        assert!(query_ctx.is_introspection());
+    }
+
     #[test]
     #[should_panic]
     fn test_desc_twice() {
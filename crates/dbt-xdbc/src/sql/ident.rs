@@ -124,7 +124,7 @@ pub const fn quote_char(backend: Backend) -> char {
     match backend {
         BigQuery | Databricks | DatabricksODBC => '`',
         Snowflake => '"',
-        Redshift | RedshiftODBC | Postgres | Salesforce | DuckDB => '"',
+        Redshift | RedshiftODBC | Postgres | Salesforce | DuckDB | Trino => '"',
         Generic { .. } => '"',
     }
 }
@@ -134,7 +134,7 @@ pub const fn canonical_quote(backend: Backend) -> QuotingStyle {
     use Backend::*;
     match backend {
         BigQuery | Databricks | DatabricksODBC => QuotingStyle::Backtick,
-        Snowflake | Redshift | RedshiftODBC | Postgres | Salesforce | DuckDB => {
+        Snowflake | Redshift | RedshiftODBC | Postgres | Salesforce | DuckDB | Trino => {
             QuotingStyle::Double
         }
         Generic { .. } => QuotingStyle::Double,
@@ -161,6 +161,7 @@ pub fn is_valid_ident_char(c: char, backend: Backend) -> bool {
         | RedshiftODBC
         | Salesforce
         | DuckDB
+        | Trino
         | Generic { .. } => c.is_alphanumeric() || c == '_',
     }
 }
@@ -11,6 +11,7 @@ pub fn sorted_keywords_for(backend: Backend) -> &'static [&'static str] {
         Snowflake => SNOWFLAKE_RESERVED_KEYWORDS,
         BigQuery => BIGQUERY_RESERVED_KEYWORDS,
         Redshift | RedshiftODBC => REDSHIFT_RESERVED_KEYWORDS,
+        Trino => TRINO_RESERVED_KEYWORDS,
         // TODO: fill in other dialects' keywords and define a default fallback
         Databricks | DatabricksODBC | Postgres | Salesforce | DuckDB | Generic { .. } => &[],
     }
@@ -205,7 +206,6 @@ static SNOWFLAKE_RESERVED_KEYWORDS: &[&str] = &[
     "WITH",
 ];
 
-#[allow(dead_code)]
 static TRINO_RESERVED_KEYWORDS: &[&str] = &[
     "ALTER",
     "AND",
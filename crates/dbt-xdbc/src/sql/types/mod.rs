@@ -202,6 +202,8 @@ pub fn default_time_unit(backend: Backend) -> TimeUnit {
         Snowflake | Databricks | DatabricksODBC => Nanosecond,
         BigQuery | Redshift | RedshiftODBC => Microsecond,
         Postgres | Salesforce | DuckDB => Microsecond,
+        // https://trino.io/docs/current/language/types.html#timestamp
+        Trino => Millisecond,
         Generic { .. } => Microsecond, // a reasonable default
     }
 }
@@ -552,6 +554,29 @@ impl SqlType {
             }
             // }}}
 
+            // Trino {{{
+            (Trino, Integer) => write!(out, "INTEGER"),
+            // Trino has no FLOAT type, only REAL and DOUBLE
+            (Trino, Float(_) | Double) => write!(out, "DOUBLE"),
+            (Trino, Numeric(None) | BigNumeric(None)) => write!(out, "DECIMAL"),
+            (Trino, Numeric(Some((p, None))) | BigNumeric(Some((p, None)))) => {
+                write!(out, "DECIMAL({p})")
+            }
+            (Trino, Numeric(Some((p, Some(s)))) | BigNumeric(Some((p, Some(s))))) => {
+                write!(out, "DECIMAL({p}, {s})")
+            }
+            (Trino, Text | Clob) => write!(out, "VARCHAR"),
+            (Trino, Binary(_) | Blob) => write!(out, "VARBINARY"),
+            (Trino, DateTime) => write!(out, "TIMESTAMP"),
+            (Trino, Map(Some((key, value)))) => {
+                write!(out, "MAP(")?;
+                key.write(backend, out)?;
+                write!(out, ", ")?;
+                value.write(backend, out)?;
+                write!(out, ")")
+            }
+            // }}}
+
             // Generic SQL / Fallback logic {{{
             (_, Boolean) => write!(out, "BOOLEAN"),
             (_, TinyInt) => write!(out, "TINYINT"),
@@ -664,12 +689,12 @@ impl SqlType {
             (_, Array(None)) => write!(out, "ARRAY"),
             (backend, Array(Some(inner))) => {
                 match backend {
-                    Snowflake => write!(out, "ARRAY(")?,
+                    Snowflake | Trino => write!(out, "ARRAY(")?,
                     _ => write!(out, "ARRAY<")?,
                 }
                 inner.write(backend, out)?;
                 match backend {
-                    Snowflake => write!(out, ")"),
+                    Snowflake | Trino => write!(out, ")"),
                     _ => write!(out, ">"),
                 }
             }
@@ -681,6 +706,7 @@ impl SqlType {
                     Postgres | Salesforce | DuckDB => write!(out, "(")?,
                     // Redshift doesn't support object/struct types
                     Redshift | RedshiftODBC => write!(out, "(")?,
+                    Trino => write!(out, "ROW(")?,
                     Generic { .. } => write!(out, "STRUCT<")?,
                 }
                 for (i, field) in fields.iter().enumerate() {
@@ -718,6 +744,7 @@ impl SqlType {
                     BigQuery | Databricks | DatabricksODBC => write!(out, ">"),
                     Postgres | Salesforce | DuckDB => write!(out, ")"),
                     Redshift | RedshiftODBC => write!(out, ")"),
+                    Trino => write!(out, ")"),
                     Generic { .. } => write!(out, ">"),
                 }
             }
@@ -1101,6 +1128,14 @@ impl SqlType {
             }
             // }}}
 
+            // Trino {{{
+            (Trino, Numeric(None) | BigNumeric(None)) => {
+                // DECIMAL without precision and scale is DECIMAL(38, 0)
+                // https://trino.io/docs/current/language/types.html#decimal
+                DataType::Decimal128(38, 0)
+            }
+            // }}}
+
             // PostgreSQL {{{
             (Postgres | Salesforce | Generic { .. }, Numeric(None) | BigNumeric(None)) => {
                 // PostgreSQL's NUMERIC type is truly arbitrary (precision can go to a 1000!). When
@@ -1166,6 +1201,9 @@ impl SqlType {
                     // TIME's default precision on PostgreSQL is 6 (microseconds)
                     // https://www.postgresql.org/docs/current/datatype-datetime.html
                     (Postgres | Salesforce | DuckDB, None) => TimeUnit::Microsecond,
+                    // TIME's default precision on Trino is 3 (milliseconds)
+                    // https://trino.io/docs/current/language/types.html#time
+                    (Trino, None) => TimeUnit::Millisecond,
                     (Generic { .. }, None) => {
                         // we pick microseconds as a reasonable default
                         TimeUnit::Microsecond
@@ -1299,6 +1337,14 @@ impl SqlType {
                             )
                     }
                     BigQuery | Postgres | DuckDB => MonthDayNano, // MonthDayNano is exactly what BQ and PG use internally
+                    // Trino only has INTERVAL YEAR TO MONTH and INTERVAL DAY TO SECOND (in millis)
+                    // https://trino.io/docs/current/language/types.html#interval-year-to-month
+                    Trino => fields
+                        .map(|range| match range {
+                            (Year | Month, _) => YearMonth,
+                            _ => DayTime,
+                        })
+                        .unwrap_or(MonthDayNano),
                     Salesforce => MonthDayNano, // Salesforce seems to follow PostgreSQL
                     Generic { .. } => MonthDayNano, // Reasonable default
                 };
@@ -1425,6 +1471,7 @@ const BIGQUERY_KEYS: [&str; 4] = ["BIGQUERY:type", "type_text", "Type", "type"];
 const DATABRICKS_KEYS: [&str; 2] = ["DBX:type", "type_text"];
 const REDSHIFT_KEYS: [&str; 2] = ["REDSHIFT:type", "type_text"];
 const DUCKDB_KEYS: [&str; 2] = ["DUCKDB:type", "type_text"];
+const TRINO_KEYS: [&str; 2] = ["TRINO:type", "type_text"];
 const GENERIC_KEYS: [&str; 2] = ["SQL:type", "type_text"];
 
 fn metadata_type_candidate_keys(backend: Backend) -> &'static [&'static str] {
@@ -1436,6 +1483,7 @@ fn metadata_type_candidate_keys(backend: Backend) -> &'static [&'static str] {
         Backend::Redshift | Backend::RedshiftODBC => &REDSHIFT_KEYS,
         Backend::DatabricksODBC => &DATABRICKS_KEYS,
         Backend::DuckDB => &DUCKDB_KEYS,
+        Backend::Trino => &TRINO_KEYS,
         Backend::Generic { .. } => &GENERIC_KEYS,
     }
 }
@@ -2183,7 +2231,7 @@ impl<'source> Parser<'source> {
                     SqlType::Geography(srid.map(str::to_string))
                 } else if eqi(w, "ARRAY") {
                     let (left, right) = match backend {
-                        Snowflake => (Token::LParen, Token::RParen),
+                        Snowflake | Trino => (Token::LParen, Token::RParen),
                         _ => (Token::LAngle, Token::RAngle),
                     };
                     if self.match_(left) {
@@ -2209,12 +2257,25 @@ impl<'source> Parser<'source> {
                         None
                     };
                     SqlType::Struct(inner_fields)
+                } else if eqi(w, "ROW") && backend == Trino {
+                    // Trino's struct type: ROW(name type, ...)
+                    let inner_fields = if self.match_(Token::LParen) {
+                        let fields = self.struct_fields(backend, Token::RParen)?;
+                        Some(fields)
+                    } else {
+                        None
+                    };
+                    SqlType::Struct(inner_fields)
                 } else if eqi(w, "MAP") {
-                    let kv = if self.match_(Token::LAngle) {
+                    let (left, right) = match backend {
+                        Trino => (Token::LParen, Token::RParen),
+                        _ => (Token::LAngle, Token::RAngle),
+                    };
+                    let kv = if self.match_(left) {
                         let key_type = self.parse_unconstrained_type(backend)?;
                         self.expect(Token::Comma)?;
                         let value_type = self.parse_unconstrained_type(backend)?;
-                        self.expect(Token::RAngle)?;
+                        self.expect(right)?;
                         Some((Box::new(key_type), Box::new(value_type)))
                     } else {
                         None
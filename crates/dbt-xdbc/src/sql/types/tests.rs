@@ -1080,6 +1080,7 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
                 Redshift | RedshiftODBC => rs,
                 Databricks | DatabricksODBC => dbx,
                 DuckDB => todo!("DuckDB tests not implemented yet"),
+                Trino => todo!("Trino tests not implemented yet"),
                 Generic { .. } => generic,
             };
             (line, t, s)
@@ -1181,3 +1182,39 @@ fn test_struct_on_databricks() {
         assert_eq!(fields[2].name(), "active");
     }
 }
+
+#[test]
+fn test_types_on_trino() {
+    let s = "ROW(name VARCHAR, tags ARRAY(VARCHAR), attrs MAP(VARCHAR, DOUBLE))";
+    let t = Struct(Some(vec![
+        StructField::new(Ident::new("name", Trino), SqlType::varchar(None), true),
+        StructField::new(
+            Ident::new("tags", Trino),
+            Array(Some(Box::new(SqlType::varchar(None)))),
+            true,
+        ),
+        StructField::new(
+            Ident::new("attrs", Trino),
+            Map(Some((Box::new(SqlType::varchar(None)), Box::new(Double)))),
+            true,
+        ),
+    ]));
+    assert_roundtrip(line!(), &t, s, Trino);
+
+    assert_roundtrip(line!(), &Text, "VARCHAR", Trino);
+    assert_roundtrip(
+        line!(),
+        &Numeric(Some((10, Some(2)))),
+        "DECIMAL(10, 2)",
+        Trino,
+    );
+    assert_parses_to(line!(), "varbinary", &Binary(None), Trino);
+
+    let (t, _) = SqlType::parse(Trino, "timestamp(6) with time zone").unwrap();
+    assert_eq!(t.to_string(Trino), "TIMESTAMP(6) WITH TIME ZONE");
+    let (t, _) = SqlType::parse(Trino, "timestamp").unwrap();
+    assert_eq!(
+        t.pick_best_arrow_type(Trino),
+        DataType::Timestamp(TimeUnit::Millisecond, None)
+    );
+}
@@ -0,0 +1,37 @@
+// Names of Database options for the Trino ADBC Driver
+//
+// The driver takes the coordinator location and most settings as a DSN in the
+// `uri` option, with the same syntax as the Trino Go client:
+//
+//     http[s]://user[:password]@host[:port][?parameters]
+//
+// https://github.com/trinodb/trino-go-client#dsn-data-source-name
+
+// DSN query parameters {{{
+
+/// The catalog used by unqualified table names
+pub const CATALOG: &str = "catalog";
+/// The schema used by unqualified table names
+pub const SCHEMA: &str = "schema";
+/// Name reported to the coordinator as the source of the queries
+pub const SOURCE: &str = "source";
+/// Session properties, formatted as `key:value` pairs separated by `;`
+pub const SESSION_PROPERTIES: &str = "session_properties";
+/// Catalog roles, formatted as `catalog:role` pairs separated by `;`
+pub const ROLES: &str = "roles";
+/// JWT sent as a bearer token on every request
+pub const ACCESS_TOKEN: &str = "accessToken";
+/// Path of a PEM file with the certificate authorities trusted for TLS
+pub const SSL_CERT_PATH: &str = "SSLCertPath";
+
+// }}}
+
+/// Path of the PEM client certificate used for mutual TLS authentication
+pub const CLIENT_CERTIFICATE: &str = "adbc.trino.tls.client_certificate";
+/// Path of the PEM private key of [CLIENT_CERTIFICATE]
+pub const CLIENT_PRIVATE_KEY: &str = "adbc.trino.tls.client_private_key";
+/// Set to `false` to skip the verification of the server certificate
+pub const TLS_VERIFY: &str = "adbc.trino.tls.verify";
+
+/// The value of [SOURCE] sent by dbt
+pub const DEFAULT_SOURCE: &str = "dbt-trino";
@@ -166,6 +166,15 @@ mod tests {
                 builder.with_named_option("path", database_path)?;
                 Ok(builder)
             }
+            Backend::Trino => {
+                // The container of `dbt-test-containers-start-db --db trino`
+                let uri = env::var("ADBC_TRINO_URI").unwrap_or_else(|_| {
+                    "http://test@localhost:8099?catalog=memory&schema=default".to_owned()
+                });
+                let mut builder = database::Builder::new(backend);
+                builder.with_parse_uri(uri)?;
+                Ok(builder)
+            }
             Backend::Generic { .. } => unimplemented!("generic backend database builder in tests"),
         }?;
         if backend == Backend::Snowflake {
@@ -268,6 +277,7 @@ mod tests {
                 | Backend::Redshift
                 | Backend::Databricks
                 | Backend::DuckDB
+                | Backend::Trino
                 | Backend::DatabricksODBC
                 | Backend::RedshiftODBC => {
                     assert_eq!(batch.column(0).as_primitive::<Int32Type>().value(0), 42);
@@ -317,6 +327,12 @@ mod tests {
         execute_statement(Backend::DuckDB)
     }
 
+    #[test_with::env(ADBC_TRINO_TESTS)]
+    #[test]
+    fn statement_execute_trino() -> Result<()> {
+        execute_statement(Backend::Trino)
+    }
+
     #[cfg(feature = "odbc")]
     #[test_with::env(DATABRICKS_TOKEN)]
     #[test]
@@ -63,6 +63,105 @@ impl ::prost::Name for QueryExecuted {
         "/v1.public.events.fusion.query.QueryExecuted".into()
     }
 }
+/// Emitted when a statement that failed with a transient error is about to be retried.
+#[cfg_attr(any(test, feature = "test-utils"), derive(::fake::Dummy))]
+#[derive(crate::macros::ProtoNew)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct QueryRetried {
+    /// Blake3, 16-bytes hash of the raw SQL string encoded as hex.
+    #[prost(string, tag = "1")]
+    pub sql_hash: ::prost::alloc::string::String,
+    /// Type of the adapter (e.g. "snowflake", "bigquery", "postgres", etc.)
+    #[prost(string, tag = "2")]
+    pub adapter_type: ::prost::alloc::string::String,
+    /// unique_id of the node that generated this query. Some queries may not be associated with a single node.
+    #[prost(string, optional, tag = "3")]
+    pub unique_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Number of this retry, starting at 1.
+    #[prost(uint32, tag = "4")]
+    pub retry: u32,
+    /// Maximum number of retries allowed for the statement.
+    #[prost(uint32, tag = "5")]
+    pub max_retries: u32,
+    /// Wait before the retry, in milliseconds.
+    #[prost(uint32, tag = "6")]
+    pub backoff_ms: u32,
+    /// Execution phase during which this query was evaluated, if available
+    #[prost(enumeration = "super::phase::ExecutionPhase", optional, tag = "7")]
+    #[cfg_attr(
+        any(test, feature = "test-utils"),
+        dummy(
+            expr = "Some(::fake::Fake::fake::<super::phase::ExecutionPhase>(&::fake::Faker) as i32)"
+        )
+    )]
+    pub phase: ::core::option::Option<i32>,
+    /// Arbitrary adapter defined error message of the failed attempt
+    #[prost(string, optional, tag = "8")]
+    pub query_error_adapter_message: ::core::option::Option<
+        ::prost::alloc::string::String,
+    >,
+    /// Arbitrary adapter (vendor) defined error code of the failed attempt
+    #[prost(int32, optional, tag = "9")]
+    pub query_error_vendor_code: ::core::option::Option<i32>,
+}
+impl crate::StaticName for QueryRetried {
+    const FULL_NAME: &'static str = "v1.public.events.fusion.query.QueryRetried";
+    const TYPE_URL: &'static str = "/v1.public.events.fusion.query.QueryRetried";
+}
+impl ::prost::Name for QueryRetried {
+    const NAME: &'static str = "QueryRetried";
+    const PACKAGE: &'static str = "v1.public.events.fusion.query";
+    fn full_name() -> ::prost::alloc::string::String {
+        "v1.public.events.fusion.query.QueryRetried".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/v1.public.events.fusion.query.QueryRetried".into()
+    }
+}
+/// Emitted when the connection pool of a target is closed, with the activity of the pool since it was opened.
+#[cfg_attr(any(test, feature = "test-utils"), derive(::fake::Dummy))]
+#[derive(crate::macros::ProtoNew)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ConnectionPoolStats {
+    /// Type of the adapter (e.g. "snowflake", "bigquery", "postgres", etc.)
+    #[prost(string, tag = "1")]
+    pub adapter_type: ::prost::alloc::string::String,
+    /// Connections handed out by the pool.
+    #[prost(uint64, tag = "2")]
+    pub checkouts: u64,
+    /// Checkouts that had to wait for a connection to go back to the pool.
+    #[prost(uint64, tag = "3")]
+    pub waits: u64,
+    /// Total time spent waiting in those checkouts, in milliseconds.
+    #[prost(uint64, tag = "4")]
+    pub wait_time_ms: u64,
+    /// Connections opened, including reconnects.
+    #[prost(uint64, tag = "5")]
+    pub connects: u64,
+    /// Connections whose session was lost and that were replaced by a new one.
+    #[prost(uint64, tag = "6")]
+    pub reconnects: u64,
+    /// Idle connections that failed the validation query.
+    #[prost(uint64, tag = "7")]
+    pub validation_failures: u64,
+    /// Connections closed for being idle or old for too long.
+    #[prost(uint64, tag = "8")]
+    pub expired: u64,
+}
+impl crate::StaticName for ConnectionPoolStats {
+    const FULL_NAME: &'static str = "v1.public.events.fusion.query.ConnectionPoolStats";
+    const TYPE_URL: &'static str = "/v1.public.events.fusion.query.ConnectionPoolStats";
+}
+impl ::prost::Name for ConnectionPoolStats {
+    const NAME: &'static str = "ConnectionPoolStats";
+    const PACKAGE: &'static str = "v1.public.events.fusion.query";
+    fn full_name() -> ::prost::alloc::string::String {
+        "v1.public.events.fusion.query.ConnectionPoolStats".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/v1.public.events.fusion.query.ConnectionPoolStats".into()
+    }
+}
 /// Outcome of a query execution
 #[cfg_attr(any(test, feature = "test-utils"), derive(::fake::Dummy))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
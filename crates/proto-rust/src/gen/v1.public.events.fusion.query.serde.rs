@@ -1,3 +1,248 @@
+impl serde::Serialize for ConnectionPoolStats {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.adapter_type.is_empty() {
+            len += 1;
+        }
+        if self.checkouts != 0 {
+            len += 1;
+        }
+        if self.waits != 0 {
+            len += 1;
+        }
+        if self.wait_time_ms != 0 {
+            len += 1;
+        }
+        if self.connects != 0 {
+            len += 1;
+        }
+        if self.reconnects != 0 {
+            len += 1;
+        }
+        if self.validation_failures != 0 {
+            len += 1;
+        }
+        if self.expired != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("v1.public.events.fusion.query.ConnectionPoolStats", len)?;
+        if !self.adapter_type.is_empty() {
+            struct_ser.serialize_field("adapter_type", &self.adapter_type)?;
+        }
+        if self.checkouts != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("checkouts", ToString::to_string(&self.checkouts).as_str())?;
+        }
+        if self.waits != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("waits", ToString::to_string(&self.waits).as_str())?;
+        }
+        if self.wait_time_ms != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("wait_time_ms", ToString::to_string(&self.wait_time_ms).as_str())?;
+        }
+        if self.connects != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("connects", ToString::to_string(&self.connects).as_str())?;
+        }
+        if self.reconnects != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("reconnects", ToString::to_string(&self.reconnects).as_str())?;
+        }
+        if self.validation_failures != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("validation_failures", ToString::to_string(&self.validation_failures).as_str())?;
+        }
+        if self.expired != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("expired", ToString::to_string(&self.expired).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ConnectionPoolStats {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "adapter_type",
+            "adapterType",
+            "checkouts",
+            "waits",
+            "wait_time_ms",
+            "waitTimeMs",
+            "connects",
+            "reconnects",
+            "validation_failures",
+            "validationFailures",
+            "expired",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AdapterType,
+            Checkouts,
+            Waits,
+            WaitTimeMs,
+            Connects,
+            Reconnects,
+            ValidationFailures,
+            Expired,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "adapterType" | "adapter_type" => Ok(GeneratedField::AdapterType),
+                            "checkouts" => Ok(GeneratedField::Checkouts),
+                            "waits" => Ok(GeneratedField::Waits),
+                            "waitTimeMs" | "wait_time_ms" => Ok(GeneratedField::WaitTimeMs),
+                            "connects" => Ok(GeneratedField::Connects),
+                            "reconnects" => Ok(GeneratedField::Reconnects),
+                            "validationFailures" | "validation_failures" => Ok(GeneratedField::ValidationFailures),
+                            "expired" => Ok(GeneratedField::Expired),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ConnectionPoolStats;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct v1.public.events.fusion.query.ConnectionPoolStats")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConnectionPoolStats, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut adapter_type__ = None;
+                let mut checkouts__ = None;
+                let mut waits__ = None;
+                let mut wait_time_ms__ = None;
+                let mut connects__ = None;
+                let mut reconnects__ = None;
+                let mut validation_failures__ = None;
+                let mut expired__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AdapterType => {
+                            if adapter_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("adapterType"));
+                            }
+                            adapter_type__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Checkouts => {
+                            if checkouts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("checkouts"));
+                            }
+                            checkouts__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Waits => {
+                            if waits__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("waits"));
+                            }
+                            waits__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::WaitTimeMs => {
+                            if wait_time_ms__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("waitTimeMs"));
+                            }
+                            wait_time_ms__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Connects => {
+                            if connects__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("connects"));
+                            }
+                            connects__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Reconnects => {
+                            if reconnects__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reconnects"));
+                            }
+                            reconnects__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ValidationFailures => {
+                            if validation_failures__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validationFailures"));
+                            }
+                            validation_failures__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Expired => {
+                            if expired__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expired"));
+                            }
+                            expired__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ConnectionPoolStats {
+                    adapter_type: adapter_type__.unwrap_or_default(),
+                    checkouts: checkouts__.unwrap_or_default(),
+                    waits: waits__.unwrap_or_default(),
+                    wait_time_ms: wait_time_ms__.unwrap_or_default(),
+                    connects: connects__.unwrap_or_default(),
+                    reconnects: reconnects__.unwrap_or_default(),
+                    validation_failures: validation_failures__.unwrap_or_default(),
+                    expired: expired__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("v1.public.events.fusion.query.ConnectionPoolStats", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for QueryExecuted {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -355,3 +600,251 @@ impl<'de> serde::Deserialize<'de> for QueryOutcome {
         deserializer.deserialize_any(GeneratedVisitor)
     }
 }
+impl serde::Serialize for QueryRetried {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.sql_hash.is_empty() {
+            len += 1;
+        }
+        if !self.adapter_type.is_empty() {
+            len += 1;
+        }
+        if self.unique_id.is_some() {
+            len += 1;
+        }
+        if self.retry != 0 {
+            len += 1;
+        }
+        if self.max_retries != 0 {
+            len += 1;
+        }
+        if self.backoff_ms != 0 {
+            len += 1;
+        }
+        if self.phase.is_some() {
+            len += 1;
+        }
+        if self.query_error_adapter_message.is_some() {
+            len += 1;
+        }
+        if self.query_error_vendor_code.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("v1.public.events.fusion.query.QueryRetried", len)?;
+        if !self.sql_hash.is_empty() {
+            struct_ser.serialize_field("sql_hash", &self.sql_hash)?;
+        }
+        if !self.adapter_type.is_empty() {
+            struct_ser.serialize_field("adapter_type", &self.adapter_type)?;
+        }
+        if let Some(v) = self.unique_id.as_ref() {
+            struct_ser.serialize_field("unique_id", v)?;
+        }
+        if self.retry != 0 {
+            struct_ser.serialize_field("retry", &self.retry)?;
+        }
+        if self.max_retries != 0 {
+            struct_ser.serialize_field("max_retries", &self.max_retries)?;
+        }
+        if self.backoff_ms != 0 {
+            struct_ser.serialize_field("backoff_ms", &self.backoff_ms)?;
+        }
+        if let Some(v) = self.phase.as_ref() {
+            let v = super::phase::ExecutionPhase::try_from(*v)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", *v)))?;
+            struct_ser.serialize_field("phase", &v)?;
+        }
+        if let Some(v) = self.query_error_adapter_message.as_ref() {
+            struct_ser.serialize_field("query_error_adapter_message", v)?;
+        }
+        if let Some(v) = self.query_error_vendor_code.as_ref() {
+            struct_ser.serialize_field("query_error_vendor_code", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueryRetried {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "sql_hash",
+            "sqlHash",
+            "adapter_type",
+            "adapterType",
+            "unique_id",
+            "uniqueId",
+            "retry",
+            "max_retries",
+            "maxRetries",
+            "backoff_ms",
+            "backoffMs",
+            "phase",
+            "query_error_adapter_message",
+            "queryErrorAdapterMessage",
+            "query_error_vendor_code",
+            "queryErrorVendorCode",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SqlHash,
+            AdapterType,
+            UniqueId,
+            Retry,
+            MaxRetries,
+            BackoffMs,
+            Phase,
+            QueryErrorAdapterMessage,
+            QueryErrorVendorCode,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "sqlHash" | "sql_hash" => Ok(GeneratedField::SqlHash),
+                            "adapterType" | "adapter_type" => Ok(GeneratedField::AdapterType),
+                            "uniqueId" | "unique_id" => Ok(GeneratedField::UniqueId),
+                            "retry" => Ok(GeneratedField::Retry),
+                            "maxRetries" | "max_retries" => Ok(GeneratedField::MaxRetries),
+                            "backoffMs" | "backoff_ms" => Ok(GeneratedField::BackoffMs),
+                            "phase" => Ok(GeneratedField::Phase),
+                            "queryErrorAdapterMessage" | "query_error_adapter_message" => Ok(GeneratedField::QueryErrorAdapterMessage),
+                            "queryErrorVendorCode" | "query_error_vendor_code" => Ok(GeneratedField::QueryErrorVendorCode),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueryRetried;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct v1.public.events.fusion.query.QueryRetried")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueryRetried, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut sql_hash__ = None;
+                let mut adapter_type__ = None;
+                let mut unique_id__ = None;
+                let mut retry__ = None;
+                let mut max_retries__ = None;
+                let mut backoff_ms__ = None;
+                let mut phase__ = None;
+                let mut query_error_adapter_message__ = None;
+                let mut query_error_vendor_code__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SqlHash => {
+                            if sql_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sqlHash"));
+                            }
+                            sql_hash__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AdapterType => {
+                            if adapter_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("adapterType"));
+                            }
+                            adapter_type__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::UniqueId => {
+                            if unique_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("uniqueId"));
+                            }
+                            unique_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Retry => {
+                            if retry__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("retry"));
+                            }
+                            retry__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxRetries => {
+                            if max_retries__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxRetries"));
+                            }
+                            max_retries__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BackoffMs => {
+                            if backoff_ms__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("backoffMs"));
+                            }
+                            backoff_ms__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Phase => {
+                            if phase__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("phase"));
+                            }
+                            phase__ = map_.next_value::<::std::option::Option<super::phase::ExecutionPhase>>()?.map(|x| x as i32);
+                        }
+                        GeneratedField::QueryErrorAdapterMessage => {
+                            if query_error_adapter_message__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("queryErrorAdapterMessage"));
+                            }
+                            query_error_adapter_message__ = map_.next_value()?;
+                        }
+                        GeneratedField::QueryErrorVendorCode => {
+                            if query_error_vendor_code__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("queryErrorVendorCode"));
+                            }
+                            query_error_vendor_code__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(QueryRetried {
+                    sql_hash: sql_hash__.unwrap_or_default(),
+                    adapter_type: adapter_type__.unwrap_or_default(),
+                    unique_id: unique_id__,
+                    retry: retry__.unwrap_or_default(),
+                    max_retries: max_retries__.unwrap_or_default(),
+                    backoff_ms: backoff_ms__.unwrap_or_default(),
+                    phase: phase__,
+                    query_error_adapter_message: query_error_adapter_message__,
+                    query_error_vendor_code: query_error_vendor_code__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("v1.public.events.fusion.query.QueryRetried", FIELDS, GeneratedVisitor)
+    }
+}